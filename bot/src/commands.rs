@@ -2,11 +2,16 @@ use crate::error::BotError;
 use crate::state::BotState;
 use poise::Command;
 
+mod achievements;
 mod birthday;
+mod economy;
 mod farm;
 mod game;
+mod leveling;
+mod lottery;
 mod owner;
 mod ping;
+mod rank;
 mod settings;
 mod space;
 mod wallet;
@@ -15,10 +20,15 @@ mod youtube;
 pub fn get_commands() -> Vec<Command<BotState, BotError>> {
     vec![
         ping::ping(),
+        achievements::achievements(),
         game::game(),
         birthday::birthday(),
+        economy::economy(),
         farm::farm(),
+        leveling::leveling(),
+        lottery::lottery(),
         owner::sync_guild(),
+        rank::rank(),
         settings::settings(),
         space::space(),
         wallet::wallet(),
@@ -0,0 +1,72 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::emoji::EmojiType;
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::time::format_time_relative_at;
+use crate::Context;
+use neobabu_core::achievements::Achievement;
+use neobabu_core::types::currency::Currency;
+use poise::serenity_prelude::{CreateEmbed, Member};
+use strum::IntoEnumIterator;
+
+/// View your or another user's achievements.
+#[poise::command(slash_command, guild_only, user_cooldown = "5")]
+pub async fn achievements(ctx: Context<'_>, member: Option<Member>) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let author_member = ctx.author_member().await;
+    let target = match &member {
+        Some(m) => m,
+        None => author_member.as_deref().ok_or(BotError::GuildCommandOnly)?,
+    };
+    if target.user.bot {
+        return Err(BotError::TargetBotOrYourself);
+    }
+
+    let unlocks = ctx
+        .stores()
+        .achievement_unlock
+        .find_by_user_id(target.user.id.to_string())
+        .await?;
+
+    let trophy = ctx.emoji_text(EmojiType::Trophy);
+    let citrine = ctx.emoji_text(Currency::Citrine.into());
+    let mut description = String::new();
+    for achievement in Achievement::iter() {
+        let definition = achievement.definition();
+        let reward = definition
+            .reward
+            .map(|reward| format!(" | **`{reward}`** {citrine}"))
+            .unwrap_or_default();
+        let unlock = unlocks
+            .iter()
+            .find(|unlock| unlock.achievement() == Some(achievement));
+
+        let line = match unlock {
+            Some(unlock) => format!(
+                "{trophy} **{}** {}\n-# {}{reward}\n",
+                definition.name,
+                format_time_relative_at(unlock.unlocked_at.and_utc()),
+                definition.description
+            ),
+            None => format!(
+                "🔒 {}\n-# {}{reward}\n",
+                definition.name, definition.description
+            ),
+        };
+        description.push_str(&line);
+    }
+
+    let embed = CreateEmbed::default()
+        .member_full(&ctx, target)
+        .await
+        .title(format!(
+            "Achievements `{}/{}`",
+            unlocks.len(),
+            Achievement::iter().count()
+        ))
+        .description(description);
+    ctx.send(embed.create_reply()).await?;
+
+    Ok(())
+}
@@ -2,12 +2,20 @@ use crate::error::BotResult;
 use crate::Context;
 
 mod admin;
+mod calendar;
+mod privacy;
 mod set;
 mod upcoming;
 
 #[poise::command(
     slash_command,
-    subcommands("admin::admin", "set::set", "upcoming::upcoming"),
+    subcommands(
+        "admin::admin",
+        "calendar::calendar",
+        "privacy::privacy",
+        "set::set",
+        "upcoming::upcoming"
+    ),
     guild_only
 )]
 pub async fn birthday(_ctx: Context<'_>) -> BotResult<()> {
@@ -4,73 +4,234 @@ use crate::ui::color::UiColor;
 use crate::ui::message::CreateEmbedExt;
 use crate::utils::formatting::format_bool;
 use crate::Context;
+use neobabu_core::birthday::announcement::{
+    self, BirthdayAnnouncement, parse_image_url, parse_template, parse_variants,
+};
 use neobabu_core::stores::{IntoActiveModel, Set};
-use poise::serenity_prelude::{Channel, CreateEmbed};
+use poise::CreateReply;
+use poise::serenity_prelude::{Channel, CreateEmbed, Role};
+
+/// The age shown when previewing announcements.
+const PREVIEW_AGE: u8 = 21;
+const MAX_PREVIEW_LENGTH: usize = 4000;
 
 /// Customize server-wide birthday settings.
 #[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+#[allow(clippy::too_many_arguments)]
 pub async fn admin(
     ctx: Context<'_>,
     #[description = "Whether to enable or disable birthday notifications."] enable: Option<bool>,
     #[description = "The channel to send birthday notifications to."]
     #[channel_types("Text")]
     channel: Option<Channel>,
+    #[description = "The role to grant members for their birthday."] role: Option<Role>,
+    #[description = "Stop granting a birthday role."] clear_role: Option<bool>,
+    #[description = "Announcement variants separated by |, e.g. Happy {belated} birthday {user}!"]
+    template: Option<String>,
+    #[description = "Variants for belated wishes separated by |, the announcement is used if empty."]
+    belated_template: Option<String>,
+    #[description = "An https:// image link to attach to announcements."] image: Option<String>,
+    #[description = "Go back to the default announcement without an image."]
+    reset_announcement: Option<bool>,
+    #[description = "Preview the announcement, changes to it are not saved."] preview: Option<bool>,
 ) -> BotResult<()> {
     ctx.defer_ephemeral().await?;
 
+    let templates = template.as_deref().map(parse_variants).transpose()?;
+    let belated_templates = belated_template
+        .as_deref()
+        .map(parse_variants)
+        .transpose()?;
+    let image_url = image.as_deref().map(parse_image_url).transpose()?;
+
     let guild = ctx.fetch_guild_model().await?;
     let guild_birthday = ctx.stores().guild_birthday.fetch_or_create(&guild).await?;
 
-    let mut updated = false;
+    if preview.unwrap_or(false) {
+        let preview = AnnouncementPreview {
+            templates: templates.unwrap_or_else(|| guild_birthday.announcement_templates()),
+            belated_templates: belated_templates
+                .unwrap_or_else(|| guild_birthday.belated_templates()),
+            image_url: image_url.or(guild_birthday.announcement_image_url),
+        };
+        ctx.send(preview.create_reply(&ctx)?).await?;
+        return Ok(());
+    }
+
+    let clear_role = clear_role.unwrap_or(false);
+    let reset_announcement = reset_announcement.unwrap_or(false);
+    let updated = enable.is_some()
+        || channel.is_some()
+        || role.is_some()
+        || clear_role
+        || templates.is_some()
+        || belated_templates.is_some()
+        || image_url.is_some()
+        || reset_announcement;
+    let current = guild_birthday.clone();
     let mut active = guild_birthday.into_active_model();
+
     if let Some(enable) = enable {
         active.enabled = Set(enable);
-        updated = true;
     }
-
     if let Some(channel) = channel {
         active.notification_channel_id = Set(Some(channel.id().to_string()));
-        updated = true;
+    }
+    if clear_role {
+        active.notification_role_id = Set(None);
+    } else if let Some(role) = role {
+        active.notification_role_id = Set(Some(role.id.to_string()));
+    }
+    if reset_announcement {
+        active.announcement_templates = Set(Vec::<String>::new().into());
+        active.belated_templates = Set(Vec::<String>::new().into());
+        active.announcement_image_url = Set(None);
+    } else {
+        if let Some(templates) = templates {
+            active.announcement_templates = Set(templates.into());
+        }
+        if let Some(belated_templates) = belated_templates {
+            active.belated_templates = Set(belated_templates.into());
+        }
+        if let Some(image_url) = image_url {
+            active.announcement_image_url = Set(Some(image_url));
+        }
     }
 
-    let embed = if updated {
-        let guild_birthday = ctx.stores().guild_birthday.update(active).await?;
+    let guild_birthday = if updated {
+        ctx.stores().guild_birthday.update(active).await?
+    } else {
+        current
+    };
 
-        let channel = if let Some(channel_id) = guild_birthday.notification_channel_id {
-            format!("<#{}>", channel_id)
+    let format_channel = |id: Option<String>| id.map_or("`None`".into(), |id| format!("<#{id}>"));
+    let format_role = |id: Option<String>| id.map_or("`None`".into(), |id| format!("<@&{id}>"));
+    let format_variants = |count: usize| match count {
+        0 => "`Default`".to_string(),
+        1 => "`1` variant".to_string(),
+        count => format!("`{count}` variants"),
+    };
+
+    let embed = CreateEmbed::default()
+        .title(if updated {
+            "Birthday Settings Updated"
         } else {
-            "`None`".to_string()
-        };
+            "Birthday Settings"
+        })
+        .field(
+            "Enabled",
+            format!("`{}`", format_bool(guild_birthday.enabled)),
+            false,
+        )
+        .field(
+            "Channel",
+            format_channel(guild_birthday.notification_channel_id.clone()),
+            false,
+        )
+        .field(
+            "Role",
+            format_role(guild_birthday.notification_role_id.clone()),
+            false,
+        )
+        .field(
+            "Announcement",
+            format_variants(guild_birthday.announcement_templates().len()),
+            true,
+        )
+        .field(
+            "Belated",
+            format_variants(guild_birthday.belated_templates().len()),
+            true,
+        )
+        .field(
+            "Image",
+            guild_birthday
+                .announcement_image_url
+                .clone()
+                .unwrap_or("`None`".to_string()),
+            true,
+        );
 
-        CreateEmbed::default()
-            .success_user(ctx.author())
-            .title("Birthday Settings Updated")
-            .field(
-                "Enabled",
-                format!("`{}`", format_bool(guild_birthday.enabled)),
-                false,
-            )
-            .field("Channel", channel, false)
+    let embed = if updated {
+        embed.success_user(ctx.author())
     } else {
-        let channel = if let Some(channel_id) = active.notification_channel_id.as_ref() {
-            format!("<#{}>", channel_id)
+        embed.ui_color(UiColor::Gray).user(ctx.author())
+    };
+
+    ctx.send(embed.create_reply().ephemeral(true)).await?;
+
+    Ok(())
+}
+
+struct AnnouncementPreview {
+    templates: Vec<String>,
+    belated_templates: Vec<String>,
+    image_url: Option<String>,
+}
+
+impl AnnouncementPreview {
+    fn create_reply(&self, ctx: &Context<'_>) -> BotResult<CreateReply> {
+        let server = ctx
+            .guild()
+            .map(|guild| guild.name.clone())
+            .unwrap_or_default();
+        let announcement = |is_belated| BirthdayAnnouncement {
+            user_id: ctx.author().id.to_string(),
+            age: Some(PREVIEW_AGE),
+            is_belated,
+            server: server.clone(),
+        };
+
+        let regular = Self::render_variants(&self.templates, &announcement(false))?;
+        let belated = if self.belated_templates.is_empty() {
+            Self::render_variants(&self.templates, &announcement(true))?
         } else {
-            "`None`".to_string()
+            Self::render_variants(&self.belated_templates, &announcement(true))?
         };
 
-        CreateEmbed::default()
+        let mut regular_embed = CreateEmbed::default()
+            .ui_color(UiColor::Pink)
+            .title("Announcement Preview")
+            .description(regular);
+        if let Some(image_url) = &self.image_url {
+            regular_embed = regular_embed.image(image_url);
+        }
+        let belated_embed = CreateEmbed::default()
             .ui_color(UiColor::Gray)
-            .user(ctx.author())
-            .title("Birthday Settings")
-            .field(
-                "Enabled",
-                format!("`{}`", format_bool(*active.enabled.as_ref())),
-                false,
-            )
-            .field("Channel", channel, false)
-    };
+            .title("Belated Preview")
+            .description(belated)
+            .footer_text(format!(
+                "Previewed with an age of {PREVIEW_AGE}, variants mentioning the age are skipped if it is unknown."
+            ));
 
-    ctx.send(embed.create_reply().ephemeral(true)).await?;
+        Ok(CreateReply::default()
+            .embed(regular_embed)
+            .embed(belated_embed)
+            .ephemeral(true))
+    }
 
-    Ok(())
+    fn render_variants(
+        templates: &[String],
+        announcement: &BirthdayAnnouncement,
+    ) -> BotResult<String> {
+        if templates.is_empty() {
+            let rendered = announcement.render(&announcement::default_template(true));
+            return Ok(format!("*Default:* {rendered}"));
+        }
+
+        let mut description = String::new();
+        for (index, template) in templates.iter().enumerate() {
+            let line = format!(
+                "**`{}.`** {}\n",
+                index + 1,
+                announcement.render(&parse_template(template)?)
+            );
+            if description.len() + line.len() > MAX_PREVIEW_LENGTH {
+                description.push('…');
+                break;
+            }
+            description.push_str(&line);
+        }
+        Ok(description)
+    }
 }
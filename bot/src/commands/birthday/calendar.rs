@@ -0,0 +1,80 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::color::UiColor;
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use chrono::{Datelike, Utc};
+use neobabu_core::error::CoreError;
+use neobabu_core::types::feature::Feature;
+use poise::serenity_prelude::{CreateAttachment, CreateEmbed};
+
+const MAX_LEGEND_LENGTH: usize = 4000;
+
+/// View the birthdays of this server's members within a month.
+#[poise::command(slash_command, guild_only, user_cooldown = "30")]
+pub async fn calendar(
+    ctx: Context<'_>,
+    #[description = "The month to show, upcoming months are preferred. Defaults to the current one."]
+    #[min = 1]
+    #[max = 12]
+    month: Option<u32>,
+) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let guild = ctx.fetch_guild_model().await?;
+    let guild_birthday = ctx.stores().guild_birthday.fetch_or_create(&guild).await?;
+    if !guild_birthday.enabled {
+        return Err(BotError::Core(CoreError::FeatureNotEnabled(
+            Feature::Birthday,
+        )));
+    }
+
+    let author = ctx.fetch_author_model().await?;
+    let today = Utc::now()
+        .with_timezone(&author.timezone().unwrap_or(chrono_tz::UTC))
+        .date_naive();
+    let month = month.unwrap_or(today.month());
+    let year = if month < today.month() {
+        today.year() + 1
+    } else {
+        today.year()
+    };
+
+    let calendar = ctx
+        .services()
+        .birthday
+        .calendar(&guild.id, year, month)
+        .await?
+        .with_today(today);
+    let png_bytes = calendar.render_png(ctx.o2d())?;
+
+    let mut legend = String::new();
+    for (day, user_ids) in &calendar.birthdays {
+        let users = user_ids
+            .iter()
+            .map(|user_id| format!("<@{user_id}>"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let line = format!("**`{day:>2}`** {users}\n");
+        if legend.len() + line.len() > MAX_LEGEND_LENGTH {
+            legend.push('…');
+            break;
+        }
+        legend.push_str(&line);
+    }
+    if legend.is_empty() {
+        legend = "`No birthdays this month.`".to_string();
+    }
+
+    let attachment = CreateAttachment::bytes(png_bytes, "calendar.png");
+    let embed = CreateEmbed::default()
+        .ui_color(UiColor::Orange)
+        .title(format!("Birthday Calendar | {}", calendar.title()))
+        .description(legend)
+        .image("attachment://calendar.png");
+
+    ctx.send(embed.create_reply().attachment(attachment))
+        .await?;
+
+    Ok(())
+}
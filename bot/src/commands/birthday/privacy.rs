@@ -0,0 +1,114 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::color::UiColor;
+use crate::ui::message::CreateEmbedExt;
+use crate::utils::formatting::format_bool;
+use crate::Context;
+use neobabu_core::error::CoreError;
+use neobabu_core::stores::{IntoActiveModel, Set};
+use poise::serenity_prelude::CreateEmbed;
+
+/// Choose where your birthday is shown and announced.
+#[poise::command(slash_command, guild_only, ephemeral, user_cooldown = "5")]
+pub async fn privacy(
+    ctx: Context<'_>,
+    #[description = "Leave your age out of announcements."] hide_age: Option<bool>,
+    #[description = "Hide yourself from the upcoming birthdays and the calendar."]
+    hide_from_listings: Option<bool>,
+    #[description = "Whether your birthday is announced at all."] announcements: Option<bool>,
+    #[description = "Whether your birthday is announced and listed on this server."]
+    announce_here: Option<bool>,
+) -> BotResult<()> {
+    ctx.defer_ephemeral().await?;
+
+    let user = ctx.fetch_author_model().await?;
+    let guild = ctx.fetch_guild_model().await?;
+    let Some(user_birthday) = ctx.stores().user_birthday.find_by_user_id(&user.id).await? else {
+        return Err(BotError::Core(CoreError::NoBirthdaySet));
+    };
+
+    let updated = hide_age.is_some()
+        || hide_from_listings.is_some()
+        || announcements.is_some()
+        || announce_here.is_some();
+    let current = user_birthday.clone();
+    let mut active = user_birthday.into_active_model();
+
+    if let Some(hide_age) = hide_age {
+        active.hide_age = Set(hide_age);
+    }
+    if let Some(hide_from_listings) = hide_from_listings {
+        active.hide_from_listings = Set(hide_from_listings);
+    }
+    if let Some(announcements) = announcements {
+        active.opt_out_announcements = Set(!announcements);
+    }
+
+    let user_birthday = if updated {
+        ctx.stores().user_birthday.update_settings(active).await?
+    } else {
+        current
+    };
+
+    match announce_here {
+        Some(true) => {
+            ctx.stores()
+                .birthday_opt_out
+                .delete(&user.id, &guild.id)
+                .await?;
+        }
+        Some(false) => {
+            ctx.stores()
+                .birthday_opt_out
+                .insert(&user.id, &guild.id)
+                .await?;
+        }
+        None => {}
+    }
+    let announced_here = !ctx
+        .stores()
+        .birthday_opt_out
+        .exists(&user.id, &guild.id)
+        .await?;
+
+    let mut embed = CreateEmbed::default()
+        .title(if updated {
+            "Birthday Privacy Updated"
+        } else {
+            "Birthday Privacy"
+        })
+        .field(
+            "Hide Age",
+            format!("`{}`", format_bool(user_birthday.hide_age)),
+            true,
+        )
+        .field(
+            "Hide From Listings",
+            format!("`{}`", format_bool(user_birthday.hide_from_listings)),
+            true,
+        )
+        .field(
+            "Announcements",
+            format!("`{}`", format_bool(!user_birthday.opt_out_announcements)),
+            true,
+        )
+        .field(
+            "Announced Here",
+            format!("`{}`", format_bool(announced_here)),
+            true,
+        );
+
+    if user_birthday.opt_out_announcements {
+        embed = embed.footer_text("Announcements are off on every server.");
+    }
+
+    let embed = if updated {
+        embed.success_user(ctx.author())
+    } else {
+        embed.ui_color(UiColor::Gray).user(ctx.author())
+    };
+
+    ctx.send(embed.create_reply().ephemeral(true)).await?;
+
+    Ok(())
+}
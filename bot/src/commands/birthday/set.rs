@@ -1,9 +1,9 @@
 use crate::context::ContextExt;
 use crate::error::BotResult;
 use crate::ui::color::UiColor;
-use crate::ui::message::interactive::state::simple_accept::SimpleAcceptStateTrait;
-use crate::ui::message::interactive::InteractiveMessage;
 use crate::ui::message::CreateEmbedExt;
+use crate::ui::message::interactive::InteractiveMessage;
+use crate::ui::message::interactive::state::simple_accept::SimpleAcceptStateTrait;
 use crate::Context;
 use poise::serenity_prelude::{ComponentInteraction, CreateEmbed};
 
@@ -46,14 +46,14 @@ impl SimpleAcceptStateTrait for BirthdaySet {
         let mut embed = CreateEmbed::default()
             .warning_user(ctx.author())
             .title("Do you want to set your birthday?")
-            .description("Your birthday will be set globally and may be **announced** on servers you have interacted with (where this bot is on).\n\nYou will **not** be able to change it again for a while. If you did not specify your birth year the bot will not announce your age.\n\n**Are you sure you want to proceed?**")
+            .description("Your birthday will be set globally and may be **announced** on servers you have interacted with (where this bot is on).\n\nYou will **not** be able to change it again for a while. If you did not specify your birth year the bot will not announce your age. Use `/birthday privacy` to hide your age or opt out of announcements.\n\n**Are you sure you want to proceed?**")
             .field("Day", self.day.to_string(), true)
             .field("Month", self.month.to_string(), true);
 
         if let Some(year) = self.year {
             embed = embed.field("Year", year.to_string(), true);
             embed = embed.footer_text(
-                "Since you specified your birth year, people will be able to know your age.",
+                "Since you specified your birth year, people will be able to know your age unless you hide it.",
             )
         };
 
@@ -0,0 +1,70 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use neobabu_core::database::entity::{economy_audit, user};
+use neobabu_core::types::user_permissions::UserPermissions;
+use poise::serenity_prelude::{CreateEmbed, User};
+
+mod audit;
+mod freeze;
+mod modify;
+mod pending;
+mod unfreeze;
+
+/// Economy administration, reserved for bot owners and administrators.
+#[poise::command(
+    prefix_command,
+    hide_in_help,
+    aliases("eco"),
+    check = "check_admin",
+    subcommands(
+        "modify::grant",
+        "modify::revoke",
+        "modify::set",
+        "freeze::freeze",
+        "unfreeze::unfreeze",
+        "pending::pending",
+        "audit::audit"
+    )
+)]
+pub async fn economy(_ctx: Context<'_>) -> BotResult<()> {
+    Ok(())
+}
+
+async fn check_admin(ctx: Context<'_>) -> BotResult<bool> {
+    if ctx.framework().options().owners.contains(&ctx.author().id) {
+        return Ok(true);
+    }
+
+    let user = ctx.fetch_author_model().await?;
+    Ok(user.has_permissions(UserPermissions::ADMINISTRATOR))
+}
+
+async fn fetch_target(ctx: &Context<'_>, user: &User) -> BotResult<user::Model> {
+    if user.bot {
+        return Err(BotError::TargetBotOrYourself);
+    }
+
+    Ok(ctx
+        .stores()
+        .user
+        .fetch_or_create(user.id.to_string())
+        .await?)
+}
+
+fn audit_embed(title: &str, user: &User, audit: &economy_audit::Model) -> CreateEmbed {
+    let mut embed = CreateEmbed::default()
+        .success_user(user)
+        .title(title)
+        .field("Admin", format!("<@{}>", audit.admin_id), true);
+
+    if let (Some(before), Some(after)) = (audit.balance_before, audit.balance_after) {
+        embed = embed.field("Balance", format!("**`{before}`** → **`{after}`**"), true);
+    }
+    if let Some(reason) = &audit.reason {
+        embed = embed.field("Reason", reason, false);
+    }
+
+    embed.footer_text(format!("Audit ID: {}", audit.id))
+}
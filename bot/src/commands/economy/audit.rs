@@ -0,0 +1,61 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::color::UiColor;
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::time::format_time_relative_at;
+use crate::Context;
+use neobabu_core::types::currency::Currency;
+use neobabu_core::types::economy_audit_action::EconomyAuditAction;
+use poise::serenity_prelude::{CreateEmbed, User};
+
+const AUDIT_ENTRIES: u64 = 10;
+
+/// Show the latest admin actions on a user's wallet.
+#[poise::command(prefix_command, hide_in_help)]
+pub async fn audit(ctx: Context<'_>, user: User) -> BotResult<()> {
+    let target = super::fetch_target(&ctx, &user).await?;
+    let entries = ctx
+        .stores()
+        .economy
+        .audit_log(&target, AUDIT_ENTRIES)
+        .await?;
+
+    let mut description = String::new();
+    for entry in entries.iter() {
+        let action = EconomyAuditAction::try_from(entry.action)
+            .map(|action| action.to_string())
+            .unwrap_or_else(|_| entry.action.to_string());
+        description.push_str(&format!(
+            "{} **{action}** by <@{}>",
+            format_time_relative_at(entry.created_at.and_utc()),
+            entry.admin_id
+        ));
+        if let Some(amount) = entry.amount {
+            let currency = entry
+                .currency
+                .and_then(|currency| Currency::try_from(currency).ok())
+                .map(|currency| ctx.emoji_text(currency.into()))
+                .unwrap_or_default();
+            description.push_str(&format!(" of **`{amount}`** {currency}"));
+        }
+        if let (Some(before), Some(after)) = (entry.balance_before, entry.balance_after) {
+            description.push_str(&format!(", **`{before}`** → **`{after}`**"));
+        }
+        if let Some(reason) = &entry.reason {
+            description.push_str(&format!("\n-# {reason}"));
+        }
+        description.push('\n');
+    }
+    if description.is_empty() {
+        description.push_str("No admin actions recorded.");
+    }
+
+    let embed = CreateEmbed::default()
+        .user(&user)
+        .ui_color(UiColor::Yellow)
+        .title("Audit Log")
+        .description(description);
+    ctx.send(embed.create_reply()).await?;
+
+    Ok(())
+}
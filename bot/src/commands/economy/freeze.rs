@@ -0,0 +1,28 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use poise::serenity_prelude::{CreateEmbed, User};
+
+/// Freeze a user's wallet, blocking reservations and transfers.
+#[poise::command(prefix_command, hide_in_help)]
+pub async fn freeze(ctx: Context<'_>, user: User, #[rest] reason: Option<String>) -> BotResult<()> {
+    let admin = ctx.fetch_author_model().await?;
+    let target = super::fetch_target(&ctx, &user).await?;
+    let frozen = ctx.stores().economy.freeze(&admin, &target, reason).await?;
+
+    let embed = if frozen {
+        CreateEmbed::default()
+            .success_user(&user)
+            .title("Wallet Frozen")
+            .description(format!("The wallet of <@{}> has been frozen.", user.id))
+    } else {
+        CreateEmbed::default()
+            .warning_user(&user)
+            .title("Already Frozen")
+            .description(format!("The wallet of <@{}> is already frozen.", user.id))
+    };
+    ctx.send(embed.create_reply()).await?;
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use neobabu_core::types::currency::Currency;
+use poise::serenity_prelude::User;
+
+/// Grant Citrine to a user.
+#[poise::command(prefix_command, hide_in_help)]
+pub async fn grant(
+    ctx: Context<'_>,
+    user: User,
+    amount: i64,
+    #[rest] reason: Option<String>,
+) -> BotResult<()> {
+    if amount <= 0 {
+        return Err(BotError::AmountNotPositive);
+    }
+
+    let admin = ctx.fetch_author_model().await?;
+    let target = super::fetch_target(&ctx, &user).await?;
+    let audit = ctx
+        .stores()
+        .economy
+        .admin_grant(&admin, &target, Currency::Citrine, amount, reason)
+        .await?;
+
+    let embed = super::audit_embed(
+        &format!("Granted {amount} {}", Currency::Citrine),
+        &user,
+        &audit,
+    );
+    ctx.send(embed.create_reply()).await?;
+
+    Ok(())
+}
+
+/// Revoke Citrine from a user, the balance will not go below zero.
+#[poise::command(prefix_command, hide_in_help)]
+pub async fn revoke(
+    ctx: Context<'_>,
+    user: User,
+    amount: i64,
+    #[rest] reason: Option<String>,
+) -> BotResult<()> {
+    if amount <= 0 {
+        return Err(BotError::AmountNotPositive);
+    }
+
+    let admin = ctx.fetch_author_model().await?;
+    let target = super::fetch_target(&ctx, &user).await?;
+    let audit = ctx
+        .stores()
+        .economy
+        .admin_revoke(&admin, &target, Currency::Citrine, amount, reason)
+        .await?;
+
+    let embed = super::audit_embed(
+        &format!("Revoked {amount} {}", Currency::Citrine),
+        &user,
+        &audit,
+    );
+    ctx.send(embed.create_reply()).await?;
+
+    Ok(())
+}
+
+/// Set the Citrine balance of a user.
+#[poise::command(prefix_command, hide_in_help)]
+pub async fn set(
+    ctx: Context<'_>,
+    user: User,
+    amount: i64,
+    #[rest] reason: Option<String>,
+) -> BotResult<()> {
+    let admin = ctx.fetch_author_model().await?;
+    let target = super::fetch_target(&ctx, &user).await?;
+    let audit = ctx
+        .stores()
+        .economy
+        .admin_set(&admin, &target, Currency::Citrine, amount, reason)
+        .await?;
+
+    let embed = super::audit_embed(&format!("Set {} balance", Currency::Citrine), &user, &audit);
+    ctx.send(embed.create_reply()).await?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::color::UiColor;
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::time::format_time_relative_at;
+use crate::Context;
+use neobabu_core::types::currency::Currency;
+use poise::serenity_prelude::{CreateEmbed, User};
+
+/// Inspect the pending reservations and frozen state of a user's wallet.
+#[poise::command(prefix_command, hide_in_help)]
+pub async fn pending(ctx: Context<'_>, user: User) -> BotResult<()> {
+    let target = super::fetch_target(&ctx, &user).await?;
+    let reservations = ctx.stores().economy.pending_reservations(&target).await?;
+    let frozen = ctx.stores().economy.find_frozen(&target).await?;
+
+    let mut description = String::new();
+    for reservation in reservations.iter() {
+        let currency = Currency::try_from(reservation.currency)
+            .map(|currency| ctx.emoji_text(currency.into()))
+            .unwrap_or_else(|_| format!("`{}`", reservation.currency));
+        description.push_str(&format!(
+            "`{}`: **`{}`** {currency}, expires {}\n",
+            reservation.reference_id,
+            reservation.amount,
+            format_time_relative_at(reservation.expires_at.and_utc())
+        ));
+    }
+    if description.is_empty() {
+        description.push_str("No pending reservations.");
+    }
+
+    let mut embed = CreateEmbed::default()
+        .user(&user)
+        .ui_color(UiColor::Yellow)
+        .title("Pending Reservations")
+        .description(description);
+
+    if let Some(frozen) = frozen {
+        let reason = frozen.reason.as_deref().unwrap_or("No reason given");
+        embed = embed.field(
+            "Frozen",
+            format!("By <@{}>: {reason}", frozen.frozen_by),
+            false,
+        );
+    }
+
+    ctx.send(embed.create_reply()).await?;
+
+    Ok(())
+}
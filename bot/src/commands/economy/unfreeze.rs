@@ -0,0 +1,36 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use poise::serenity_prelude::{CreateEmbed, User};
+
+/// Unfreeze a user's wallet.
+#[poise::command(prefix_command, hide_in_help)]
+pub async fn unfreeze(
+    ctx: Context<'_>,
+    user: User,
+    #[rest] reason: Option<String>,
+) -> BotResult<()> {
+    let admin = ctx.fetch_author_model().await?;
+    let target = super::fetch_target(&ctx, &user).await?;
+    let unfrozen = ctx
+        .stores()
+        .economy
+        .unfreeze(&admin, &target, reason)
+        .await?;
+
+    let embed = if unfrozen {
+        CreateEmbed::default()
+            .success_user(&user)
+            .title("Wallet Unfrozen")
+            .description(format!("The wallet of <@{}> has been unfrozen.", user.id))
+    } else {
+        CreateEmbed::default()
+            .warning_user(&user)
+            .title("Not Frozen")
+            .description(format!("The wallet of <@{}> is not frozen.", user.id))
+    };
+    ctx.send(embed.create_reply()).await?;
+
+    Ok(())
+}
@@ -2,11 +2,30 @@ use crate::error::BotResult;
 use crate::Context;
 
 mod blackjack;
+mod connect4;
+mod game2048;
+mod matchmaking;
+mod minesweeper;
+mod poker;
+mod puzzles;
+mod rating;
+mod replay;
 mod rps;
 
 #[poise::command(
     slash_command,
-    subcommands("blackjack::blackjack", "rps::rps"),
+    subcommands(
+        "blackjack::blackjack",
+        "connect4::connect4",
+        "game2048::game2048",
+        "matchmaking::matchmaking",
+        "minesweeper::minesweeper",
+        "poker::poker",
+        "puzzles::puzzles",
+        "rating::rating",
+        "replay::replay",
+        "rps::rps"
+    ),
     guild_only
 )]
 pub async fn game(_ctx: Context<'_>) -> BotResult<()> {
@@ -1,10 +1,15 @@
 use crate::error::BotResult;
 use crate::Context;
 
+mod rules;
 mod start;
 mod stats;
 
-#[poise::command(slash_command, guild_only, subcommands("start::start", "stats::stats"))]
+#[poise::command(
+    slash_command,
+    guild_only,
+    subcommands("rules::rules", "start::start", "stats::stats")
+)]
 pub async fn blackjack(_ctx: Context<'_>) -> BotResult<()> {
     Ok(())
 }
@@ -0,0 +1,70 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::color::UiColor;
+use crate::ui::games::blackjack::format_rules;
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use neobabu_core::games::blackjack::rules::BlackjackPreset;
+use neobabu_core::stores::{IntoActiveModel, Set};
+use poise::serenity_prelude::CreateEmbed;
+
+#[derive(Debug, Clone, Copy, poise::ChoiceParameter)]
+pub enum PresetOption {
+    Classic,
+    Vegas,
+    Tourist,
+}
+
+impl From<PresetOption> for BlackjackPreset {
+    fn from(option: PresetOption) -> Self {
+        match option {
+            PresetOption::Classic => BlackjackPreset::Classic,
+            PresetOption::Vegas => BlackjackPreset::Vegas,
+            PresetOption::Tourist => BlackjackPreset::Tourist,
+        }
+    }
+}
+
+/// View or choose the house rules of Blackjack games on this server.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn rules(
+    ctx: Context<'_>,
+    #[description = "The preset of house rules to play with."] preset: Option<PresetOption>,
+) -> BotResult<()> {
+    ctx.defer_ephemeral().await?;
+
+    let guild = ctx.fetch_guild_model().await?;
+    let settings = ctx.stores().guild_blackjack.fetch_or_create(&guild).await?;
+
+    let settings = if let Some(preset) = preset {
+        let mut active = settings.into_active_model();
+        active.preset = Set(BlackjackPreset::from(preset).into());
+        ctx.stores().guild_blackjack.update(active).await?
+    } else {
+        settings
+    };
+
+    let mut embed = if preset.is_some() {
+        CreateEmbed::default()
+            .success_user(ctx.author())
+            .title("Blackjack Rules Updated")
+    } else {
+        CreateEmbed::default()
+            .ui_color(UiColor::Gray)
+            .user(ctx.author())
+            .title("Blackjack Rules")
+    };
+
+    for preset in BlackjackPreset::ALL {
+        let name = if preset == settings.preset() {
+            format!("{preset} (active)")
+        } else {
+            preset.to_string()
+        };
+        embed = embed.field(name, format_rules(&preset.rules()), false);
+    }
+
+    ctx.send(embed.create_reply().ephemeral(true)).await?;
+
+    Ok(())
+}
@@ -3,11 +3,11 @@ use crate::error::{BotError, BotResult};
 use crate::ui::games::blackjack::BlackjackUi;
 use crate::ui::message::interactive::InteractiveMessage;
 use crate::Context;
-use neobabu_core::games::blackjack::BlackjackGame;
+use neobabu_core::error::CoreError;
 use neobabu_core::types::currency::Currency;
 use std::time::Duration;
 
-/// Start a game of Blackjack, which up to 4 players can join.
+/// Start a game of Blackjack with the house rules of this server, which other players can join.
 #[poise::command(slash_command, guild_only, user_cooldown = "20")]
 pub async fn start(
     ctx: Context<'_>,
@@ -16,13 +16,15 @@ pub async fn start(
     ctx.defer().await?;
     let user = ctx.fetch_author_model().await?;
 
-    let mut game = BlackjackGame::default();
-
     if let Some(wager) = wager {
         if wager == 0 {
             return Err(BotError::WagerZero);
         }
 
+        if ctx.stores().economy.is_frozen(&user).await? {
+            return Err(CoreError::WalletFrozen.into());
+        }
+
         let balance = ctx
             .stores()
             .economy
@@ -31,15 +33,26 @@ pub async fn start(
         if wager as i64 > balance.available {
             return Err(BotError::InsufficientFunds(Currency::Citrine));
         }
+    }
+
+    // The shoe is only taken off the table once the wager is known to be valid, every exit from
+    // here on puts it back.
+    let guild = ctx.fetch_guild_model().await?;
+    let settings = ctx.stores().guild_blackjack.fetch_or_create(&guild).await?;
+    let mut game = ctx.services().blackjack.new_game(&settings).await?;
+    if let Some(wager) = wager {
         game = game.with_wager(wager);
     }
 
-    ctx.services()
-        .blackjack
-        .register_user(&mut game, &user, 4)
-        .await?;
+    let blackjack = &ctx.services().blackjack;
+    if let Err(err) = blackjack.register_user(&mut game, &user).await {
+        blackjack.return_shoe(&game).await?;
+        return Err(err.into());
+    }
 
-    let ui = BlackjackUi::new(game);
+    let mut ui = BlackjackUi::new(game);
+    ui.player_names
+        .insert(user.id.clone(), ctx.author().display_name().to_string());
     InteractiveMessage::new(&ctx, ui)
         .timeout(Duration::from_mins(14))
         .allow_anyone_to_interact(true)
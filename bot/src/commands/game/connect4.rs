@@ -0,0 +1,15 @@
+use crate::error::BotResult;
+use crate::Context;
+
+mod challenge;
+mod lobby;
+mod stats;
+
+#[poise::command(
+    slash_command,
+    guild_only,
+    subcommands("challenge::challenge", "lobby::lobby", "stats::stats")
+)]
+pub async fn connect4(_ctx: Context<'_>) -> BotResult<()> {
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::games::connect4::Connect4Ui;
+use crate::ui::message::interactive::InteractiveMessage;
+use crate::Context;
+use neobabu_core::error::CoreError;
+use neobabu_core::games::connect4::difficulty::Connect4Difficulty;
+use neobabu_core::games::connect4::Connect4Game;
+use neobabu_core::types::currency::Currency;
+use poise::serenity_prelude::User;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, poise::ChoiceParameter)]
+pub enum DifficultyOption {
+    Easy,
+    Medium,
+    Hard,
+    Perfect,
+}
+
+impl From<DifficultyOption> for Connect4Difficulty {
+    fn from(option: DifficultyOption) -> Self {
+        match option {
+            DifficultyOption::Easy => Connect4Difficulty::Easy,
+            DifficultyOption::Medium => Connect4Difficulty::Medium,
+            DifficultyOption::Hard => Connect4Difficulty::Hard,
+            DifficultyOption::Perfect => Connect4Difficulty::Perfect,
+        }
+    }
+}
+
+/// Challenge a user, or the bot if nobody is given, to a game of Connect Four.
+#[poise::command(slash_command, guild_only, user_cooldown = "20")]
+pub async fn challenge(
+    ctx: Context<'_>,
+    #[description = "Who to challenge, leave empty to play against the bot"] opponent: Option<User>,
+    #[description = "How much Citrine to bet, only against other users"] wager: Option<u32>,
+    #[description = "How strong the bot plays"] difficulty: Option<DifficultyOption>,
+) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let author = ctx.author();
+    let (opponent_id, against_ai) = match &opponent {
+        Some(opponent) if opponent.id == author.id => return Err(BotError::TargetYourself),
+        Some(opponent) => (opponent.id.to_string(), opponent.bot),
+        None => (ctx.framework().bot_id.to_string(), true),
+    };
+
+    let user = ctx.fetch_author_model().await?;
+    let mut game = Connect4Game::new(&user.id, &opponent_id);
+    if against_ai {
+        let difficulty = difficulty.map(Into::into).unwrap_or_default();
+        game = game.with_ai(&opponent_id, difficulty);
+    }
+
+    if let Some(wager) = wager {
+        if wager == 0 {
+            return Err(BotError::WagerZero);
+        }
+
+        if against_ai {
+            return Err(BotError::WagerAgainstBot);
+        }
+
+        if ctx.stores().economy.is_frozen(&user).await? {
+            return Err(CoreError::WalletFrozen.into());
+        }
+
+        game = game.with_wager(wager);
+        let reserved = ctx.services().connect4.reserve_wager(&game, &user).await?;
+        if !reserved {
+            return Err(BotError::InsufficientFunds(Currency::Citrine));
+        }
+    }
+
+    let ui = Connect4Ui::new(game, user.id.clone(), opponent_id);
+    InteractiveMessage::new(&ctx, ui)
+        .timeout(Duration::from_mins(50))
+        .allow_anyone_to_interact(true)
+        .tick_interval(Duration::from_secs(2))
+        .run()
+        .await?;
+
+    Ok(())
+}
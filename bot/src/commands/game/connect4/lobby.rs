@@ -0,0 +1,50 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::games::connect4::Connect4Rules;
+use crate::ui::games::turn_based::{TurnBasedRules, TurnBasedUi};
+use crate::ui::message::interactive::InteractiveMessage;
+use crate::Context;
+use neobabu_core::error::CoreError;
+use neobabu_core::types::currency::Currency;
+use std::time::Duration;
+
+/// Open a lobby for a game of Connect Four against whoever joins first.
+#[poise::command(slash_command, guild_only, user_cooldown = "20")]
+pub async fn lobby(
+    ctx: Context<'_>,
+    #[description = "How much Citrine every player bets"] wager: Option<u32>,
+) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let user = ctx.fetch_author_model().await?;
+    let ui = TurnBasedUi::new(Connect4Rules, &user.id, wager.unwrap_or(0) as i64);
+    let time_limit = ui.rules.time_limit();
+
+    if let Some(wager) = wager {
+        if wager == 0 {
+            return Err(BotError::WagerZero);
+        }
+
+        if ctx.stores().economy.is_frozen(&user).await? {
+            return Err(CoreError::WalletFrozen.into());
+        }
+
+        let reserved = ctx
+            .services()
+            .wager
+            .reserve(&ui.reference_id, &user.id, ui.stake, time_limit)
+            .await?;
+        if !reserved {
+            return Err(BotError::InsufficientFunds(Currency::Citrine));
+        }
+    }
+
+    InteractiveMessage::new(&ctx, ui)
+        .timeout(time_limit)
+        .allow_anyone_to_interact(true)
+        .tick_interval(Duration::from_secs(2))
+        .run()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use neobabu_core::games::connect4::difficulty::Connect4Difficulty;
+use poise::serenity_prelude::{CreateEmbed, Member};
+
+/// View your or another user's Connect Four stats.
+#[poise::command(slash_command, guild_only)]
+pub async fn stats(ctx: Context<'_>, member: Option<Member>) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let author_member = ctx.author_member().await;
+    let target = match &member {
+        Some(m) => m,
+        None => author_member.as_deref().ok_or(BotError::GuildCommandOnly)?,
+    };
+
+    let user = ctx
+        .stores()
+        .user
+        .fetch_or_create(target.user.id.to_string())
+        .await?;
+    let c4_user = ctx.stores().connect4_user.fetch_or_create(&user).await?;
+    let c4_ai = ctx.stores().connect4_ai.find_by_user_id(&user.id).await?;
+
+    let mut vs_ai = String::new();
+    for difficulty in Connect4Difficulty::ALL {
+        let (wins, losses, draws) = c4_ai
+            .iter()
+            .find(|record| record.difficulty == i16::from(difficulty))
+            .map(|record| (record.wins, record.losses, record.draws))
+            .unwrap_or_default();
+        vs_ai.push_str(&format!(
+            "**{difficulty}** **`{wins}W {losses}L {draws}D`**\n"
+        ));
+    }
+
+    let mut embed = CreateEmbed::default()
+        .member_full(&ctx, target)
+        .await
+        .title("Connect Four Stats")
+        .field(
+            "Games Played",
+            format!("**`{}`**", c4_user.games_played()),
+            true,
+        )
+        .field(
+            "Win Chance",
+            format!("**`{:.2}%`**", c4_user.win_chance() * 100.0),
+            true,
+        )
+        .field(
+            "avg. Length",
+            format!("**`{:.2}`** moves", c4_user.avg_length()),
+            true,
+        )
+        .field("Wins", format!("**`{}`**", c4_user.wins), true)
+        .field("Losses", format!("**`{}`**", c4_user.losses), true)
+        .field("Draws", format!("**`{}`**", c4_user.draws), true)
+        .field("Against the Bot", vs_ai, false);
+
+    if target.user.id != ctx.author().id {
+        let head_to_head = ctx
+            .stores()
+            .connect4_games
+            .find_by_id(ctx.author().id.to_string(), &user.id)
+            .await?;
+        let (wins, losses, draws) = head_to_head
+            .map(|games| games.record_for(&user.id))
+            .unwrap_or_default();
+        embed = embed.field(
+            "Head to Head",
+            format!(
+                "<@{}> **`{wins}W {losses}L {draws}D`** against <@{}>",
+                user.id,
+                ctx.author().id
+            ),
+            false,
+        );
+    }
+
+    ctx.send(embed.create_reply()).await?;
+    Ok(())
+}
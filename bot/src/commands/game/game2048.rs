@@ -0,0 +1,29 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::games::game2048::Game2048Ui;
+use crate::ui::message::interactive::InteractiveMessage;
+use crate::Context;
+use neobabu_core::games::game2048::Game2048;
+use neobabu_core::types::puzzle_kind::PuzzleKind;
+use std::time::Duration;
+
+/// Slide and merge tiles to reach 2048, your best score is kept.
+#[poise::command(slash_command, guild_only, rename = "2048", user_cooldown = "10")]
+pub async fn game2048(ctx: Context<'_>) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let user = ctx.fetch_author_model().await?;
+    let best = ctx
+        .stores()
+        .puzzle_score
+        .find_by_id(&user.id, PuzzleKind::Game2048)
+        .await?;
+
+    let ui = Game2048Ui::new(Game2048::new(), user, best);
+    InteractiveMessage::new(&ctx, ui)
+        .timeout(Duration::from_mins(30))
+        .run()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use crate::commands::game::rating::RatedGameOption;
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::color::UiColor;
+use crate::ui::games::connect4::Connect4Ui;
+use crate::ui::games::rps::RPSUi;
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::message::interactive::InteractiveMessage;
+use crate::Context;
+use chrono::Utc;
+use neobabu_core::games::connect4::Connect4Game;
+use neobabu_core::games::rps::RPSGame;
+use neobabu_core::types::rated_game_kind::RatedGameKind;
+use poise::serenity_prelude::CreateEmbed;
+use std::time::Duration;
+
+/// How long a player waits for an opponent before leaving the queue.
+const QUEUE_TIMEOUT: Duration = Duration::from_mins(5);
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Wait for an opponent of a similar rating in this server and play a rated game against them.
+#[poise::command(slash_command, guild_only, user_cooldown = "10")]
+pub async fn matchmaking(
+    ctx: Context<'_>,
+    #[description = "Which game to play"] game: RatedGameOption,
+) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let guild_id = ctx.guild_id_string().ok_or(BotError::GuildCommandOnly)?;
+    let kind = RatedGameKind::from(game);
+    let user = ctx.fetch_author_model().await?;
+
+    let opponent_id = ctx
+        .services()
+        .game_rating
+        .join_queue(&guild_id, kind, &user)
+        .await?;
+    let Some(opponent_id) = opponent_id else {
+        return wait_for_opponent(ctx, &guild_id, kind, &user.id).await;
+    };
+
+    let expected = ctx
+        .services()
+        .game_rating
+        .expected_score(kind, &user.id, &opponent_id)
+        .await?;
+    let embed = CreateEmbed::new()
+        .ui_color(UiColor::Gray)
+        .title(format!("Matchmaking | {kind}"))
+        .description(format!(
+            "<@{}> was matched with <@{opponent_id}>, who is expected to win **`{:.0}%`** of the time.",
+            user.id,
+            (1.0 - expected) * 100.0
+        ));
+    ctx.send(embed.create_reply()).await?;
+
+    match kind {
+        RatedGameKind::Connect4 => {
+            let game = Connect4Game::new(&user.id, &opponent_id);
+            let ui = Connect4Ui::new(game, user.id.clone(), opponent_id);
+            InteractiveMessage::new(&ctx, ui)
+                .timeout(Duration::from_mins(50))
+                .allow_anyone_to_interact(true)
+                .tick_interval(Duration::from_secs(2))
+                .run()
+                .await?;
+        }
+        RatedGameKind::RockPaperScissors => {
+            let opponent = ctx.stores().user.fetch_or_create(&opponent_id).await?;
+            let game_time = Duration::from_secs(300);
+            let state = RPSUi::new(RPSGame::new(user, opponent), Utc::now() + game_time);
+            InteractiveMessage::new(&ctx, state)
+                .timeout(game_time + Duration::from_secs(60))
+                .tick_interval(Duration::from_secs(2))
+                .allow_anyone_to_interact(true)
+                .run()
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Keeps the player in the queue until someone is matched with them, who then starts the game.
+async fn wait_for_opponent(
+    ctx: Context<'_>,
+    guild_id: &str,
+    kind: RatedGameKind,
+    user_id: &str,
+) -> BotResult<()> {
+    let title = format!("Matchmaking | {kind}");
+    let searching = CreateEmbed::new()
+        .ui_color(UiColor::Gray)
+        .title(&title)
+        .description(format!(
+            "<@{user_id}> is looking for an opponent, use `/game matchmaking` to play against them."
+        ));
+    let reply = ctx.send(searching.create_reply()).await?;
+
+    let services = ctx.services();
+    let started = tokio::time::Instant::now();
+    let mut matched = false;
+    while started.elapsed() < QUEUE_TIMEOUT {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        if !services.game_rating.is_queued(guild_id, kind, user_id) {
+            matched = true;
+            break;
+        }
+    }
+    // Someone may have been matched with the player right before they would have left.
+    if !matched {
+        matched = !services.game_rating.leave_queue(guild_id, kind, user_id);
+    }
+
+    let embed = if matched {
+        CreateEmbed::new()
+            .ui_color(UiColor::Success)
+            .title(&title)
+            .description(format!(
+                "An opponent was found for <@{user_id}>, the game was started where they joined."
+            ))
+    } else {
+        CreateEmbed::new()
+            .ui_color(UiColor::Gray)
+            .title(&title)
+            .description(format!("No opponent was found for <@{user_id}>."))
+    };
+    reply.edit(ctx, embed.create_reply()).await?;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::games::minesweeper::MinesweeperUi;
+use crate::ui::message::interactive::InteractiveMessage;
+use crate::Context;
+use neobabu_core::games::minesweeper::MinesweeperGame;
+use neobabu_core::games::minesweeper::difficulty::MinesweeperDifficulty;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, poise::ChoiceParameter)]
+pub enum MinesweeperDifficultyOption {
+    #[name = "Beginner (9x9, 10 mines)"]
+    Beginner,
+    #[name = "Intermediate (16x16, 40 mines)"]
+    Intermediate,
+    #[name = "Expert (24x16, 80 mines)"]
+    Expert,
+}
+
+impl From<MinesweeperDifficultyOption> for MinesweeperDifficulty {
+    fn from(option: MinesweeperDifficultyOption) -> Self {
+        match option {
+            MinesweeperDifficultyOption::Beginner => MinesweeperDifficulty::Beginner,
+            MinesweeperDifficultyOption::Intermediate => MinesweeperDifficulty::Intermediate,
+            MinesweeperDifficultyOption::Expert => MinesweeperDifficulty::Expert,
+        }
+    }
+}
+
+/// Clear a minefield as fast as you can, your best time per difficulty is kept.
+#[poise::command(slash_command, guild_only, user_cooldown = "10")]
+pub async fn minesweeper(
+    ctx: Context<'_>,
+    #[description = "How large the board is and how many mines it has"] difficulty: Option<
+        MinesweeperDifficultyOption,
+    >,
+) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let difficulty = difficulty
+        .map(MinesweeperDifficulty::from)
+        .unwrap_or(MinesweeperDifficulty::Beginner);
+    let user = ctx.fetch_author_model().await?;
+    let best = ctx
+        .stores()
+        .puzzle_score
+        .find_by_id(&user.id, difficulty.puzzle_kind())
+        .await?;
+
+    let ui = MinesweeperUi::new(MinesweeperGame::new(difficulty), user, best);
+    InteractiveMessage::new(&ctx, ui)
+        .timeout(Duration::from_mins(30))
+        .run()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::games::poker::PokerRules;
+use crate::ui::games::turn_based::{TurnBasedRules, TurnBasedUi};
+use crate::ui::message::interactive::InteractiveMessage;
+use crate::Context;
+use neobabu_core::error::CoreError;
+use neobabu_core::types::currency::Currency;
+use std::time::Duration;
+
+/// Open a Texas Hold'em table, everyone buys in with the same amount of Citrine.
+#[poise::command(slash_command, guild_only, user_cooldown = "60")]
+pub async fn poker(
+    ctx: Context<'_>,
+    #[description = "How much Citrine every player buys in with"]
+    #[min = 20]
+    #[max = 100000]
+    buy_in: u32,
+    #[description = "The big blind, a fiftieth of the buy-in by default"]
+    #[min = 2]
+    big_blind: Option<u32>,
+    #[description = "How many hands are played at most"]
+    #[min = 1]
+    #[max = 50]
+    hands: Option<u32>,
+) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let big_blind = big_blind.unwrap_or((buy_in / 50).max(2));
+    if big_blind > buy_in / 10 {
+        return Err(BotError::BigBlindTooHigh);
+    }
+
+    let user = ctx.fetch_author_model().await?;
+    if ctx.stores().economy.is_frozen(&user).await? {
+        return Err(CoreError::WalletFrozen.into());
+    }
+
+    let rules = PokerRules {
+        buy_in: buy_in as i64,
+        big_blind: big_blind as i64,
+        hands: hands.unwrap_or(10),
+    };
+    let ui = TurnBasedUi::new(rules, &user.id, buy_in as i64);
+    let time_limit = ui.rules.time_limit();
+    let reserved = ctx
+        .services()
+        .wager
+        .reserve(&ui.reference_id, &user.id, ui.stake, time_limit)
+        .await?;
+    if !reserved {
+        return Err(BotError::InsufficientFunds(Currency::Citrine));
+    }
+
+    InteractiveMessage::new(&ctx, ui)
+        .timeout(time_limit)
+        .allow_anyone_to_interact(true)
+        .tick_interval(Duration::from_secs(2))
+        .run()
+        .await?;
+
+    Ok(())
+}
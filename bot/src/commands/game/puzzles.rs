@@ -0,0 +1,56 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::time::format_stopwatch;
+use crate::Context;
+use neobabu_core::types::puzzle_kind::PuzzleKind;
+use poise::serenity_prelude::{CreateEmbed, Member};
+
+/// View your or another user's best Minesweeper times and 2048 scores.
+#[poise::command(slash_command, guild_only)]
+pub async fn puzzles(ctx: Context<'_>, member: Option<Member>) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let author_member = ctx.author_member().await;
+    let target = match &member {
+        Some(m) => m,
+        None => author_member.as_deref().ok_or(BotError::GuildCommandOnly)?,
+    };
+    if target.user.bot {
+        return Err(BotError::TargetBotOrYourself);
+    }
+
+    let scores = ctx
+        .stores()
+        .puzzle_score
+        .find_by_user_id(target.user.id.to_string())
+        .await?;
+
+    let mut embed = CreateEmbed::default()
+        .member_full(&ctx, target)
+        .await
+        .title("Puzzles");
+    for kind in PuzzleKind::ALL {
+        let score = scores.iter().find(|score| score.kind() == Some(kind));
+        let best = if kind.is_timed() {
+            score
+                .and_then(|score| score.best_time_ms)
+                .map(format_stopwatch)
+        } else {
+            score
+                .and_then(|score| score.best_score)
+                .map(|score| score.to_string())
+        };
+        let value = match (score, best) {
+            (Some(score), Some(best)) => {
+                format!("**`{best}`**\n`{}` won of `{}`", score.wins, score.games)
+            }
+            (Some(score), None) => format!("*Not won yet*\n`{}` played", score.games),
+            (None, _) => "*Not played yet*".to_string(),
+        };
+        embed = embed.field(kind.to_string(), value, true);
+    }
+
+    ctx.send(embed.create_reply()).await?;
+    Ok(())
+}
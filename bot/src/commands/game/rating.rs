@@ -0,0 +1,101 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use neobabu_core::games::rating::chart::RatingChart;
+use neobabu_core::types::rated_game_kind::RatedGameKind;
+use poise::serenity_prelude::{CreateAttachment, CreateEmbed, Member};
+
+/// How many of the most recent games the chart shows.
+const CHART_GAMES: u64 = 100;
+
+#[derive(Debug, Clone, Copy, poise::ChoiceParameter)]
+pub enum RatedGameOption {
+    #[name = "Rock Paper Scissors"]
+    RockPaperScissors,
+    #[name = "Connect Four"]
+    Connect4,
+}
+
+impl From<RatedGameOption> for RatedGameKind {
+    fn from(option: RatedGameOption) -> Self {
+        match option {
+            RatedGameOption::RockPaperScissors => RatedGameKind::RockPaperScissors,
+            RatedGameOption::Connect4 => RatedGameKind::Connect4,
+        }
+    }
+}
+
+/// View your or another user's game ratings and how they changed over time.
+#[poise::command(slash_command, guild_only, user_cooldown = "5")]
+pub async fn rating(
+    ctx: Context<'_>,
+    member: Option<Member>,
+    #[description = "Which game to chart, the most played one by default"] game: Option<
+        RatedGameOption,
+    >,
+) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let author_member = ctx.author_member().await;
+    let target = match &member {
+        Some(m) => m,
+        None => author_member.as_deref().ok_or(BotError::GuildCommandOnly)?,
+    };
+    if target.user.bot {
+        return Err(BotError::TargetBotOrYourself);
+    }
+
+    let user_id = target.user.id.to_string();
+    let mut ratings = Vec::new();
+    for kind in RatedGameKind::ALL {
+        let rating = ctx.stores().game_rating.find_by_id(&user_id, kind).await?;
+        ratings.push((kind, rating));
+    }
+
+    let kind = match game {
+        Some(game) => game.into(),
+        None => ratings
+            .iter()
+            .max_by_key(|(_, rating)| rating.as_ref().map_or(0, |rating| rating.games))
+            .map(|(kind, _)| *kind)
+            .unwrap_or(RatedGameKind::Connect4),
+    };
+
+    let history = ctx
+        .stores()
+        .game_rating
+        .history(&user_id, kind, CHART_GAMES)
+        .await?;
+    let chart = RatingChart {
+        name: target.display_name().to_string(),
+        game: kind.to_string(),
+        ratings: history.iter().map(|entry| entry.rating).collect(),
+    };
+    let png_bytes = chart.render_png(ctx.o2d())?;
+    let attachment = CreateAttachment::bytes(png_bytes, "rating.png");
+
+    let mut embed = CreateEmbed::default()
+        .member_full(&ctx, target)
+        .await
+        .title("Game Ratings")
+        .footer_text("Ratings are within ± of the true strength with 95% certainty")
+        .image("attachment://rating.png");
+    for (kind, rating) in ratings {
+        let value = match rating {
+            Some(rating) if rating.games > 0 => format!(
+                "**`{:.0}`** ±{:.0}\n`{}` games",
+                rating.rating,
+                rating.deviation * 2.0,
+                rating.games
+            ),
+            _ => "*Unrated*".to_string(),
+        };
+        embed = embed.field(kind.to_string(), value, true);
+    }
+
+    ctx.send(embed.create_reply().attachment(attachment))
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::games::replay::GameReplay;
+use crate::ui::message::interactive::state::pagination::PaginationStateTrait;
+use crate::ui::message::interactive::InteractiveMessage;
+use crate::Context;
+use std::time::Duration;
+
+/// Step through a finished game move by move.
+#[poise::command(slash_command, guild_only, user_cooldown = "10")]
+pub async fn replay(
+    ctx: Context<'_>,
+    #[description = "The id of the game, shown once it is over"] id: String,
+) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let Some(record) = ctx.services().game_record.find(&id).await? else {
+        return Err(BotError::GameNotFound);
+    };
+    let Some(replay) = GameReplay::new(record)? else {
+        return Err(BotError::GameNotFound);
+    };
+
+    InteractiveMessage::new(&ctx, replay.build())
+        .timeout(Duration::from_secs(600))
+        .run()
+        .await?;
+
+    Ok(())
+}
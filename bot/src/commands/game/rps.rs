@@ -3,12 +3,13 @@ use crate::Context;
 
 mod challenge;
 mod stats;
+mod tournament;
 
 #[poise::command(
     slash_command,
     guild_only,
     rename = "rock-paper-scissors",
-    subcommands("challenge::challenge", "stats::stats")
+    subcommands("challenge::challenge", "stats::stats", "tournament::tournament")
 )]
 pub async fn rps(_ctx: Context<'_>) -> BotResult<()> {
     Ok(())
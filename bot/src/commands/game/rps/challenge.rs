@@ -6,14 +6,46 @@ use crate::ui::message::interactive::InteractiveMessage;
 use crate::ui::message::CreateEmbedExt;
 use crate::Context;
 use chrono::Utc;
-use neobabu_core::games::rps::choice::RPSChoice;
+use neobabu_core::error::CoreError;
+use neobabu_core::games::rps::bot::RPSBotMode;
 use neobabu_core::games::rps::RPSGame;
+use neobabu_core::types::currency::Currency;
 use poise::serenity_prelude::{CreateEmbed, User};
 use std::time::Duration;
 
-/// Challenge a user to a game of Rock Paper Scissors.
+#[derive(Debug, Clone, Copy, poise::ChoiceParameter)]
+pub enum SeriesOption {
+    #[name = "Best of 1"]
+    BestOf1,
+    #[name = "Best of 3"]
+    BestOf3,
+    #[name = "Best of 5"]
+    BestOf5,
+    #[name = "Best of 7"]
+    BestOf7,
+}
+
+impl SeriesOption {
+    pub fn best_of(self) -> u8 {
+        match self {
+            SeriesOption::BestOf1 => 1,
+            SeriesOption::BestOf3 => 3,
+            SeriesOption::BestOf5 => 5,
+            SeriesOption::BestOf7 => 7,
+        }
+    }
+}
+
+/// Challenge a user to a game or a best-of series of Rock Paper Scissors.
 #[poise::command(slash_command, guild_only, user_cooldown = "20")]
-pub async fn challenge(ctx: Context<'_>, opponent: User) -> BotResult<()> {
+pub async fn challenge(
+    ctx: Context<'_>,
+    opponent: User,
+    #[description = "How many rounds the series lasts at most"] series: Option<SeriesOption>,
+    #[description = "How much Citrine both players bet, not against bots"] wager: Option<u32>,
+    #[description = "Against a bot, let it throw at random instead of learning from you"]
+    fair: Option<bool>,
+) -> BotResult<()> {
     ctx.defer().await?;
 
     let author = ctx.author();
@@ -36,19 +68,52 @@ pub async fn challenge(ctx: Context<'_>, opponent: User) -> BotResult<()> {
             user_1.id, user_2.id
         ));
 
-    let mut game = RPSGame::new(user_1, user_2);
+    let best_of = series.map(SeriesOption::best_of).unwrap_or(1);
+    let mut game = RPSGame::new(user_1, user_2).with_best_of(best_of);
     if opponent.bot {
-        game.choice_2 = Some(RPSChoice::random());
+        let mode = if fair.unwrap_or(false) {
+            RPSBotMode::Fair
+        } else {
+            RPSBotMode::Adaptive
+        };
+        let bot = ctx.services().rps.create_bot(&game.user_1, mode).await?;
+        game = game.with_bot(bot);
+        game.play_bot();
+    }
+
+    // The wager is only locked with the first throw, but the challenger should be able to cover
+    // it from the start.
+    if let Some(wager) = wager {
+        if wager == 0 {
+            return Err(BotError::WagerZero);
+        }
+
+        if opponent.bot {
+            return Err(BotError::WagerAgainstBot);
+        }
+
+        if ctx.stores().economy.is_frozen(&game.user_1).await? {
+            return Err(CoreError::WalletFrozen.into());
+        }
+
+        let balance = ctx
+            .stores()
+            .economy
+            .balance(&game.user_1, Currency::Citrine)
+            .await?;
+        if wager as i64 > balance.available {
+            return Err(BotError::InsufficientFunds(Currency::Citrine));
+        }
+        game = game.with_wager(wager);
     }
 
-    let state = RPSUi {
-        game,
-        timeout_at: Utc::now() + Duration::from_secs(300),
-        bot_session: opponent.bot,
-    };
+    let game_time = Duration::from_secs(300 * best_of as u64);
+    let state = RPSUi::new(game, Utc::now() + game_time);
 
+    // Games time out on a tick so that wagers are refunded, the message timeout is a fallback.
     InteractiveMessage::new(&ctx, state)
-        .timeout(Duration::from_secs(300))
+        .timeout(game_time + Duration::from_secs(60))
+        .tick_interval(Duration::from_secs(2))
         .on_timeout(timeout_embed)
         .allow_anyone_to_interact(true)
         .run()
@@ -83,6 +83,42 @@ pub async fn stats(ctx: Context<'_>, member: Option<Member>) -> BotResult<()> {
                 stats.win_rate() * 100.0
             ),
             true,
+        )
+        .field(
+            "Citrine Wagered",
+            format!(
+                "**`{}`** {}",
+                stats.citrine_wagered,
+                ctx.emoji_text(EmojiType::Citrine)
+            ),
+            true,
+        )
+        .field(
+            "Citrine Won",
+            format!(
+                "**`{}`** {}",
+                stats.citrine_won,
+                ctx.emoji_text(EmojiType::Citrine)
+            ),
+            true,
+        )
+        .field(
+            "Citrine Lost",
+            format!(
+                "**`{}`** {}",
+                stats.citrine_lost,
+                ctx.emoji_text(EmojiType::Citrine)
+            ),
+            true,
+        )
+        .field(
+            "Net Gain",
+            format!(
+                "**`{}`** {}",
+                stats.net_gain(),
+                ctx.emoji_text(EmojiType::Citrine)
+            ),
+            true,
         );
 
     ctx.send(embed.create_reply()).await?;
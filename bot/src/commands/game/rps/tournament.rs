@@ -0,0 +1,30 @@
+use crate::commands::game::rps::challenge::SeriesOption;
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::games::rps::tournament::RPSTournamentUi;
+use crate::ui::message::interactive::InteractiveMessage;
+use crate::Context;
+use neobabu_core::games::rps::tournament::RPSTournament;
+use std::time::Duration;
+
+/// Open sign-ups for a Rock Paper Scissors tournament, the bracket is played out in this channel.
+#[poise::command(slash_command, guild_only, user_cooldown = "60")]
+pub async fn tournament(
+    ctx: Context<'_>,
+    #[description = "How many rounds every match lasts at most"] series: Option<SeriesOption>,
+) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let host = ctx.fetch_author_model().await?;
+    let best_of = series.map(SeriesOption::best_of).unwrap_or(1);
+    let ui = RPSTournamentUi::new(RPSTournament::new(host, best_of));
+
+    InteractiveMessage::new(&ctx, ui)
+        .timeout(Duration::from_mins(60))
+        .allow_anyone_to_interact(true)
+        .tick_interval(Duration::from_secs(2))
+        .run()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use crate::error::BotResult;
+use crate::Context;
+
+mod admin;
+mod reward;
+
+#[poise::command(
+    slash_command,
+    subcommands("admin::admin", "reward::reward"),
+    guild_only
+)]
+pub async fn leveling(_ctx: Context<'_>) -> BotResult<()> {
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::color::UiColor;
+use crate::ui::message::CreateEmbedExt;
+use crate::utils::formatting::format_bool;
+use crate::Context;
+use neobabu_core::stores::{IntoActiveModel, Set};
+use poise::serenity_prelude::{Channel, CreateEmbed};
+
+/// Customize server-wide level up notification settings.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn admin(
+    ctx: Context<'_>,
+    #[description = "Whether to enable or disable level up notifications."] enable: Option<bool>,
+    #[description = "The channel to send level up notifications to."]
+    #[channel_types("Text")]
+    channel: Option<Channel>,
+) -> BotResult<()> {
+    ctx.defer_ephemeral().await?;
+
+    let guild = ctx.fetch_guild_model().await?;
+    let guild_leveling = ctx.stores().guild_leveling.fetch_or_create(&guild).await?;
+    let rewards = ctx
+        .stores()
+        .guild_level_reward
+        .find_by_guild_id(&guild.id)
+        .await?;
+
+    let mut active = guild_leveling.into_active_model();
+    let updated = enable.is_some() || channel.is_some();
+
+    if let Some(enable) = enable {
+        active.enabled = Set(enable);
+    }
+    if let Some(channel) = channel {
+        active.notification_channel_id = Set(Some(channel.id().to_string()));
+    }
+
+    let (enabled, channel_id) = if updated {
+        let model = ctx.stores().guild_leveling.update(active).await?;
+        (model.enabled, model.notification_channel_id)
+    } else {
+        (
+            *active.enabled.as_ref(),
+            active.notification_channel_id.as_ref().clone(),
+        )
+    };
+
+    let channel = channel_id.map_or("`None`".into(), |id| format!("<#{id}>"));
+    let rewards = if rewards.is_empty() {
+        "`None`".to_string()
+    } else {
+        rewards
+            .iter()
+            .map(|reward| format!("Level `{}`: <@&{}>", reward.level, reward.role_id))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let embed = if updated {
+        CreateEmbed::default()
+            .success_user(ctx.author())
+            .title("Leveling Settings Updated")
+    } else {
+        CreateEmbed::default()
+            .ui_color(UiColor::Gray)
+            .user(ctx.author())
+            .title("Leveling Settings")
+    }
+    .field("Enabled", format!("`{}`", format_bool(enabled)), false)
+    .field("Channel", channel, false)
+    .field("Reward Roles", rewards, false);
+
+    ctx.send(embed.create_reply().ephemeral(true)).await?;
+
+    Ok(())
+}
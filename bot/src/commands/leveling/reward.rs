@@ -0,0 +1,53 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use poise::serenity_prelude::{CreateEmbed, Role};
+
+/// Set or remove the role rewarded for reaching a server level.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn reward(
+    ctx: Context<'_>,
+    #[description = "The server level at which the role is granted."]
+    #[min = 1]
+    #[max = 500]
+    level: i32,
+    #[description = "The role to grant, leave empty to remove the reward."] role: Option<Role>,
+) -> BotResult<()> {
+    ctx.defer_ephemeral().await?;
+
+    let guild = ctx.fetch_guild_model().await?;
+
+    let embed = if let Some(role) = role {
+        ctx.stores()
+            .guild_level_reward
+            .set(&guild, level, role.id.to_string())
+            .await?;
+        CreateEmbed::default()
+            .success_user(ctx.author())
+            .title("Level Reward Set")
+            .description(format!(
+                "Members reaching level `{level}` will receive <@&{}>.",
+                role.id
+            ))
+    } else if ctx
+        .stores()
+        .guild_level_reward
+        .delete(&guild, level)
+        .await?
+    {
+        CreateEmbed::default()
+            .success_user(ctx.author())
+            .title("Level Reward Removed")
+            .description(format!("Level `{level}` no longer grants a role."))
+    } else {
+        CreateEmbed::default()
+            .warning_user(ctx.author())
+            .title("No Level Reward")
+            .description(format!("Level `{level}` has no reward role."))
+    };
+
+    ctx.send(embed.create_reply().ephemeral(true)).await?;
+
+    Ok(())
+}
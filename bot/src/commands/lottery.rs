@@ -0,0 +1,15 @@
+use crate::error::BotResult;
+use crate::Context;
+
+mod admin;
+mod buy;
+mod info;
+
+#[poise::command(
+    slash_command,
+    subcommands("admin::admin", "buy::buy", "info::info"),
+    guild_only
+)]
+pub async fn lottery(_ctx: Context<'_>) -> BotResult<()> {
+    Ok(())
+}
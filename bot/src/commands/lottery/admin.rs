@@ -0,0 +1,121 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::color::UiColor;
+use crate::ui::message::CreateEmbedExt;
+use crate::utils::formatting::format_bool;
+use crate::Context;
+use neobabu_core::stores::{IntoActiveModel, Set};
+use neobabu_core::types::currency::Currency;
+use poise::serenity_prelude::{Channel, CreateEmbed};
+
+/// Customize the server lottery, changes to the price and duration apply from the next round.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn admin(
+    ctx: Context<'_>,
+    #[description = "Whether to enable or disable the lottery."] enable: Option<bool>,
+    #[description = "The channel to announce lottery draws in."]
+    #[channel_types("Text")]
+    channel: Option<Channel>,
+    #[description = "The price of a single ticket in Citrine."]
+    #[min = 1]
+    #[max = 10000]
+    ticket_price: Option<i64>,
+    #[description = "Percentage of the pot which is burned instead of paid out."]
+    #[min = 0]
+    #[max = 50]
+    sink_percent: Option<i16>,
+    #[description = "How many hours a lottery round lasts."]
+    #[min = 1]
+    #[max = 720]
+    round_hours: Option<i32>,
+) -> BotResult<()> {
+    ctx.defer_ephemeral().await?;
+
+    let guild = ctx.fetch_guild_model().await?;
+    let settings = ctx.stores().guild_lottery.fetch_or_create(&guild).await?;
+
+    let mut active = settings.clone().into_active_model();
+    let updated = enable.is_some()
+        || channel.is_some()
+        || ticket_price.is_some()
+        || sink_percent.is_some()
+        || round_hours.is_some();
+
+    if let Some(enable) = enable {
+        active.enabled = Set(enable);
+    }
+    if let Some(channel) = channel {
+        active.notification_channel_id = Set(Some(channel.id().to_string()));
+    }
+    if let Some(ticket_price) = ticket_price {
+        active.ticket_price = Set(ticket_price);
+    }
+    if let Some(sink_percent) = sink_percent {
+        active.sink_percent = Set(sink_percent);
+    }
+    if let Some(round_hours) = round_hours {
+        active.round_hours = Set(round_hours);
+    }
+
+    let settings = if updated {
+        ctx.stores().guild_lottery.update(active).await?
+    } else {
+        settings
+    };
+
+    // Tickets of a disabled lottery are never drawn, so their reservations are released.
+    let refunded = if enable == Some(false) {
+        ctx.services()
+            .lottery
+            .refund_open_round(&settings.guild_id)
+            .await?
+    } else {
+        0
+    };
+
+    let channel = settings
+        .notification_channel_id
+        .as_ref()
+        .map_or("`None`".into(), |id| format!("<#{id}>"));
+    let citrine = ctx.emoji_text(Currency::Citrine.into());
+
+    let embed = if updated {
+        CreateEmbed::default()
+            .success_user(ctx.author())
+            .title("Lottery Settings Updated")
+    } else {
+        CreateEmbed::default()
+            .ui_color(UiColor::Gray)
+            .user(ctx.author())
+            .title("Lottery Settings")
+    }
+    .field(
+        "Enabled",
+        format!("`{}`", format_bool(settings.enabled)),
+        false,
+    )
+    .field("Channel", channel, false)
+    .field(
+        "Ticket Price",
+        format!("`{}` {citrine}", settings.ticket_price),
+        true,
+    )
+    .field("Sink", format!("`{}%`", settings.sink_percent), true)
+    .field(
+        "Round Duration",
+        format!("`{}h`", settings.round_hours),
+        true,
+    );
+
+    let embed = if refunded > 0 {
+        embed.footer_text(format!(
+            "Refunded {refunded} ticket purchases of the open round."
+        ))
+    } else {
+        embed
+    };
+
+    ctx.send(embed.create_reply().ephemeral(true)).await?;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::time::format_time_relative_at;
+use crate::Context;
+use neobabu_core::types::currency::Currency;
+use poise::serenity_prelude::CreateEmbed;
+
+/// Buy lottery tickets with Citrine, the Citrine is only spent once the lottery is drawn.
+#[poise::command(slash_command, guild_only, user_cooldown = "5")]
+pub async fn buy(
+    ctx: Context<'_>,
+    #[description = "How many tickets to buy"]
+    #[min = 1]
+    #[max = 100]
+    tickets: u32,
+) -> BotResult<()> {
+    ctx.defer_ephemeral().await?;
+
+    let user = ctx.fetch_author_model().await?;
+    let guild = ctx.fetch_guild_model().await?;
+
+    let Some((round, ticket)) = ctx
+        .services()
+        .lottery
+        .buy_tickets(&user, &guild, tickets)
+        .await?
+    else {
+        return Err(BotError::InsufficientFunds(Currency::Citrine));
+    };
+
+    let citrine = ctx.emoji_text(Currency::Citrine.into());
+    let embed = CreateEmbed::default()
+        .success_user(ctx.author())
+        .title("Tickets Bought")
+        .description(format!(
+            "You bought **`{}`** ticket(s) for **`{}`** {citrine}.\nThe lottery will be drawn {}.",
+            ticket.tickets,
+            ticket.cost,
+            format_time_relative_at(round.draws_at.and_utc())
+        ));
+    ctx.send(embed.create_reply().ephemeral(true)).await?;
+
+    Ok(())
+}
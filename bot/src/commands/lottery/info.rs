@@ -0,0 +1,63 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::color::UiColor;
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::time::format_time_relative_at;
+use crate::Context;
+use neobabu_core::error::CoreError;
+use neobabu_core::types::currency::Currency;
+use neobabu_core::types::feature::Feature;
+use poise::serenity_prelude::CreateEmbed;
+
+/// View the current lottery jackpot and your tickets.
+#[poise::command(slash_command, guild_only)]
+pub async fn info(ctx: Context<'_>) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let user = ctx.fetch_author_model().await?;
+    let guild = ctx.fetch_guild_model().await?;
+    let settings = ctx.stores().guild_lottery.fetch_or_create(&guild).await?;
+    if !settings.enabled {
+        return Err(BotError::Core(CoreError::FeatureNotEnabled(
+            Feature::Lottery,
+        )));
+    }
+
+    let round = ctx.services().lottery.open_round(&settings).await?;
+    let tickets = ctx.stores().lottery_ticket.find_by_round(round.id).await?;
+
+    let total_tickets: i64 = tickets.iter().map(|ticket| ticket.tickets as i64).sum();
+    let own_tickets: i64 = tickets
+        .iter()
+        .filter(|ticket| ticket.user_id == user.id)
+        .map(|ticket| ticket.tickets as i64)
+        .sum();
+    let jackpot = round.carried_over + tickets.iter().map(|ticket| ticket.cost).sum::<i64>();
+
+    let citrine = ctx.emoji_text(Currency::Citrine.into());
+    let embed = CreateEmbed::default()
+        .ui_color(UiColor::Yellow)
+        .title("LOTTERY")
+        .field("Jackpot", format!("**`{jackpot}`** {citrine}"), true)
+        .field(
+            "Ticket Price",
+            format!("**`{}`** {citrine}", round.ticket_price),
+            true,
+        )
+        .field(
+            "Draw",
+            format_time_relative_at(round.draws_at.and_utc()),
+            true,
+        )
+        .field("Tickets Sold", format!("**`{total_tickets}`**"), true)
+        .field("Your Tickets", format!("**`{own_tickets}`**"), true)
+        .field(
+            "Seed Hash (SHA-256)",
+            format!("`{}`", round.seed_hash),
+            false,
+        )
+        .footer_text("The seed is revealed after the draw, it has to match the seed hash.");
+    ctx.send(embed.create_reply()).await?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use crate::context::ContextExt;
+use crate::error::{BotError, BotResult};
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use neobabu_core::leveling::rank_card::RankCard;
+use poise::serenity_prelude::{CreateAttachment, CreateEmbed, Member};
+
+/// View your or another user's server and global level.
+#[poise::command(slash_command, guild_only, user_cooldown = "5")]
+pub async fn rank(ctx: Context<'_>, member: Option<Member>) -> BotResult<()> {
+    ctx.defer().await?;
+
+    let author_member = ctx.author_member().await;
+    let target = match &member {
+        Some(m) => m,
+        None => author_member.as_deref().ok_or(BotError::GuildCommandOnly)?,
+    };
+    if target.user.bot {
+        return Err(BotError::TargetBotOrYourself);
+    }
+
+    let user = ctx
+        .stores()
+        .user
+        .fetch_or_create(target.user.id.to_string())
+        .await?;
+    let guild = ctx.fetch_guild_model().await?;
+    let rank = ctx.services().leveling.rank(&user, &guild).await?;
+
+    let card = RankCard {
+        name: target.display_name().to_string(),
+        rank: Some(rank.position),
+        guild: rank.guild,
+        global: rank.global,
+    };
+    let png_bytes = card.render_png(ctx.o2d())?;
+    let attachment = CreateAttachment::bytes(png_bytes, "rank.png");
+
+    let embed = CreateEmbed::default()
+        .member_full(&ctx, target)
+        .await
+        .title("Rank")
+        .field("Position", format!("**`#{}`**", rank.position), true)
+        .field("Messages", format!("**`{}`**", rank.messages), true)
+        .field("Total XP", format!("**`{}`**", rank.guild.total_xp), true)
+        .image("attachment://rank.png");
+
+    ctx.send(embed.create_reply().attachment(attachment))
+        .await?;
+
+    Ok(())
+}
@@ -2,9 +2,9 @@ use crate::context::ContextExt;
 use crate::error::BotResult;
 use crate::ui::autocomplete::autocomplete_timezone;
 use crate::ui::color::UiColor;
-use crate::ui::message::interactive::state::simple_accept::SimpleAcceptStateTrait;
-use crate::ui::message::interactive::InteractiveMessage;
 use crate::ui::message::CreateEmbedExt;
+use crate::ui::message::interactive::InteractiveMessage;
+use crate::ui::message::interactive::state::simple_accept::SimpleAcceptStateTrait;
 use crate::utils::formatting::humane_datetime;
 use crate::Context;
 use chrono_tz::Tz;
@@ -87,7 +87,8 @@ impl SimpleAcceptStateTrait for TimezoneState {
     ) -> BotResult<()> {
         let mut active = self.user.clone().into_active_model();
         active.preferred_timezone = Set(Some(self.tz.to_string()));
-        ctx.stores().user.update(active).await?;
+        let user = ctx.stores().user.update(active).await?;
+        ctx.services().birthday.reschedule(&user).await?;
         Ok(())
     }
 
@@ -1,4 +1,5 @@
 #[derive(serde::Deserialize)]
+#[allow(dead_code)]
 pub struct BotConfigCredentials {
     #[serde(default)]
     pub nasa_api: Option<String>,
@@ -5,8 +5,12 @@ use poise::serenity_prelude as serenity;
 use tokio::sync::broadcast;
 use tracing::{error, info};
 
+mod achievement_unlocked;
 mod birthday_dm;
 mod birthday_notification;
+mod birthday_role_expired;
+mod level_up;
+mod lottery_drawn;
 mod new_apod;
 mod new_youtube_video;
 
@@ -35,10 +39,18 @@ async fn handle_event(
     event: CoreEvent,
 ) -> BotResult<()> {
     match event {
+        CoreEvent::AchievementUnlocked(event) => {
+            achievement_unlocked::handle(ctx, state, *event).await?
+        }
         CoreEvent::BirthdayDM(event) => birthday_dm::handle(ctx, state, *event).await?,
         CoreEvent::BirthdayNotification(event) => {
             birthday_notification::handle(ctx, state, *event).await?
         }
+        CoreEvent::BirthdayRoleExpired(event) => {
+            birthday_role_expired::handle(ctx, state, *event).await?
+        }
+        CoreEvent::LevelUp(event) => level_up::handle(ctx, state, *event).await?,
+        CoreEvent::LotteryDrawn(event) => lottery_drawn::handle(ctx, state, *event).await?,
         CoreEvent::NewApod(apod) => new_apod::handle(ctx, state, *apod).await?,
         CoreEvent::NewYoutubeVideo(event) => new_youtube_video::handle(ctx, state, *event).await?,
     }
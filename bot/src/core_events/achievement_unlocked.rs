@@ -0,0 +1,27 @@
+use crate::error::BotResult;
+use crate::state::BotState;
+use crate::ui::emoji::EmojiType;
+use neobabu_core::events::achievement_unlocked::AchievementUnlocked;
+use neobabu_core::types::currency::Currency;
+use poise::serenity_prelude::{Context, CreateMessage, UserId};
+
+pub async fn handle(ctx: &Context, state: &BotState, event: AchievementUnlocked) -> BotResult<()> {
+    let user_id = UserId::new(event.user_id.parse()?);
+    let definition = event.achievement.definition();
+
+    let trophy = state.get_emoji_text(EmojiType::Trophy);
+    let mut message = format!(
+        "{trophy} Achievement unlocked: **{}**\n-# {}",
+        definition.name, definition.description
+    );
+    if let Some(reward) = definition.reward {
+        let citrine = state.get_emoji_text(Currency::Citrine.into());
+        message.push_str(&format!("\nYou received **`{reward}`** {citrine}"));
+    }
+
+    user_id
+        .dm(ctx, CreateMessage::new().content(message))
+        .await?;
+
+    Ok(())
+}
@@ -1,31 +1,96 @@
 use crate::error::BotResult;
 use crate::state::BotState;
+use crate::ui::color::UiColor;
 use crate::ui::emoji::EmojiType;
+use crate::ui::message::CreateEmbedExt;
+use chrono::NaiveDateTime;
+use neobabu_core::birthday::announcement::{self, BirthdayAnnouncement};
 use neobabu_core::events::birthday_notification::BirthdayNotification;
-use poise::serenity_prelude::{ChannelId, Context, CreateMessage, GuildId, UserId};
+use poise::serenity_prelude::{
+    ChannelId, Context, CreateAllowedMentions, CreateEmbed, CreateMessage, GuildId, Member, RoleId,
+    UserId,
+};
+use tracing::warn;
 
 pub async fn handle(ctx: &Context, state: &BotState, event: BirthdayNotification) -> BotResult<()> {
     let guild_id = GuildId::new(event.guild_id.parse()?);
     let user_id = UserId::new(event.user_id.parse()?);
-    let channel_id = ChannelId::new(event.channel_id.parse()?);
 
-    let Ok(_member) = guild_id.member(ctx, user_id).await else {
+    let Ok(member) = guild_id.member(ctx, user_id).await else {
         return Ok(());
     };
 
-    let balloon = state.get_emoji_text(EmojiType::BalloonRed);
-    let sparkle = state.get_emoji_text(EmojiType::Sparkle);
-    let belated_text = if event.is_belated { " belated" } else { "" };
-    let age_text = match event.age {
-        Some(age) => format!(" turned `{age}`"),
-        None => "".to_string(),
+    if let Some(role_id) = event.role_id {
+        grant_role(ctx, state, &member, &role_id, event.role_expires_at).await?;
+    }
+
+    let Some(channel_id) = event.channel_id else {
+        return Ok(());
     };
+    let channel_id = ChannelId::new(channel_id.parse()?);
 
-    let server_message =
-        format!("{balloon} <@{user_id}>{age_text}, **Happy{belated_text} birthday!** {sparkle}");
-    channel_id
-        .send_message(ctx, CreateMessage::new().content(server_message))
-        .await?;
+    let birthday_announcement = BirthdayAnnouncement {
+        user_id: event.user_id,
+        age: event.age,
+        is_belated: event.is_belated,
+        server: guild_id.name(ctx).unwrap_or_default(),
+    };
+    let server_message = match event.template {
+        Some(template) => birthday_announcement.render(&template),
+        None => {
+            let balloon = state.get_emoji_text(EmojiType::BalloonRed);
+            let sparkle = state.get_emoji_text(EmojiType::Sparkle);
+            let template = announcement::default_template(event.age.is_some());
+            format!(
+                "{balloon} {} {sparkle}",
+                birthday_announcement.render(&template)
+            )
+        }
+    };
+
+    let mut message = CreateMessage::new()
+        .content(server_message)
+        .allowed_mentions(CreateAllowedMentions::new().users([user_id]));
+    if let Some(image_url) = event.image_url {
+        message = message.embed(CreateEmbed::new().ui_color(UiColor::Pink).image(image_url));
+    }
+    channel_id.send_message(ctx, message).await?;
+
+    Ok(())
+}
+
+/// Grants the birthday role and records when to remove it again. The removal is only recorded
+/// once the bot added the role, so that it never takes a role the member had before.
+async fn grant_role(
+    ctx: &Context,
+    state: &BotState,
+    member: &Member,
+    role_id: &str,
+    expires_at: NaiveDateTime,
+) -> BotResult<()> {
+    let role = RoleId::new(role_id.parse()?);
+    if member.roles.contains(&role) {
+        return Ok(());
+    }
 
+    if let Err(err) = member.add_role(ctx, role).await {
+        warn!(
+            "Failed to grant birthday role '{role}' in guild '{}': {err}",
+            member.guild_id
+        );
+        return Ok(());
+    }
+
+    state
+        .core
+        .stores
+        .birthday_role
+        .save(
+            member.user.id.to_string(),
+            member.guild_id.to_string(),
+            role_id,
+            expires_at,
+        )
+        .await?;
     Ok(())
 }
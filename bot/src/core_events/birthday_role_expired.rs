@@ -0,0 +1,25 @@
+use crate::error::BotResult;
+use crate::state::BotState;
+use neobabu_core::events::birthday_role_expired::BirthdayRoleExpired;
+use poise::serenity_prelude::{Context, GuildId, RoleId, UserId};
+use tracing::warn;
+
+pub async fn handle(ctx: &Context, _state: &BotState, event: BirthdayRoleExpired) -> BotResult<()> {
+    let guild_id = GuildId::new(event.guild_id.parse()?);
+    let user_id = UserId::new(event.user_id.parse()?);
+    let role_id = RoleId::new(event.role_id.parse()?);
+
+    let Ok(member) = guild_id.member(ctx, user_id).await else {
+        return Ok(());
+    };
+
+    if !member.roles.contains(&role_id) {
+        return Ok(());
+    }
+
+    if let Err(err) = member.remove_role(ctx, role_id).await {
+        warn!("Failed to remove birthday role '{role_id}' in guild '{guild_id}': {err}")
+    }
+
+    Ok(())
+}
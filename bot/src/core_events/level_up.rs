@@ -0,0 +1,62 @@
+use crate::error::BotResult;
+use crate::state::BotState;
+use crate::ui::emoji::EmojiType;
+use neobabu_core::events::level_up::LevelUp;
+use poise::serenity_prelude::{
+    ChannelId, Context, CreateAllowedMentions, CreateMessage, GuildId, RoleId, UserId,
+};
+use tracing::warn;
+
+pub async fn handle(ctx: &Context, state: &BotState, event: LevelUp) -> BotResult<()> {
+    let guild_id = GuildId::new(event.guild_id.parse()?);
+    let user_id = UserId::new(event.user_id.parse()?);
+
+    let Ok(member) = guild_id.member(ctx, user_id).await else {
+        return Ok(());
+    };
+
+    let mut granted_roles = Vec::new();
+    for role_id in event.reward_role_ids.iter() {
+        let role_id = RoleId::new(role_id.parse()?);
+        if member.roles.contains(&role_id) {
+            continue;
+        }
+
+        match member.add_role(ctx, role_id).await {
+            Ok(_) => granted_roles.push(role_id),
+            Err(err) => {
+                warn!("Failed to grant level reward role '{role_id}' in guild '{guild_id}': {err}")
+            }
+        }
+    }
+
+    let Some(channel_id) = event.channel_id else {
+        return Ok(());
+    };
+    let channel_id = ChannelId::new(channel_id.parse()?);
+
+    let sparkle = state.get_emoji_text(EmojiType::Sparkle);
+    let mut message = format!(
+        "{sparkle} <@{user_id}> reached **level `{}`**!",
+        event.level
+    );
+    if !granted_roles.is_empty() {
+        let roles = granted_roles
+            .iter()
+            .map(|role_id| format!("<@&{role_id}>"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        message.push_str(&format!("\nUnlocked: {roles}"));
+    }
+
+    channel_id
+        .send_message(
+            ctx,
+            CreateMessage::new()
+                .content(message)
+                .allowed_mentions(CreateAllowedMentions::new().users([user_id])),
+        )
+        .await?;
+
+    Ok(())
+}
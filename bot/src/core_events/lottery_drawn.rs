@@ -0,0 +1,57 @@
+use crate::error::BotResult;
+use crate::state::BotState;
+use crate::ui::color::UiColor;
+use crate::ui::emoji::EmojiType;
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::time::format_time_relative_at;
+use neobabu_core::events::lottery_drawn::LotteryDrawn;
+use poise::serenity_prelude::{ChannelId, Context, CreateEmbed, CreateMessage};
+
+pub async fn handle(ctx: &Context, state: &BotState, event: LotteryDrawn) -> BotResult<()> {
+    let Some(channel_id) = event.channel_id else {
+        return Ok(());
+    };
+    let channel_id = ChannelId::new(channel_id.parse()?);
+    let citrine = state.get_emoji_text(EmojiType::Citrine);
+
+    let description = match (&event.winner_id, event.winning_ticket) {
+        (Some(winner_id), Some(ticket)) => format!(
+            "<@{winner_id}> won **`{}`** {citrine} with ticket **`#{}`** out of `{}`!",
+            event.payout,
+            ticket + 1,
+            event.total_tickets
+        ),
+        _ => format!(
+            "Nobody bought a ticket, the jackpot of **`{}`** {citrine} carries over!",
+            event.next_jackpot
+        ),
+    };
+
+    let mut embed = CreateEmbed::default()
+        .ui_color(UiColor::Yellow)
+        .title("LOTTERY DRAW")
+        .description(description)
+        .field("Pot", format!("**`{}`** {citrine}", event.pot), true);
+
+    if event.sink > 0 {
+        embed = embed.field("Burned", format!("**`{}`** {citrine}", event.sink), true);
+    }
+    if let Some(next_draw_at) = event.next_draw_at {
+        embed = embed.field("Next Draw", format_time_relative_at(next_draw_at), true);
+    }
+
+    embed = embed
+        .field("Seed", format!("`{}`", event.seed), false)
+        .field(
+            "Seed Hash (SHA-256)",
+            format!("`{}`", event.seed_hash),
+            false,
+        )
+        .footer_text(format!("Round {}", event.round_id));
+
+    channel_id
+        .send_message(ctx, CreateMessage::new().embed(embed))
+        .await?;
+
+    Ok(())
+}
@@ -12,12 +12,18 @@ pub type BotResult<T> = Result<T, BotError>;
 
 #[derive(Debug, thiserror::Error)]
 pub enum BotError {
+    #[error("Amount must be greater than zero.")]
+    AmountNotPositive,
+    #[error("The big blind can be at most a tenth of the buy-in.")]
+    BigBlindTooHigh,
     #[error("{0}")]
     Core(#[from] neobabu_core::error::CoreError),
     #[error("Error reading environment variable: {0}")]
     Env(#[from] std::env::VarError),
     #[error("Farm not found.")]
     FarmNotFound,
+    #[error("No finished game with this id was found.")]
+    GameNotFound,
     #[error("This command can only be used in a guild.")]
     GuildCommandOnly,
     #[error("You do not have enough {0:?} to execute this command.")]
@@ -40,6 +46,8 @@ pub enum BotError {
     TargetYourself,
     #[error("Error deserializing TOML: {0}")]
     TomlDeserialize(#[from] toml::de::Error),
+    #[error("You cannot wager against the bot.")]
+    WagerAgainstBot,
     #[error("Wager must be greater than zero.")]
     WagerZero,
 }
@@ -48,12 +56,16 @@ impl BotError {
     pub fn is_user_error(&self) -> bool {
         match self {
             Self::Core(error) => error.is_user_error(),
-            Self::FarmNotFound
+            Self::AmountNotPositive
+            | Self::BigBlindTooHigh
+            | Self::FarmNotFound
+            | Self::GameNotFound
             | Self::GuildCommandOnly
             | Self::InsufficientFunds(_)
             | Self::InvalidTimezone(_)
             | Self::TargetBotOrYourself
             | Self::TargetYourself
+            | Self::WagerAgainstBot
             | Self::WagerZero => true,
             Self::Env(_)
             | Self::Io(_)
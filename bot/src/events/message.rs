@@ -15,16 +15,12 @@ pub async fn handle(
     };
     let user_id = message.author.id.to_string();
 
-    let user = state.core.stores.user.fetch_or_create(user_id).await?;
-    let guild = state.core.stores.guild.fetch_or_create(guild_id).await?;
-    let _user_guild = state
+    // Only buffered in memory, the leveling flush job also registers the user guild membership
+    state
         .core
-        .stores
-        .user_guild
-        .fetch_or_create(&user, &guild)
-        .await?;
-
-    // For now this is just for registering which guilds a user actually interacted with
+        .services
+        .leveling
+        .record_message(&user_id, &guild_id, &message.content);
 
     Ok(())
 }
@@ -51,6 +51,7 @@ async fn main() {
         .setup(move |ctx, _ready, _framework| {
             Box::pin(async move {
                 state.core.start_jobs().await?;
+                ui::games::void_active_games(ctx, &state).await?;
                 let event_rx = state.core.event_bus.subscribe();
                 core_events::listen(ctx.clone(), state.clone(), event_rx).await;
                 Ok(state)
@@ -1,9 +1,9 @@
 use crate::config::BotConfig;
 use crate::error::BotResult;
 use crate::ui::emoji::EmojiType;
+use neobabu_core::NeobabuCore;
 use neobabu_core::config::Config;
 use neobabu_core::rendering::o2d::prelude::O2DRenderer;
-use neobabu_core::NeobabuCore;
 use poise::serenity_prelude::{EmojiId, ReactionType};
 use std::sync::Arc;
 use tracing::info;
@@ -64,8 +64,12 @@ fn load_core_config() -> BotResult<Config> {
     let youtube_api_key = std::env::var("YOUTUBE_API_KEY")?;
     let youtube_hub_callback_url = std::env::var("YOUTUBE_HUB_CALLBACK_URL")?;
     let youtube_hub_secret = std::env::var("YOUTUBE_HUB_SECRET")?;
+    let birthday_notification_hour = std::env::var("BIRTHDAY_NOTIFICATION_HOUR")
+        .ok()
+        .and_then(|hour| hour.parse().ok());
     Ok(Config {
         db_url,
+        birthday_notification_hour,
         nasa_api_key: Some(nasa_api_key),
         youtube_api_key: Some(youtube_api_key),
         youtube_hub_callback_url: Some(youtube_hub_callback_url),
@@ -4,8 +4,10 @@ use neobabu_core::games::farming::season::Season;
 #[derive(Debug, Copy, Clone)]
 pub enum UiColor {
     Lime,
+    #[allow(dead_code)]
     LightGray,
     Gray,
+    #[allow(dead_code)]
     DarkGray,
     LightRed,
     Orange,
@@ -13,6 +15,7 @@ pub enum UiColor {
     Pink,
     Nasa,
     Youtube,
+    #[allow(dead_code)]
     Config,
     Success,
     Warning,
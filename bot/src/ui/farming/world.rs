@@ -16,9 +16,7 @@ pub async fn show_world(ctx: &Context<'_>, world: farming_world::Model) -> BotRe
     let season = data.current_season();
     let season_emoji = ctx.emoji_text(EmojiType::from(season));
     let time = humane_time(data.current_time());
-    let time_info = format!("{season_emoji} **`{season}`** | {time}");
-
-    let description = format!("{time_info}");
+    let description = format!("{season_emoji} **`{season}`** | {time}");
 
     let attachment = CreateAttachment::bytes(png_bytes, "world.png");
     let embed = CreateEmbed::new()
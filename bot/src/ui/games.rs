@@ -1,2 +1,97 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::state::BotState;
+use crate::ui::color::UiColor;
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use neobabu_core::games::persistence::PersistentGame;
+use poise::serenity_prelude as serenity;
+use poise::serenity_prelude::{
+    ChannelId, CreateEmbed, EditAttachments, EditMessage, Message, MessageId,
+};
+use std::fmt::Display;
+use tracing::{info, warn};
+
 pub mod blackjack;
+pub mod connect4;
+pub mod game2048;
+pub mod minesweeper;
+pub mod poker;
+pub mod replay;
 pub mod rps;
+pub mod turn_based;
+
+/// Points players of a finished game to its replay.
+pub fn replay_footer(game_id: impl Display) -> String {
+    format!("Replay with /game replay {game_id}")
+}
+
+/// Keeps the stored state of a game in sync with its message, see [`InteractiveState::persist`].
+///
+/// [`InteractiveState::persist`]: crate::ui::message::interactive::state::InteractiveState::persist
+pub async fn persist_game<G: PersistentGame>(
+    ctx: &Context<'_>,
+    game: &G,
+    message: &Message,
+    finished: bool,
+) -> BotResult<()> {
+    let active_game = &ctx.services().active_game;
+    if finished {
+        active_game.remove(game).await?;
+    } else {
+        active_game
+            .save(game, message.channel_id.to_string(), message.id.to_string())
+            .await?;
+    }
+    Ok(())
+}
+
+/// Running games cannot be resumed after a restart, as their interactions ended with the previous
+/// process. They are voided instead, their wagers refunded and their messages updated.
+pub async fn void_active_games(ctx: &serenity::Context, state: &BotState) -> BotResult<()> {
+    let games = state.core.services.active_game.void_all().await?;
+    if games.is_empty() {
+        return Ok(());
+    }
+
+    for game in &games {
+        let (Ok(channel_id), Ok(message_id)) = (
+            game.channel_id.parse::<u64>(),
+            game.message_id.parse::<u64>(),
+        ) else {
+            continue;
+        };
+
+        let title = game
+            .kind()
+            .map(|kind| kind.to_string().to_uppercase())
+            .unwrap_or_else(|| "GAME".to_string());
+        let embed = CreateEmbed::new()
+            .ui_color(UiColor::Gray)
+            .title(format!("{title} | VOIDED"))
+            .description(
+                "This game was voided because the bot restarted. All wagers have been refunded.",
+            );
+
+        let edit = EditMessage::new()
+            .content("")
+            .embed(embed)
+            .components(vec![])
+            .attachments(EditAttachments::new());
+        if let Err(err) = ChannelId::new(channel_id)
+            .edit_message(ctx, MessageId::new(message_id), edit)
+            .await
+        {
+            warn!(
+                "Failed to update the message of voided game '{}': {err}",
+                game.id
+            );
+        }
+    }
+
+    info!(
+        "Voided {} games which were running before the restart",
+        games.len()
+    );
+    Ok(())
+}
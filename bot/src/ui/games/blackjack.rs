@@ -1,21 +1,55 @@
 use crate::context::ContextExt;
 use crate::error::BotResult;
 use crate::ui::emoji::EmojiType;
+use crate::ui::games::{persist_game, replay_footer};
+use crate::ui::message::CreateEmbedExt;
 use crate::ui::message::interactive::state::{InteractiveState, InteractiveStateResponse};
 use crate::ui::time::format_time_relative_at;
 use crate::Context;
-use neobabu_core::games::blackjack::{BlackjackGame, BlackjackMove, BlackjackOutcome};
-use neobabu_core::games::playing_cards::PlayingCardDeck;
+use neobabu_core::error::CoreError;
+use neobabu_core::games::blackjack::rules::BlackjackRules;
+use neobabu_core::games::blackjack::table::BlackjackTable;
+use neobabu_core::games::blackjack::{
+    BlackjackGame, BlackjackHand, BlackjackMove, BlackjackOutcome,
+};
 use poise::serenity_prelude::{
-    ButtonStyle, Color, ComponentInteraction, CreateActionRow, CreateButton, CreateEmbed,
+    ButtonStyle, Color, ComponentInteraction, CreateActionRow, CreateAttachment, CreateButton,
+    CreateEmbed, Message,
 };
 use std::collections::HashMap;
 use std::ops::Add;
 
+/// Summarizes house rules, e.g. for the waiting screen and the rules command.
+pub fn format_rules(rules: &BlackjackRules) -> String {
+    let decks = if rules.decks == 1 {
+        "`1` deck".to_string()
+    } else {
+        format!("`{}` decks", rules.decks)
+    };
+    let soft_17 = if rules.dealer_hits_soft_17 {
+        "dealer hits soft 17"
+    } else {
+        "dealer stands on soft 17"
+    };
+    let (numerator, denominator) = rules.blackjack_payout;
+    let surrender = if rules.surrender {
+        "surrender allowed"
+    } else {
+        "no surrender"
+    };
+
+    format!(
+        "{decks} · {soft_17} · blackjack pays `{numerator}:{denominator}` · {surrender} · up to `{}` players",
+        rules.max_players
+    )
+}
+
 pub struct BlackjackUi {
     pub game: BlackjackGame,
     pub dealer_emoji: EmojiType,
     pub player_emoji: HashMap<String, EmojiType>,
+    /// Display names for the table image, which cannot render mentions.
+    pub player_names: HashMap<String, String>,
     pub starts_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -25,6 +59,7 @@ impl Default for BlackjackUi {
             game: BlackjackGame::new(),
             dealer_emoji: EmojiType::random_professional(),
             player_emoji: HashMap::new(),
+            player_names: HashMap::new(),
             starts_at: chrono::Utc::now().add(chrono::Duration::seconds(20)),
         }
     }
@@ -40,64 +75,81 @@ impl BlackjackUi {
         )
     }
 
-    fn format_deck(&self, ctx: &Context, deck: &PlayingCardDeck) -> String {
-        let mut result = String::new();
-        for card in deck.iter_cards() {
-            result.push_str(&ctx.emoji_text(card.into()));
-        }
-        result
-    }
-
     fn format_dealer(&self, ctx: &Context) -> String {
         let emoji = ctx.emoji_text(self.dealer_emoji);
 
         if !self.game.has_started() {
             format!("{emoji} **`Dealer`** *is waiting...*")
+        } else if !self.game.is_over() {
+            // The hole card stays hidden until all players are done.
+            let up_card = self.game.dealer_up_card();
+            let score = up_card.map(|card| card.score()).unwrap_or(0);
+            format!("{emoji} **`Dealer`** **`{score}+?`**")
         } else {
             let score = self.game.dealer.score();
-            let deck = self.format_deck(ctx, self.game.dealer.deck());
-            format!("{emoji} **`Dealer`** **`{score}`** {deck}")
+            format!("{emoji} **`Dealer`** **`{score}`**")
+        }
+    }
+
+    fn format_hand(&self, hand: &BlackjackHand) -> String {
+        format!("**`{}`**", hand.score())
+    }
+
+    fn format_hand_status(&self, id: &str, index: usize, hand: &BlackjackHand) -> String {
+        let is_current = self
+            .game
+            .players
+            .get(id)
+            .is_some_and(|player| player.current_hand == index);
+
+        if self.game.is_to_play(id) && is_current {
+            self.player_to_move_till()
+                .map(|time| format!(" | **AUTO-STAND** {}", format_time_relative_at(time)))
+                .unwrap_or_default()
+        } else if hand.surrendered {
+            " | **SURRENDERED**".to_string()
+        } else if hand.is_bust() {
+            " | **BUST**".to_string()
+        } else if hand.doubled {
+            " | **DOUBLED**".to_string()
+        } else if hand.standing {
+            " | **STANDING**".to_string()
+        } else {
+            " | *waiting...*".to_string()
         }
     }
 
     fn format_player(&self, ctx: &Context, id: impl AsRef<str>) -> String {
+        let id = id.as_ref();
         let emoji_type = self
             .player_emoji
-            .get(id.as_ref())
+            .get(id)
             .unwrap_or(&EmojiType::FaceBeamingSmile);
         let emoji = ctx.emoji_text(*emoji_type);
 
-        if !self.game.has_started() {
-            format!("{emoji} <@{}> *is waiting...*", id.as_ref())
-        } else {
-            let score = self
-                .game
-                .players
-                .get(id.as_ref())
-                .map(|p| p.score())
-                .unwrap_or(0);
-            let deck = self
-                .game
-                .players
-                .get(id.as_ref())
-                .map(|p| self.format_deck(ctx, p.deck()))
-                .unwrap_or_default();
+        let Some(player) = self
+            .game
+            .players
+            .get(id)
+            .filter(|_| self.game.has_started())
+        else {
+            return format!("{emoji} <@{id}> *is waiting...*");
+        };
 
-            let base_text = format!("{emoji} <@{}> **`{score}`** {deck}", id.as_ref());
-            if self.game.is_to_play(id.as_ref()) {
-                let to_move_till = self
-                    .player_to_move_till()
-                    .map(|time| format!(" | **AUTO-STAND** {}", format_time_relative_at(time)))
-                    .unwrap_or_default();
-                format!("{base_text}{to_move_till}")
-            } else if self.game.is_bust(id.as_ref()) {
-                format!("{base_text} | **BUST**")
-            } else if self.game.is_stand(id.as_ref()) {
-                format!("{base_text} | **STANDING**")
-            } else {
-                format!("{base_text} | *waiting...*")
-            }
+        let insured = if player.insured { " | **INSURED**" } else { "" };
+        if let [hand] = player.hands.as_slice() {
+            let hand_text = self.format_hand(hand);
+            let status = self.format_hand_status(id, 0, hand);
+            return format!("{emoji} <@{id}> {hand_text}{status}{insured}");
         }
+
+        let mut text = format!("{emoji} <@{id}>{insured}");
+        for (index, hand) in player.hands.iter().enumerate() {
+            let hand_text = self.format_hand(hand);
+            let status = self.format_hand_status(id, index, hand);
+            text.push_str(&format!("\n> {hand_text}{status}"));
+        }
+        text
     }
 
     fn format_players(&self, ctx: &Context) -> String {
@@ -121,8 +173,10 @@ impl BlackjackUi {
     fn embed_waiting(&self, ctx: &Context) -> CreateEmbed {
         let players = self.format_players(ctx);
         let description = format!(
-            "*Game starts {}*\n\n{players}\n*4 people can join the game by clicking the button below.\nIf its your turn, you will have 20 seconds to hit or stand.\nThe game will start automatically.*",
-            format_time_relative_at(self.starts_at)
+            "*Game starts {}*\n\n{players}\n{}\n\n*{} people can join the game by clicking the button below.\nIf its your turn, you will have 20 seconds to hit, stand, double down or split.\nThe game will start automatically.*",
+            format_time_relative_at(self.starts_at),
+            format_rules(&self.game.rules),
+            self.game.rules.max_players
         );
 
         CreateEmbed::default()
@@ -137,50 +191,112 @@ impl BlackjackUi {
         CreateEmbed::default()
             .title("BLACKJACK | GAME ON")
             .description(players)
+            .image("attachment://blackjack.png")
             .color(Color::default())
     }
 
+    fn format_outcome(
+        &self,
+        ctx: &Context,
+        outcome: &BlackjackOutcome,
+        amount: Option<u32>,
+    ) -> String {
+        if let Some(amount) = amount {
+            let citrine = ctx.emoji_text(EmojiType::Citrine);
+            match outcome {
+                BlackjackOutcome::Win | BlackjackOutcome::Blackjack => {
+                    format!("**`+{amount}`** {citrine}")
+                }
+                BlackjackOutcome::Loss | BlackjackOutcome::Surrender => {
+                    format!("**`-{amount}`** {citrine}")
+                }
+                BlackjackOutcome::Push => format!("**`±0`** {citrine}"),
+            }
+        } else {
+            match outcome {
+                BlackjackOutcome::Win => "**`WON`**",
+                BlackjackOutcome::Blackjack => "**`BLACKJACK`**",
+                BlackjackOutcome::Loss => "**`LOST`**",
+                BlackjackOutcome::Push => "**`PUSHED`**",
+                BlackjackOutcome::Surrender => "**`SURRENDERED`**",
+            }
+            .to_string()
+        }
+    }
+
     fn embed_finished(&self, ctx: &Context) -> CreateEmbed {
         let outcomes = self.game.get_outcomes().unwrap_or_default();
 
         let dealer = self.format_dealer(ctx);
 
         let mut players = String::new();
-        for (user_id, outcome) in outcomes {
+        for (user_id, outcomes) in outcomes {
             let Some(player) = self.game.players.get(&user_id) else {
                 continue;
             };
 
-            let emotion = match outcome {
-                BlackjackOutcome::Win => ctx.emoji_text(EmojiType::random_winner()),
-                BlackjackOutcome::Loss => ctx.emoji_text(EmojiType::random_loser()),
-                BlackjackOutcome::Push => ctx.emoji_text(EmojiType::random_waiting()),
-            };
-            let score = player.score();
-            let deck = self.format_deck(ctx, player.deck());
-
-            let outcome = if let Some(wager) = self.game.wager {
-                match outcome {
-                    BlackjackOutcome::Win => {
-                        format!("**`+{wager}`** {}", ctx.emoji_text(EmojiType::Citrine))
-                    }
-                    BlackjackOutcome::Loss => {
-                        format!("**`-{wager}`** {}", ctx.emoji_text(EmojiType::Citrine))
-                    }
-                    BlackjackOutcome::Push => {
-                        format!("**`±0`** {}", ctx.emoji_text(EmojiType::Citrine))
-                    }
-                }
+            let wins = outcomes
+                .iter()
+                .filter(|outcome| {
+                    matches!(outcome, BlackjackOutcome::Win | BlackjackOutcome::Blackjack)
+                })
+                .count();
+            let losses = outcomes
+                .iter()
+                .filter(|outcome| {
+                    matches!(
+                        outcome,
+                        BlackjackOutcome::Loss | BlackjackOutcome::Surrender
+                    )
+                })
+                .count();
+            let emotion = if wins > losses {
+                ctx.emoji_text(EmojiType::random_winner())
+            } else if losses > wins {
+                ctx.emoji_text(EmojiType::random_loser())
             } else {
-                match outcome {
-                    BlackjackOutcome::Win => "**`WON`**",
-                    BlackjackOutcome::Loss => "**`LOST`**",
-                    BlackjackOutcome::Push => "**`PUSHED`**",
-                }
-                .to_string()
+                ctx.emoji_text(EmojiType::random_waiting())
             };
 
-            let text = format!("{emotion} <@{user_id}> **`{score}`** {deck} | {outcome}");
+            let insurance = self
+                .game
+                .get_insurance_outcome(&user_id)
+                .map(|outcome| {
+                    let stake = match outcome {
+                        BlackjackOutcome::Win => self.game.insurance_cost().map(|cost| cost * 2),
+                        _ => self.game.insurance_cost(),
+                    };
+                    format!(" | INSURANCE {}", self.format_outcome(ctx, &outcome, stake))
+                })
+                .unwrap_or_default();
+
+            let hands = player
+                .hands
+                .iter()
+                .zip(&outcomes)
+                .map(|(hand, outcome)| {
+                    let amount = self
+                        .game
+                        .wager
+                        .map(|wager| self.game.rules.settlement(hand.stake(wager), outcome));
+                    format!(
+                        "{} | {}",
+                        self.format_hand(hand),
+                        self.format_outcome(ctx, outcome, amount)
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            let text = if let [hand] = hands.as_slice() {
+                format!("{emotion} <@{user_id}> {hand}{insurance}")
+            } else {
+                let hands = hands
+                    .iter()
+                    .map(|hand| format!("> {hand}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!("{emotion} <@{user_id}>{insurance}\n{hands}")
+            };
             players.push_str(&text);
             players.push('\n');
         }
@@ -190,43 +306,79 @@ impl BlackjackUi {
         CreateEmbed::default()
             .title("BLACKJACK | FINISHED")
             .description(description)
+            .image("attachment://blackjack.png")
+            .footer_text(replay_footer(self.game.id))
             .color(Color::default())
     }
 
+    fn can_current_player_play(&self, move_: BlackjackMove) -> bool {
+        self.game
+            .current_player
+            .as_ref()
+            .is_some_and(|id| self.game.can_play(id, move_))
+    }
+
     async fn handle_join(
         &mut self,
         ctx: &Context<'_>,
         id: impl AsRef<str>,
+        name: impl Into<String>,
     ) -> BotResult<InteractiveStateResponse> {
         if !self.game.players.contains_key(id.as_ref()) {
             let user = ctx.stores().user.fetch_or_create(&id).await?;
-            ctx.services()
+            // Players with a frozen wallet cannot join, without ending the table for everyone.
+            match ctx
+                .services()
                 .blackjack
-                .register_user(&mut self.game, &user, 4)
-                .await?;
+                .register_user(&mut self.game, &user)
+                .await
+            {
+                Err(CoreError::WalletFrozen) => return Ok(InteractiveStateResponse::default()),
+                result => result?,
+            }
             self.player_emoji
                 .insert(id.as_ref().to_string(), EmojiType::random_waiting());
+            self.player_names
+                .insert(id.as_ref().to_string(), name.into());
             Ok(InteractiveStateResponse::new_update())
         } else {
             Ok(InteractiveStateResponse::default())
         }
     }
 
-    fn handle_play(
+    async fn handle_play(
         &mut self,
+        ctx: &Context<'_>,
         id: impl AsRef<str>,
         move_: BlackjackMove,
-    ) -> InteractiveStateResponse {
-        let did_play = self.game.play(id.as_ref(), move_);
+    ) -> BotResult<InteractiveStateResponse> {
+        if !self.game.can_play(id.as_ref(), move_) {
+            return Ok(InteractiveStateResponse::default());
+        }
+
+        let user = ctx.stores().user.fetch_or_create(&id).await?;
+        // A frozen wallet cannot cover a split or insurance either.
+        let did_play = match ctx
+            .services()
+            .blackjack
+            .play(&mut self.game, &user, move_)
+            .await
+        {
+            Err(CoreError::WalletFrozen) => false,
+            result => result?,
+        };
         if did_play {
-            InteractiveStateResponse::new_update()
+            Ok(InteractiveStateResponse::new_update())
         } else {
-            InteractiveStateResponse::default()
+            Ok(InteractiveStateResponse::default())
         }
     }
 
     async fn handle_finish(&self, ctx: &Context<'_>) -> BotResult<()> {
-        Ok(ctx.services().blackjack.resolve_game(&self.game).await?)
+        ctx.services().game_record.save(&self.game).await?;
+        ctx.services().blackjack.resolve_game(&self.game).await?;
+        ctx.services().blackjack.return_shoe(&self.game).await?;
+        Ok(())
     }
 }
 
@@ -248,9 +400,16 @@ impl InteractiveState for BlackjackUi {
     ) -> BotResult<InteractiveStateResponse> {
         let id = interaction.user.id.to_string();
         let response = match interaction.data.custom_id.as_str() {
-            "join_game" => self.handle_join(ctx, id).await?,
-            "play_hit" => self.handle_play(id, BlackjackMove::Hit),
-            "play_stand" => self.handle_play(id, BlackjackMove::Stand),
+            "join_game" => {
+                self.handle_join(ctx, id, interaction.user.display_name())
+                    .await?
+            }
+            "play_hit" => self.handle_play(ctx, id, BlackjackMove::Hit).await?,
+            "play_stand" => self.handle_play(ctx, id, BlackjackMove::Stand).await?,
+            "play_double" => self.handle_play(ctx, id, BlackjackMove::DoubleDown).await?,
+            "play_split" => self.handle_play(ctx, id, BlackjackMove::Split).await?,
+            "play_insurance" => self.handle_play(ctx, id, BlackjackMove::Insurance).await?,
+            "play_surrender" => self.handle_play(ctx, id, BlackjackMove::Surrender).await?,
             _ => return Ok(InteractiveStateResponse::default()),
         };
 
@@ -272,6 +431,24 @@ impl InteractiveState for BlackjackUi {
         }
     }
 
+    async fn render_attachments(&self, ctx: &Context) -> BotResult<Vec<CreateAttachment>> {
+        if !self.game.has_started() {
+            return Ok(Vec::new());
+        }
+
+        let png_bytes =
+            BlackjackTable::new(&self.game, &self.player_names).render_png(ctx.o2d())?;
+        Ok(vec![CreateAttachment::bytes(png_bytes, "blackjack.png")])
+    }
+
+    async fn persist(&self, ctx: &Context, message: &Message, finished: bool) -> BotResult<()> {
+        persist_game(ctx, &self.game, message, finished).await
+    }
+
+    async fn on_abandon(&mut self, ctx: &Context) -> BotResult<()> {
+        Ok(ctx.services().blackjack.abandon_game(&self.game).await?)
+    }
+
     async fn render_rows(&self, _ctx: &Context) -> BotResult<Vec<CreateActionRow>> {
         if !self.game.has_started() {
             let text = if let Some(wager) = self.game.wager {
@@ -286,14 +463,40 @@ impl InteractiveState for BlackjackUi {
                     .style(ButtonStyle::Success),
             ])])
         } else {
-            Ok(vec![CreateActionRow::Buttons(vec![
-                CreateButton::new("play_hit")
-                    .label("Hit")
-                    .style(ButtonStyle::Success),
-                CreateButton::new("play_stand")
-                    .label("Stand")
-                    .style(ButtonStyle::Danger),
-            ])])
+            let mut extra = vec![
+                CreateButton::new("play_insurance")
+                    .label("Insurance")
+                    .style(ButtonStyle::Secondary)
+                    .disabled(!self.can_current_player_play(BlackjackMove::Insurance)),
+            ];
+            if self.game.rules.surrender {
+                extra.push(
+                    CreateButton::new("play_surrender")
+                        .label("Surrender")
+                        .style(ButtonStyle::Secondary)
+                        .disabled(!self.can_current_player_play(BlackjackMove::Surrender)),
+                );
+            }
+
+            Ok(vec![
+                CreateActionRow::Buttons(vec![
+                    CreateButton::new("play_hit")
+                        .label("Hit")
+                        .style(ButtonStyle::Success),
+                    CreateButton::new("play_stand")
+                        .label("Stand")
+                        .style(ButtonStyle::Danger),
+                    CreateButton::new("play_double")
+                        .label("Double Down")
+                        .style(ButtonStyle::Primary)
+                        .disabled(!self.can_current_player_play(BlackjackMove::DoubleDown)),
+                    CreateButton::new("play_split")
+                        .label("Split")
+                        .style(ButtonStyle::Primary)
+                        .disabled(!self.can_current_player_play(BlackjackMove::Split)),
+                ]),
+                CreateActionRow::Buttons(extra),
+            ])
         }
     }
 
@@ -0,0 +1,443 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::color::UiColor;
+use crate::ui::emoji::EmojiType;
+use crate::ui::games::turn_based::TurnBasedRules;
+use crate::ui::games::{persist_game, replay_footer};
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::message::interactive::state::{InteractiveState, InteractiveStateResponse};
+use crate::ui::time::format_time_relative_at;
+use crate::Context;
+use chrono::{DateTime, Utc};
+use neobabu_core::error::CoreError;
+use neobabu_core::games::connect4::Connect4Game;
+use neobabu_core::games::connect4::coords::Connect4Coords;
+use poise::serenity_prelude::{
+    ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton, CreateEmbed, Message,
+};
+
+const TURN_SECONDS: i64 = 60;
+const ACCEPT_SECONDS: i64 = 120;
+const COLUMN_LABELS: [&str; 7] = ["1️⃣", "2️⃣", "3️⃣", "4️⃣", "5️⃣", "6️⃣", "7️⃣"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connect4Challenge {
+    Pending,
+    Accepted,
+    Declined,
+    Expired,
+    Unaffordable,
+}
+
+pub struct Connect4Ui {
+    pub game: Connect4Game,
+    pub challenger_id: String,
+    pub opponent_id: String,
+    pub challenge: Connect4Challenge,
+    pub accept_until: DateTime<Utc>,
+    pub turn_until: DateTime<Utc>,
+}
+
+impl Connect4Ui {
+    /// Games against the AI do not need to be accepted and start immediately.
+    pub fn new(game: Connect4Game, challenger_id: String, opponent_id: String) -> Self {
+        let now = Utc::now();
+        let mut ui = Self {
+            game,
+            challenger_id,
+            opponent_id,
+            challenge: Connect4Challenge::Pending,
+            accept_until: now + chrono::Duration::seconds(ACCEPT_SECONDS),
+            turn_until: now + chrono::Duration::seconds(TURN_SECONDS),
+        };
+
+        if ui.game.ai_player.is_some() {
+            ui.accept();
+        }
+
+        ui
+    }
+
+    fn accept(&mut self) {
+        self.challenge = Connect4Challenge::Accepted;
+        self.play_ai_if_to_play();
+        self.turn_until = Utc::now() + chrono::Duration::seconds(TURN_SECONDS);
+    }
+
+    fn play_ai_if_to_play(&mut self) {
+        if self.game.is_ai_to_play() {
+            // The harder difficulties search for up to a few seconds.
+            tokio::task::block_in_place(|| self.game.play_ai());
+        }
+    }
+
+    fn disc(&self, player_1: bool) -> &'static str {
+        if player_1 { "🔴" } else { "🟡" }
+    }
+
+    fn format_player(&self, ctx: &Context, player_1: bool) -> String {
+        let id = if player_1 {
+            &self.game.player_1
+        } else {
+            &self.game.player_2
+        };
+
+        let face = if self.game.is_ai(id) {
+            EmojiType::FaceRobot
+        } else {
+            match self.game.winner() {
+                Some(winner) if winner == player_1 => EmojiType::random_winner(),
+                Some(_) => EmojiType::random_loser(),
+                None if self.game.is_draw() => EmojiType::random_loser(),
+                None => EmojiType::FaceThinking,
+            }
+        };
+
+        format!("{} {} <@{id}>", self.disc(player_1), ctx.emoji_text(face))
+    }
+
+    fn format_wager(&self, ctx: &Context) -> String {
+        if let Some(wager) = self.game.wager {
+            format!(
+                "**WAGER: `{wager}`** {}\n\n",
+                ctx.emoji_text(EmojiType::Citrine)
+            )
+        } else {
+            "".to_string()
+        }
+    }
+
+    fn format_status(&self, ctx: &Context) -> String {
+        match self.challenge {
+            Connect4Challenge::Pending => {
+                return format!(
+                    "*<@{}> has to accept the challenge {}*",
+                    self.opponent_id,
+                    format_time_relative_at(self.accept_until)
+                );
+            }
+            Connect4Challenge::Declined => {
+                return "**The challenge was declined.**".to_string();
+            }
+            Connect4Challenge::Expired => {
+                return "**The challenge was not accepted in time.**".to_string();
+            }
+            Connect4Challenge::Unaffordable => {
+                return format!("**<@{}> cannot cover the wager.**", self.opponent_id);
+            }
+            Connect4Challenge::Accepted => {}
+        }
+
+        if let Some(winner_id) = self.game.winner_id() {
+            let forfeit = if self.game.forfeited_by.is_some() {
+                " *(opponent ran out of time)*"
+            } else {
+                ""
+            };
+            let payout = if let Some(wager) = self.game.wager {
+                format!(" **`+{wager}`** {}", ctx.emoji_text(EmojiType::Citrine))
+            } else {
+                "".to_string()
+            };
+            format!(
+                "{} <@{winner_id}> **wins!**{payout}{forfeit}",
+                ctx.emoji_text(EmojiType::Trophy)
+            )
+        } else if self.game.is_draw() {
+            format!("**{} It's a draw!**", ctx.emoji_text(EmojiType::Pvp))
+        } else {
+            format!(
+                "{} <@{}> to play | **AUTO-FORFEIT** {}",
+                self.disc(self.game.state.is_1_to_play),
+                self.game.player_to_play(),
+                format_time_relative_at(self.turn_until)
+            )
+        }
+    }
+
+    fn build_color(&self) -> UiColor {
+        match self.challenge {
+            Connect4Challenge::Accepted => {}
+            Connect4Challenge::Pending => return UiColor::Pink,
+            _ => return UiColor::Gray,
+        }
+
+        if self.game.winner().is_some() {
+            UiColor::Success
+        } else if self.game.is_draw() {
+            UiColor::Warning
+        } else {
+            UiColor::Pink
+        }
+    }
+
+    async fn handle_accept(
+        &mut self,
+        ctx: &Context<'_>,
+        user_id: String,
+    ) -> BotResult<InteractiveStateResponse> {
+        if self.challenge != Connect4Challenge::Pending || user_id != self.opponent_id {
+            return Ok(InteractiveStateResponse::new());
+        }
+
+        let user = ctx.stores().user.fetch_or_create(&user_id).await?;
+        // A frozen wallet cannot cover the wager either.
+        let reserved = match ctx
+            .services()
+            .connect4
+            .reserve_wager(&self.game, &user)
+            .await
+        {
+            Err(CoreError::WalletFrozen) => false,
+            result => result?,
+        };
+        if !reserved {
+            ctx.services().connect4.cancel_wagers(&self.game).await?;
+            self.challenge = Connect4Challenge::Unaffordable;
+            return Ok(InteractiveStateResponse::new_halt());
+        }
+
+        self.accept();
+        Ok(InteractiveStateResponse::new_update())
+    }
+
+    async fn handle_decline(
+        &mut self,
+        ctx: &Context<'_>,
+        user_id: String,
+    ) -> BotResult<InteractiveStateResponse> {
+        if self.challenge != Connect4Challenge::Pending
+            || (user_id != self.opponent_id && user_id != self.challenger_id)
+        {
+            return Ok(InteractiveStateResponse::new());
+        }
+
+        ctx.services().connect4.cancel_wagers(&self.game).await?;
+        self.challenge = Connect4Challenge::Declined;
+        Ok(InteractiveStateResponse::new_halt())
+    }
+
+    async fn handle_play(
+        &mut self,
+        ctx: &Context<'_>,
+        user_id: String,
+        col: u8,
+    ) -> BotResult<InteractiveStateResponse> {
+        if self.challenge != Connect4Challenge::Accepted || !self.game.play(user_id, col) {
+            return Ok(InteractiveStateResponse::new());
+        }
+
+        self.play_ai_if_to_play();
+        self.turn_until = Utc::now() + chrono::Duration::seconds(TURN_SECONDS);
+
+        if self.game.is_over() {
+            self.handle_finish(ctx).await?;
+            return Ok(InteractiveStateResponse::new_halt());
+        }
+
+        Ok(InteractiveStateResponse::new_update())
+    }
+
+    async fn handle_finish(&self, ctx: &Context<'_>) -> BotResult<()> {
+        ctx.services().game_record.save(&self.game).await?;
+        ctx.services().game_rating.record(&self.game).await?;
+        Ok(ctx.services().connect4.resolve_game(&self.game).await?)
+    }
+}
+
+pub fn format_board(game: &Connect4Game) -> String {
+    let board = &game.state.board;
+    let last_move = game.moves.last().copied();
+
+    let mut text = String::new();
+    for row in (0..6).rev() {
+        for col in 0..7 {
+            let coords = Connect4Coords::from_row_col(row, col);
+            let is_last = last_move == Some(col) && board.heights[col as usize] == row + 1;
+            let symbol = if board.player_1.is_set(coords) {
+                if is_last { "❤️" } else { "🔴" }
+            } else if board.player_2.is_set(coords) {
+                if is_last { "💛" } else { "🟡" }
+            } else {
+                "⚫"
+            };
+            text.push_str(symbol);
+        }
+        text.push('\n');
+    }
+    text.push_str(&COLUMN_LABELS.concat());
+    text
+}
+
+fn column_rows(game: &Connect4Game) -> Vec<CreateActionRow> {
+    let buttons = (0..7u8)
+        .map(|col| {
+            CreateButton::new(format!("c4_col_{col}"))
+                .label(format!("{}", col + 1))
+                .style(ButtonStyle::Secondary)
+                .disabled(!game.state.can_play(col))
+        })
+        .collect::<Vec<_>>();
+    let (first, second) = buttons.split_at(4);
+
+    vec![
+        CreateActionRow::Buttons(first.to_vec()),
+        CreateActionRow::Buttons(second.to_vec()),
+    ]
+}
+
+#[async_trait::async_trait]
+impl InteractiveState for Connect4Ui {
+    async fn handle_interaction(
+        &mut self,
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+    ) -> BotResult<InteractiveStateResponse> {
+        let user_id = interaction.user.id.to_string();
+        let custom_id = interaction.data.custom_id.as_str();
+
+        if let Some(col) = custom_id.strip_prefix("c4_col_") {
+            let Ok(col) = col.parse::<u8>() else {
+                return Ok(InteractiveStateResponse::new());
+            };
+            return self.handle_play(ctx, user_id, col).await;
+        }
+
+        match custom_id {
+            "c4_accept" => self.handle_accept(ctx, user_id).await,
+            "c4_decline" => self.handle_decline(ctx, user_id).await,
+            _ => Ok(InteractiveStateResponse::new()),
+        }
+    }
+
+    async fn render_content(&self, _ctx: &Context) -> BotResult<Option<String>> {
+        if self.challenge == Connect4Challenge::Pending {
+            Ok(Some(format!(
+                "**<@{}>, you were challenged to a game of Connect Four by <@{}>!**",
+                self.opponent_id, self.challenger_id
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn render_embed(&self, ctx: &Context) -> BotResult<CreateEmbed> {
+        let description = format!(
+            "{}{}\n{}\n\n{}\n\n{}",
+            self.format_wager(ctx),
+            self.format_player(ctx, true),
+            self.format_player(ctx, false),
+            format_board(&self.game),
+            self.format_status(ctx),
+        );
+
+        let embed = CreateEmbed::new()
+            .title("Connect Four")
+            .ui_color(self.build_color())
+            .description(description);
+        if self.game.is_over() {
+            Ok(embed.footer_text(replay_footer(self.game.id)))
+        } else {
+            Ok(embed)
+        }
+    }
+
+    async fn persist(&self, ctx: &Context, message: &Message, finished: bool) -> BotResult<()> {
+        persist_game(ctx, &self.game, message, finished).await
+    }
+
+    async fn on_abandon(&mut self, ctx: &Context) -> BotResult<()> {
+        match self.challenge {
+            Connect4Challenge::Pending => {}
+            Connect4Challenge::Accepted if !self.game.is_over() => {}
+            _ => return Ok(()),
+        }
+        Ok(ctx.services().connect4.cancel_wagers(&self.game).await?)
+    }
+
+    async fn render_rows(&self, _ctx: &Context) -> BotResult<Vec<CreateActionRow>> {
+        match self.challenge {
+            Connect4Challenge::Pending => {
+                let accept_text = if let Some(wager) = self.game.wager {
+                    format!("Accept ({wager} Citrine)")
+                } else {
+                    "Accept".to_string()
+                };
+
+                Ok(vec![CreateActionRow::Buttons(vec![
+                    CreateButton::new("c4_accept")
+                        .label(accept_text)
+                        .style(ButtonStyle::Success),
+                    CreateButton::new("c4_decline")
+                        .label("Decline")
+                        .style(ButtonStyle::Danger),
+                ])])
+            }
+            Connect4Challenge::Accepted if !self.game.is_over() => Ok(column_rows(&self.game)),
+            _ => Ok(vec![]),
+        }
+    }
+
+    async fn on_tick(&mut self, ctx: &Context) -> BotResult<InteractiveStateResponse> {
+        let now = Utc::now();
+
+        match self.challenge {
+            Connect4Challenge::Pending if now >= self.accept_until => {
+                ctx.services().connect4.cancel_wagers(&self.game).await?;
+                self.challenge = Connect4Challenge::Expired;
+                Ok(InteractiveStateResponse::new_halt())
+            }
+            Connect4Challenge::Accepted if !self.game.is_over() && now >= self.turn_until => {
+                self.game.forfeit();
+                self.handle_finish(ctx).await?;
+                Ok(InteractiveStateResponse::new_halt())
+            }
+            _ => Ok(InteractiveStateResponse::new()),
+        }
+    }
+}
+
+/// Connect Four through an open lobby, where the first two players to join and get ready play
+/// each other.
+pub struct Connect4Rules;
+
+#[async_trait::async_trait]
+impl TurnBasedRules for Connect4Rules {
+    type Game = Connect4Game;
+
+    const TITLE: &'static str = "Connect Four";
+    const KEY: &'static str = "c4-lobby";
+    const MIN_PLAYERS: usize = 2;
+    const MAX_PLAYERS: usize = 2;
+
+    fn create_game(&self, players: &[String]) -> Connect4Game {
+        Connect4Game::new(&players[0], &players[1])
+    }
+
+    fn render_board(&self, _ctx: &Context, game: &Connect4Game) -> String {
+        format!(
+            "🔴 <@{}>\n🟡 <@{}>\n\n{}",
+            game.player_1,
+            game.player_2,
+            format_board(game)
+        )
+    }
+
+    fn render_moves(&self, game: &Connect4Game) -> Vec<CreateActionRow> {
+        column_rows(game)
+    }
+
+    fn parse_move(&self, custom_id: &str) -> Option<u8> {
+        custom_id.strip_prefix("c4_col_")?.parse().ok()
+    }
+
+    async fn finish(&self, ctx: &Context, game: &Connect4Game) -> BotResult<()> {
+        ctx.services().game_record.save(game).await?;
+        ctx.services().game_rating.record(game).await?;
+        Ok(ctx.services().connect4.resolve_game(game).await?)
+    }
+
+    fn replay_id(&self, game: &Connect4Game) -> Option<String> {
+        Some(game.id.to_string())
+    }
+}
@@ -0,0 +1,176 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::color::UiColor;
+use crate::ui::emoji::EmojiType;
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::message::interactive::state::{InteractiveState, InteractiveStateResponse};
+use crate::Context;
+use neobabu_core::database::entity::{puzzle_score, user};
+use neobabu_core::games::game2048::Game2048;
+use neobabu_core::games::game2048::image::Game2048Image;
+use neobabu_core::types::grid::cardinal::Cardinal;
+use poise::serenity_prelude::{
+    ButtonStyle, ComponentInteraction, CreateActionRow, CreateAttachment, CreateButton, CreateEmbed,
+};
+
+const DIRECTIONS: [(&str, &str, Cardinal); 4] = [
+    ("2048_west", "⬅️", Cardinal::West),
+    ("2048_north", "⬆️", Cardinal::North),
+    ("2048_south", "⬇️", Cardinal::South),
+    ("2048_east", "➡️", Cardinal::East),
+];
+
+pub struct Game2048Ui {
+    pub game: Game2048,
+    pub user: user::Model,
+    /// The player's best results, updated once the game ends.
+    pub best: Option<puzzle_score::Model>,
+    pub new_best: bool,
+    /// Set once the game ended, either because no tile can move or the player stopped.
+    pub finished: bool,
+}
+
+impl Game2048Ui {
+    pub fn new(game: Game2048, user: user::Model, best: Option<puzzle_score::Model>) -> Self {
+        Self {
+            game,
+            user,
+            best,
+            new_best: false,
+            finished: false,
+        }
+    }
+
+    fn format_best(&self) -> String {
+        match self.best.as_ref().and_then(|best| best.best_score) {
+            Some(score) => format!("**`{score}`**"),
+            None => "*None yet*".to_string(),
+        }
+    }
+
+    fn format_status(&self, ctx: &Context) -> String {
+        if !self.finished {
+            return if self.game.has_won() {
+                "**You reached 2048!** Keep going for a higher score.".to_string()
+            } else {
+                "*Slide the tiles, equal tiles merge into one.*".to_string()
+            };
+        }
+
+        let new_best = if self.new_best {
+            " **New best score!**"
+        } else {
+            ""
+        };
+        format!(
+            "{} <@{}> **finished with `{}` points.**{new_best}",
+            ctx.emoji_text(if self.game.has_won() {
+                EmojiType::Trophy
+            } else {
+                EmojiType::random_loser()
+            }),
+            self.user.id,
+            self.game.score
+        )
+    }
+
+    fn build_color(&self) -> UiColor {
+        if self.game.has_won() {
+            UiColor::Success
+        } else if self.finished {
+            UiColor::Gray
+        } else {
+            UiColor::Pink
+        }
+    }
+
+    async fn handle_finish(&mut self, ctx: &Context<'_>) -> BotResult<()> {
+        self.finished = true;
+        let record = ctx
+            .services()
+            .puzzle
+            .record_2048(&self.user, &self.game)
+            .await?;
+        self.new_best = record.new_best;
+        self.best = Some(record.score);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl InteractiveState for Game2048Ui {
+    async fn handle_interaction(
+        &mut self,
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+    ) -> BotResult<InteractiveStateResponse> {
+        let custom_id = interaction.data.custom_id.as_str();
+        if custom_id == "2048_stop" {
+            self.handle_finish(ctx).await?;
+            return Ok(InteractiveStateResponse::new_halt());
+        }
+
+        let Some((_, _, direction)) = DIRECTIONS.iter().find(|(id, _, _)| *id == custom_id) else {
+            return Ok(InteractiveStateResponse::new());
+        };
+        if !self.game.slide(*direction) {
+            return Ok(InteractiveStateResponse::new());
+        }
+
+        if self.game.is_over() {
+            self.handle_finish(ctx).await?;
+            return Ok(InteractiveStateResponse::new_halt());
+        }
+        Ok(InteractiveStateResponse::new_update())
+    }
+
+    async fn render_embed(&self, ctx: &Context) -> BotResult<CreateEmbed> {
+        let description = format!(
+            "<@{}> **`{}`** points · **`{}`** moves\n\n{}",
+            self.user.id,
+            self.game.score,
+            self.game.moves,
+            self.format_status(ctx)
+        );
+
+        Ok(CreateEmbed::new()
+            .title("2048")
+            .ui_color(self.build_color())
+            .description(description)
+            .field(
+                "Highest Tile",
+                format!("**`{}`**", self.game.highest_tile()),
+                true,
+            )
+            .field("Best Score", self.format_best(), true)
+            .image("attachment://2048.png"))
+    }
+
+    async fn render_attachments(&self, ctx: &Context) -> BotResult<Vec<CreateAttachment>> {
+        let png_bytes = Game2048Image::new(&self.game).render_png(ctx.o2d())?;
+        Ok(vec![CreateAttachment::bytes(png_bytes, "2048.png")])
+    }
+
+    async fn render_rows(&self, _ctx: &Context) -> BotResult<Vec<CreateActionRow>> {
+        if self.finished {
+            return Ok(vec![]);
+        }
+
+        let mut buttons = DIRECTIONS
+            .iter()
+            .map(|(id, label, direction)| {
+                CreateButton::new(*id)
+                    .label(*label)
+                    .style(ButtonStyle::Secondary)
+                    .disabled(!self.game.can_slide(*direction))
+            })
+            .collect::<Vec<_>>();
+        buttons.push(
+            CreateButton::new("2048_stop")
+                .label("Stop")
+                .style(ButtonStyle::Danger),
+        );
+
+        Ok(vec![CreateActionRow::Buttons(buttons)])
+    }
+}
@@ -0,0 +1,242 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::color::UiColor;
+use crate::ui::emoji::EmojiType;
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::message::interactive::state::{InteractiveState, InteractiveStateResponse};
+use crate::ui::time::{format_stopwatch, format_time_relative_at};
+use crate::Context;
+use chrono::Utc;
+use neobabu_core::database::entity::{puzzle_score, user};
+use neobabu_core::games::minesweeper::image::MinesweeperImage;
+use neobabu_core::games::minesweeper::{
+    MinesweeperGame, MinesweeperStatus, MinesweeperTileState, column_label,
+};
+use poise::serenity_prelude::{
+    ButtonStyle, ComponentInteraction, ComponentInteractionDataKind, CreateActionRow,
+    CreateAttachment, CreateButton, CreateEmbed, CreateSelectMenu, CreateSelectMenuKind,
+    CreateSelectMenuOption,
+};
+
+/// A tile is picked with a column and a row menu, then revealed or flagged with the buttons.
+pub struct MinesweeperUi {
+    pub game: MinesweeperGame,
+    pub user: user::Model,
+    /// The player's best results, updated once the game ends.
+    pub best: Option<puzzle_score::Model>,
+    pub new_best: bool,
+    pub column: Option<u8>,
+    pub row: Option<u8>,
+}
+
+impl MinesweeperUi {
+    pub fn new(
+        game: MinesweeperGame,
+        user: user::Model,
+        best: Option<puzzle_score::Model>,
+    ) -> Self {
+        Self {
+            game,
+            user,
+            best,
+            new_best: false,
+            column: None,
+            row: None,
+        }
+    }
+
+    fn selected(&self) -> Option<(u8, u8)> {
+        Some((self.column?, self.row?))
+    }
+
+    fn selected_state(&self) -> Option<MinesweeperTileState> {
+        let (x, y) = self.selected()?;
+        self.game.grid.get_tile(x, y).map(|tile| tile.state)
+    }
+
+    fn format_best(&self) -> String {
+        match self.best.as_ref().and_then(|best| best.best_time_ms) {
+            Some(ms) => format!("**`{}`**", format_stopwatch(ms)),
+            None => "*None yet*".to_string(),
+        }
+    }
+
+    fn format_status(&self, ctx: &Context) -> String {
+        let elapsed = format_stopwatch(self.game.elapsed_ms(Utc::now()));
+        match self.game.status {
+            MinesweeperStatus::Won => {
+                let new_best = if self.new_best {
+                    " **New best time!**"
+                } else {
+                    ""
+                };
+                format!(
+                    "{} <@{}> **cleared the board in `{elapsed}`!**{new_best}",
+                    ctx.emoji_text(EmojiType::Trophy),
+                    self.user.id
+                )
+            }
+            MinesweeperStatus::Lost { x, y } => format!(
+                "{} <@{}> **hit a mine on `{}{}` after `{elapsed}`.**",
+                ctx.emoji_text(EmojiType::random_loser()),
+                self.user.id,
+                column_label(x),
+                y + 1
+            ),
+            MinesweeperStatus::Playing => match self.game.started_at {
+                Some(started_at) => format!(
+                    "{} Started {}",
+                    ctx.emoji_text(EmojiType::Clock),
+                    format_time_relative_at(started_at)
+                ),
+                None => "*Pick a column and a row, the first tile you reveal is always safe.*"
+                    .to_string(),
+            },
+        }
+    }
+
+    fn build_color(&self) -> UiColor {
+        match self.game.status {
+            MinesweeperStatus::Playing => UiColor::Pink,
+            MinesweeperStatus::Won => UiColor::Success,
+            MinesweeperStatus::Lost { .. } => UiColor::Gray,
+        }
+    }
+
+    async fn handle_finish(&mut self, ctx: &Context<'_>) -> BotResult<()> {
+        let record = ctx
+            .services()
+            .puzzle
+            .record_minesweeper(&self.user, &self.game)
+            .await?;
+        self.new_best = record.new_best;
+        self.best = Some(record.score);
+        Ok(())
+    }
+
+    fn select_menu(
+        custom_id: &str,
+        placeholder: &str,
+        count: u8,
+        selected: Option<u8>,
+        label: impl Fn(u8) -> String,
+    ) -> CreateActionRow {
+        let options = (0..count)
+            .map(|index| {
+                CreateSelectMenuOption::new(label(index), index.to_string())
+                    .default_selection(selected == Some(index))
+            })
+            .collect();
+        CreateActionRow::SelectMenu(
+            CreateSelectMenu::new(custom_id, CreateSelectMenuKind::String { options })
+                .placeholder(placeholder),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl InteractiveState for MinesweeperUi {
+    async fn handle_interaction(
+        &mut self,
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+    ) -> BotResult<InteractiveStateResponse> {
+        let selected = match &interaction.data.kind {
+            ComponentInteractionDataKind::StringSelect { values } => {
+                values.first().and_then(|value| value.parse::<u8>().ok())
+            }
+            _ => None,
+        };
+
+        let changed = match (interaction.data.custom_id.as_str(), self.selected()) {
+            ("ms_column", _) => {
+                self.column = selected;
+                true
+            }
+            ("ms_row", _) => {
+                self.row = selected;
+                true
+            }
+            ("ms_reveal", Some((x, y))) => self.game.reveal(x, y),
+            ("ms_flag", Some((x, y))) => self.game.toggle_flag(x, y),
+            _ => false,
+        };
+        if !changed {
+            return Ok(InteractiveStateResponse::new());
+        }
+
+        if self.game.is_over() {
+            self.handle_finish(ctx).await?;
+            return Ok(InteractiveStateResponse::new_halt());
+        }
+        Ok(InteractiveStateResponse::new_update())
+    }
+
+    async fn render_embed(&self, ctx: &Context) -> BotResult<CreateEmbed> {
+        let description = format!(
+            "<@{}> **`{}`** mines left\n\n{}",
+            self.user.id,
+            self.game.mines_left(),
+            self.format_status(ctx)
+        );
+
+        let embed = CreateEmbed::new()
+            .title(format!("Minesweeper | {}", self.game.difficulty))
+            .ui_color(self.build_color())
+            .description(description)
+            .field("Best Time", self.format_best(), true)
+            .image("attachment://minesweeper.png");
+        match self.selected() {
+            Some((x, y)) if !self.game.is_over() => {
+                Ok(embed.footer_text(format!("Selected {}{}", column_label(x), y + 1)))
+            }
+            _ => Ok(embed),
+        }
+    }
+
+    async fn render_attachments(&self, ctx: &Context) -> BotResult<Vec<CreateAttachment>> {
+        let (column, row) = if self.game.is_over() {
+            (None, None)
+        } else {
+            (self.column, self.row)
+        };
+        let png_bytes = MinesweeperImage::new(&self.game, column, row).render_png(ctx.o2d())?;
+        Ok(vec![CreateAttachment::bytes(png_bytes, "minesweeper.png")])
+    }
+
+    async fn render_rows(&self, _ctx: &Context) -> BotResult<Vec<CreateActionRow>> {
+        if self.game.is_over() {
+            return Ok(vec![]);
+        }
+
+        let state = self.selected_state();
+        let flag_label = if state == Some(MinesweeperTileState::Flagged) {
+            "Unflag"
+        } else {
+            "Flag"
+        };
+
+        Ok(vec![
+            Self::select_menu(
+                "ms_column",
+                "Column",
+                self.game.grid.width(),
+                self.column,
+                |x| format!("Column {}", column_label(x)),
+            ),
+            Self::select_menu("ms_row", "Row", self.game.grid.height(), self.row, |y| {
+                format!("Row {}", y + 1)
+            }),
+            CreateActionRow::Buttons(vec![
+                CreateButton::new("ms_reveal")
+                    .label("Reveal")
+                    .style(ButtonStyle::Success)
+                    .disabled(state.is_none() || state == Some(MinesweeperTileState::Flagged)),
+                CreateButton::new("ms_flag")
+                    .label(flag_label)
+                    .style(ButtonStyle::Danger)
+                    .disabled(state.is_none() || state == Some(MinesweeperTileState::Revealed)),
+            ]),
+        ])
+    }
+}
@@ -0,0 +1,248 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::color::UiColor;
+use crate::ui::emoji::EmojiType;
+use crate::ui::games::turn_based::TurnBasedRules;
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use neobabu_core::games::playing_cards::PlayingCard;
+use neobabu_core::games::poker::hand::evaluate;
+use neobabu_core::games::poker::{
+    PokerAction, PokerGame, PokerHandResult, MAX_PLAYERS, MIN_PLAYERS,
+};
+use poise::serenity_prelude::{
+    ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton, CreateEmbed,
+    CreateInteractionResponse, CreateInteractionResponseMessage,
+};
+use std::time::Duration;
+
+/// How long a hand may take before the table is called off, enough for several betting rounds at
+/// a full table.
+const HAND_TIME: Duration = Duration::from_mins(8);
+
+/// Texas Hold'em, every player buys in with the stake of the lobby.
+pub struct PokerRules {
+    pub buy_in: i64,
+    pub big_blind: i64,
+    pub hands: u32,
+}
+
+impl PokerRules {
+    fn format_cards(ctx: &Context, cards: &[PlayingCard]) -> String {
+        cards
+            .iter()
+            .map(|card| ctx.emoji_text(EmojiType::from(*card)))
+            .collect::<Vec<_>>()
+            .join("")
+    }
+
+    fn format_seats(game: &PokerGame) -> String {
+        game.seats
+            .iter()
+            .enumerate()
+            .map(|(index, seat)| {
+                let turn = if game.to_act == Some(index) {
+                    "▶️"
+                } else {
+                    "▫️"
+                };
+                let dealer = if game.dealer == index { " 🔘" } else { "" };
+                let status = if !seat.in_hand {
+                    " *out*".to_string()
+                } else if seat.folded {
+                    " *folded*".to_string()
+                } else if seat.is_all_in() {
+                    " **ALL-IN**".to_string()
+                } else if seat.bet > 0 {
+                    format!(" | bet `{}`", seat.bet)
+                } else {
+                    "".to_string()
+                };
+                format!("{turn} <@{}>{dealer} `{}`{status}", seat.id, seat.stack)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn format_last_hand(ctx: &Context, result: &PokerHandResult) -> String {
+        let pots = result
+            .pots
+            .iter()
+            .map(|pot| {
+                let winners = pot
+                    .winners
+                    .iter()
+                    .map(|id| format!("<@{id}>"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let hand = result
+                    .shown
+                    .iter()
+                    .find(|(id, _, _)| pot.winners.contains(id))
+                    .map(|(_, _, rank)| format!(" with **{}**", rank.category.name()))
+                    .unwrap_or_default();
+                format!("> {winners} won `{}`{hand}", pot.amount)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let shown = result
+            .shown
+            .iter()
+            .map(|(id, cards, _)| format!("> <@{id}> {}", Self::format_cards(ctx, cards)))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            "**Hand {}** {}\n{pots}\n{shown}",
+            result.number,
+            Self::format_cards(ctx, &result.community)
+        )
+    }
+
+    fn respond_cards(ctx: &Context, game: &PokerGame, user_id: &str) -> CreateEmbed {
+        let Some(seat) = game.seat(user_id) else {
+            return CreateEmbed::new()
+                .ui_color(UiColor::Gray)
+                .description("*You are not playing at this table.*");
+        };
+
+        let hand = if seat.is_live() && game.community.len() >= 3 {
+            let cards = [seat.hole_cards.as_slice(), game.community.as_slice()].concat();
+            format!("\n\n*{}*", evaluate(&cards).category.name())
+        } else {
+            "".to_string()
+        };
+
+        CreateEmbed::new()
+            .title(format!("YOUR CARDS | HAND {}", game.hand_number))
+            .ui_color(UiColor::Pink)
+            .description(format!(
+                "{}{hand}",
+                Self::format_cards(ctx, &seat.hole_cards)
+            ))
+    }
+}
+
+#[async_trait::async_trait]
+impl TurnBasedRules for PokerRules {
+    type Game = PokerGame;
+
+    const TITLE: &'static str = "Texas Hold'em";
+    const KEY: &'static str = "poker";
+    const MIN_PLAYERS: usize = MIN_PLAYERS;
+    const MAX_PLAYERS: usize = MAX_PLAYERS;
+
+    fn create_game(&self, players: &[String]) -> PokerGame {
+        let mut game = PokerGame::new(players, self.buy_in, self.big_blind, self.hands);
+        game.start_hand();
+        game
+    }
+
+    fn render_board(&self, ctx: &Context, game: &PokerGame) -> String {
+        let board = if game.community.is_empty() {
+            "*No community cards yet*".to_string()
+        } else {
+            Self::format_cards(ctx, &game.community)
+        };
+        let last_hand = game
+            .last_hand
+            .as_ref()
+            .map(|result| format!("\n\n{}", Self::format_last_hand(ctx, result)))
+            .unwrap_or_default();
+
+        format!(
+            "**Hand {}/{}** | Blinds `{}/{}` | Pot `{}`\n{board}\n\n{}{last_hand}",
+            game.hand_number,
+            game.max_hands,
+            game.small_blind,
+            game.big_blind,
+            game.pot(),
+            Self::format_seats(game)
+        )
+    }
+
+    fn render_moves(&self, game: &PokerGame) -> Vec<CreateActionRow> {
+        let cards = CreateButton::new("poker_cards")
+            .label("View Cards")
+            .style(ButtonStyle::Secondary);
+        let Some(seat) = game.seat_to_act() else {
+            return vec![CreateActionRow::Buttons(vec![cards])];
+        };
+
+        let to_call = game.to_call(seat);
+        let all_in = seat.bet + seat.stack;
+        let min_raise = game.min_raise_to();
+        let pot_raise = game.current_bet + game.pot() + to_call;
+        let check_or_call = if to_call == 0 {
+            CreateButton::new("poker_check").label("Check")
+        } else {
+            CreateButton::new("poker_call").label(format!("Call {to_call}"))
+        };
+
+        vec![
+            CreateActionRow::Buttons(vec![
+                CreateButton::new("poker_fold")
+                    .label("Fold")
+                    .style(ButtonStyle::Danger),
+                check_or_call.style(ButtonStyle::Primary),
+                CreateButton::new(format!("poker_raise_{min_raise}"))
+                    .label(format!("Raise to {min_raise}"))
+                    .style(ButtonStyle::Secondary)
+                    .disabled(min_raise >= all_in),
+                CreateButton::new(format!("poker_pot_{pot_raise}"))
+                    .label(format!("Pot ({pot_raise})"))
+                    .style(ButtonStyle::Secondary)
+                    .disabled(pot_raise <= min_raise || pot_raise >= all_in),
+                CreateButton::new("poker_allin")
+                    .label(format!("All-in ({all_in})"))
+                    .style(ButtonStyle::Danger),
+            ]),
+            CreateActionRow::Buttons(vec![cards]),
+        ]
+    }
+
+    /// The lobby and every hand of the table.
+    fn time_limit(&self) -> Duration {
+        Duration::from_mins(10) + HAND_TIME * self.hands
+    }
+
+    fn parse_move(&self, custom_id: &str) -> Option<PokerAction> {
+        match custom_id {
+            "poker_fold" => Some(PokerAction::Fold),
+            "poker_check" => Some(PokerAction::Check),
+            "poker_call" => Some(PokerAction::Call),
+            "poker_allin" => Some(PokerAction::AllIn),
+            _ => custom_id
+                .strip_prefix("poker_raise_")
+                .or_else(|| custom_id.strip_prefix("poker_pot_"))?
+                .parse()
+                .ok()
+                .map(PokerAction::Raise),
+        }
+    }
+
+    /// Hole cards are only ever shown to their player, as an ephemeral response.
+    async fn respond(
+        &self,
+        ctx: &Context,
+        game: &PokerGame,
+        interaction: &ComponentInteraction,
+    ) -> BotResult<bool> {
+        if interaction.data.custom_id != "poker_cards" {
+            return Ok(false);
+        }
+
+        let embed = Self::respond_cards(ctx, game, &interaction.user.id.to_string());
+        interaction
+            .create_response(
+                ctx.serenity_context(),
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .embed(embed)
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,219 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::color::UiColor;
+use crate::ui::emoji::EmojiType;
+use crate::ui::games::connect4::format_board;
+use crate::ui::games::rps::RPSUi;
+use crate::ui::message::interactive::state::pagination::PaginationStateTrait;
+use crate::ui::message::CreateEmbedExt;
+use crate::Context;
+use neobabu_core::database::entity::game_record;
+use neobabu_core::games::blackjack::{BlackjackGame, BlackjackHand};
+use neobabu_core::games::connect4::Connect4Game;
+use neobabu_core::games::record::{LoggedMove, ReplayableGame};
+use neobabu_core::games::rps::RPSGame;
+use neobabu_core::types::active_game_kind::ActiveGameKind;
+use poise::serenity_prelude::CreateEmbed;
+use std::fmt::Display;
+
+/// Steps through a finished game, the first page shows the game before the first move and
+/// every following page the game after one more move.
+pub struct GameReplay {
+    record: game_record::Model,
+    kind: ActiveGameKind,
+    page: usize,
+    moves: usize,
+}
+
+/// The position after a number of moves and the move that led to it.
+struct ReplayFrame {
+    board: String,
+    last_move: Option<String>,
+}
+
+impl GameReplay {
+    /// Returns `None` for records of games that cannot be replayed.
+    pub fn new(record: game_record::Model) -> BotResult<Option<Self>> {
+        let Some(kind) = record.kind() else {
+            return Ok(None);
+        };
+        let moves = match kind {
+            ActiveGameKind::Blackjack => record.moves::<BlackjackGame>()?.len(),
+            ActiveGameKind::Connect4 => record.moves::<Connect4Game>()?.len(),
+            ActiveGameKind::RockPaperScissors => record.moves::<RPSGame>()?.len(),
+            ActiveGameKind::RockPaperScissorsTournament | ActiveGameKind::TurnBased => {
+                return Ok(None);
+            }
+        };
+
+        Ok(Some(Self {
+            record,
+            kind,
+            page: 0,
+            moves,
+        }))
+    }
+
+    fn frame(&self, ctx: &Context, moves: usize) -> BotResult<ReplayFrame> {
+        match self.kind {
+            ActiveGameKind::Blackjack => {
+                let game = self.record.replay::<BlackjackGame>(moves)?;
+                Ok(ReplayFrame {
+                    board: format_blackjack(ctx, &game),
+                    last_move: self
+                        .last_move::<BlackjackGame, _>(moves, |move_| move_.to_string())?,
+                })
+            }
+            ActiveGameKind::Connect4 => {
+                let game = self.record.replay::<Connect4Game>(moves)?;
+                Ok(ReplayFrame {
+                    board: format!(
+                        "🔴 <@{}>\n🟡 <@{}>\n\n{}",
+                        game.player_1,
+                        game.player_2,
+                        format_board(&game)
+                    ),
+                    last_move: self
+                        .last_move::<Connect4Game, _>(moves, |col| format!("Column {}", col + 1))?,
+                })
+            }
+            ActiveGameKind::RockPaperScissors | ActiveGameKind::RockPaperScissorsTournament => {
+                let game = self.record.replay::<RPSGame>(moves)?;
+                Ok(ReplayFrame {
+                    board: format_rps(ctx, &game),
+                    last_move: self.last_move::<RPSGame, _>(moves, |choice| choice.to_string())?,
+                })
+            }
+            // Only the wagers of turn-based games are stored, they are never recorded.
+            ActiveGameKind::TurnBased => Ok(ReplayFrame {
+                board: String::new(),
+                last_move: None,
+            }),
+        }
+    }
+
+    /// Describes the move that led to the position after the given number of moves, with the
+    /// time it was played at.
+    fn last_move<G: ReplayableGame, L: Display>(
+        &self,
+        moves: usize,
+        label: impl Fn(&G::Move) -> L,
+    ) -> BotResult<Option<String>> {
+        let log = self.record.moves::<G>()?;
+        let Some(LoggedMove {
+            player,
+            action,
+            at_ms,
+        }) = moves.checked_sub(1).and_then(|index| log.moves.get(index))
+        else {
+            return Ok(None);
+        };
+        let seconds = at_ms / 1000;
+        Ok(Some(format!(
+            "<@{player}> played **{}** at `{}:{:02}`",
+            label(action),
+            seconds / 60,
+            seconds % 60
+        )))
+    }
+
+    fn format_outcomes(&self) -> BotResult<String> {
+        Ok(self
+            .record
+            .outcomes()?
+            .iter()
+            .map(|(player, outcome)| format!("<@{player}> **{outcome}**"))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+fn format_hand(ctx: &Context, hand: &BlackjackHand) -> String {
+    let cards = hand
+        .deck
+        .iter_cards()
+        .map(|card| ctx.emoji_text(EmojiType::from(card)))
+        .collect::<String>();
+    format!("{cards} `{}`", hand.score())
+}
+
+fn format_blackjack(ctx: &Context, game: &BlackjackGame) -> String {
+    let players = game
+        .iter_players()
+        .map(|(id, player)| {
+            let hands = player
+                .hands
+                .iter()
+                .map(|hand| format_hand(ctx, hand))
+                .collect::<Vec<_>>()
+                .join(" | ");
+            format!("<@{id}> {hands}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("**Dealer** {}\n\n{players}", format_hand(ctx, &game.dealer))
+}
+
+fn format_rps(ctx: &Context, game: &RPSGame) -> String {
+    let rounds = game
+        .rounds
+        .iter()
+        .enumerate()
+        .map(|(index, round)| {
+            format!(
+                "Round {}: {} vs {}",
+                index + 1,
+                RPSUi::emoji_from_choice(ctx, round.choice_1),
+                RPSUi::emoji_from_choice(ctx, round.choice_2)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let rounds = if rounds.is_empty() {
+        "*No rounds played yet*".to_string()
+    } else {
+        rounds
+    };
+    format!("<@{}> vs <@{}>\n\n{rounds}", game.user_1.id, game.user_2.id)
+}
+
+#[async_trait::async_trait]
+impl PaginationStateTrait for GameReplay {
+    fn get_page(&self) -> usize {
+        self.page
+    }
+
+    fn set_page(&mut self, page: usize) {
+        self.page = page;
+    }
+
+    fn max_pages(&self) -> usize {
+        self.moves + 1
+    }
+
+    async fn render_page(&self, page: usize, ctx: &Context) -> BotResult<CreateEmbed> {
+        let frame = self.frame(ctx, page)?;
+        let header = match frame.last_move {
+            Some(last_move) => format!("**Move {page}/{}** {last_move}", self.moves),
+            None => format!("**Start** of {} moves", self.moves),
+        };
+        let outcomes = if page == self.moves {
+            format!("\n\n{}", self.format_outcomes()?)
+        } else {
+            "".to_string()
+        };
+        let seed = self
+            .record
+            .seed()
+            .map(|seed| format!(" | Seed `{seed}`"))
+            .unwrap_or_default();
+
+        Ok(CreateEmbed::new()
+            .ui_color(UiColor::Gray)
+            .title(format!("REPLAY | {}", self.kind.to_string().to_uppercase()))
+            .description(format!(
+                "{header}\n\n{}{outcomes}\n\n-# Game `{}`{seed}",
+                frame.board, self.record.id
+            )))
+    }
+}
@@ -2,34 +2,107 @@ use crate::context::ContextExt;
 use crate::error::BotResult;
 use crate::ui::color::UiColor;
 use crate::ui::emoji::EmojiType;
+use crate::ui::games::{persist_game, replay_footer};
 use crate::ui::message::interactive::state::{InteractiveState, InteractiveStateResponse};
 use crate::ui::message::CreateEmbedExt;
 use crate::ui::time::format_time_relative_at;
 use crate::Context;
 use chrono::{DateTime, Utc};
+use neobabu_core::error::CoreError;
 use neobabu_core::games::rps::choice::RPSChoice;
 use neobabu_core::games::rps::state::RPSState;
 use neobabu_core::games::rps::RPSGame;
 use poise::serenity_prelude::{
-    ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton, CreateEmbed,
+    ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton, CreateEmbed, Message,
 };
 use poise::CreateReply;
 
+pub mod tournament;
+
+/// Why a game ended without a result, in which case all locked wagers are refunded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RPSCancellation {
+    TimedOut,
+    /// The player with the given id could not cover the wager.
+    Unaffordable(String),
+}
+
 pub struct RPSUi {
     pub game: RPSGame,
     pub timeout_at: DateTime<Utc>,
-    pub bot_session: bool,
+    pub cancelled: Option<RPSCancellation>,
 }
 
 impl RPSUi {
+    pub fn new(game: RPSGame, timeout_at: DateTime<Utc>) -> Self {
+        Self {
+            game,
+            timeout_at,
+            cancelled: None,
+        }
+    }
+
     pub fn build_embed(&self, ctx: &Context) -> CreateEmbed {
+        if let Some(cancellation) = &self.cancelled {
+            return self.build_cancelled_embed(ctx, cancellation);
+        }
+
         let description = self.build_description(ctx);
         let color = self.build_color();
 
-        CreateEmbed::new()
+        let embed = CreateEmbed::new()
             .title("Rock Paper Scissors")
             .ui_color(color)
-            .description(description)
+            .description(description);
+        if self.game.state().is_finished() {
+            embed.footer_text(replay_footer(self.game.id))
+        } else {
+            embed
+        }
+    }
+
+    fn build_cancelled_embed(&self, ctx: &Context, cancellation: &RPSCancellation) -> CreateEmbed {
+        let reason = match cancellation {
+            RPSCancellation::TimedOut => format!(
+                "The game between <@{}> and <@{}> has ended without a winner.",
+                self.game.user_1.id, self.game.user_2.id
+            ),
+            RPSCancellation::Unaffordable(id) => {
+                format!("The game was cancelled, <@{id}> cannot cover the wager.")
+            }
+        };
+        let refund = if self.game.wager.is_some() {
+            format!(
+                "\n\n*All locked {} Citrine has been refunded.*",
+                ctx.emoji_text(EmojiType::Citrine)
+            )
+        } else {
+            "".to_string()
+        };
+
+        CreateEmbed::new()
+            .title("Rock Paper Scissors")
+            .ui_color(UiColor::Gray)
+            .description(format!("{reason}{refund}"))
+    }
+
+    fn get_wager_text(&self, ctx: &Context) -> String {
+        let Some(wager) = self.game.wager else {
+            return "".to_string();
+        };
+
+        let lock = if self.game.state().is_ongoing()
+            && !(self.game.is_locked(&self.game.user_1.id)
+                && self.game.is_locked(&self.game.user_2.id))
+        {
+            "\n*Your first throw locks your wager.*"
+        } else {
+            ""
+        };
+        format!(
+            "**WAGER: `{wager}`** {}{lock}\n\n",
+            ctx.emoji_text(EmojiType::Citrine)
+        )
     }
 
     fn build_description(&self, ctx: &Context) -> String {
@@ -52,13 +125,50 @@ impl RPSUi {
         let choice_2 = self.get_choice_text(ctx, false);
 
         let state = self.get_state_text(ctx);
+        let series = self.get_series_text(ctx);
+        let wager = self.get_wager_text(ctx);
 
         format!(
-            "{face_1} <@{}> {choice_1}\n{face_2} <@{}> {choice_2}{state}{time_left}",
+            "{wager}{series}{face_1} <@{}> {choice_1}\n{face_2} <@{}> {choice_2}{state}{time_left}",
             self.game.user_1.id, self.game.user_2.id
         )
     }
 
+    fn get_series_text(&self, ctx: &Context) -> String {
+        if !self.game.is_series() {
+            return "".to_string();
+        }
+
+        let rounds = self
+            .game
+            .rounds
+            .iter()
+            .enumerate()
+            .map(|(index, round)| {
+                format!(
+                    "> `{}` {} vs {}",
+                    index + 1,
+                    Self::emoji_from_choice(ctx, round.choice_1),
+                    Self::emoji_from_choice(ctx, round.choice_2)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let rounds = if rounds.is_empty() {
+            "".to_string()
+        } else {
+            format!("{rounds}\n")
+        };
+
+        format!(
+            "**Best of {} | Round {} | `{} - {}`**\n{rounds}\n",
+            self.game.best_of,
+            self.game.round_number(),
+            self.game.wins_1(),
+            self.game.wins_2()
+        )
+    }
+
     fn build_color(&self) -> UiColor {
         match self.game.state() {
             RPSState::Winner1 => UiColor::Success,
@@ -69,7 +179,7 @@ impl RPSUi {
     }
 
     fn get_face(&self, is_1: bool) -> EmojiType {
-        if self.bot_session && !is_1 {
+        if self.game.against_bot() && !is_1 {
             return EmojiType::FaceRobot;
         }
 
@@ -108,19 +218,29 @@ impl RPSUi {
     }
 
     fn get_choice_text(&self, ctx: &Context, is_1: bool) -> String {
-        let state = self.game.state();
+        if self.game.state().is_finished() {
+            let choice =
+                self.game.last_round().map(
+                    |round| {
+                        if is_1 {
+                            round.choice_1
+                        } else {
+                            round.choice_2
+                        }
+                    },
+                );
+            return choice
+                .map(|choice| format!("**chose** {}", Self::emoji_from_choice(ctx, choice)))
+                .unwrap_or_default();
+        }
+
         let choice = if is_1 {
             self.game.choice_1
         } else {
             self.game.choice_2
         };
-
-        if let Some(choice) = choice {
-            if state.is_ongoing() {
-                "**chose `???`**".to_string()
-            } else {
-                format!("**chose** {}", Self::emoji_from_choice(ctx, choice))
-            }
+        if choice.is_some() {
+            "**chose `???`**".to_string()
         } else {
             "*is thinking...*".to_string()
         }
@@ -151,7 +271,7 @@ impl RPSUi {
         }
     }
 
-    fn emoji_from_choice(ctx: &Context, choice: RPSChoice) -> String {
+    pub fn emoji_from_choice(ctx: &Context, choice: RPSChoice) -> String {
         match choice {
             RPSChoice::Rock => ctx.emoji_text(EmojiType::Rock),
             RPSChoice::Paper => ctx.emoji_text(EmojiType::Paper),
@@ -165,7 +285,29 @@ impl RPSUi {
         interaction: &ComponentInteraction,
         choice: RPSChoice,
     ) -> BotResult<InteractiveStateResponse> {
-        let did_play = self.game.play(interaction.user.id.to_string(), choice);
+        let id = interaction.user.id.to_string();
+        let user = if id == self.game.user_1.id {
+            self.game.user_1.clone()
+        } else if id == self.game.user_2.id {
+            self.game.user_2.clone()
+        } else {
+            return Ok(InteractiveStateResponse::new());
+        };
+
+        if self.game.state().is_ongoing() && !self.game.is_locked(&id) {
+            // A frozen wallet cannot cover the wager either.
+            let locked = match ctx.services().rps.lock_wager(&mut self.game, &user).await {
+                Err(CoreError::WalletFrozen) => false,
+                result => result?,
+            };
+            if !locked {
+                ctx.services().rps.cancel_wagers(&self.game).await?;
+                self.cancelled = Some(RPSCancellation::Unaffordable(id));
+                return Ok(InteractiveStateResponse::new_halt());
+            }
+        }
+
+        let did_play = self.game.play(id, choice);
         if !did_play {
             return Ok(InteractiveStateResponse::new());
         }
@@ -173,26 +315,34 @@ impl RPSUi {
         let state = self.game.state();
         if state.is_finished() {
             self.game.register_end(&ctx.data().core).await?;
+        } else {
+            self.game.play_bot();
         }
 
+        let (series, match_name) = if self.game.is_series() {
+            let best_of = format!("best of {}", self.game.best_of);
+            (format!(" the {best_of}"), best_of)
+        } else {
+            ("".to_string(), "game".to_string())
+        };
         match state {
             RPSState::Winner1 => {
                 ctx.send(CreateReply::default().content(format!(
-                    "**<@{}> `won` against <@{}>, in Rock Paper Scissors!!**",
+                    "**<@{}> `won`{series} against <@{}>, in Rock Paper Scissors!!**",
                     self.game.user_1.id, self.game.user_2.id
                 )))
                 .await?;
             }
             RPSState::Winner2 => {
                 ctx.send(CreateReply::default().content(format!(
-                    "**<@{}> `won` against <@{}>, in Rock Paper Scissors!!**",
+                    "**<@{}> `won`{series} against <@{}>, in Rock Paper Scissors!!**",
                     self.game.user_2.id, self.game.user_1.id
                 )))
                 .await?;
             }
             RPSState::Draw => {
                 ctx.send(CreateReply::default().content(format!(
-                    "**<@{}> and <@{}>, your game of Rock Paper Scissors ended in a `draw`!**",
+                    "**<@{}> and <@{}>, your {match_name} of Rock Paper Scissors ended in a `draw`!**",
                     self.game.user_1.id, self.game.user_2.id
                 )))
                 .await?;
@@ -222,7 +372,7 @@ impl InteractiveState for RPSUi {
     }
 
     async fn render_content(&self, _ctx: &Context) -> BotResult<Option<String>> {
-        if !self.game.state().is_finished() {
+        if self.cancelled.is_none() && !self.game.state().is_finished() {
             Ok(Some(format!(
                 "**<@{}>, you were challenged to a game of Rock Paper Scissors by <@{}>!**",
                 self.game.user_2.id, self.game.user_1.id
@@ -236,8 +386,21 @@ impl InteractiveState for RPSUi {
         Ok(self.build_embed(ctx))
     }
 
+    async fn persist(&self, ctx: &Context, message: &Message, finished: bool) -> BotResult<()> {
+        persist_game(ctx, &self.game, message, finished).await
+    }
+
+    async fn on_abandon(&mut self, ctx: &Context) -> BotResult<()> {
+        if self.cancelled.is_some() || self.game.state().is_finished() {
+            return Ok(());
+        }
+        ctx.services().rps.cancel_wagers(&self.game).await?;
+        self.cancelled = Some(RPSCancellation::TimedOut);
+        Ok(())
+    }
+
     async fn render_rows(&self, ctx: &Context) -> BotResult<Vec<CreateActionRow>> {
-        if self.game.state().is_finished() {
+        if self.cancelled.is_some() || self.game.state().is_finished() {
             Ok(vec![])
         } else {
             Ok(vec![CreateActionRow::Buttons(vec![
@@ -253,4 +416,17 @@ impl InteractiveState for RPSUi {
             ])])
         }
     }
+
+    async fn on_tick(&mut self, ctx: &Context) -> BotResult<InteractiveStateResponse> {
+        if self.cancelled.is_some()
+            || self.game.state().is_finished()
+            || Utc::now() < self.timeout_at
+        {
+            return Ok(InteractiveStateResponse::new());
+        }
+
+        ctx.services().rps.cancel_wagers(&self.game).await?;
+        self.cancelled = Some(RPSCancellation::TimedOut);
+        Ok(InteractiveStateResponse::new_halt())
+    }
 }
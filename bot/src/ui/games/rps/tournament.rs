@@ -0,0 +1,381 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::color::UiColor;
+use crate::ui::emoji::EmojiType;
+use crate::ui::games::persist_game;
+use crate::ui::games::rps::RPSUi;
+use crate::ui::message::interactive::state::{InteractiveState, InteractiveStateResponse};
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::time::format_time_relative_at;
+use crate::Context;
+use chrono::{DateTime, Utc};
+use neobabu_core::games::rps::choice::RPSChoice;
+use neobabu_core::games::rps::tournament::{RPSMatch, RPSTournament, MAX_PLAYERS};
+use neobabu_core::games::rps::RPSGame;
+use poise::serenity_prelude::{
+    ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton, CreateEmbed, Message,
+};
+use poise::CreateReply;
+
+const SIGN_UP_TIME: chrono::Duration = chrono::Duration::minutes(2);
+const ROUND_TIME: chrono::Duration = chrono::Duration::seconds(30);
+
+pub struct RPSTournamentUi {
+    pub tournament: RPSTournament,
+    pub starts_at: DateTime<Utc>,
+    /// When the players of the current match forfeit if they have not chosen yet.
+    pub round_ends_at: Option<DateTime<Utc>>,
+    pub cancelled: bool,
+}
+
+impl RPSTournamentUi {
+    pub fn new(tournament: RPSTournament) -> Self {
+        Self {
+            tournament,
+            starts_at: Utc::now() + SIGN_UP_TIME,
+            round_ends_at: None,
+            cancelled: false,
+        }
+    }
+
+    fn round_name(&self, round: usize) -> String {
+        match self.tournament.bracket.len() - round {
+            1 => "Final".to_string(),
+            2 => "Semifinals".to_string(),
+            3 => "Quarterfinals".to_string(),
+            _ => format!("Round {}", round + 1),
+        }
+    }
+
+    fn format_slot(player: &Option<String>, is_winner: bool) -> String {
+        match player {
+            Some(id) if is_winner => format!("**<@{id}>**"),
+            Some(id) => format!("<@{id}>"),
+            None => "`TBD`".to_string(),
+        }
+    }
+
+    fn format_match(&self, round: usize, result: &RPSMatch) -> String {
+        if round == 0 && result.is_bye() {
+            let player = result.player_1.as_ref().or(result.player_2.as_ref());
+            return format!("> {} *bye*", Self::format_slot(&player.cloned(), false));
+        }
+
+        let winner = result.winner.as_deref();
+        let player_1 = Self::format_slot(&result.player_1, winner == result.player_1.as_deref());
+        let player_2 = Self::format_slot(&result.player_2, winner == result.player_2.as_deref());
+        let score = if winner.is_none() {
+            "vs".to_string()
+        } else if result.forfeited {
+            "`FF`".to_string()
+        } else {
+            format!("`{} - {}`", result.wins_1, result.wins_2)
+        };
+
+        format!("> {player_1} {score} {player_2}")
+    }
+
+    fn format_bracket(&self) -> String {
+        self.tournament
+            .bracket
+            .iter()
+            .enumerate()
+            .map(|(round, matches)| {
+                let matches = matches
+                    .iter()
+                    .map(|result| self.format_match(round, result))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!("**{}**\n{matches}", self.round_name(round))
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    fn format_current(&self, ctx: &Context, game: &RPSGame) -> String {
+        let player = |id: &str, choice: Option<RPSChoice>| {
+            if choice.is_some() {
+                format!(
+                    "{} <@{id}> **chose `???`**",
+                    ctx.emoji_text(EmojiType::FaceShushing)
+                )
+            } else {
+                format!(
+                    "{} <@{id}> *is thinking...*",
+                    ctx.emoji_text(EmojiType::FaceThinking)
+                )
+            }
+        };
+
+        let last_round = game
+            .last_round()
+            .map(|round| {
+                format!(
+                    "\n*Last round:* {} vs {}",
+                    RPSUi::emoji_from_choice(ctx, round.choice_1),
+                    RPSUi::emoji_from_choice(ctx, round.choice_2)
+                )
+            })
+            .unwrap_or_default();
+        let forfeit = self
+            .round_ends_at
+            .map(|time| {
+                format!(
+                    "\n\n*{} Forfeit {}*",
+                    ctx.emoji_text(EmojiType::Clock),
+                    format_time_relative_at(time)
+                )
+            })
+            .unwrap_or_default();
+
+        format!(
+            "{} **Best of {} | Round {} | `{} - {}`**\n{}\n{}{last_round}{forfeit}",
+            ctx.emoji_text(EmojiType::Pvp),
+            game.best_of,
+            game.round_number(),
+            game.wins_1(),
+            game.wins_2(),
+            player(&game.user_1.id, game.choice_1),
+            player(&game.user_2.id, game.choice_2),
+        )
+    }
+
+    fn embed_sign_up(&self) -> CreateEmbed {
+        let players = self
+            .tournament
+            .players
+            .iter()
+            .map(|player| format!("> <@{}>", player.id))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let description = format!(
+            "*Hosted by <@{}> | Best of {} | Starts {}*\n\n**Players ({}/{MAX_PLAYERS})**\n{players}\n\n*Sign up by clicking the button below. The host can start early, players are seeded by their wins.\nEvery round, you will have {} seconds to choose or you forfeit the match.*",
+            self.tournament.host_id,
+            self.tournament.best_of,
+            format_time_relative_at(self.starts_at),
+            self.tournament.players.len(),
+            ROUND_TIME.num_seconds()
+        );
+
+        CreateEmbed::new()
+            .title("ROCK PAPER SCISSORS TOURNAMENT | SIGN-UPS")
+            .ui_color(UiColor::Pink)
+            .description(description)
+    }
+
+    fn embed_playing(&self, ctx: &Context) -> CreateEmbed {
+        let (title, current) = match &self.tournament.current {
+            Some((round, _, game)) => (
+                format!(
+                    "ROCK PAPER SCISSORS TOURNAMENT | {}",
+                    self.round_name(*round).to_uppercase()
+                ),
+                self.format_current(ctx, game),
+            ),
+            None => ("ROCK PAPER SCISSORS TOURNAMENT".to_string(), "".to_string()),
+        };
+
+        CreateEmbed::new()
+            .title(title)
+            .ui_color(UiColor::Pink)
+            .description(format!("{current}\n\n{}", self.format_bracket()))
+    }
+
+    fn embed_finished(&self, ctx: &Context) -> CreateEmbed {
+        let winner = self
+            .tournament
+            .winner()
+            .map(|id| {
+                format!(
+                    "{} <@{id}> **won the tournament!**\n\n",
+                    ctx.emoji_text(EmojiType::Trophy)
+                )
+            })
+            .unwrap_or_default();
+
+        CreateEmbed::new()
+            .title("ROCK PAPER SCISSORS TOURNAMENT | FINISHED")
+            .ui_color(UiColor::Success)
+            .description(format!("{winner}{}", self.format_bracket()))
+    }
+
+    fn embed_cancelled(&self) -> CreateEmbed {
+        CreateEmbed::new()
+            .title("ROCK PAPER SCISSORS TOURNAMENT | CANCELLED")
+            .ui_color(UiColor::Gray)
+            .description("Not enough players signed up for the tournament.")
+    }
+
+    async fn handle_join(
+        &mut self,
+        ctx: &Context<'_>,
+        id: String,
+    ) -> BotResult<InteractiveStateResponse> {
+        let user = ctx.stores().user.fetch_or_create(&id).await?;
+        let joined = self.tournament.join(user);
+        Ok(InteractiveStateResponse::new().update(joined))
+    }
+
+    async fn handle_start(&mut self, ctx: &Context<'_>) -> BotResult<InteractiveStateResponse> {
+        if self.tournament.has_started() {
+            return Ok(InteractiveStateResponse::new());
+        }
+
+        if !self.tournament.can_start() {
+            self.cancelled = true;
+            return Ok(InteractiveStateResponse::new_halt());
+        }
+
+        ctx.services()
+            .rps
+            .start_tournament(&mut self.tournament)
+            .await?;
+        self.round_ends_at = Some(Utc::now() + ROUND_TIME);
+        Ok(InteractiveStateResponse::new_update())
+    }
+
+    async fn handle_choice(
+        &mut self,
+        ctx: &Context<'_>,
+        id: String,
+        choice: RPSChoice,
+    ) -> BotResult<InteractiveStateResponse> {
+        let Some(game) = self.tournament.current_game_mut() else {
+            return Ok(InteractiveStateResponse::new());
+        };
+
+        let rounds = game.rounds.len();
+        if !game.play(id, choice) {
+            return Ok(InteractiveStateResponse::new());
+        }
+        if game.rounds.len() > rounds {
+            self.round_ends_at = Some(Utc::now() + ROUND_TIME);
+        }
+
+        if game.state().is_finished() {
+            game.register_end(&ctx.data().core).await?;
+            self.tournament.finish_current();
+        }
+
+        self.handle_progress(ctx).await
+    }
+
+    /// Posts the final bracket once the last match is over.
+    async fn handle_progress(&mut self, ctx: &Context<'_>) -> BotResult<InteractiveStateResponse> {
+        if !self.tournament.is_over() {
+            return Ok(InteractiveStateResponse::new_update());
+        }
+
+        self.round_ends_at = None;
+        let winner = self.tournament.winner().unwrap_or_default();
+        ctx.send(
+            CreateReply::default()
+                .content(format!(
+                    "**<@{winner}> `won` the Rock Paper Scissors tournament!!**"
+                ))
+                .embed(self.embed_finished(ctx)),
+        )
+        .await?;
+        Ok(InteractiveStateResponse::new_halt())
+    }
+
+    /// Whoever has not chosen in time forfeits, if neither did the lower seed goes out.
+    async fn handle_forfeit(&mut self, ctx: &Context<'_>) -> BotResult<InteractiveStateResponse> {
+        let Some(game) = self.tournament.current_game() else {
+            return Ok(InteractiveStateResponse::new());
+        };
+
+        let loser_id = if game.choice_1.is_none() && game.choice_2.is_some() {
+            game.user_1.id.clone()
+        } else {
+            game.user_2.id.clone()
+        };
+        if let Some((winner, loser)) = self.tournament.forfeit_current(&loser_id) {
+            ctx.services().rps.register_winner(&winner, &loser).await?;
+        }
+
+        self.round_ends_at = Some(Utc::now() + ROUND_TIME);
+        self.handle_progress(ctx).await
+    }
+}
+
+#[async_trait::async_trait]
+impl InteractiveState for RPSTournamentUi {
+    async fn handle_interaction(
+        &mut self,
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+    ) -> BotResult<InteractiveStateResponse> {
+        let id = interaction.user.id.to_string();
+        match interaction.data.custom_id.as_str() {
+            "rps_tournament_join" => self.handle_join(ctx, id).await,
+            "rps_tournament_start" if id == self.tournament.host_id => self.handle_start(ctx).await,
+            "rps_rock" => self.handle_choice(ctx, id, RPSChoice::Rock).await,
+            "rps_paper" => self.handle_choice(ctx, id, RPSChoice::Paper).await,
+            "rps_scissors" => self.handle_choice(ctx, id, RPSChoice::Scissors).await,
+            _ => Ok(InteractiveStateResponse::new()),
+        }
+    }
+
+    async fn render_embed(&self, ctx: &Context) -> BotResult<CreateEmbed> {
+        if self.cancelled {
+            Ok(self.embed_cancelled())
+        } else if !self.tournament.has_started() {
+            Ok(self.embed_sign_up())
+        } else if !self.tournament.is_over() {
+            Ok(self.embed_playing(ctx))
+        } else {
+            Ok(self.embed_finished(ctx))
+        }
+    }
+
+    async fn persist(&self, ctx: &Context, message: &Message, finished: bool) -> BotResult<()> {
+        persist_game(ctx, &self.tournament, message, finished).await
+    }
+
+    async fn render_rows(&self, ctx: &Context) -> BotResult<Vec<CreateActionRow>> {
+        if !self.tournament.has_started() {
+            return Ok(vec![CreateActionRow::Buttons(vec![
+                CreateButton::new("rps_tournament_join")
+                    .label("Join")
+                    .style(ButtonStyle::Success)
+                    .disabled(self.tournament.players.len() >= MAX_PLAYERS),
+                CreateButton::new("rps_tournament_start")
+                    .label("Start")
+                    .style(ButtonStyle::Primary)
+                    .disabled(!self.tournament.can_start()),
+            ])]);
+        }
+
+        if self.tournament.is_over() {
+            return Ok(vec![]);
+        }
+
+        Ok(vec![CreateActionRow::Buttons(vec![
+            CreateButton::new("rps_rock")
+                .style(ButtonStyle::Secondary)
+                .emoji(ctx.emoji(EmojiType::Rock)),
+            CreateButton::new("rps_paper")
+                .style(ButtonStyle::Secondary)
+                .emoji(ctx.emoji(EmojiType::Paper)),
+            CreateButton::new("rps_scissors")
+                .style(ButtonStyle::Secondary)
+                .emoji(ctx.emoji(EmojiType::Scissors)),
+        ])])
+    }
+
+    async fn on_tick(&mut self, ctx: &Context) -> BotResult<InteractiveStateResponse> {
+        let now = Utc::now();
+        if !self.tournament.has_started() {
+            if now >= self.starts_at {
+                return self.handle_start(ctx).await;
+            }
+            return Ok(InteractiveStateResponse::new());
+        }
+
+        match self.round_ends_at {
+            Some(round_ends_at) if now >= round_ends_at => self.handle_forfeit(ctx).await,
+            _ => Ok(InteractiveStateResponse::new()),
+        }
+    }
+}
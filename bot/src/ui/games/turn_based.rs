@@ -0,0 +1,493 @@
+use crate::context::ContextExt;
+use crate::error::BotResult;
+use crate::ui::color::UiColor;
+use crate::ui::emoji::EmojiType;
+use crate::ui::games::{persist_game, replay_footer};
+use crate::ui::message::CreateEmbedExt;
+use crate::ui::message::interactive::state::{InteractiveState, InteractiveStateResponse};
+use crate::ui::time::format_time_relative_at;
+use crate::Context;
+use chrono::{DateTime, Utc};
+use neobabu_core::error::CoreError;
+use neobabu_core::games::turn_based::lobby::Lobby;
+use neobabu_core::games::turn_based::{TurnBasedGame, TurnBasedWagers, TurnOutcome};
+use poise::serenity_prelude::{
+    ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton, CreateEmbed, Message,
+};
+use std::time::Duration;
+use uuid::Uuid;
+
+const LOBBY_TIME: chrono::Duration = chrono::Duration::minutes(5);
+const TURN_TIME: chrono::Duration = chrono::Duration::seconds(60);
+
+/// The rules of a game played through [`TurnBasedUi`], which takes care of the lobby, turn
+/// timeouts and wagers.
+#[async_trait::async_trait]
+pub trait TurnBasedRules: Send + Sync {
+    type Game: TurnBasedGame;
+
+    const TITLE: &'static str;
+    /// Prefixes the reference of the wagers held for a game.
+    const KEY: &'static str;
+    const MIN_PLAYERS: usize;
+    const MAX_PLAYERS: usize;
+
+    /// Sets up the game for the players in turn order.
+    fn create_game(&self, players: &[String]) -> Self::Game;
+
+    fn render_board(&self, ctx: &Context, game: &Self::Game) -> String;
+
+    /// The buttons to make a move with, interactions outside the lobby are passed to
+    /// [`TurnBasedRules::parse_move`].
+    fn render_moves(&self, game: &Self::Game) -> Vec<CreateActionRow>;
+
+    fn parse_move(&self, custom_id: &str) -> Option<<Self::Game as TurnBasedGame>::Move>;
+
+    /// Gets interactions during the game before they are parsed as moves, returns whether it
+    /// responded to the interaction, e.g. to show a player something only they may see.
+    async fn respond(
+        &self,
+        _ctx: &Context,
+        _game: &Self::Game,
+        _interaction: &ComponentInteraction,
+    ) -> BotResult<bool> {
+        Ok(false)
+    }
+
+    /// Called once the game is over and its wagers are settled, e.g. to record stats.
+    async fn finish(&self, _ctx: &Context, _game: &Self::Game) -> BotResult<()> {
+        Ok(())
+    }
+
+    /// The id a finished game can be replayed with, if it is recorded in [`Self::finish`].
+    fn replay_id(&self, _game: &Self::Game) -> Option<String> {
+        None
+    }
+
+    /// How long a game may run at most, lobby included. The message times out after it, which
+    /// calls the game off, and the stakes are held for as long.
+    fn time_limit(&self) -> Duration {
+        Duration::from_mins(60)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnBasedStatus {
+    Lobby,
+    Playing,
+    Finished,
+    Abandoned,
+}
+
+pub struct TurnBasedUi<R: TurnBasedRules> {
+    /// Identifies the stored wagers of the game, see [`InteractiveState::persist`].
+    pub id: Uuid,
+    pub rules: R,
+    pub lobby: Lobby,
+    pub game: Option<R::Game>,
+    pub reference_id: String,
+    /// How much Citrine every player puts up, the host's stake has to be held under the
+    /// reference before the lobby opens.
+    pub stake: i64,
+    pub status: TurnBasedStatus,
+    pub lobby_until: DateTime<Utc>,
+    pub turn_until: DateTime<Utc>,
+}
+
+impl<R: TurnBasedRules> TurnBasedUi<R> {
+    pub fn new(rules: R, host_id: impl Into<String>, stake: i64) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            rules,
+            lobby: Lobby::new(host_id, R::MIN_PLAYERS, R::MAX_PLAYERS),
+            game: None,
+            reference_id: format!("{}-{}", R::KEY, nanoid::nanoid!()),
+            stake,
+            status: TurnBasedStatus::Lobby,
+            lobby_until: now + LOBBY_TIME,
+            turn_until: now + TURN_TIME,
+        }
+    }
+
+    fn format_stake(&self, ctx: &Context) -> String {
+        if self.stake > 0 {
+            format!(
+                "**WAGER: `{}`** {}\n\n",
+                self.stake,
+                ctx.emoji_text(EmojiType::Citrine)
+            )
+        } else {
+            "".to_string()
+        }
+    }
+
+    fn format_lobby(&self, ctx: &Context) -> String {
+        let players = self
+            .lobby
+            .players
+            .iter()
+            .map(|id| {
+                let face = if self.lobby.is_ready(id) {
+                    EmojiType::FaceCool
+                } else {
+                    EmojiType::FaceThinking
+                };
+                let host = if *id == self.lobby.host_id {
+                    " *(host)*"
+                } else {
+                    ""
+                };
+                format!("> {} <@{id}>{host}", ctx.emoji_text(face))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            "**Players ({}/{})**\n{players}{}\n\n*Join and get ready, the host can start once at least {} players are ready. The lobby closes {}.*",
+            self.lobby.players.len(),
+            self.lobby.max_players,
+            self.format_spectators(),
+            self.lobby.min_players,
+            format_time_relative_at(self.lobby_until)
+        )
+    }
+
+    fn format_spectators(&self) -> String {
+        if self.lobby.spectators.is_empty() {
+            return "".to_string();
+        }
+
+        let spectators = self
+            .lobby
+            .spectators
+            .iter()
+            .map(|id| format!("<@{id}>"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("\n\n*Spectating: {spectators}*")
+    }
+
+    fn format_game(&self, ctx: &Context, game: &R::Game) -> String {
+        let status = if game.is_over() {
+            self.format_outcomes(ctx, game)
+        } else {
+            game.current_player()
+                .map(|id| {
+                    format!(
+                        "<@{id}> to play | **AUTO-FORFEIT** {}",
+                        format_time_relative_at(self.turn_until)
+                    )
+                })
+                .unwrap_or_default()
+        };
+
+        format!(
+            "{}\n\n{status}{}",
+            self.rules.render_board(ctx, game),
+            self.format_spectators()
+        )
+    }
+
+    fn format_outcomes(&self, ctx: &Context, game: &R::Game) -> String {
+        let outcomes = game.outcomes();
+        if outcomes
+            .iter()
+            .all(|(_, outcome)| *outcome == TurnOutcome::Draw)
+        {
+            return format!("**{} It's a draw!**", ctx.emoji_text(EmojiType::Pvp));
+        }
+
+        game.settlement(self.stake)
+            .into_iter()
+            .zip(outcomes)
+            .filter(|(_, (_, outcome))| *outcome == TurnOutcome::Win)
+            .map(|((id, amount), _)| {
+                let payout = if amount > 0 {
+                    format!(" **`+{amount}`** {}", ctx.emoji_text(EmojiType::Citrine))
+                } else {
+                    "".to_string()
+                };
+                format!(
+                    "{} <@{id}> **wins!**{payout}",
+                    ctx.emoji_text(EmojiType::Trophy)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn build_color(&self) -> UiColor {
+        match self.status {
+            TurnBasedStatus::Lobby | TurnBasedStatus::Playing => UiColor::Pink,
+            TurnBasedStatus::Finished => UiColor::Success,
+            TurnBasedStatus::Abandoned => UiColor::Gray,
+        }
+    }
+
+    async fn handle_join(
+        &mut self,
+        ctx: &Context<'_>,
+        user_id: &str,
+    ) -> BotResult<InteractiveStateResponse> {
+        if self.lobby.join(user_id).is_err() {
+            return Ok(InteractiveStateResponse::new());
+        }
+
+        // A frozen wallet cannot cover the stake either.
+        let reserved = match ctx
+            .services()
+            .wager
+            .reserve(
+                &self.reference_id,
+                user_id,
+                self.stake,
+                self.rules.time_limit(),
+            )
+            .await
+        {
+            Err(CoreError::WalletFrozen) => false,
+            result => result?,
+        };
+        if !reserved {
+            let _ = self.lobby.leave(user_id);
+            return Ok(InteractiveStateResponse::new());
+        }
+
+        Ok(InteractiveStateResponse::new_update())
+    }
+
+    async fn handle_leave(
+        &mut self,
+        ctx: &Context<'_>,
+        user_id: &str,
+        spectate: bool,
+    ) -> BotResult<InteractiveStateResponse> {
+        let was_player = self.lobby.is_player(user_id);
+        let left = if spectate {
+            self.lobby.spectate(user_id)
+        } else {
+            self.lobby.leave(user_id)
+        };
+        if left.is_err() {
+            return Ok(InteractiveStateResponse::new());
+        }
+
+        if was_player {
+            ctx.services()
+                .wager
+                .cancel(&self.reference_id, user_id)
+                .await?;
+        }
+
+        if self.lobby.is_empty() {
+            self.status = TurnBasedStatus::Abandoned;
+            return Ok(InteractiveStateResponse::new_halt());
+        }
+
+        Ok(InteractiveStateResponse::new_update())
+    }
+
+    fn handle_start(&mut self, user_id: &str) -> InteractiveStateResponse {
+        if self.lobby.start(user_id).is_err() {
+            return InteractiveStateResponse::new();
+        }
+
+        self.game = Some(self.rules.create_game(&self.lobby.players));
+        self.status = TurnBasedStatus::Playing;
+        self.turn_until = Utc::now() + TURN_TIME;
+        InteractiveStateResponse::new_update()
+    }
+
+    async fn handle_move(
+        &mut self,
+        ctx: &Context<'_>,
+        user_id: &str,
+        custom_id: &str,
+    ) -> BotResult<InteractiveStateResponse> {
+        let Some(move_) = self.rules.parse_move(custom_id) else {
+            return Ok(InteractiveStateResponse::new());
+        };
+        let Some(game) = self.game.as_mut() else {
+            return Ok(InteractiveStateResponse::new());
+        };
+        if !game.play(user_id, move_) {
+            return Ok(InteractiveStateResponse::new());
+        }
+
+        self.turn_until = Utc::now() + TURN_TIME;
+        self.finish_if_over(ctx).await
+    }
+
+    async fn finish_if_over(&mut self, ctx: &Context<'_>) -> BotResult<InteractiveStateResponse> {
+        let Some(game) = self.game.as_ref().filter(|game| game.is_over()) else {
+            return Ok(InteractiveStateResponse::new_update());
+        };
+
+        ctx.services()
+            .wager
+            .settle_game(&self.reference_id, game, self.stake)
+            .await?;
+        self.rules.finish(ctx, game).await?;
+        self.status = TurnBasedStatus::Finished;
+        Ok(InteractiveStateResponse::new_halt())
+    }
+}
+
+#[async_trait::async_trait]
+impl<R: TurnBasedRules> InteractiveState for TurnBasedUi<R> {
+    async fn handle_interaction(
+        &mut self,
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+    ) -> BotResult<InteractiveStateResponse> {
+        let user_id = interaction.user.id.to_string();
+
+        match (self.status, interaction.data.custom_id.as_str()) {
+            (TurnBasedStatus::Lobby, "lobby_join") => self.handle_join(ctx, &user_id).await,
+            (TurnBasedStatus::Lobby, "lobby_leave") => {
+                self.handle_leave(ctx, &user_id, false).await
+            }
+            (TurnBasedStatus::Lobby, "lobby_ready") => {
+                let toggled = self.lobby.toggle_ready(&user_id).is_ok();
+                Ok(InteractiveStateResponse::new().update(toggled))
+            }
+            (TurnBasedStatus::Lobby, "lobby_start") => Ok(self.handle_start(&user_id)),
+            (TurnBasedStatus::Lobby | TurnBasedStatus::Playing, "lobby_spectate") => {
+                self.handle_leave(ctx, &user_id, true).await
+            }
+            (TurnBasedStatus::Playing, custom_id) => {
+                if let Some(game) = &self.game
+                    && self.rules.respond(ctx, game, interaction).await?
+                {
+                    return Ok(InteractiveStateResponse::new().responded(true));
+                }
+
+                self.handle_move(ctx, &user_id, custom_id).await
+            }
+            _ => Ok(InteractiveStateResponse::new()),
+        }
+    }
+
+    async fn render_embed(&self, ctx: &Context) -> BotResult<CreateEmbed> {
+        let (subtitle, body) = match (&self.status, &self.game) {
+            (TurnBasedStatus::Lobby, _) => ("LOBBY", self.format_lobby(ctx)),
+            (TurnBasedStatus::Abandoned, _) => (
+                "ABANDONED",
+                "*The lobby was closed, all wagers have been refunded.*".to_string(),
+            ),
+            (TurnBasedStatus::Finished, Some(game)) => ("FINISHED", self.format_game(ctx, game)),
+            (_, Some(game)) => ("PLAYING", self.format_game(ctx, game)),
+            (_, None) => ("PLAYING", "".to_string()),
+        };
+
+        let embed = CreateEmbed::new()
+            .title(format!("{} | {subtitle}", R::TITLE.to_uppercase()))
+            .ui_color(self.build_color())
+            .description(format!("{}{body}", self.format_stake(ctx)));
+        let replay_id = self
+            .game
+            .as_ref()
+            .filter(|_| self.status == TurnBasedStatus::Finished)
+            .and_then(|game| self.rules.replay_id(game));
+        match replay_id {
+            Some(id) => Ok(embed.footer_text(replay_footer(id))),
+            None => Ok(embed),
+        }
+    }
+
+    async fn render_rows(&self, _ctx: &Context) -> BotResult<Vec<CreateActionRow>> {
+        let spectate = CreateButton::new("lobby_spectate")
+            .label("Spectate")
+            .style(ButtonStyle::Secondary);
+
+        match (&self.status, &self.game) {
+            (TurnBasedStatus::Lobby, _) => {
+                let join_text = if self.stake > 0 {
+                    format!("Join ({} Citrine)", self.stake)
+                } else {
+                    "Join".to_string()
+                };
+
+                Ok(vec![CreateActionRow::Buttons(vec![
+                    CreateButton::new("lobby_join")
+                        .label(join_text)
+                        .style(ButtonStyle::Success)
+                        .disabled(self.lobby.is_full()),
+                    CreateButton::new("lobby_ready")
+                        .label("Ready")
+                        .style(ButtonStyle::Primary),
+                    CreateButton::new("lobby_start")
+                        .label("Start")
+                        .style(ButtonStyle::Primary)
+                        .disabled(!self.lobby.can_start()),
+                    CreateButton::new("lobby_leave")
+                        .label("Leave")
+                        .style(ButtonStyle::Danger),
+                    spectate,
+                ])])
+            }
+            (TurnBasedStatus::Playing, Some(game)) => {
+                let mut rows = self.rules.render_moves(game);
+                rows.push(CreateActionRow::Buttons(vec![spectate]));
+                Ok(rows)
+            }
+            _ => Ok(vec![]),
+        }
+    }
+
+    async fn on_tick(&mut self, ctx: &Context) -> BotResult<InteractiveStateResponse> {
+        let now = Utc::now();
+
+        match self.status {
+            TurnBasedStatus::Lobby if now >= self.lobby_until => {
+                ctx.services()
+                    .wager
+                    .cancel_all(&self.reference_id, &self.lobby.players)
+                    .await?;
+                self.status = TurnBasedStatus::Abandoned;
+                Ok(InteractiveStateResponse::new_halt())
+            }
+            TurnBasedStatus::Playing if now >= self.turn_until => {
+                let Some(game) = self.game.as_mut() else {
+                    return Ok(InteractiveStateResponse::new());
+                };
+                if let Some(player) = game.current_player() {
+                    game.time_out(&player);
+                }
+
+                self.turn_until = now + TURN_TIME;
+                self.finish_if_over(ctx).await
+            }
+            _ => Ok(InteractiveStateResponse::new()),
+        }
+    }
+
+    /// A game that runs out of time is called off and settled as it stands, an open lobby is
+    /// closed and refunded.
+    async fn on_abandon(&mut self, ctx: &Context) -> BotResult<()> {
+        let wager = &ctx.services().wager;
+        match (self.status, &self.game) {
+            (TurnBasedStatus::Playing, Some(game)) => {
+                let settlement = game.abandoned_settlement(self.stake);
+                wager.settle(&self.reference_id, &settlement).await?;
+            }
+            (TurnBasedStatus::Lobby | TurnBasedStatus::Playing, _) => {
+                wager
+                    .cancel_all(&self.reference_id, &self.lobby.players)
+                    .await?;
+            }
+            (TurnBasedStatus::Finished | TurnBasedStatus::Abandoned, _) => {}
+        }
+        Ok(())
+    }
+
+    async fn persist(&self, ctx: &Context, message: &Message, finished: bool) -> BotResult<()> {
+        let wagers = TurnBasedWagers {
+            id: self.id,
+            reference_id: self.reference_id.clone(),
+            players: self.lobby.players.clone(),
+        };
+        persist_game(ctx, &wagers, message, finished).await
+    }
+}
@@ -6,10 +6,11 @@ use crate::Context;
 use poise::futures_util::StreamExt;
 use poise::serenity_prelude::{
     ComponentInteraction, CreateEmbed, CreateEmbedFooter, CreateInteractionResponse,
-    CreateInteractionResponseMessage,
+    CreateInteractionResponseMessage, EditMessage, Message,
 };
 use poise::{CreateReply, ReplyHandle};
 use std::time::Duration;
+use tracing::warn;
 
 pub mod state;
 
@@ -55,16 +56,18 @@ impl<'a> InteractiveMessage<'a> {
     }
 
     pub async fn run(mut self) -> BotResult<()> {
-        let mut reply = CreateReply::default()
-            .embed(self.state.render_embed(self.ctx).await?)
-            .components(self.state.render_rows(self.ctx).await?);
-        if let Some(message) = &self.state.render_content(self.ctx).await? {
-            reply = reply.content(message);
-        }
+        let reply_handle = match self.send().await {
+            Ok(reply_handle) => reply_handle,
+            Err(error) => {
+                self.abandon(None).await;
+                return Err(error);
+            }
+        };
 
-        let reply_handle = self.ctx.send(reply).await?;
         let result = self.do_run(&reply_handle).await;
         if let Err(error) = result {
+            self.abandon(reply_handle.message().await.ok().as_deref())
+                .await;
             let error_embed = handle_command_error(error, self.ctx).await;
             reply_handle
                 .edit(
@@ -77,8 +80,23 @@ impl<'a> InteractiveMessage<'a> {
         Ok(())
     }
 
+    async fn send(&mut self) -> BotResult<ReplyHandle<'a>> {
+        let mut reply = CreateReply::default()
+            .embed(self.state.render_embed(self.ctx).await?)
+            .components(self.state.render_rows(self.ctx).await?);
+        if let Some(message) = &self.state.render_content(self.ctx).await? {
+            reply = reply.content(message);
+        }
+        for attachment in self.state.render_attachments(self.ctx).await? {
+            reply = reply.attachment(attachment);
+        }
+
+        Ok(self.ctx.send(reply).await?)
+    }
+
     async fn do_run(&mut self, reply_handle: &ReplyHandle<'_>) -> BotResult<()> {
         let message = reply_handle.message().await?;
+        self.state.persist(self.ctx, &message, false).await?;
         let collector = message.await_component_interaction(self.ctx.serenity_context());
 
         let mut collector_stream = collector.stream();
@@ -102,7 +120,11 @@ impl<'a> InteractiveMessage<'a> {
 
                     let response = self.state.handle_interaction(self.ctx, &interaction).await?;
 
-                    if response.do_update {
+                    if response.responded {
+                        if response.do_update {
+                            self.update_reply(reply_handle, &response).await?;
+                        }
+                    } else if response.do_update {
                         self.update_interaction(&interaction, &response).await?;
                     } else {
                         interaction
@@ -113,6 +135,10 @@ impl<'a> InteractiveMessage<'a> {
                             .await?;
                     }
 
+                    if response.do_update || response.do_stop {
+                        self.state.persist(self.ctx, &message, response.do_stop).await?;
+                    }
+
                     if response.do_stop {
                         return Ok(());
                     }
@@ -130,6 +156,10 @@ impl<'a> InteractiveMessage<'a> {
                         self.update_reply(reply_handle, &response).await?;
                     }
 
+                    if response.do_update || response.do_stop {
+                        self.state.persist(self.ctx, &message, response.do_stop).await?;
+                    }
+
                     if response.do_stop {
                         return Ok(());
                     }
@@ -152,6 +182,8 @@ impl<'a> InteractiveMessage<'a> {
                                 .components(vec![]),
                         )
                         .await?;
+                    self.state.on_abandon(self.ctx).await?;
+                    self.state.persist(self.ctx, &message, true).await?;
 
                     break;
                 }
@@ -161,6 +193,19 @@ impl<'a> InteractiveMessage<'a> {
         Ok(())
     }
 
+    /// Lets the state release what it holds after the message failed. Errors are only logged, as
+    /// the error of the message itself is what is shown.
+    async fn abandon(&mut self, message: Option<&Message>) {
+        if let Err(err) = self.state.on_abandon(self.ctx).await {
+            warn!("Failed to abandon the state of an interactive message: {err}");
+        }
+        if let Some(message) = message
+            && let Err(err) = self.state.persist(self.ctx, message, true).await
+        {
+            warn!("Failed to persist the state of an interactive message: {err}");
+        }
+    }
+
     async fn update_interaction(
         &mut self,
         interaction: &ComponentInteraction,
@@ -184,6 +229,7 @@ impl<'a> InteractiveMessage<'a> {
                                 .unwrap_or_default(),
                         )
                         .embed(self.state.render_embed(self.ctx).await?)
+                        .files(self.state.render_attachments(self.ctx).await?)
                         .components(components),
                 ),
             )
@@ -202,18 +248,32 @@ impl<'a> InteractiveMessage<'a> {
             self.state.render_rows(self.ctx).await?
         };
 
-        reply_handle
+        let reply = CreateReply::default()
+            .content(
+                self.state
+                    .render_content(self.ctx)
+                    .await?
+                    .unwrap_or_default(),
+            )
+            .embed(self.state.render_embed(self.ctx).await?)
+            .components(components);
+
+        let attachments = self.state.render_attachments(self.ctx).await?;
+        if attachments.is_empty() {
+            reply_handle.edit(*self.ctx, reply).await?;
+            return Ok(());
+        }
+
+        // Poise drops attachments when editing an interaction response, so the message itself
+        // is edited instead.
+        let reply = attachments
+            .into_iter()
+            .fold(reply, |reply, attachment| reply.attachment(attachment));
+        let mut message = reply_handle.message().await?.into_owned();
+        message
             .edit(
-                *self.ctx,
-                CreateReply::default()
-                    .content(
-                        self.state
-                            .render_content(self.ctx)
-                            .await?
-                            .unwrap_or_default(),
-                    )
-                    .embed(self.state.render_embed(self.ctx).await?)
-                    .components(components),
+                self.ctx.serenity_context(),
+                reply.to_prefix_edit(EditMessage::new()),
             )
             .await?;
         Ok(())
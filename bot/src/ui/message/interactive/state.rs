@@ -1,6 +1,8 @@
 use crate::error::BotResult;
 use crate::Context;
-use poise::serenity_prelude::{ComponentInteraction, CreateActionRow, CreateEmbed};
+use poise::serenity_prelude::{
+    ComponentInteraction, CreateActionRow, CreateAttachment, CreateEmbed, Message,
+};
 
 pub mod accept;
 pub mod pagination;
@@ -20,15 +22,34 @@ pub trait InteractiveState: Send + Sync {
         Ok(None)
     }
 
+    /// Files sent along with every render, replacing the previous ones.
+    async fn render_attachments(&self, _ctx: &Context) -> BotResult<Vec<CreateAttachment>> {
+        Ok(Vec::new())
+    }
+
     async fn on_tick(&mut self, _ctx: &Context) -> BotResult<InteractiveStateResponse> {
         Ok(InteractiveStateResponse::default())
     }
+
+    /// Called once the message is sent and after every update, `finished` is set once the message
+    /// stops or times out. Allows the state to outlive the message, e.g. across restarts.
+    async fn persist(&self, _ctx: &Context, _message: &Message, _finished: bool) -> BotResult<()> {
+        Ok(())
+    }
+
+    /// Called when the message times out or fails before the state stopped by itself, right
+    /// before it is persisted as finished. Allows releasing what the state still holds.
+    async fn on_abandon(&mut self, _ctx: &Context) -> BotResult<()> {
+        Ok(())
+    }
 }
 
 #[derive(Default)]
 pub struct InteractiveStateResponse {
     pub do_update: bool,
     pub do_stop: bool,
+    /// The state already responded to the interaction itself, e.g. with an ephemeral message.
+    pub responded: bool,
 }
 
 impl InteractiveStateResponse {
@@ -40,6 +61,7 @@ impl InteractiveStateResponse {
         Self {
             do_update: true,
             do_stop: true,
+            ..Default::default()
         }
     }
 
@@ -59,4 +81,9 @@ impl InteractiveStateResponse {
         self.do_update = update;
         self
     }
+
+    pub fn responded(mut self, responded: bool) -> Self {
+        self.responded = responded;
+        self
+    }
 }
@@ -35,6 +35,7 @@ pub trait AcceptStateTrait: Sized + Send + Sync {
         "Deny"
     }
 
+    #[allow(dead_code)]
     fn build(self) -> AcceptState<Self> {
         AcceptState(self)
     }
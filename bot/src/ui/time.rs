@@ -8,3 +8,14 @@ pub fn format_time_relative_in(duration: std::time::Duration) -> String {
 pub fn format_time_relative_at(date_time: chrono::DateTime<chrono::Utc>) -> String {
     format!("<t:{}:R>", date_time.timestamp())
 }
+
+/// Formats a duration like a stopwatch, e.g. `1:05.32`.
+pub fn format_stopwatch(ms: i64) -> String {
+    let centiseconds = ms.max(0) / 10;
+    format!(
+        "{}:{:02}.{:02}",
+        centiseconds / 6000,
+        centiseconds / 100 % 60,
+        centiseconds % 100
+    )
+}
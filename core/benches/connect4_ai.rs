@@ -0,0 +1,73 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use neobabu_core::games::connect4::ai::Connect4AI;
+use neobabu_core::games::connect4::difficulty::Connect4Difficulty;
+use neobabu_core::games::connect4::solver::{Connect4Solver, Position, TranspositionTable};
+use neobabu_core::games::connect4::state::Connect4State;
+use std::hint::black_box;
+
+// Positions from the test sets of Pascal Pons' solver, columns are 1-based.
+const ENDGAME: &str = "2252576253462244111563365343671351441";
+const MIDGAME: &str = "7422341735647741166133573473242566";
+const OPENING: &str = "4453";
+// Just past the opening book, where Perfect has the most to solve.
+const PAST_BOOK: &str = "44445555";
+
+fn state(moves: &str) -> Connect4State {
+    let mut state = Connect4State::default();
+    for col in moves.bytes() {
+        state.play(col - b'1');
+    }
+    state
+}
+
+fn bench_solver(c: &mut Criterion) {
+    for (name, moves) in [("endgame", ENDGAME), ("midgame", MIDGAME)] {
+        let position = Position::from(&state(moves));
+        c.bench_function(&format!("solve {name}"), |b| {
+            b.iter(|| {
+                let mut solver = Connect4Solver::new(TranspositionTable::SMALL);
+                black_box(solver.solve(black_box(&position)))
+            })
+        });
+    }
+
+    // What Perfect solves on its first move past the opening book.
+    let position = Position::from(&state(PAST_BOOK));
+    let mut group = c.benchmark_group("solve");
+    group.sample_size(10);
+    group.bench_function("past book", |b| {
+        b.iter(|| {
+            let mut solver = Connect4Solver::new(TranspositionTable::LARGE);
+            black_box(solver.best_move_weak(black_box(&position)))
+        })
+    });
+    group.finish();
+}
+
+fn bench_ai(c: &mut Criterion) {
+    let opening = state(OPENING);
+    for difficulty in [Connect4Difficulty::Easy, Connect4Difficulty::Medium] {
+        c.bench_function(&format!("best move {difficulty}"), |b| {
+            b.iter(|| black_box(Connect4AI::best_move(black_box(&opening), difficulty)))
+        });
+    }
+
+    // Both search for up to a second per move, so fewer samples are taken. Perfect keeps its
+    // solvers between moves, so only its first iteration solves the position from scratch.
+    let mut group = c.benchmark_group("best move");
+    group.sample_size(10);
+    for (difficulty, moves) in [
+        (Connect4Difficulty::Hard, OPENING),
+        (Connect4Difficulty::Hard, PAST_BOOK),
+        (Connect4Difficulty::Perfect, PAST_BOOK),
+    ] {
+        let state = state(moves);
+        group.bench_function(format!("{difficulty} {moves}"), |b| {
+            b.iter(|| black_box(Connect4AI::best_move(black_box(&state), difficulty)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_solver, bench_ai);
+criterion_main!(benches);
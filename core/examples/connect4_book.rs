@@ -0,0 +1,86 @@
+//! Generates the Connect Four opening book in `src/games/connect4/book.rs`.
+//!
+//! Usage: `cargo run --release --example connect4_book -- [max moves]`
+//!
+//! The entries already in the book are kept, so it can be extended without solving them again.
+
+use neobabu_core::games::connect4::book::BOOK;
+use neobabu_core::games::connect4::solver::{Connect4Solver, Position, TranspositionTable};
+use std::collections::BTreeMap;
+
+fn main() {
+    let max_moves = std::env::args()
+        .nth(1)
+        .and_then(|arg| arg.parse().ok())
+        .unwrap_or(8);
+
+    let mut solver = Connect4Solver::new(TranspositionTable::LARGE);
+    let mut book = BOOK.iter().copied().collect::<BTreeMap<_, _>>();
+
+    let empty = Position {
+        current: 0,
+        mask: 0,
+        moves: 0,
+    };
+
+    // The AI moving first plays on even move counts, the AI moving second on odd ones.
+    for ai_first in [true, false] {
+        expand(&mut solver, &mut book, empty, ai_first, max_moves);
+    }
+
+    println!("pub const BOOK: &[(u64, u8)] = &[");
+    for (key, col) in book {
+        println!("    ({key:#x}, {col}),");
+    }
+    println!("];");
+}
+
+fn expand(
+    solver: &mut Connect4Solver,
+    book: &mut BTreeMap<u64, u8>,
+    position: Position,
+    ai_to_play: bool,
+    max_moves: u32,
+) {
+    if position.moves > max_moves || position.can_win_next() {
+        return;
+    }
+
+    if !ai_to_play {
+        for col in 0..7 {
+            if position.can_play(col) {
+                let mut next = position;
+                next.play(col);
+                expand(solver, book, next, true, max_moves);
+            }
+        }
+        return;
+    }
+
+    let col = if let Some(col) = book.get(&position.key()) {
+        *col
+    } else if let Some(col) = book.get(&position.mirrored_key()) {
+        6 - *col
+    } else {
+        let col = if position.moves == 0 {
+            // Solving the empty board takes far too long, the centre is known to win.
+            3
+        } else {
+            let (col, score) = solver
+                .best_move_weak(&position)
+                .expect("solver without deadline");
+            eprintln!(
+                "{:>2} moves, key {:#x}: column {col}, score {score}",
+                position.moves,
+                position.key()
+            );
+            col
+        };
+        book.insert(position.key(), col);
+        col
+    };
+
+    let mut next = position;
+    next.play(col);
+    expand(solver, book, next, false, max_moves);
+}
@@ -10,6 +10,22 @@ mod m20251204_132745_initial_inventory;
 mod m20251204_203659_initial_farming;
 mod m20251207_115149_initial_blackjack;
 mod m20251208_151406_initial_economy;
+mod m20251212_184512_economy_admin;
+mod m20251213_141022_initial_leveling;
+mod m20251215_090311_initial_lottery;
+mod m20251216_103214_initial_connect4;
+mod m20251218_141530_blackjack_rules;
+mod m20251219_093012_active_games;
+mod m20251220_101544_rps_wagers;
+mod m20251221_164208_rps_bot;
+mod m20251222_114205_game_records;
+mod m20251223_152107_game_ratings;
+mod m20251224_093418_achievements;
+mod m20251226_141537_puzzle_scores;
+mod m20251227_102318_birthday_roles;
+mod m20251228_154422_birthday_templates;
+mod m20251230_113047_birthday_privacy;
+mod m20251231_094512_blackjack_shoe;
 
 pub struct Migrator;
 
@@ -27,6 +43,22 @@ impl MigratorTrait for Migrator {
             Box::new(m20251204_203659_initial_farming::Migration),
             Box::new(m20251207_115149_initial_blackjack::Migration),
             Box::new(m20251208_151406_initial_economy::Migration),
+            Box::new(m20251212_184512_economy_admin::Migration),
+            Box::new(m20251213_141022_initial_leveling::Migration),
+            Box::new(m20251215_090311_initial_lottery::Migration),
+            Box::new(m20251216_103214_initial_connect4::Migration),
+            Box::new(m20251218_141530_blackjack_rules::Migration),
+            Box::new(m20251219_093012_active_games::Migration),
+            Box::new(m20251220_101544_rps_wagers::Migration),
+            Box::new(m20251221_164208_rps_bot::Migration),
+            Box::new(m20251222_114205_game_records::Migration),
+            Box::new(m20251223_152107_game_ratings::Migration),
+            Box::new(m20251224_093418_achievements::Migration),
+            Box::new(m20251226_141537_puzzle_scores::Migration),
+            Box::new(m20251227_102318_birthday_roles::Migration),
+            Box::new(m20251228_154422_birthday_templates::Migration),
+            Box::new(m20251230_113047_birthday_privacy::Migration),
+            Box::new(m20251231_094512_blackjack_shoe::Migration),
         ]
     }
 }
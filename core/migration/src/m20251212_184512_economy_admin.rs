@@ -0,0 +1,106 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EconomyFrozen::Table)
+                    .if_not_exists()
+                    .col(string(EconomyFrozen::UserId).primary_key())
+                    .col(string(EconomyFrozen::FrozenBy))
+                    .col(string_null(EconomyFrozen::Reason).default(Expr::null()))
+                    .col(timestamp(EconomyFrozen::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(EconomyFrozen::Table, EconomyFrozen::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(EconomyAudit::Table)
+                    .if_not_exists()
+                    .col(uuid(EconomyAudit::Id).primary_key())
+                    .col(string(EconomyAudit::AdminId))
+                    .col(string(EconomyAudit::UserId))
+                    .col(small_integer(EconomyAudit::Action))
+                    .col(small_integer_null(EconomyAudit::Currency).default(Expr::null()))
+                    .col(big_integer_null(EconomyAudit::Amount).default(Expr::null()))
+                    .col(big_integer_null(EconomyAudit::BalanceBefore).default(Expr::null()))
+                    .col(big_integer_null(EconomyAudit::BalanceAfter).default(Expr::null()))
+                    .col(string_null(EconomyAudit::Reason).default(Expr::null()))
+                    .col(timestamp(EconomyAudit::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(EconomyAudit::Table, EconomyAudit::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(EconomyAudit::Table)
+                    .col(EconomyAudit::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EconomyFrozen::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(EconomyAudit::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum EconomyFrozen {
+    Table,
+    UserId,
+    FrozenBy,
+    Reason,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum EconomyAudit {
+    Table,
+    Id,
+    AdminId,
+    UserId,
+    Action,
+    Currency,
+    Amount,
+    BalanceBefore,
+    BalanceAfter,
+    Reason,
+    CreatedAt,
+}
@@ -0,0 +1,190 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserLevel::Table)
+                    .col(string(UserLevel::UserId).primary_key())
+                    .col(big_integer(UserLevel::Xp).default(0))
+                    .col(integer(UserLevel::Level).default(0))
+                    .col(big_integer(UserLevel::MessageCount).default(0))
+                    .col(timestamp(UserLevel::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(UserLevel::UpdatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(UserLevel::Table, UserLevel::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserGuildLevel::Table)
+                    .col(string(UserGuildLevel::UserId))
+                    .col(string(UserGuildLevel::GuildId))
+                    .col(big_integer(UserGuildLevel::Xp).default(0))
+                    .col(integer(UserGuildLevel::Level).default(0))
+                    .col(big_integer(UserGuildLevel::MessageCount).default(0))
+                    .col(timestamp(UserGuildLevel::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(UserGuildLevel::UpdatedAt).default(Expr::current_timestamp()))
+                    .primary_key(
+                        Index::create()
+                            .col(UserGuildLevel::UserId)
+                            .col(UserGuildLevel::GuildId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(UserGuildLevel::Table, UserGuildLevel::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(UserGuildLevel::Table, UserGuildLevel::GuildId)
+                            .to(Guild::Table, Guild::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_guild_level_guild_id_xp")
+                    .table(UserGuildLevel::Table)
+                    .col(UserGuildLevel::GuildId)
+                    .col(UserGuildLevel::Xp)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(GuildLeveling::Table)
+                    .col(string(GuildLeveling::GuildId).primary_key())
+                    .col(boolean(GuildLeveling::Enabled).default(false))
+                    .col(string_null(GuildLeveling::NotificationChannelId).default(Expr::null()))
+                    .col(timestamp(GuildLeveling::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(GuildLeveling::UpdatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GuildLeveling::Table, GuildLeveling::GuildId)
+                            .to(Guild::Table, Guild::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(GuildLevelReward::Table)
+                    .col(string(GuildLevelReward::GuildId))
+                    .col(integer(GuildLevelReward::Level))
+                    .col(string(GuildLevelReward::RoleId))
+                    .col(timestamp(GuildLevelReward::CreatedAt).default(Expr::current_timestamp()))
+                    .primary_key(
+                        Index::create()
+                            .col(GuildLevelReward::GuildId)
+                            .col(GuildLevelReward::Level),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GuildLevelReward::Table, GuildLevelReward::GuildId)
+                            .to(Guild::Table, Guild::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GuildLevelReward::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(GuildLeveling::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(UserGuildLevel::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(UserLevel::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Guild {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum UserLevel {
+    Table,
+    UserId,
+    Xp,
+    Level,
+    MessageCount,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum UserGuildLevel {
+    Table,
+    UserId,
+    GuildId,
+    Xp,
+    Level,
+    MessageCount,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum GuildLeveling {
+    Table,
+    GuildId,
+    Enabled,
+    NotificationChannelId,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum GuildLevelReward {
+    Table,
+    GuildId,
+    Level,
+    RoleId,
+    CreatedAt,
+}
@@ -0,0 +1,176 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GuildLottery::Table)
+                    .col(string(GuildLottery::GuildId).primary_key())
+                    .col(boolean(GuildLottery::Enabled).default(false))
+                    .col(string_null(GuildLottery::NotificationChannelId).default(Expr::null()))
+                    .col(big_integer(GuildLottery::TicketPrice).default(10))
+                    .col(small_integer(GuildLottery::SinkPercent).default(0))
+                    .col(integer(GuildLottery::RoundHours).default(168))
+                    .col(timestamp(GuildLottery::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(GuildLottery::UpdatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GuildLottery::Table, GuildLottery::GuildId)
+                            .to(Guild::Table, Guild::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(LotteryRound::Table)
+                    .col(uuid(LotteryRound::Id).primary_key())
+                    .col(string(LotteryRound::GuildId))
+                    .col(string(LotteryRound::Seed))
+                    .col(string(LotteryRound::SeedHash))
+                    .col(big_integer(LotteryRound::TicketPrice))
+                    .col(big_integer(LotteryRound::CarriedOver).default(0))
+                    .col(big_integer_null(LotteryRound::Pot).default(Expr::null()))
+                    .col(big_integer_null(LotteryRound::Sink).default(Expr::null()))
+                    .col(string_null(LotteryRound::WinnerId).default(Expr::null()))
+                    .col(big_integer_null(LotteryRound::WinningTicket).default(Expr::null()))
+                    .col(timestamp(LotteryRound::DrawsAt))
+                    .col(timestamp_null(LotteryRound::DrawnAt).default(Expr::null()))
+                    .col(timestamp(LotteryRound::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(LotteryRound::Table, LotteryRound::GuildId)
+                            .to(Guild::Table, Guild::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_lottery_round_guild_id")
+                    .table(LotteryRound::Table)
+                    .col(LotteryRound::GuildId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(LotteryTicket::Table)
+                    .col(uuid(LotteryTicket::Id).primary_key())
+                    .col(uuid(LotteryTicket::RoundId))
+                    .col(string(LotteryTicket::UserId))
+                    .col(integer(LotteryTicket::Tickets))
+                    .col(big_integer(LotteryTicket::Cost))
+                    .col(timestamp(LotteryTicket::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(LotteryTicket::Table, LotteryTicket::RoundId)
+                            .to(LotteryRound::Table, LotteryRound::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(LotteryTicket::Table, LotteryTicket::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_lottery_ticket_round_id")
+                    .table(LotteryTicket::Table)
+                    .col(LotteryTicket::RoundId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(LotteryTicket::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(LotteryRound::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(GuildLottery::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Guild {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum GuildLottery {
+    Table,
+    GuildId,
+    Enabled,
+    NotificationChannelId,
+    TicketPrice,
+    SinkPercent,
+    RoundHours,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum LotteryRound {
+    Table,
+    Id,
+    GuildId,
+    Seed,
+    SeedHash,
+    TicketPrice,
+    CarriedOver,
+    Pot,
+    Sink,
+    WinnerId,
+    WinningTicket,
+    DrawsAt,
+    DrawnAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum LotteryTicket {
+    Table,
+    Id,
+    RoundId,
+    UserId,
+    Tickets,
+    Cost,
+    CreatedAt,
+}
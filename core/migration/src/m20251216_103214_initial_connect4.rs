@@ -0,0 +1,152 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .if_not_exists()
+                    .table(Connect4User::Table)
+                    .col(string(Connect4User::UserId).primary_key())
+                    .col(integer(Connect4User::Wins).default(0))
+                    .col(integer(Connect4User::Losses).default(0))
+                    .col(integer(Connect4User::Draws).default(0))
+                    .col(big_integer(Connect4User::MovesTotal).default(0))
+                    .col(timestamp(Connect4User::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(Connect4User::UpdatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Connect4User::Table, Connect4User::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .if_not_exists()
+                    .table(Connect4Games::Table)
+                    .col(string(Connect4Games::UserId1))
+                    .col(string(Connect4Games::UserId2))
+                    .col(integer(Connect4Games::Wins1).default(0))
+                    .col(integer(Connect4Games::Wins2).default(0))
+                    .col(integer(Connect4Games::Draws).default(0))
+                    .col(timestamp(Connect4Games::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(Connect4Games::UpdatedAt).default(Expr::current_timestamp()))
+                    .primary_key(
+                        Index::create()
+                            .col(Connect4Games::UserId1)
+                            .col(Connect4Games::UserId2),
+                    )
+                    .check(Expr::col(Connect4Games::UserId1).lt(Expr::col(Connect4Games::UserId2)))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Connect4Games::Table, Connect4Games::UserId1)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Connect4Games::Table, Connect4Games::UserId2)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .if_not_exists()
+                    .table(Connect4Ai::Table)
+                    .col(string(Connect4Ai::UserId))
+                    .col(small_integer(Connect4Ai::Difficulty))
+                    .col(integer(Connect4Ai::Wins).default(0))
+                    .col(integer(Connect4Ai::Losses).default(0))
+                    .col(integer(Connect4Ai::Draws).default(0))
+                    .col(timestamp(Connect4Ai::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(Connect4Ai::UpdatedAt).default(Expr::current_timestamp()))
+                    .primary_key(
+                        Index::create()
+                            .col(Connect4Ai::UserId)
+                            .col(Connect4Ai::Difficulty),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Connect4Ai::Table, Connect4Ai::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Connect4Ai::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Connect4Games::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Connect4User::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Connect4User {
+    Table,
+    UserId,
+    Wins,
+    Losses,
+    Draws,
+    MovesTotal,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Connect4Games {
+    Table,
+    UserId1,
+    UserId2,
+    Wins1,
+    Wins2,
+    Draws,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Connect4Ai {
+    Table,
+    UserId,
+    Difficulty,
+    Wins,
+    Losses,
+    Draws,
+    CreatedAt,
+    UpdatedAt,
+}
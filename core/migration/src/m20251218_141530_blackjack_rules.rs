@@ -0,0 +1,48 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GuildBlackjack::Table)
+                    .col(string(GuildBlackjack::GuildId).primary_key())
+                    .col(small_integer(GuildBlackjack::Preset).default(0))
+                    .col(timestamp(GuildBlackjack::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(GuildBlackjack::UpdatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GuildBlackjack::Table, GuildBlackjack::GuildId)
+                            .to(Guild::Table, Guild::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GuildBlackjack::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Guild {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum GuildBlackjack {
+    Table,
+    GuildId,
+    Preset,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,42 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ActiveGame::Table)
+                    .col(uuid(ActiveGame::Id).primary_key())
+                    .col(small_integer(ActiveGame::Kind))
+                    .col(string(ActiveGame::ChannelId))
+                    .col(string(ActiveGame::MessageId))
+                    .col(json_binary(ActiveGame::State))
+                    .col(timestamp(ActiveGame::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(ActiveGame::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ActiveGame::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ActiveGame {
+    Table,
+    Id,
+    Kind,
+    ChannelId,
+    MessageId,
+    State,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RpsUser::Table)
+                    .add_column(big_integer(RpsUser::TotalCitrineWagered).default(0))
+                    .add_column(big_integer(RpsUser::TotalCitrineWon).default(0))
+                    .add_column(big_integer(RpsUser::TotalCitrineLost).default(0))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RpsUser::Table)
+                    .drop_column(RpsUser::TotalCitrineWagered)
+                    .drop_column(RpsUser::TotalCitrineWon)
+                    .drop_column(RpsUser::TotalCitrineLost)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RpsUser {
+    Table,
+    TotalCitrineWagered,
+    TotalCitrineWon,
+    TotalCitrineLost,
+}
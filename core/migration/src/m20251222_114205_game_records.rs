@@ -0,0 +1,46 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GameRecord::Table)
+                    .col(uuid(GameRecord::Id).primary_key())
+                    .col(small_integer(GameRecord::Kind))
+                    .col(json_binary(GameRecord::Players))
+                    .col(big_integer_null(GameRecord::Seed))
+                    .col(json_binary(GameRecord::Setup))
+                    .col(json_binary(GameRecord::Moves))
+                    .col(json_binary(GameRecord::Outcomes))
+                    .col(timestamp(GameRecord::StartedAt))
+                    .col(timestamp(GameRecord::EndedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GameRecord::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum GameRecord {
+    Table,
+    Id,
+    Kind,
+    Players,
+    Seed,
+    Setup,
+    Moves,
+    Outcomes,
+    StartedAt,
+    EndedAt,
+}
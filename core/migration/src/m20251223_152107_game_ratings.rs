@@ -0,0 +1,114 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .if_not_exists()
+                    .table(GameRating::Table)
+                    .col(string(GameRating::UserId))
+                    .col(small_integer(GameRating::Kind))
+                    .col(double(GameRating::Rating).default(1500.0))
+                    .col(double(GameRating::Deviation).default(350.0))
+                    .col(double(GameRating::Volatility).default(0.06))
+                    .col(integer(GameRating::Games).default(0))
+                    .col(timestamp(GameRating::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(GameRating::UpdatedAt).default(Expr::current_timestamp()))
+                    .primary_key(
+                        Index::create()
+                            .col(GameRating::UserId)
+                            .col(GameRating::Kind),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GameRating::Table, GameRating::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .if_not_exists()
+                    .table(GameRatingHistory::Table)
+                    .col(uuid(GameRatingHistory::Id).primary_key())
+                    .col(string(GameRatingHistory::UserId))
+                    .col(small_integer(GameRatingHistory::Kind))
+                    .col(double(GameRatingHistory::Rating))
+                    .col(double(GameRatingHistory::Deviation))
+                    .col(uuid(GameRatingHistory::GameId))
+                    .col(timestamp(GameRatingHistory::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GameRatingHistory::Table, GameRatingHistory::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GameRatingHistory::Table)
+                    .col(GameRatingHistory::UserId)
+                    .col(GameRatingHistory::Kind)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GameRatingHistory::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(GameRating::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum GameRating {
+    Table,
+    UserId,
+    Kind,
+    Rating,
+    Deviation,
+    Volatility,
+    Games,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum GameRatingHistory {
+    Table,
+    Id,
+    UserId,
+    Kind,
+    Rating,
+    Deviation,
+    GameId,
+    CreatedAt,
+}
@@ -0,0 +1,54 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .if_not_exists()
+                    .table(AchievementUnlock::Table)
+                    .col(string(AchievementUnlock::UserId))
+                    .col(small_integer(AchievementUnlock::Achievement))
+                    .col(
+                        timestamp(AchievementUnlock::UnlockedAt).default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(AchievementUnlock::UserId)
+                            .col(AchievementUnlock::Achievement),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(AchievementUnlock::Table, AchievementUnlock::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AchievementUnlock::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum AchievementUnlock {
+    Table,
+    UserId,
+    Achievement,
+    UnlockedAt,
+}
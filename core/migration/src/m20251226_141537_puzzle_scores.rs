@@ -0,0 +1,62 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .if_not_exists()
+                    .table(PuzzleScore::Table)
+                    .col(string(PuzzleScore::UserId))
+                    .col(small_integer(PuzzleScore::Kind))
+                    .col(integer(PuzzleScore::Games).default(0))
+                    .col(integer(PuzzleScore::Wins).default(0))
+                    .col(big_integer_null(PuzzleScore::BestScore).default(Expr::null()))
+                    .col(big_integer_null(PuzzleScore::BestTimeMs).default(Expr::null()))
+                    .col(timestamp(PuzzleScore::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(PuzzleScore::UpdatedAt).default(Expr::current_timestamp()))
+                    .primary_key(
+                        Index::create()
+                            .col(PuzzleScore::UserId)
+                            .col(PuzzleScore::Kind),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PuzzleScore::Table, PuzzleScore::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PuzzleScore::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum PuzzleScore {
+    Table,
+    UserId,
+    Kind,
+    Games,
+    Wins,
+    BestScore,
+    BestTimeMs,
+    CreatedAt,
+    UpdatedAt,
+}
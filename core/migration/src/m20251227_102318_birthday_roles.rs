@@ -0,0 +1,104 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GuildBirthday::Table)
+                    .add_column(
+                        string_null(GuildBirthday::NotificationRoleId).default(Expr::null()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .if_not_exists()
+                    .table(BirthdayRole::Table)
+                    .col(string(BirthdayRole::UserId))
+                    .col(string(BirthdayRole::GuildId))
+                    .col(string(BirthdayRole::RoleId))
+                    .col(timestamp(BirthdayRole::ExpiresAt))
+                    .col(timestamp(BirthdayRole::CreatedAt).default(Expr::current_timestamp()))
+                    .primary_key(
+                        Index::create()
+                            .col(BirthdayRole::UserId)
+                            .col(BirthdayRole::GuildId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(BirthdayRole::Table, BirthdayRole::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(BirthdayRole::Table, BirthdayRole::GuildId)
+                            .to(Guild::Table, Guild::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_birthday_role_expires_at")
+                    .table(BirthdayRole::Table)
+                    .col(BirthdayRole::ExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BirthdayRole::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GuildBirthday::Table)
+                    .drop_column(GuildBirthday::NotificationRoleId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Guild {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum GuildBirthday {
+    Table,
+    NotificationRoleId,
+}
+
+#[derive(DeriveIden)]
+enum BirthdayRole {
+    Table,
+    UserId,
+    GuildId,
+    RoleId,
+    ExpiresAt,
+    CreatedAt,
+}
@@ -0,0 +1,43 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GuildBirthday::Table)
+                    .add_column(json_binary(GuildBirthday::AnnouncementTemplates).default("[]"))
+                    .add_column(json_binary(GuildBirthday::BelatedTemplates).default("[]"))
+                    .add_column(
+                        string_null(GuildBirthday::AnnouncementImageUrl).default(Expr::null()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GuildBirthday::Table)
+                    .drop_column(GuildBirthday::AnnouncementTemplates)
+                    .drop_column(GuildBirthday::BelatedTemplates)
+                    .drop_column(GuildBirthday::AnnouncementImageUrl)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum GuildBirthday {
+    Table,
+    AnnouncementTemplates,
+    BelatedTemplates,
+    AnnouncementImageUrl,
+}
@@ -0,0 +1,94 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserBirthday::Table)
+                    .add_column(boolean(UserBirthday::HideAge).default(false))
+                    .add_column(boolean(UserBirthday::HideFromListings).default(false))
+                    .add_column(boolean(UserBirthday::OptOutAnnouncements).default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .if_not_exists()
+                    .table(BirthdayOptOut::Table)
+                    .col(string(BirthdayOptOut::UserId))
+                    .col(string(BirthdayOptOut::GuildId))
+                    .col(timestamp(BirthdayOptOut::CreatedAt).default(Expr::current_timestamp()))
+                    .primary_key(
+                        Index::create()
+                            .col(BirthdayOptOut::UserId)
+                            .col(BirthdayOptOut::GuildId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(BirthdayOptOut::Table, BirthdayOptOut::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(BirthdayOptOut::Table, BirthdayOptOut::GuildId)
+                            .to(Guild::Table, Guild::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BirthdayOptOut::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserBirthday::Table)
+                    .drop_column(UserBirthday::HideAge)
+                    .drop_column(UserBirthday::HideFromListings)
+                    .drop_column(UserBirthday::OptOutAnnouncements)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Guild {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum UserBirthday {
+    Table,
+    HideAge,
+    HideFromListings,
+    OptOutAnnouncements,
+}
+
+#[derive(DeriveIden)]
+enum BirthdayOptOut {
+    Table,
+    UserId,
+    GuildId,
+    CreatedAt,
+}
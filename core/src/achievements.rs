@@ -0,0 +1,177 @@
+//! Achievements for milestones across the games.
+//!
+//! Services report what just happened to a player as an [`AchievementEvent`], together with the
+//! stats it changed. Every achievement that is not unlocked yet checks its condition against the
+//! event and is unlocked once it is met, paying out its reward if it has one.
+
+use crate::database::entity::{black_jack_user, connect4_user};
+use crate::games::connect4::difficulty::Connect4Difficulty;
+use crate::games::rps::stats::RPSStats;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use std::fmt::Display;
+use strum::EnumIter;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive, EnumIter)]
+#[repr(i16)]
+pub enum Achievement {
+    BlackjackNatural = 0,
+    BlackjackWinStreak = 1,
+    BlackjackNaturalStreak = 2,
+    RockPaperScissorsFirstWin = 3,
+    RockPaperScissorsVeteran = 4,
+    Connect4FirstWin = 5,
+    Connect4BeatPerfect = 6,
+    FarmFirstWorld = 7,
+}
+
+pub struct AchievementDefinition {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// The Citrine paid out when the achievement is unlocked.
+    pub reward: Option<u32>,
+}
+
+/// What happened to a player, with their stats after it.
+#[derive(Debug, Clone)]
+pub enum AchievementEvent {
+    BlackjackResolved(black_jack_user::Model),
+    Connect4Finished {
+        stats: connect4_user::Model,
+        /// Set if the player beat the bot on this difficulty.
+        defeated_ai: Option<Connect4Difficulty>,
+    },
+    RockPaperScissorsFinished(RPSStats),
+    FarmWorldCreated {
+        worlds: u64,
+    },
+}
+
+impl Achievement {
+    pub fn definition(&self) -> AchievementDefinition {
+        let (name, description, reward) = match self {
+            Self::BlackjackNatural => ("Natural", "Get a blackjack.", None),
+            Self::BlackjackWinStreak => {
+                ("On a Roll", "Win 5 hands of blackjack in a row.", Some(500))
+            }
+            Self::BlackjackNaturalStreak => (
+                "Lightning Strikes Twice",
+                "Get a blackjack in 2 hands in a row.",
+                Some(1000),
+            ),
+            Self::RockPaperScissorsFirstWin => {
+                ("First Throw", "Win a game of Rock Paper Scissors.", None)
+            }
+            Self::RockPaperScissorsVeteran => (
+                "Hand Signals",
+                "Play 100 games of Rock Paper Scissors.",
+                Some(250),
+            ),
+            Self::Connect4FirstWin => ("Four in a Row", "Win a game of Connect Four.", None),
+            Self::Connect4BeatPerfect => (
+                "Unbeatable?",
+                "Beat the bot on Perfect in Connect Four.",
+                Some(2500),
+            ),
+            Self::FarmFirstWorld => ("Homesteader", "Create your first farm world.", Some(100)),
+        };
+
+        AchievementDefinition {
+            name,
+            description,
+            reward,
+        }
+    }
+
+    /// Whether the event fulfills the condition of the achievement.
+    pub fn is_met(&self, event: &AchievementEvent) -> bool {
+        match (self, event) {
+            (Self::BlackjackNatural, AchievementEvent::BlackjackResolved(stats)) => {
+                stats.blackjack_count >= 1
+            }
+            (Self::BlackjackWinStreak, AchievementEvent::BlackjackResolved(stats)) => {
+                stats.longest_win_streak >= 5
+            }
+            (Self::BlackjackNaturalStreak, AchievementEvent::BlackjackResolved(stats)) => {
+                stats.longest_blackjack_streak >= 2
+            }
+            (
+                Self::RockPaperScissorsFirstWin,
+                AchievementEvent::RockPaperScissorsFinished(stats),
+            ) => stats.wins >= 1,
+            (
+                Self::RockPaperScissorsVeteran,
+                AchievementEvent::RockPaperScissorsFinished(stats),
+            ) => stats.total_played() >= 100,
+            (Self::Connect4FirstWin, AchievementEvent::Connect4Finished { stats, .. }) => {
+                stats.wins >= 1
+            }
+            (Self::Connect4BeatPerfect, AchievementEvent::Connect4Finished { defeated_ai, .. }) => {
+                *defeated_ai == Some(Connect4Difficulty::Perfect)
+            }
+            (Self::FarmFirstWorld, AchievementEvent::FarmWorldCreated { worlds }) => *worlds >= 1,
+            _ => false,
+        }
+    }
+}
+
+impl Display for Achievement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.definition().name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    fn rps_stats(wins: u64, losses: u64) -> RPSStats {
+        RPSStats {
+            wins,
+            losses,
+            draws: 0,
+            rock: 0,
+            paper: 0,
+            scissors: 0,
+            citrine_wagered: 0,
+            citrine_won: 0,
+            citrine_lost: 0,
+        }
+    }
+
+    fn met_by(event: &AchievementEvent) -> Vec<Achievement> {
+        Achievement::iter()
+            .filter(|achievement| achievement.is_met(event))
+            .collect()
+    }
+
+    #[test]
+    fn test_rps_games() {
+        let event = AchievementEvent::RockPaperScissorsFinished(rps_stats(0, 99));
+        assert!(met_by(&event).is_empty());
+
+        let event = AchievementEvent::RockPaperScissorsFinished(rps_stats(1, 99));
+        assert_eq!(
+            met_by(&event),
+            vec![
+                Achievement::RockPaperScissorsFirstWin,
+                Achievement::RockPaperScissorsVeteran
+            ]
+        );
+    }
+
+    #[test]
+    fn test_conditions_only_match_their_events() {
+        let event = AchievementEvent::FarmWorldCreated { worlds: 1 };
+        assert_eq!(met_by(&event), vec![Achievement::FarmFirstWorld]);
+    }
+
+    #[test]
+    fn test_ids_are_stable() {
+        for achievement in Achievement::iter() {
+            let id = i16::from(achievement);
+            assert_eq!(Achievement::try_from(id).ok(), Some(achievement));
+        }
+        assert_eq!(i16::from(Achievement::FarmFirstWorld), 7);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod announcement;
+pub mod calendar;
+pub mod due;
@@ -0,0 +1,184 @@
+use crate::error::{CoreError, CoreResult};
+use crate::utils::template::Template;
+use rand::seq::IndexedRandom;
+
+pub const PLACEHOLDERS: &[&str] = &["user", "age", "ordinal_age", "belated", "server"];
+const AGE_PLACEHOLDERS: [&str; 2] = ["age", "ordinal_age"];
+/// Variants are entered on a single line, separated by this character.
+pub const VARIANT_SEPARATOR: char = '|';
+pub const MAX_VARIANTS: usize = 10;
+pub const MAX_TEMPLATE_LENGTH: usize = 500;
+
+const DEFAULT_TEMPLATE: &str = "{user}, **Happy {belated} birthday!**";
+const DEFAULT_AGE_TEMPLATE: &str = "{user} turned `{age}`, **Happy {belated} birthday!**";
+
+/// The values a birthday announcement template is rendered with.
+#[derive(Debug, Clone)]
+pub struct BirthdayAnnouncement {
+    pub user_id: String,
+    pub age: Option<u8>,
+    pub is_belated: bool,
+    pub server: String,
+}
+
+impl BirthdayAnnouncement {
+    pub fn render(&self, template: &Template) -> String {
+        template.render(|name| match name {
+            "user" => format!("<@{}>", self.user_id),
+            "age" => self.age.map(|age| age.to_string()).unwrap_or_default(),
+            "ordinal_age" => self.age.map(ordinal).unwrap_or_default(),
+            "belated" if self.is_belated => "belated".to_string(),
+            "server" => self.server.clone(),
+            _ => String::new(),
+        })
+    }
+}
+
+pub fn parse_template(source: &str) -> CoreResult<Template> {
+    if source.chars().count() > MAX_TEMPLATE_LENGTH {
+        return Err(CoreError::invalid_template(format!(
+            "Templates can be at most {MAX_TEMPLATE_LENGTH} characters long."
+        )));
+    }
+    Template::parse(source, PLACEHOLDERS)
+}
+
+/// Splits and validates the variants of a template, an empty input yields no variants.
+pub fn parse_variants(source: &str) -> CoreResult<Vec<String>> {
+    let variants = source
+        .split(VARIANT_SEPARATOR)
+        .map(str::trim)
+        .filter(|variant| !variant.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+
+    if variants.len() > MAX_VARIANTS {
+        return Err(CoreError::invalid_template(format!(
+            "At most {MAX_VARIANTS} variants are allowed."
+        )));
+    }
+
+    for variant in &variants {
+        parse_template(variant)?;
+    }
+
+    Ok(variants)
+}
+
+/// Picks a random variant, preferring the belated ones for belated wishes.
+///
+/// Variants mentioning the age are skipped if it is unknown, `None` means the default template applies.
+pub fn pick_template(
+    templates: &[String],
+    belated_templates: &[String],
+    is_belated: bool,
+    has_age: bool,
+) -> Option<Template> {
+    let usable = |variants: &[String]| {
+        variants
+            .iter()
+            .filter_map(|variant| parse_template(variant).ok())
+            .filter(|template| has_age || !AGE_PLACEHOLDERS.iter().any(|p| template.uses(p)))
+            .collect::<Vec<_>>()
+    };
+
+    let mut candidates = Vec::new();
+    if is_belated {
+        candidates = usable(belated_templates);
+    }
+    if candidates.is_empty() {
+        candidates = usable(templates);
+    }
+
+    candidates.choose(&mut rand::rng()).cloned()
+}
+
+pub fn parse_image_url(source: &str) -> CoreResult<String> {
+    match url::Url::parse(source.trim()) {
+        Ok(url) if url.scheme() == "https" => Ok(url.to_string()),
+        _ => Err(CoreError::invalid_template(
+            "The image has to be an `https://` link.",
+        )),
+    }
+}
+
+pub fn default_template(has_age: bool) -> Template {
+    let source = if has_age {
+        DEFAULT_AGE_TEMPLATE
+    } else {
+        DEFAULT_TEMPLATE
+    };
+    Template::parse(source, PLACEHOLDERS).expect("default birthday template is valid")
+}
+
+pub fn ordinal(number: u8) -> String {
+    let suffix = match (number % 10, number % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{number}{suffix}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn announcement(age: Option<u8>, is_belated: bool) -> BirthdayAnnouncement {
+        BirthdayAnnouncement {
+            user_id: "1".to_string(),
+            age,
+            is_belated,
+            server: "Neo".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_renders_default_templates() {
+        assert_eq!(
+            announcement(Some(21), false).render(&default_template(true)),
+            "<@1> turned `21`, **Happy birthday!**"
+        );
+        assert_eq!(
+            announcement(None, true).render(&default_template(false)),
+            "<@1>, **Happy belated birthday!**"
+        );
+    }
+
+    #[test]
+    fn test_formats_ordinals() {
+        let ordinals = [1, 2, 3, 4, 11, 12, 13, 21, 22, 101, 111, 112]
+            .map(ordinal)
+            .join(" ");
+        assert_eq!(
+            ordinals,
+            "1st 2nd 3rd 4th 11th 12th 13th 21st 22nd 101st 111th 112th"
+        );
+    }
+
+    #[test]
+    fn test_parses_variants() {
+        let variants = parse_variants(" Hi {user} | | Welcome to {server}, {user}").unwrap();
+        assert_eq!(variants, ["Hi {user}", "Welcome to {server}, {user}"]);
+        assert!(parse_variants("").unwrap().is_empty());
+        assert!(parse_variants("Hi {name}").is_err());
+        assert!(parse_variants(&"{user}|".repeat(MAX_VARIANTS + 1)).is_err());
+    }
+
+    #[test]
+    fn test_picks_usable_templates() {
+        let templates = ["{user} is {ordinal_age}".to_string()];
+        let belated = ["Late, {user}".to_string()];
+
+        assert!(pick_template(&templates, &[], false, false).is_none());
+        let template = pick_template(&templates, &belated, true, true).unwrap();
+        assert_eq!(announcement(Some(30), true).render(&template), "Late, <@1>");
+        let template = pick_template(&templates, &belated, false, true).unwrap();
+        assert_eq!(
+            announcement(Some(30), false).render(&template),
+            "<@1> is 30th"
+        );
+    }
+}
@@ -0,0 +1,321 @@
+use crate::database::entity::user_birthday;
+use crate::error::CoreResult;
+use crate::rendering::o2d::prelude::{
+    LayerO2D, O2DRenderable, O2DRenderer, Object2D, PositionO2D, TextVisual,
+};
+use crate::utils::dates::{birthday_in_year, iter_date_days_of_month};
+use chrono::{Datelike, Month, NaiveDate};
+use image::Rgba;
+use std::collections::BTreeMap;
+use std::io::Cursor;
+
+const TILE_SIZE: u8 = 4;
+/// Every day is drawn as a box of 7x6 image tiles.
+const CELL_WIDTH: u8 = 7;
+const CELL_HEIGHT: u8 = 6;
+const GAP: u8 = 1;
+const PADDING: u8 = 1;
+const TITLE_HEIGHT: u8 = 4;
+const WEEKDAYS_HEIGHT: u8 = 3;
+const GRID_START: u8 = PADDING + TITLE_HEIGHT + WEEKDAYS_HEIGHT;
+const WIDTH: u8 = PADDING * 2 + 7 * CELL_WIDTH + 6 * GAP;
+const MAX_MARKERS: usize = 3;
+const CHAR_WIDTH: u32 = 6;
+/// Text is drawn upwards from its position, the top of its glyphs being this many pixels above.
+const GLYPH_TOP: u32 = 9;
+
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+const BACKGROUND: Rgba<u8> = Rgba([35, 32, 45, 255]);
+const DAY: Rgba<u8> = Rgba([52, 48, 63, 255]);
+const BIRTHDAY: Rgba<u8> = Rgba([120, 62, 98, 255]);
+const MARKER: Rgba<u8> = Rgba([244, 143, 177, 255]);
+const TODAY: Rgba<u8> = Rgba([224, 180, 84, 255]);
+const TEXT: Rgba<u8> = Rgba([217, 211, 217, 255]);
+const TEXT_MUTED: Rgba<u8> = Rgba([140, 134, 150, 255]);
+
+#[derive(Debug, Clone, Copy)]
+enum CalendarLayer {
+    Background,
+    Cells,
+    Text,
+}
+
+impl LayerO2D for CalendarLayer {
+    fn get_z_index(&self) -> u8 {
+        match self {
+            Self::Background => 0,
+            Self::Cells => 1,
+            Self::Text => 2,
+        }
+    }
+}
+
+/// A month grid starting on Mondays, days with birthdays are marked with a dot per birthday.
+pub struct BirthdayCalendar {
+    /// The first day of the shown month.
+    pub month: NaiveDate,
+    /// The ids of the users celebrating, by day of the month.
+    pub birthdays: BTreeMap<u32, Vec<String>>,
+    /// Highlighted if it lies within the shown month.
+    pub today: Option<NaiveDate>,
+}
+
+impl BirthdayCalendar {
+    pub fn new(year: i32, month: u32) -> Option<Self> {
+        Some(Self {
+            month: NaiveDate::from_ymd_opt(year, month, 1)?,
+            birthdays: BTreeMap::new(),
+            today: None,
+        })
+    }
+
+    pub fn with_today(mut self, today: NaiveDate) -> Self {
+        self.today = Some(today);
+        self
+    }
+
+    /// Adds the birthday if it is celebrated within the shown month, returns whether it was.
+    pub fn add(&mut self, user_birthday: &user_birthday::Model) -> bool {
+        let Some(date) = birthday_in_year(
+            user_birthday.day as u32,
+            user_birthday.month as u32,
+            self.month.year(),
+        ) else {
+            return false;
+        };
+        if date.month() != self.month.month() {
+            return false;
+        }
+
+        self.birthdays
+            .entry(date.day())
+            .or_default()
+            .push(user_birthday.user_id.clone());
+        true
+    }
+
+    pub fn title(&self) -> String {
+        let month = Month::try_from(self.month.month() as u8)
+            .map(|month| month.name())
+            .unwrap_or_default();
+        format!("{month} {}", self.month.year())
+    }
+
+    pub fn render_png(&self, o2d: &O2DRenderer) -> CoreResult<Vec<u8>> {
+        let image = o2d.render(std::slice::from_ref(self), self.height(), WIDTH, TILE_SIZE)?;
+        let scaled = image::imageops::resize(
+            &image,
+            image.width() * 3,
+            image.height() * 3,
+            image::imageops::FilterType::Nearest,
+        );
+        drop(image);
+
+        let mut bytes = Cursor::new(Vec::new());
+        scaled.write_to(&mut bytes, image::ImageFormat::Png)?;
+
+        let opts = oxipng::Options::default();
+        let optimized = oxipng::optimize_from_memory(&bytes.into_inner(), &opts)?;
+
+        Ok(optimized)
+    }
+
+    /// The column and row of the day within the grid.
+    fn cell_of(&self, date: NaiveDate) -> (u8, u8) {
+        let first_weekday = self.month.weekday().num_days_from_monday();
+        let index = first_weekday + date.day0();
+        ((index % 7) as u8, (index / 7) as u8)
+    }
+
+    fn rows(&self) -> u8 {
+        let days = iter_date_days_of_month(self.month).count() as u32;
+        (self.month.weekday().num_days_from_monday() + days).div_ceil(7) as u8
+    }
+
+    fn height(&self) -> u8 {
+        let rows = self.rows();
+        GRID_START + rows * CELL_HEIGHT + (rows - 1) * GAP + PADDING
+    }
+
+    /// Text whose glyphs start at the given pixel.
+    fn text(text: impl Into<String>, color: Rgba<u8>, x: u32, y: u32) -> Object2D {
+        let tile_size = TILE_SIZE as u32;
+        let y = y + GLYPH_TOP;
+        Object2D::text(
+            TextVisual::new(text, color),
+            PositionO2D::from_tile_xy_layer(
+                (x / tile_size) as u8,
+                (y / tile_size) as u8,
+                CalendarLayer::Text,
+            )
+            .with_offsets((x % tile_size) as i8, (y % tile_size) as i8),
+        )
+    }
+
+    fn centered_text(text: &str, color: Rgba<u8>, center_x: u32, y: u32) -> Object2D {
+        let text_width = text.chars().count() as u32 * CHAR_WIDTH - 1;
+        Self::text(text, color, center_x.saturating_sub(text_width / 2), y)
+    }
+
+    fn header(&self) -> Vec<Object2D> {
+        let tile_size = TILE_SIZE as u32;
+        let mut objects = vec![Self::centered_text(
+            &self.title(),
+            TEXT,
+            WIDTH as u32 * tile_size / 2,
+            PADDING as u32 * tile_size + 4,
+        )];
+
+        for (column, weekday) in WEEKDAYS.iter().enumerate() {
+            let x = (PADDING + column as u8 * (CELL_WIDTH + GAP)) as u32 * tile_size;
+            objects.push(Self::centered_text(
+                weekday,
+                TEXT_MUTED,
+                x + (CELL_WIDTH as u32 * tile_size) / 2,
+                (PADDING + TITLE_HEIGHT) as u32 * tile_size + 2,
+            ));
+        }
+
+        objects
+    }
+
+    fn day(&self, date: NaiveDate) -> Vec<Object2D> {
+        let mut objects = Vec::new();
+        let (column, row) = self.cell_of(date);
+        let tile_x = PADDING + column * (CELL_WIDTH + GAP);
+        let tile_y = GRID_START + row * (CELL_HEIGHT + GAP);
+        let birthdays = self.birthdays.get(&date.day()).map_or(0, Vec::len);
+
+        let background = if birthdays > 0 { BIRTHDAY } else { DAY };
+        for dx in 0..CELL_WIDTH {
+            for dy in 0..CELL_HEIGHT {
+                objects.push(Object2D::color(
+                    background,
+                    PositionO2D::from_tile_xy_layer(tile_x + dx, tile_y + dy, CalendarLayer::Cells),
+                ));
+            }
+        }
+
+        let tile_size = TILE_SIZE as u32;
+        let text_color = if self.today == Some(date) {
+            TODAY
+        } else {
+            TEXT
+        };
+        objects.push(Self::text(
+            date.day().to_string(),
+            text_color,
+            tile_x as u32 * tile_size + 3,
+            tile_y as u32 * tile_size + 3,
+        ));
+
+        let marker_y = tile_y + CELL_HEIGHT - 2;
+        if birthdays > MAX_MARKERS {
+            objects.push(Self::text(
+                birthdays.to_string(),
+                MARKER,
+                tile_x as u32 * tile_size + 3,
+                tile_y as u32 * tile_size + 14,
+            ));
+        } else {
+            for index in 0..birthdays as u8 {
+                objects.push(Object2D::color(
+                    MARKER,
+                    PositionO2D::from_tile_xy_layer(
+                        tile_x + 1 + index * 2,
+                        marker_y,
+                        CalendarLayer::Text,
+                    ),
+                ));
+            }
+        }
+
+        objects
+    }
+}
+
+impl O2DRenderable for BirthdayCalendar {
+    fn to_objects(&self) -> Vec<Object2D> {
+        let mut objects = Vec::new();
+
+        for x in 0..WIDTH {
+            for y in 0..self.height() {
+                objects.push(Object2D::color(
+                    BACKGROUND,
+                    PositionO2D::from_tile_xy_layer(x, y, CalendarLayer::Background),
+                ));
+            }
+        }
+
+        objects.extend(self.header());
+        for date in iter_date_days_of_month(self.month) {
+            objects.extend(self.day(date));
+        }
+
+        objects
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_birthday(user_id: &str, day: i16, month: i16) -> user_birthday::Model {
+        let created_at = NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        user_birthday::Model {
+            user_id: user_id.to_string(),
+            day,
+            month,
+            year: None,
+            next_birthday: created_at,
+            hide_age: false,
+            hide_from_listings: false,
+            opt_out_announcements: false,
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    #[test]
+    fn test_places_birthdays_of_the_month() {
+        let mut calendar = BirthdayCalendar::new(2025, 6).unwrap();
+        assert!(calendar.add(&user_birthday("1", 3, 6)));
+        assert!(calendar.add(&user_birthday("2", 3, 6)));
+        assert!(!calendar.add(&user_birthday("3", 3, 7)));
+
+        assert_eq!(calendar.birthdays.len(), 1);
+        assert_eq!(calendar.birthdays[&3], ["1", "2"]);
+        assert_eq!(calendar.title(), "June 2025");
+    }
+
+    #[test]
+    fn test_leap_day_birthdays_follow_the_year() {
+        let mut common = BirthdayCalendar::new(2025, 2).unwrap();
+        assert!(!common.add(&user_birthday("1", 29, 2)));
+        let mut march = BirthdayCalendar::new(2025, 3).unwrap();
+        assert!(march.add(&user_birthday("1", 29, 2)));
+        assert!(march.birthdays.contains_key(&1));
+
+        let mut leap = BirthdayCalendar::new(2028, 2).unwrap();
+        assert!(leap.add(&user_birthday("1", 29, 2)));
+        assert!(leap.birthdays.contains_key(&29));
+    }
+
+    #[test]
+    fn test_lays_out_weeks_from_monday() {
+        // June 2025 starts on a Sunday and spans six weeks.
+        let calendar = BirthdayCalendar::new(2025, 6).unwrap();
+        let date = |day| NaiveDate::from_ymd_opt(2025, 6, day).unwrap();
+        assert_eq!(calendar.cell_of(date(1)), (6, 0));
+        assert_eq!(calendar.cell_of(date(2)), (0, 1));
+        assert_eq!(calendar.cell_of(date(30)), (0, 5));
+        assert_eq!(calendar.rows(), 6);
+
+        // February 2027 starts on a Monday and fits four weeks.
+        assert_eq!(BirthdayCalendar::new(2027, 2).unwrap().rows(), 4);
+    }
+}
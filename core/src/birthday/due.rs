@@ -0,0 +1,118 @@
+use crate::database::entity::user_birthday;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+/// A birthday whose announcement is due, as seen from the user's timezone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueBirthday {
+    /// The local date the birthday was celebrated on.
+    pub date: NaiveDate,
+    pub age: Option<u8>,
+    /// The announcement is late, the local birthday already ended.
+    pub is_belated: bool,
+}
+
+impl DueBirthday {
+    pub fn new(user_birthday: &user_birthday::Model, tz: Tz, now: DateTime<Utc>) -> Self {
+        let date = user_birthday
+            .next_birthday
+            .and_utc()
+            .with_timezone(&tz)
+            .date_naive();
+        let age = user_birthday
+            .year
+            .and_then(|year| u8::try_from(date.year() - year as i32).ok());
+
+        Self {
+            date,
+            age,
+            is_belated: date < now.with_timezone(&tz).date_naive(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::dates::upcoming_birthday;
+    use chrono::TimeZone;
+
+    fn user_birthday(day: i16, month: i16, year: Option<i16>, tz: Tz) -> user_birthday::Model {
+        let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let next_birthday = upcoming_birthday(day as u32, month as u32, 9, tz, created_at).unwrap();
+        user_birthday::Model {
+            user_id: "1".to_string(),
+            day,
+            month,
+            year,
+            next_birthday: next_birthday.naive_utc(),
+            hide_age: false,
+            hide_from_listings: false,
+            opt_out_announcements: false,
+            created_at: created_at.naive_utc(),
+            updated_at: created_at.naive_utc(),
+        }
+    }
+
+    #[test]
+    fn test_belated_is_decided_by_the_local_date() {
+        let tz = chrono_tz::Pacific::Auckland;
+        let model = user_birthday(10, 3, Some(2000), tz);
+        // 09:00 on March 10th in Auckland (UTC+13) is 20:00 on March 9th in UTC.
+        let due_at = Utc.with_ymd_and_hms(2025, 3, 9, 20, 0, 0).unwrap();
+        assert_eq!(model.next_birthday, due_at.naive_utc());
+
+        let on_time = DueBirthday::new(&model, tz, due_at + chrono::Duration::hours(14));
+        assert_eq!(on_time.date, NaiveDate::from_ymd_opt(2025, 3, 10).unwrap());
+        assert_eq!(on_time.age, Some(25));
+        assert!(!on_time.is_belated);
+
+        // Past midnight in Auckland, still March 10th in UTC.
+        let late = DueBirthday::new(&model, tz, due_at + chrono::Duration::hours(16));
+        assert!(late.is_belated);
+    }
+
+    #[test]
+    fn test_leap_day_birthdays_age_in_common_years() {
+        let tz = chrono_tz::UTC;
+        let model = user_birthday(29, 2, Some(2004), tz);
+        let due_at = model.next_birthday.and_utc();
+
+        let due = DueBirthday::new(&model, tz, due_at);
+        assert_eq!(due.date, NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+        assert_eq!(due.age, Some(21));
+        assert!(!due.is_belated);
+    }
+
+    #[test]
+    fn test_leap_day_birthdays_follow_the_local_date() {
+        let tz = chrono_tz::Pacific::Auckland;
+        let model = user_birthday(29, 2, Some(2004), tz);
+        // 09:00 on March 1st in Auckland (UTC+13) is 20:00 on February 28th in UTC.
+        let due_at = Utc.with_ymd_and_hms(2025, 2, 28, 20, 0, 0).unwrap();
+        assert_eq!(model.next_birthday, due_at.naive_utc());
+
+        let due = DueBirthday::new(&model, tz, due_at);
+        assert_eq!(due.date, NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+        assert_eq!(due.age, Some(21));
+        assert!(!due.is_belated);
+
+        // Still March 1st in UTC, but March 2nd in Auckland.
+        let late = DueBirthday::new(&model, tz, due_at + chrono::Duration::hours(16));
+        assert!(late.is_belated);
+    }
+
+    #[test]
+    fn test_unknown_or_future_years_have_no_age() {
+        let tz = chrono_tz::UTC;
+        let now = Utc.with_ymd_and_hms(2025, 6, 1, 9, 0, 0).unwrap();
+        assert_eq!(
+            DueBirthday::new(&user_birthday(1, 6, None, tz), tz, now).age,
+            None
+        );
+        assert_eq!(
+            DueBirthday::new(&user_birthday(1, 6, Some(2030), tz), tz, now).age,
+            None
+        );
+    }
+}
@@ -1,8 +1,20 @@
+const DEFAULT_BIRTHDAY_NOTIFICATION_HOUR: u32 = 9;
+
 #[derive(Default)]
 pub struct Config {
     pub db_url: String,
+    /// The local hour birthdays are announced at in every user's timezone.
+    pub birthday_notification_hour: Option<u32>,
     pub nasa_api_key: Option<String>,
     pub youtube_api_key: Option<String>,
     pub youtube_hub_callback_url: Option<String>,
     pub youtube_hub_secret: Option<String>,
 }
+
+impl Config {
+    pub fn birthday_notification_hour(&self) -> u32 {
+        self.birthday_notification_hour
+            .filter(|hour| *hour < 24)
+            .unwrap_or(DEFAULT_BIRTHDAY_NOTIFICATION_HOUR)
+    }
+}
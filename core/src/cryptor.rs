@@ -13,6 +13,7 @@ pub struct Cryptor {
 }
 
 impl Cryptor {
+    #[allow(deprecated)]
     pub fn new(key_hex: &str) -> CoreResult<Arc<Self>> {
         let key_bytes = hex::decode(key_hex)?;
         let key = Key::from_slice(&key_bytes);
@@ -21,6 +22,7 @@ impl Cryptor {
         Ok(Arc::new(cryptor))
     }
 
+    #[allow(deprecated)]
     pub fn encrypt(&self, plaintext: &str) -> CoreResult<String> {
         let mut nonce_bytes = [0u8; 12];
         OsRng.try_fill_bytes(&mut nonce_bytes)?;
@@ -34,6 +36,7 @@ impl Cryptor {
         Ok(general_purpose::STANDARD.encode(combined))
     }
 
+    #[allow(deprecated)]
     pub fn decrypt(&self, encrypted: &str) -> CoreResult<String> {
         let combined = general_purpose::STANDARD.decode(encrypted)?;
         if combined.len() < 12 {
@@ -13,16 +13,44 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::birthday_opt_out::Entity")]
+    BirthdayOptOut,
+    #[sea_orm(has_many = "super::birthday_role::Entity")]
+    BirthdayRole,
     #[sea_orm(has_one = "super::guild_apod::Entity")]
     GuildApod,
     #[sea_orm(has_one = "super::guild_birthday::Entity")]
     GuildBirthday,
+    #[sea_orm(has_one = "super::guild_blackjack::Entity")]
+    GuildBlackjack,
+    #[sea_orm(has_many = "super::guild_level_reward::Entity")]
+    GuildLevelReward,
+    #[sea_orm(has_one = "super::guild_leveling::Entity")]
+    GuildLeveling,
+    #[sea_orm(has_one = "super::guild_lottery::Entity")]
+    GuildLottery,
     #[sea_orm(has_one = "super::guild_youtube::Entity")]
     GuildYoutube,
     #[sea_orm(has_many = "super::guild_youtube_channel::Entity")]
     GuildYoutubeChannel,
+    #[sea_orm(has_many = "super::lottery_round::Entity")]
+    LotteryRound,
     #[sea_orm(has_many = "super::user_guild::Entity")]
     UserGuild,
+    #[sea_orm(has_many = "super::user_guild_level::Entity")]
+    UserGuildLevel,
+}
+
+impl Related<super::birthday_opt_out::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BirthdayOptOut.def()
+    }
+}
+
+impl Related<super::birthday_role::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BirthdayRole.def()
+    }
 }
 
 impl Related<super::guild_apod::Entity> for Entity {
@@ -37,6 +65,30 @@ impl Related<super::guild_birthday::Entity> for Entity {
     }
 }
 
+impl Related<super::guild_blackjack::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GuildBlackjack.def()
+    }
+}
+
+impl Related<super::guild_level_reward::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GuildLevelReward.def()
+    }
+}
+
+impl Related<super::guild_leveling::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GuildLeveling.def()
+    }
+}
+
+impl Related<super::guild_lottery::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GuildLottery.def()
+    }
+}
+
 impl Related<super::guild_youtube::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::GuildYoutube.def()
@@ -49,6 +101,12 @@ impl Related<super::guild_youtube_channel::Entity> for Entity {
     }
 }
 
+impl Related<super::lottery_round::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LotteryRound.def()
+    }
+}
+
 impl Related<super::user_guild::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::UserGuild.def()
@@ -64,6 +122,12 @@ impl Related<super::user::Entity> for Entity {
     }
 }
 
+impl Related<super::user_guild_level::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserGuildLevel.def()
+    }
+}
+
 impl Related<super::youtube_channel::Entity> for Entity {
     fn to() -> RelationDef {
         super::guild_youtube_channel::Relation::YoutubeChannel.def()
@@ -9,6 +9,10 @@ pub struct Model {
     pub guild_id: String,
     pub enabled: bool,
     pub notification_channel_id: Option<String>,
+    pub notification_role_id: Option<String>,
+    pub announcement_templates: Json,
+    pub belated_templates: Json,
+    pub announcement_image_url: Option<String>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
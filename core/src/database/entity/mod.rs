@@ -2,23 +2,44 @@
 
 pub mod prelude;
 
+pub mod achievement_unlock;
+pub mod active_game;
 pub mod apod;
+pub mod birthday_opt_out;
+pub mod birthday_role;
 pub mod black_jack_user;
+pub mod connect4_ai;
+pub mod connect4_games;
+pub mod connect4_user;
 pub mod dashboard_session;
 pub mod economy;
+pub mod economy_audit;
+pub mod economy_frozen;
 pub mod economy_pending;
 pub mod farming;
 pub mod farming_world;
+pub mod game_rating;
+pub mod game_rating_history;
+pub mod game_record;
 pub mod guild;
 pub mod guild_apod;
 pub mod guild_birthday;
+pub mod guild_blackjack;
+pub mod guild_level_reward;
+pub mod guild_leveling;
+pub mod guild_lottery;
 pub mod guild_youtube;
 pub mod guild_youtube_channel;
 pub mod inventory_item;
+pub mod lottery_round;
+pub mod lottery_ticket;
+pub mod puzzle_score;
 pub mod rps_games;
 pub mod rps_user;
 pub mod user;
 pub mod user_birthday;
 pub mod user_guild;
+pub mod user_guild_level;
+pub mod user_level;
 pub mod youtube_channel;
 pub mod youtube_video;
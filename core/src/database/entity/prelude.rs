@@ -1,22 +1,43 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 2.0.0-rc.9
 
+pub use super::achievement_unlock::Entity as AchievementUnlock;
+pub use super::active_game::Entity as ActiveGame;
 pub use super::apod::Entity as Apod;
+pub use super::birthday_opt_out::Entity as BirthdayOptOut;
+pub use super::birthday_role::Entity as BirthdayRole;
 pub use super::black_jack_user::Entity as BlackJackUser;
+pub use super::connect4_ai::Entity as Connect4Ai;
+pub use super::connect4_games::Entity as Connect4Games;
+pub use super::connect4_user::Entity as Connect4User;
 pub use super::dashboard_session::Entity as DashboardSession;
 pub use super::economy::Entity as Economy;
+pub use super::economy_audit::Entity as EconomyAudit;
+pub use super::economy_frozen::Entity as EconomyFrozen;
 pub use super::economy_pending::Entity as EconomyPending;
 pub use super::farming::Entity as Farming;
 pub use super::farming_world::Entity as FarmingWorld;
+pub use super::game_rating::Entity as GameRating;
+pub use super::game_rating_history::Entity as GameRatingHistory;
+pub use super::game_record::Entity as GameRecord;
 pub use super::guild::Entity as Guild;
 pub use super::guild_apod::Entity as GuildApod;
 pub use super::guild_birthday::Entity as GuildBirthday;
+pub use super::guild_blackjack::Entity as GuildBlackjack;
+pub use super::guild_level_reward::Entity as GuildLevelReward;
+pub use super::guild_leveling::Entity as GuildLeveling;
+pub use super::guild_lottery::Entity as GuildLottery;
 pub use super::guild_youtube::Entity as GuildYoutube;
 pub use super::guild_youtube_channel::Entity as GuildYoutubeChannel;
 pub use super::inventory_item::Entity as InventoryItem;
+pub use super::lottery_round::Entity as LotteryRound;
+pub use super::lottery_ticket::Entity as LotteryTicket;
+pub use super::puzzle_score::Entity as PuzzleScore;
 pub use super::rps_games::Entity as RpsGames;
 pub use super::rps_user::Entity as RpsUser;
 pub use super::user::Entity as User;
 pub use super::user_birthday::Entity as UserBirthday;
 pub use super::user_guild::Entity as UserGuild;
+pub use super::user_guild_level::Entity as UserGuildLevel;
+pub use super::user_level::Entity as UserLevel;
 pub use super::youtube_channel::Entity as YoutubeChannel;
 pub use super::youtube_video::Entity as YoutubeVideo;
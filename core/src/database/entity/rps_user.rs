@@ -10,6 +10,10 @@ pub struct Model {
     pub times_rock: i32,
     pub times_paper: i32,
     pub times_scissors: i32,
+    pub total_citrine_wagered: i64,
+    pub total_citrine_won: i64,
+    pub total_citrine_lost: i64,
+    pub recent_throws: String,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
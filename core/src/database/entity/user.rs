@@ -1,8 +1,9 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 2.0.0-rc.9
 
 use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 #[sea_orm(table_name = "user")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -18,24 +19,68 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::achievement_unlock::Entity")]
+    AchievementUnlock,
+    #[sea_orm(has_many = "super::birthday_opt_out::Entity")]
+    BirthdayOptOut,
+    #[sea_orm(has_many = "super::birthday_role::Entity")]
+    BirthdayRole,
     #[sea_orm(has_one = "super::black_jack_user::Entity")]
     BlackJackUser,
+    #[sea_orm(has_many = "super::connect4_ai::Entity")]
+    Connect4Ai,
+    #[sea_orm(has_one = "super::connect4_user::Entity")]
+    Connect4User,
     #[sea_orm(has_many = "super::economy::Entity")]
     Economy,
+    #[sea_orm(has_many = "super::economy_audit::Entity")]
+    EconomyAudit,
+    #[sea_orm(has_one = "super::economy_frozen::Entity")]
+    EconomyFrozen,
     #[sea_orm(has_many = "super::economy_pending::Entity")]
     EconomyPending,
     #[sea_orm(has_one = "super::farming::Entity")]
     Farming,
     #[sea_orm(has_many = "super::farming_world::Entity")]
     FarmingWorld,
+    #[sea_orm(has_many = "super::game_rating::Entity")]
+    GameRating,
+    #[sea_orm(has_many = "super::game_rating_history::Entity")]
+    GameRatingHistory,
     #[sea_orm(has_many = "super::inventory_item::Entity")]
     InventoryItem,
+    #[sea_orm(has_many = "super::lottery_ticket::Entity")]
+    LotteryTicket,
+    #[sea_orm(has_many = "super::puzzle_score::Entity")]
+    PuzzleScore,
     #[sea_orm(has_one = "super::rps_user::Entity")]
     RpsUser,
     #[sea_orm(has_one = "super::user_birthday::Entity")]
     UserBirthday,
     #[sea_orm(has_many = "super::user_guild::Entity")]
     UserGuild,
+    #[sea_orm(has_many = "super::user_guild_level::Entity")]
+    UserGuildLevel,
+    #[sea_orm(has_one = "super::user_level::Entity")]
+    UserLevel,
+}
+
+impl Related<super::achievement_unlock::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AchievementUnlock.def()
+    }
+}
+
+impl Related<super::birthday_opt_out::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BirthdayOptOut.def()
+    }
+}
+
+impl Related<super::birthday_role::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BirthdayRole.def()
+    }
 }
 
 impl Related<super::black_jack_user::Entity> for Entity {
@@ -44,12 +89,36 @@ impl Related<super::black_jack_user::Entity> for Entity {
     }
 }
 
+impl Related<super::connect4_ai::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Connect4Ai.def()
+    }
+}
+
+impl Related<super::connect4_user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Connect4User.def()
+    }
+}
+
 impl Related<super::economy::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Economy.def()
     }
 }
 
+impl Related<super::economy_audit::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EconomyAudit.def()
+    }
+}
+
+impl Related<super::economy_frozen::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EconomyFrozen.def()
+    }
+}
+
 impl Related<super::economy_pending::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::EconomyPending.def()
@@ -68,12 +137,36 @@ impl Related<super::farming_world::Entity> for Entity {
     }
 }
 
+impl Related<super::game_rating::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GameRating.def()
+    }
+}
+
+impl Related<super::game_rating_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GameRatingHistory.def()
+    }
+}
+
 impl Related<super::inventory_item::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::InventoryItem.def()
     }
 }
 
+impl Related<super::lottery_ticket::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LotteryTicket.def()
+    }
+}
+
+impl Related<super::puzzle_score::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PuzzleScore.def()
+    }
+}
+
 impl Related<super::rps_user::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::RpsUser.def()
@@ -101,4 +194,16 @@ impl Related<super::guild::Entity> for Entity {
     }
 }
 
+impl Related<super::user_guild_level::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserGuildLevel.def()
+    }
+}
+
+impl Related<super::user_level::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserLevel.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
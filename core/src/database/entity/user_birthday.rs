@@ -11,6 +11,9 @@ pub struct Model {
     pub month: i16,
     pub year: Option<i16>,
     pub next_birthday: DateTime,
+    pub hide_age: bool,
+    pub hide_from_listings: bool,
+    pub opt_out_announcements: bool,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
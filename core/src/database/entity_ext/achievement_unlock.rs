@@ -0,0 +1,8 @@
+use crate::achievements::Achievement;
+use crate::database::entity::achievement_unlock;
+
+impl achievement_unlock::Model {
+    pub fn achievement(&self) -> Option<Achievement> {
+        Achievement::try_from(self.achievement).ok()
+    }
+}
@@ -0,0 +1,14 @@
+use crate::database::entity::active_game;
+use crate::error::CoreResult;
+use crate::games::persistence::PersistentGame;
+use crate::types::active_game_kind::ActiveGameKind;
+
+impl active_game::Model {
+    pub fn kind(&self) -> Option<ActiveGameKind> {
+        ActiveGameKind::try_from(self.kind).ok()
+    }
+
+    pub fn game<G: PersistentGame>(&self) -> CoreResult<G> {
+        Ok(serde_json::from_value(self.state.clone())?)
+    }
+}
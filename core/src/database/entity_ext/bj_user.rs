@@ -6,11 +6,11 @@ impl black_jack_user::Model {
     }
 
     pub fn avg_stand(&self) -> f32 {
-        (self.final_stand_score_total as f32 / self.times_final_stand as f32)
+        self.final_stand_score_total as f32 / self.times_final_stand as f32
     }
 
     pub fn avg_dealer(&self) -> f32 {
-        (self.dealer_score_total as f32 / self.games_played() as f32)
+        self.dealer_score_total as f32 / self.games_played() as f32
     }
 
     pub fn win_chance(&self) -> f32 {
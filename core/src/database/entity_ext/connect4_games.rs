@@ -0,0 +1,12 @@
+use crate::database::entity::connect4_games;
+
+impl connect4_games::Model {
+    /// Returns the wins, losses and draws from the perspective of the given user.
+    pub fn record_for(&self, user_id: impl AsRef<str>) -> (i32, i32, i32) {
+        if user_id.as_ref() == self.user_id1 {
+            (self.wins1, self.wins2, self.draws)
+        } else {
+            (self.wins2, self.wins1, self.draws)
+        }
+    }
+}
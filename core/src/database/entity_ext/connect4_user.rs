@@ -0,0 +1,15 @@
+use crate::database::entity::connect4_user;
+
+impl connect4_user::Model {
+    pub fn games_played(&self) -> i32 {
+        self.wins + self.losses + self.draws
+    }
+
+    pub fn avg_length(&self) -> f32 {
+        self.moves_total as f32 / self.games_played() as f32
+    }
+
+    pub fn win_chance(&self) -> f32 {
+        self.wins as f32 / self.games_played() as f32
+    }
+}
@@ -0,0 +1,17 @@
+use crate::database::entity::game_rating;
+use crate::games::rating::Glicko2Rating;
+use crate::types::rated_game_kind::RatedGameKind;
+
+impl game_rating::Model {
+    pub fn kind(&self) -> Option<RatedGameKind> {
+        RatedGameKind::try_from(self.kind).ok()
+    }
+
+    pub fn glicko(&self) -> Glicko2Rating {
+        Glicko2Rating {
+            rating: self.rating,
+            deviation: self.deviation,
+            volatility: self.volatility,
+        }
+    }
+}
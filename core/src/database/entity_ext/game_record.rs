@@ -0,0 +1,35 @@
+use crate::database::entity::game_record;
+use crate::error::CoreResult;
+use crate::games::record::{MoveLog, ReplayableGame};
+use crate::types::active_game_kind::ActiveGameKind;
+
+impl game_record::Model {
+    pub fn kind(&self) -> Option<ActiveGameKind> {
+        ActiveGameKind::try_from(self.kind).ok()
+    }
+
+    /// Seeds are stored with the bits of a signed integer.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed.map(|seed| seed as u64)
+    }
+
+    pub fn players(&self) -> CoreResult<Vec<String>> {
+        Ok(serde_json::from_value(self.players.clone())?)
+    }
+
+    pub fn outcomes(&self) -> CoreResult<Vec<(String, String)>> {
+        Ok(serde_json::from_value(self.outcomes.clone())?)
+    }
+
+    pub fn moves<G: ReplayableGame>(&self) -> CoreResult<MoveLog<G::Move>> {
+        Ok(serde_json::from_value(self.moves.clone())?)
+    }
+
+    /// Rebuilds the game as it was after the given number of moves.
+    pub fn replay<G: ReplayableGame>(&self, moves: usize) -> CoreResult<G> {
+        let setup = serde_json::from_value(self.setup.clone())?;
+        let log = self.moves::<G>()?;
+        let moves = &log.moves[..moves.min(log.len())];
+        Ok(G::replay(setup, self.seed(), moves))
+    }
+}
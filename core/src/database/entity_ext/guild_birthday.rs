@@ -0,0 +1,12 @@
+use crate::database::entity::guild_birthday;
+
+impl guild_birthday::Model {
+    /// Unreadable variants are treated as unset, the default announcement applies then.
+    pub fn announcement_templates(&self) -> Vec<String> {
+        serde_json::from_value(self.announcement_templates.clone()).unwrap_or_default()
+    }
+
+    pub fn belated_templates(&self) -> Vec<String> {
+        serde_json::from_value(self.belated_templates.clone()).unwrap_or_default()
+    }
+}
@@ -0,0 +1,9 @@
+use crate::database::entity::guild_blackjack;
+use crate::games::blackjack::rules::BlackjackPreset;
+
+impl guild_blackjack::Model {
+    /// Unknown presets fall back to the default rules.
+    pub fn preset(&self) -> BlackjackPreset {
+        BlackjackPreset::try_from(self.preset).unwrap_or_default()
+    }
+}
@@ -1,8 +1,18 @@
+mod achievement_unlock;
+mod active_game;
 mod apod;
 mod bj_user;
+mod connect4_games;
+mod connect4_user;
 mod farming;
 mod farming_world;
+mod game_rating;
+mod game_record;
+mod guild_birthday;
+mod guild_blackjack;
 mod inventory_item;
+mod puzzle_score;
+mod rps_user;
 mod user;
 mod youtube_channel;
 mod youtube_video;
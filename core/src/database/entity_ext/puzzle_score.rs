@@ -0,0 +1,8 @@
+use crate::database::entity::puzzle_score;
+use crate::types::puzzle_kind::PuzzleKind;
+
+impl puzzle_score::Model {
+    pub fn kind(&self) -> Option<PuzzleKind> {
+        PuzzleKind::try_from(self.kind).ok()
+    }
+}
@@ -0,0 +1,20 @@
+use crate::database::entity::rps_user;
+use crate::games::rps::choice::RPSChoice;
+
+impl rps_user::Model {
+    pub fn frequencies(&self) -> [u64; 3] {
+        [
+            self.times_rock as u64,
+            self.times_paper as u64,
+            self.times_scissors as u64,
+        ]
+    }
+
+    /// The last throws of the player, oldest first.
+    pub fn recent_throws(&self) -> Vec<RPSChoice> {
+        self.recent_throws
+            .chars()
+            .filter_map(RPSChoice::from_char)
+            .collect()
+    }
+}
@@ -34,16 +34,26 @@ pub enum CoreError {
     InvalidBirthday(String),
     #[error("Invalid month: {0}")]
     InvalidMonth(u32),
+    #[error("Lottery round '{0}' has an invalid seed")]
+    InvalidLotterySeed(uuid::Uuid),
     #[error("Invalid header value: {0}")]
     InvalidHeaderValue(#[from] reqwest::header::InvalidHeaderValue),
+    #[error("Invalid template: {0}")]
+    InvalidTemplate(String),
     #[error("Invalid timezone: {0}")]
     InvalidTimezone(#[from] chrono_tz::ParseError),
     #[error("Json ser/de error: {0}")]
     JsonSerde(#[from] serde_json::Error),
+    #[error("The lottery round is being drawn, try again in a moment")]
+    LotteryRoundClosed,
     #[error("Missing NASA API key")]
     MissingNasaApiKey,
     #[error("Missing Youtube API key")]
     MissingYoutubeApiKey,
+    #[error("Amount cannot be negative")]
+    NegativeAmount,
+    #[error("No birthday set, use `/birthday set` first")]
+    NoBirthdaySet,
     #[error("No preferred timezone set")]
     NoPreferredTimezone,
     #[error("OS error: {0}")]
@@ -66,6 +76,8 @@ pub enum CoreError {
     UserYoutubeChannelLimitReached,
     #[error("UTF-8 error: {0}")]
     Utf8(#[from] std::string::FromUtf8Error),
+    #[error("This wallet is frozen")]
+    WalletFrozen,
     #[error(
         "Youtube channel not found, make sure you used the correct handle. Like @veritasium (usually found in the channels URL)."
     )]
@@ -85,10 +97,15 @@ impl CoreError {
             | Self::GuildYoutubeChannelAlreadySubscribed
             | Self::GuildYoutubeChannelLimitReached
             | Self::InvalidBirthday(_)
+            | Self::InvalidTemplate(_)
             | Self::InvalidTimezone(_)
+            | Self::LotteryRoundClosed
+            | Self::NegativeAmount
+            | Self::NoBirthdaySet
             | Self::NoPreferredTimezone
             | Self::Unauthorized
             | Self::UserYoutubeChannelLimitReached
+            | Self::WalletFrozen
             | Self::YoutubeChannelNotFound => true,
             Self::Aed(_)
             | Self::Base64Decode(_)
@@ -99,6 +116,7 @@ impl CoreError {
             | Self::Hex(_)
             | Self::Image(_)
             | Self::InvalidHeaderValue(_)
+            | Self::InvalidLotterySeed(_)
             | Self::InvalidMonth(_)
             | Self::JsonSerde(_)
             | Self::MissingNasaApiKey
@@ -119,4 +137,8 @@ impl CoreError {
     pub fn invalid_birthday(reason: impl Into<String>) -> Self {
         Self::InvalidBirthday(reason.into())
     }
+
+    pub fn invalid_template(reason: impl Into<String>) -> Self {
+        Self::InvalidTemplate(reason.into())
+    }
 }
@@ -1,25 +1,41 @@
 use crate::database::entity::apod;
+use crate::events::achievement_unlocked::AchievementUnlocked;
 use crate::events::birthday_dm::BirthdayDM;
 use crate::events::birthday_notification::BirthdayNotification;
+use crate::events::birthday_role_expired::BirthdayRoleExpired;
+use crate::events::level_up::LevelUp;
+use crate::events::lottery_drawn::LotteryDrawn;
 use std::sync::Arc;
 use tokio::sync::broadcast;
 
+pub mod achievement_unlocked;
 pub mod birthday_dm;
 pub mod birthday_notification;
+pub mod birthday_role_expired;
+pub mod level_up;
+pub mod lottery_drawn;
 pub mod new_youtube_video;
 
 #[derive(Debug, Clone, Copy)]
 pub enum CoreEventType {
+    AchievementUnlocked,
     BirthdayDM,
     BirthdayNotification,
+    BirthdayRoleExpired,
+    LevelUp,
+    LotteryDrawn,
     NewApod,
     NewYoutubeVideo,
 }
 
 #[derive(Debug, Clone)]
 pub enum CoreEvent {
+    AchievementUnlocked(Box<AchievementUnlocked>),
     BirthdayDM(Box<BirthdayDM>),
     BirthdayNotification(Box<BirthdayNotification>),
+    BirthdayRoleExpired(Box<BirthdayRoleExpired>),
+    LevelUp(Box<LevelUp>),
+    LotteryDrawn(Box<LotteryDrawn>),
     NewApod(Box<apod::Model>),
     NewYoutubeVideo(Box<new_youtube_video::NewYoutubeVideo>),
 }
@@ -27,13 +43,21 @@ pub enum CoreEvent {
 impl CoreEvent {
     pub fn event_type(&self) -> CoreEventType {
         match self {
+            Self::AchievementUnlocked(_) => CoreEventType::AchievementUnlocked,
             Self::BirthdayDM(_) => CoreEventType::BirthdayDM,
             Self::BirthdayNotification(_) => CoreEventType::BirthdayNotification,
+            Self::BirthdayRoleExpired(_) => CoreEventType::BirthdayRoleExpired,
+            Self::LevelUp(_) => CoreEventType::LevelUp,
+            Self::LotteryDrawn(_) => CoreEventType::LotteryDrawn,
             Self::NewApod(_) => CoreEventType::NewApod,
             Self::NewYoutubeVideo(_) => CoreEventType::NewYoutubeVideo,
         }
     }
 
+    pub fn achievement_unlocked(unlocked: AchievementUnlocked) -> Self {
+        Self::AchievementUnlocked(Box::new(unlocked))
+    }
+
     pub fn birthday_dm(user_id: impl Into<String>, is_belated: bool) -> Self {
         Self::BirthdayDM(Box::new(BirthdayDM {
             user_id: user_id.into(),
@@ -45,6 +69,18 @@ impl CoreEvent {
         Self::BirthdayNotification(Box::new(notification))
     }
 
+    pub fn birthday_role_expired(expired: BirthdayRoleExpired) -> Self {
+        Self::BirthdayRoleExpired(Box::new(expired))
+    }
+
+    pub fn level_up(level_up: LevelUp) -> Self {
+        Self::LevelUp(Box::new(level_up))
+    }
+
+    pub fn lottery_drawn(lottery_drawn: LotteryDrawn) -> Self {
+        Self::LotteryDrawn(Box::new(lottery_drawn))
+    }
+
     pub fn new_apod(apod: apod::Model) -> Self {
         Self::NewApod(Box::new(apod))
     }
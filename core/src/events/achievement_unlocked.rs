@@ -0,0 +1,7 @@
+use crate::achievements::Achievement;
+
+#[derive(Debug, Clone)]
+pub struct AchievementUnlocked {
+    pub user_id: String,
+    pub achievement: Achievement,
+}
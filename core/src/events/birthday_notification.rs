@@ -1,8 +1,19 @@
+use crate::utils::template::Template;
+use chrono::NaiveDateTime;
+
 #[derive(Debug, Clone)]
 pub struct BirthdayNotification {
     pub user_id: String,
     pub guild_id: String,
-    pub channel_id: String,
+    /// Set if the guild has a birthday notification channel.
+    pub channel_id: Option<String>,
+    /// Set if the guild grants a role for the day.
+    pub role_id: Option<String>,
+    /// When the role is removed again, the removal is recorded once the role was granted.
+    pub role_expires_at: NaiveDateTime,
     pub age: Option<u8>,
     pub is_belated: bool,
+    /// The guild's announcement variant, the default announcement is used if not set.
+    pub template: Option<Template>,
+    pub image_url: Option<String>,
 }
@@ -0,0 +1,6 @@
+#[derive(Debug, Clone)]
+pub struct BirthdayRoleExpired {
+    pub user_id: String,
+    pub guild_id: String,
+    pub role_id: String,
+}
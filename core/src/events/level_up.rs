@@ -0,0 +1,10 @@
+#[derive(Debug, Clone)]
+pub struct LevelUp {
+    pub user_id: String,
+    pub guild_id: String,
+    pub level: i32,
+    pub previous_level: i32,
+    /// Set if the guild has level up notifications enabled.
+    pub channel_id: Option<String>,
+    pub reward_role_ids: Vec<String>,
+}
@@ -0,0 +1,19 @@
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct LotteryDrawn {
+    pub guild_id: String,
+    /// Set if the guild has lottery notifications enabled.
+    pub channel_id: Option<String>,
+    pub round_id: Uuid,
+    pub winner_id: Option<String>,
+    pub winning_ticket: Option<u64>,
+    pub total_tickets: u64,
+    pub pot: i64,
+    pub payout: i64,
+    pub sink: i64,
+    pub seed: String,
+    pub seed_hash: String,
+    pub next_jackpot: i64,
+    pub next_draw_at: Option<chrono::DateTime<chrono::Utc>>,
+}
@@ -1,5 +1,14 @@
 pub mod blackjack;
 pub mod connect4;
 pub mod farming;
+pub mod game2048;
+pub mod lottery;
+pub mod minesweeper;
+pub mod persistence;
 pub mod playing_cards;
+pub mod poker;
+pub mod rating;
+pub mod record;
+pub mod rng;
 pub mod rps;
+pub mod turn_based;
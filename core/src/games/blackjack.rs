@@ -1,37 +1,84 @@
-use crate::games::playing_cards::PlayingCardDeck;
+use crate::games::blackjack::rules::BlackjackRules;
+use crate::games::blackjack::shoe::BlackjackShoe;
+use crate::games::playing_cards::{PlayingCard, PlayingCardDeck};
+use crate::games::record::MoveLog;
+use crate::games::rng::GameRng;
 use std::collections::HashMap;
+use std::fmt::Display;
 use std::time::Instant;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub mod rules;
+pub mod shoe;
+pub mod table;
+
+/// How many hands a player can end up with by splitting.
+pub const MAX_HANDS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum BlackjackMove {
     Hit,
     Stand,
+    /// Doubles the wager of the current hand, draws exactly one more card and stands.
+    DoubleDown,
+    /// Splits a pair into two hands with their own wager.
+    Split,
+    /// Insures against a dealer blackjack for half the wager while the dealer shows an ace.
+    Insurance,
+    /// Gives up the first two cards for half of the wager, if the rules allow it.
+    Surrender,
+}
+
+impl Display for BlackjackMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Hit => write!(f, "Hit"),
+            Self::Stand => write!(f, "Stand"),
+            Self::DoubleDown => write!(f, "Double Down"),
+            Self::Split => write!(f, "Split"),
+            Self::Insurance => write!(f, "Insurance"),
+            Self::Surrender => write!(f, "Surrender"),
+        }
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct BlackjackGame {
     pub id: Uuid,
-    pub deck: PlayingCardDeck,
-    pub dealer: BlackjackPlayer,
+    pub rules: BlackjackRules,
+    pub shoe: BlackjackShoe,
+    pub dealer: BlackjackHand,
     pub players: HashMap<String, BlackjackPlayer>,
     pub current_player: Option<String>,
     pub turn_order: Vec<String>,
+    #[serde(skip)]
     pub last_move: Option<Instant>,
     pub wager: Option<u32>,
+    pub log: MoveLog<BlackjackMove>,
+    /// The shoe as it was before this game was dealt, if it is the shoe of a table which keeps
+    /// dealing from it across games.
+    #[serde(default)]
+    pub carried_shoe: Option<BlackjackShoe>,
+    /// The guild whose table the game is dealt at, which gets the shoe back once the game ends.
+    #[serde(default)]
+    pub guild_id: Option<String>,
 }
 
 impl Default for BlackjackGame {
     fn default() -> Self {
         Self {
             id: Uuid::new_v4(),
-            deck: PlayingCardDeck::new_shuffled(),
-            dealer: BlackjackPlayer::default(),
+            rules: BlackjackRules::default(),
+            shoe: BlackjackShoe::default(),
+            dealer: BlackjackHand::default(),
             players: HashMap::new(),
             turn_order: Vec::new(),
             current_player: None,
             last_move: None,
             wager: None,
+            log: MoveLog::new(),
+            carried_shoe: None,
+            guild_id: None,
         }
     }
 }
@@ -46,9 +93,37 @@ impl BlackjackGame {
         self
     }
 
+    pub fn with_rules(mut self, rules: BlackjackRules) -> Self {
+        self.shoe = BlackjackShoe::new(&rules, GameRng::seeded(self.seed()));
+        self.rules = rules;
+        self
+    }
+
+    /// Deals from a shoe shuffled with the given seed, which together with the moves played
+    /// reproduces the game exactly.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.shoe = BlackjackShoe::new(&self.rules, GameRng::seeded(seed));
+        self
+    }
+
+    /// Deals from the shoe of a table, which the next games at the table are dealt from as well.
+    pub fn with_shoe(mut self, shoe: BlackjackShoe) -> Self {
+        self.carried_shoe = Some(shoe.clone());
+        self.shoe = shoe;
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.shoe.seed()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.players.len() >= self.rules.max_players
+    }
+
     pub fn register_player(&mut self, player: impl AsRef<str>) {
         let name = player.as_ref().to_string();
-        if !self.players.contains_key(&name) {
+        if !self.players.contains_key(&name) && !self.is_full() {
             self.players
                 .insert(name.clone(), BlackjackPlayer::default());
             self.turn_order.push(name);
@@ -62,29 +137,28 @@ impl BlackjackGame {
 
         for _ in 0..2 {
             for name in &self.turn_order {
-                if let Some(player) = self.players.get_mut(name) {
-                    if let Some(card) = self.deck.draw_top() {
-                        player.deck.add_bottom(card);
-                    }
+                if let Some(player) = self.players.get_mut(name)
+                    && let Some(card) = self.shoe.draw()
+                {
+                    player.hands[0].deck.add_bottom(card);
                 }
             }
 
-            if let Some(card) = self.deck.draw_top() {
+            if let Some(card) = self.shoe.draw() {
                 self.dealer.deck.add_bottom(card);
             }
         }
 
-        self.current_player = self.turn_order.first().cloned();
-        self.last_move = Some(Instant::now());
-
-        if let Some(name) = &self.current_player {
-            if let Some(p) = self.players.get(name) {
-                if !p.can_move() {
-                    self.next_player();
-                }
+        // A natural stands right away.
+        for player in self.players.values_mut() {
+            if player.hands[0].is_blackjack() {
+                player.hands[0].standing = true;
             }
         }
 
+        self.current_player = None;
+        self.next_player();
+
         true
     }
 
@@ -109,8 +183,13 @@ impl BlackjackGame {
             None => 0,
         };
 
-        if next_index < self.turn_order.len() {
-            self.current_player = Some(self.turn_order[next_index].clone());
+        let next_player = self.turn_order[next_index.min(self.turn_order.len())..]
+            .iter()
+            .find(|name| self.players.get(*name).is_some_and(|p| p.can_move()))
+            .cloned();
+
+        if next_player.is_some() {
+            self.current_player = next_player;
         } else {
             self.current_player = None;
             self.play_dealer();
@@ -118,8 +197,8 @@ impl BlackjackGame {
     }
 
     fn play_dealer(&mut self) {
-        while self.dealer.can_move() && self.dealer.score() < 17 {
-            if let Some(card) = self.deck.draw_top() {
+        while self.dealer.can_move() && self.dealer_hits() {
+            if let Some(card) = self.shoe.draw() {
                 self.dealer.deck.add_bottom(card);
             } else {
                 break;
@@ -128,6 +207,21 @@ impl BlackjackGame {
         self.dealer.standing = true;
     }
 
+    fn dealer_hits(&self) -> bool {
+        let score = self.dealer.score();
+        score < 17 || (score == 17 && self.rules.dealer_hits_soft_17 && self.dealer.is_soft())
+    }
+
+    /// The dealer's first card, which is dealt face up.
+    pub fn dealer_up_card(&self) -> Option<PlayingCard> {
+        self.dealer.deck.iter_cards().next()
+    }
+
+    /// The cost of insurance, which is half of the wager.
+    pub fn insurance_cost(&self) -> Option<u32> {
+        self.wager.map(|wager| wager / 2)
+    }
+
     pub fn is_to_play(&self, player: impl AsRef<str>) -> bool {
         self.current_player
             .as_ref()
@@ -135,44 +229,103 @@ impl BlackjackGame {
             .unwrap_or(false)
     }
 
-    pub fn is_bust(&self, player: impl AsRef<str>) -> bool {
-        self.players
-            .get(player.as_ref())
-            .map(|p| p.is_bust())
-            .unwrap_or(false)
-    }
+    pub fn can_play(&self, player_name: impl AsRef<str>, move_: BlackjackMove) -> bool {
+        if !self.is_to_play(&player_name) {
+            return false;
+        }
 
-    pub fn is_stand(&self, player: impl AsRef<str>) -> bool {
-        self.players
-            .get(player.as_ref())
-            .map(|p| p.standing)
-            .unwrap_or(false)
+        let Some(player) = self.players.get(player_name.as_ref()) else {
+            return false;
+        };
+        let Some(hand) = player.current_hand().filter(|hand| hand.can_move()) else {
+            return false;
+        };
+
+        match move_ {
+            BlackjackMove::Hit | BlackjackMove::Stand => true,
+            BlackjackMove::DoubleDown => hand.deck.count() == 2,
+            BlackjackMove::Split => hand.is_pair() && player.hands.len() < MAX_HANDS,
+            BlackjackMove::Insurance => {
+                !player.insured
+                    && !player.has_acted()
+                    && self.dealer_up_card().is_some_and(|card| card.is_ace())
+            }
+            BlackjackMove::Surrender => self.rules.surrender && !player.has_acted(),
+        }
     }
 
     pub fn play(&mut self, player_name: impl AsRef<str>, move_: BlackjackMove) -> bool {
         let player_name = player_name.as_ref().to_string();
 
-        if !self.is_to_play(&player_name) {
+        if !self.can_play(&player_name, move_) {
             return false;
         }
 
         let Some(player) = self.players.get_mut(&player_name) else {
             return false;
         };
+        let index = player.current_hand;
 
-        if move_ == BlackjackMove::Hit {
-            if let Some(card) = self.deck.draw_top() {
-                player.deck.add_bottom(card);
+        match move_ {
+            BlackjackMove::Hit => {
+                if let Some(card) = self.shoe.draw() {
+                    player.hands[index].deck.add_bottom(card);
+                }
+            }
+            BlackjackMove::Stand => {
+                player.hands[index].standing = true;
+            }
+            BlackjackMove::DoubleDown => {
+                let hand = &mut player.hands[index];
+                if let Some(card) = self.shoe.draw() {
+                    hand.deck.add_bottom(card);
+                }
+                hand.doubled = true;
+                hand.standing = true;
+            }
+            BlackjackMove::Split => {
+                let hand = &mut player.hands[index];
+                let mut new_hand = BlackjackHand {
+                    split: true,
+                    ..Default::default()
+                };
+                if let Some(card) = hand.deck.draw_bottom() {
+                    new_hand.deck.add_bottom(card);
+                }
+                hand.split = true;
 
-                if player.is_bust() {
-                    self.next_player();
+                for hand in [hand, &mut new_hand] {
+                    if let Some(card) = self.shoe.draw() {
+                        hand.deck.add_bottom(card);
+                    }
+                    // Split aces only get one more card each.
+                    if hand
+                        .deck
+                        .iter_cards()
+                        .next()
+                        .is_some_and(|card| card.is_ace())
+                    {
+                        hand.standing = true;
+                    }
                 }
+                player.hands.push(new_hand);
             }
-        } else {
-            player.standing = true;
+            BlackjackMove::Insurance => {
+                player.insured = true;
+            }
+            BlackjackMove::Surrender => {
+                let hand = &mut player.hands[index];
+                hand.surrendered = true;
+                hand.standing = true;
+            }
+        }
+
+        player.advance_hand();
+        if !player.can_move() {
             self.next_player();
         }
 
+        self.log.push(player_name, move_);
         self.last_move = Some(Instant::now());
         true
     }
@@ -183,45 +336,116 @@ impl BlackjackGame {
             .filter_map(|name| self.players.get(name).map(|p| (name, p)))
     }
 
-    pub fn get_outcomes(&self) -> Option<Vec<(String, BlackjackOutcome)>> {
+    /// The outcome of every hand of every player, in the order of their hands.
+    pub fn get_outcomes(&self) -> Option<Vec<(String, Vec<BlackjackOutcome>)>> {
         if !self.is_over() {
             return None;
         }
 
-        let dealer_score = self.dealer.score();
-        let dealer_bust = self.dealer.is_bust();
-
         let results = self
             .iter_players()
             .map(|(name, player)| {
-                let player_score = player.score();
-                let player_bust = player.is_bust();
-
-                let outcome = if player_bust {
-                    BlackjackOutcome::Loss
-                } else if dealer_bust || player_score > dealer_score {
-                    BlackjackOutcome::Win
-                } else if player_score == dealer_score {
-                    BlackjackOutcome::Push
-                } else {
-                    BlackjackOutcome::Loss
-                };
-
-                (name.clone(), outcome)
+                let outcomes = player
+                    .hands
+                    .iter()
+                    .map(|hand| self.get_hand_outcome(hand))
+                    .collect();
+                (name.clone(), outcomes)
             })
             .collect();
 
         Some(results)
     }
+
+    fn get_hand_outcome(&self, hand: &BlackjackHand) -> BlackjackOutcome {
+        // A blackjack beats any other hand worth 21.
+        let player_score = (hand.score(), hand.is_blackjack());
+        let dealer_score = (self.dealer.score(), self.dealer.is_blackjack());
+
+        if hand.surrendered {
+            BlackjackOutcome::Surrender
+        } else if hand.is_bust() {
+            BlackjackOutcome::Loss
+        } else if hand.is_blackjack() && !self.dealer.is_blackjack() {
+            BlackjackOutcome::Blackjack
+        } else if self.dealer.is_bust() || player_score > dealer_score {
+            BlackjackOutcome::Win
+        } else if player_score == dealer_score {
+            BlackjackOutcome::Push
+        } else {
+            BlackjackOutcome::Loss
+        }
+    }
+
+    /// Whether the insurance of a player pays out, once the game is over.
+    pub fn get_insurance_outcome(&self, player_name: impl AsRef<str>) -> Option<BlackjackOutcome> {
+        if !self.is_over() {
+            return None;
+        }
+
+        let player = self.players.get(player_name.as_ref())?;
+        if !player.insured {
+            return None;
+        }
+
+        if self.dealer.is_blackjack() {
+            Some(BlackjackOutcome::Win)
+        } else {
+            Some(BlackjackOutcome::Loss)
+        }
+    }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct BlackjackPlayer {
+    pub hands: Vec<BlackjackHand>,
+    pub current_hand: usize,
+    pub insured: bool,
+}
+
+impl Default for BlackjackPlayer {
+    fn default() -> Self {
+        Self {
+            hands: vec![BlackjackHand::default()],
+            current_hand: 0,
+            insured: false,
+        }
+    }
+}
+
+impl BlackjackPlayer {
+    pub fn current_hand(&self) -> Option<&BlackjackHand> {
+        self.hands.get(self.current_hand)
+    }
+
+    pub fn can_move(&self) -> bool {
+        self.current_hand()
+            .map(|hand| hand.can_move())
+            .unwrap_or(false)
+    }
+
+    /// Whether the player did anything besides taking insurance.
+    pub fn has_acted(&self) -> bool {
+        self.hands.len() > 1 || self.hands.iter().any(|hand| hand.deck.count() > 2)
+    }
+
+    fn advance_hand(&mut self) {
+        while self.current_hand < self.hands.len() && !self.hands[self.current_hand].can_move() {
+            self.current_hand += 1;
+        }
+    }
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct BlackjackHand {
     pub deck: PlayingCardDeck,
     pub standing: bool,
+    pub doubled: bool,
+    pub split: bool,
+    pub surrendered: bool,
 }
 
-impl BlackjackPlayer {
+impl BlackjackHand {
     pub fn deck(&self) -> &PlayingCardDeck {
         &self.deck
     }
@@ -246,14 +470,360 @@ impl BlackjackPlayer {
         self.score() > 21
     }
 
+    /// Whether an ace is counted as 11 in the score.
+    pub fn is_soft(&self) -> bool {
+        let hard = self
+            .deck
+            .iter_cards()
+            .map(|card| if card.is_ace() { 1 } else { card.score() })
+            .sum::<u8>();
+        self.deck.iter_cards().any(|card| card.is_ace()) && hard + 10 == self.score()
+    }
+
+    /// A natural 21 with the first two cards, which does not count after a split.
+    pub fn is_blackjack(&self) -> bool {
+        !self.split && self.deck.count() == 2 && self.score() == 21
+    }
+
+    pub fn is_pair(&self) -> bool {
+        let mut cards = self.deck.iter_cards();
+        match (cards.next(), cards.next(), cards.next()) {
+            (Some(first), Some(second), None) => first.rank() == second.rank(),
+            _ => false,
+        }
+    }
+
     pub fn can_move(&self) -> bool {
         !self.standing && !self.is_bust()
     }
+
+    /// How much is at stake on this hand for the given wager.
+    pub fn stake(&self, wager: u32) -> u32 {
+        if self.doubled {
+            wager.saturating_mul(2)
+        } else {
+            wager
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BlackjackOutcome {
     Win,
+    /// A natural against a dealer without one, which pays according to the rules.
+    Blackjack,
     Loss,
     Push,
+    Surrender,
+}
+
+impl Display for BlackjackOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Win => write!(f, "Win"),
+            Self::Blackjack => write!(f, "Blackjack"),
+            Self::Loss => write!(f, "Loss"),
+            Self::Push => write!(f, "Push"),
+            Self::Surrender => write!(f, "Surrender"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::blackjack::rules::BlackjackPreset;
+    use crate::games::playing_cards::PlayingCard::*;
+    use crate::games::record::ReplayableGame;
+
+    /// Deals the given cards in order, so the player gets the first and third card and the
+    /// dealer the second and fourth.
+    fn game_with_rules(rules: BlackjackRules, cards: &[PlayingCard]) -> BlackjackGame {
+        let mut deck = PlayingCardDeck::default();
+        deck.add_multiple_bottom(cards);
+
+        let mut game = BlackjackGame::new().with_rules(rules).with_wager(10);
+        game.shoe = BlackjackShoe::from_deck(deck);
+        game.register_player("player");
+        game.start_game();
+        game
+    }
+
+    fn game(cards: &[PlayingCard]) -> BlackjackGame {
+        game_with_rules(BlackjackRules::default(), cards)
+    }
+
+    #[test]
+    fn test_split_plays_hands_in_order() {
+        let mut game = game(&[Spades8, Hearts10, Hearts8, Clubs7, Clubs3, Diamonds2]);
+        assert!(!game.can_play("player", BlackjackMove::Insurance));
+        assert!(game.play("player", BlackjackMove::Split));
+
+        let player = &game.players["player"];
+        assert_eq!(player.hands.len(), 2);
+        assert_eq!(player.hands[0].score(), 11);
+        assert_eq!(player.hands[1].score(), 10);
+        assert_eq!(player.current_hand, 0);
+
+        assert!(game.play("player", BlackjackMove::Stand));
+        assert_eq!(game.players["player"].current_hand, 1);
+        assert!(game.play("player", BlackjackMove::Stand));
+        assert!(game.is_over());
+
+        let outcomes = game.get_outcomes().unwrap();
+        assert_eq!(
+            outcomes[0].1,
+            vec![BlackjackOutcome::Loss, BlackjackOutcome::Loss]
+        );
+    }
+
+    #[test]
+    fn test_split_aces_draw_one_card() {
+        let mut game = game(&[SpadesAce, Hearts10, HeartsAce, Clubs7, Clubs10, Diamonds10]);
+        assert!(game.play("player", BlackjackMove::Split));
+        assert!(game.is_over());
+
+        let player = &game.players["player"];
+        assert!(player.hands.iter().all(|hand| hand.score() == 21));
+        assert!(player.hands.iter().all(|hand| !hand.is_blackjack()));
+        assert_eq!(
+            game.get_outcomes().unwrap()[0].1,
+            vec![BlackjackOutcome::Win, BlackjackOutcome::Win]
+        );
+    }
+
+    #[test]
+    fn test_double_down_draws_one_card_and_stands() {
+        let mut game = game(&[Spades5, Hearts10, Hearts6, Clubs7, Clubs10, Diamonds10]);
+        assert!(game.play("player", BlackjackMove::DoubleDown));
+        assert!(game.is_over());
+
+        let hand = &game.players["player"].hands[0];
+        assert_eq!(hand.score(), 21);
+        assert!(hand.doubled);
+        assert_eq!(hand.stake(10), 20);
+        assert_eq!(
+            game.get_outcomes().unwrap()[0].1,
+            vec![BlackjackOutcome::Win]
+        );
+    }
+
+    #[test]
+    fn test_insurance_pays_on_dealer_blackjack() {
+        let mut game = game(&[Spades9, SpadesAce, Hearts9, HeartsKing]);
+        assert!(game.play("player", BlackjackMove::Insurance));
+        assert!(!game.can_play("player", BlackjackMove::Insurance));
+        assert!(game.play("player", BlackjackMove::Stand));
+
+        assert_eq!(game.insurance_cost(), Some(5));
+        assert_eq!(
+            game.get_insurance_outcome("player"),
+            Some(BlackjackOutcome::Win)
+        );
+        assert_eq!(
+            game.get_outcomes().unwrap()[0].1,
+            vec![BlackjackOutcome::Loss]
+        );
+    }
+
+    #[test]
+    fn test_natural_stands_and_pays_blackjack() {
+        let game = game(&[SpadesAce, Hearts10, HeartsKing, Clubs7]);
+        assert!(game.is_over());
+        assert_eq!(
+            game.get_outcomes().unwrap()[0].1,
+            vec![BlackjackOutcome::Blackjack]
+        );
+        assert_eq!(game.rules.blackjack_winnings(10), 15);
+    }
+
+    #[test]
+    fn test_dealer_soft_17() {
+        let cards = [Spades10, SpadesAce, Hearts8, Hearts6, Clubs2];
+
+        let mut standing = game(&cards);
+        assert!(standing.play("player", BlackjackMove::Stand));
+        assert_eq!(standing.dealer.score(), 17);
+
+        let mut hitting = game_with_rules(BlackjackPreset::Vegas.rules(), &cards);
+        assert!(hitting.play("player", BlackjackMove::Stand));
+        assert_eq!(hitting.dealer.score(), 19);
+    }
+
+    #[test]
+    fn test_surrender_only_if_allowed() {
+        let cards = [Spades10, Hearts10, Hearts6, Clubs9];
+        let game = game(&cards);
+        assert!(!game.can_play("player", BlackjackMove::Surrender));
+
+        let mut game = game_with_rules(BlackjackPreset::Vegas.rules(), &cards);
+        assert!(game.play("player", BlackjackMove::Surrender));
+        assert!(game.is_over());
+        assert_eq!(
+            game.get_outcomes().unwrap()[0].1,
+            vec![BlackjackOutcome::Surrender]
+        );
+        assert_eq!(game.rules.surrender_loss(10), 5);
+    }
+
+    #[test]
+    fn test_shoe_reshuffles_at_cut_card() {
+        let rules = BlackjackPreset::Vegas.rules();
+        let mut shoe = BlackjackShoe::new(&rules, GameRng::seeded(41));
+        assert_eq!(shoe.deck.count(), 6 * 52);
+
+        while shoe.deck.count() > rules.reshuffle_at() {
+            shoe.draw();
+        }
+        shoe.draw();
+        assert_eq!(shoe.deck.count(), 6 * 52 - 1);
+    }
+
+    #[test]
+    fn test_shoe_carries_over_games() {
+        let rules = BlackjackRules::default();
+        let mut shoe = BlackjackShoe::new(&rules, GameRng::seeded(7));
+        let mut reshuffles = 0;
+
+        for _ in 0..12 {
+            assert!(shoe.fits(&rules));
+            let before = shoe.deck.count();
+            let mut game = BlackjackGame::new().with_rules(rules).with_shoe(shoe);
+            game.register_player("a");
+            game.register_player("b");
+            game.start_game();
+            play_to_17(&mut game);
+
+            let hands = game.players.values().flat_map(|player| &player.hands);
+            let dealt =
+                game.dealer.deck.count() + hands.map(|hand| hand.deck.count()).sum::<usize>();
+            if game.shoe.deck.count() + dealt == before {
+                assert!(game.shoe.deck.count() >= rules.reshuffle_at());
+            } else {
+                // The cut card came up during the game, which is the only time it is shuffled.
+                reshuffles += 1;
+                assert!(before < rules.reshuffle_at() + dealt);
+            }
+
+            // Games dealt from a table's shoe replay from their record without revealing its seed.
+            assert_eq!(ReplayableGame::seed(&game), None);
+            let replayed =
+                BlackjackGame::replay(game.setup(), ReplayableGame::seed(&game), &game.log.moves);
+            assert_eq!(replayed.outcomes(), game.outcomes());
+
+            shoe = game.shoe;
+        }
+        assert!(reshuffles > 0);
+        assert!(!shoe.fits(&BlackjackPreset::Vegas.rules()));
+    }
+
+    fn seeded_game(rules: BlackjackRules, seed: u64, players: &[&str]) -> BlackjackGame {
+        let mut game = BlackjackGame::new().with_seed(seed).with_rules(rules);
+        for player in players {
+            game.register_player(player);
+        }
+        game.start_game();
+        game
+    }
+
+    /// Hits every hand below 17 and stands on the rest, like the dealer without soft 17.
+    fn play_to_17(game: &mut BlackjackGame) {
+        while let Some(name) = game.current_player.clone() {
+            let score = game.players[&name]
+                .current_hand()
+                .map_or(21, |hand| hand.score());
+            let move_ = if score < 17 {
+                BlackjackMove::Hit
+            } else {
+                BlackjackMove::Stand
+            };
+            assert!(game.play(&name, move_));
+        }
+    }
+
+    fn cards(hand: &BlackjackHand) -> Vec<PlayingCard> {
+        hand.deck.iter_cards().collect()
+    }
+
+    #[test]
+    fn test_seed_replays_game() {
+        for seed in [1, 41, 2024] {
+            let mut games =
+                [1, 2].map(|_| seeded_game(BlackjackPreset::Vegas.rules(), seed, &["a", "b", "c"]));
+            games.iter_mut().for_each(play_to_17);
+            let [first, second] = &games;
+
+            assert_eq!(first.seed(), seed);
+            assert_eq!(cards(&first.dealer), cards(&second.dealer));
+            for name in ["a", "b", "c"] {
+                let hands = |game: &BlackjackGame| {
+                    game.players[name]
+                        .hands
+                        .iter()
+                        .map(cards)
+                        .collect::<Vec<_>>()
+                };
+                assert_eq!(hands(first), hands(second));
+            }
+            assert_eq!(first.get_outcomes(), second.get_outcomes());
+        }
+
+        // The seed is kept when the rules change after it is set.
+        let game = BlackjackGame::new()
+            .with_rules(BlackjackPreset::Tourist.rules())
+            .with_seed(41)
+            .with_rules(BlackjackPreset::Vegas.rules());
+        let expected = BlackjackGame::new()
+            .with_seed(41)
+            .with_rules(BlackjackPreset::Vegas.rules());
+        assert_eq!(
+            game.shoe.deck.iter_cards().collect::<Vec<_>>(),
+            expected.shoe.deck.iter_cards().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_seeded_outcome() {
+        let mut game = seeded_game(BlackjackRules::default(), 41, &["player"]);
+        play_to_17(&mut game);
+
+        assert_eq!(
+            cards(&game.players["player"].hands[0]),
+            vec![ClubsQueen, Hearts4, Clubs3]
+        );
+        assert_eq!(
+            cards(&game.dealer),
+            vec![Diamonds3, Clubs2, Spades4, Hearts9]
+        );
+        assert_eq!(
+            game.get_outcomes().unwrap()[0].1,
+            vec![BlackjackOutcome::Loss]
+        );
+    }
+
+    #[test]
+    fn test_seeded_dealer_play() {
+        for dealer_hits_soft_17 in [false, true] {
+            let rules = BlackjackRules {
+                dealer_hits_soft_17,
+                ..BlackjackPreset::Vegas.rules()
+            };
+
+            for seed in 0..500 {
+                let mut game = seeded_game(rules, seed, &["player"]);
+                play_to_17(&mut game);
+                assert!(game.is_over());
+
+                // The dealer hits exactly until the first hand it has to stand on.
+                let dealer = cards(&game.dealer);
+                for drawn in 2..=dealer.len() {
+                    let mut hand = BlackjackHand::default();
+                    hand.deck.add_multiple_bottom(&dealer[..drawn]);
+                    let must_stand = hand.score() > 17
+                        || (hand.score() == 17 && !(dealer_hits_soft_17 && hand.is_soft()));
+                    assert_eq!(must_stand, drawn == dealer.len(), "seed {seed}: {dealer:?}");
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,100 @@
+use crate::games::blackjack::BlackjackOutcome;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use std::fmt::Display;
+
+/// The house rules a guild can choose from for its blackjack tables.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
+#[repr(i16)]
+pub enum BlackjackPreset {
+    #[default]
+    Classic = 0,
+    Vegas = 1,
+    Tourist = 2,
+}
+
+impl BlackjackPreset {
+    pub const ALL: [Self; 3] = [Self::Classic, Self::Vegas, Self::Tourist];
+
+    pub fn rules(&self) -> BlackjackRules {
+        match self {
+            Self::Classic => BlackjackRules::default(),
+            Self::Vegas => BlackjackRules {
+                decks: 6,
+                penetration: 80,
+                dealer_hits_soft_17: true,
+                blackjack_payout: (3, 2),
+                surrender: true,
+                max_players: 7,
+            },
+            Self::Tourist => BlackjackRules {
+                decks: 8,
+                penetration: 70,
+                dealer_hits_soft_17: true,
+                blackjack_payout: (6, 5),
+                surrender: false,
+                max_players: 7,
+            },
+        }
+    }
+}
+
+impl Display for BlackjackPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlackjackRules {
+    /// How many decks are shuffled into the shoe.
+    pub decks: u8,
+    /// How much of the shoe is dealt, in percent, before it is reshuffled.
+    pub penetration: u8,
+    pub dealer_hits_soft_17: bool,
+    /// What a natural blackjack pays as a ratio of the stake, e.g. `(3, 2)`.
+    pub blackjack_payout: (u32, u32),
+    /// Whether players may give up their first two cards for half of the stake.
+    pub surrender: bool,
+    pub max_players: usize,
+}
+
+impl Default for BlackjackRules {
+    fn default() -> Self {
+        Self {
+            decks: 1,
+            penetration: 75,
+            dealer_hits_soft_17: false,
+            blackjack_payout: (3, 2),
+            surrender: false,
+            max_players: 4,
+        }
+    }
+}
+
+impl BlackjackRules {
+    /// The number of cards left in the shoe at which it is reshuffled.
+    pub fn reshuffle_at(&self) -> usize {
+        let cards = self.decks as usize * 52;
+        cards - cards * self.penetration.min(100) as usize / 100
+    }
+
+    pub fn blackjack_winnings(&self, stake: u32) -> u32 {
+        let (numerator, denominator) = self.blackjack_payout;
+        stake.saturating_mul(numerator) / denominator.max(1)
+    }
+
+    /// What is lost by surrendering a hand, half of the stake rounded up.
+    pub fn surrender_loss(&self, stake: u32) -> u32 {
+        stake.div_ceil(2)
+    }
+
+    /// How much of the stake is won or lost with the given outcome, nothing for a push.
+    pub fn settlement(&self, stake: u32, outcome: &BlackjackOutcome) -> u32 {
+        match outcome {
+            BlackjackOutcome::Win | BlackjackOutcome::Loss => stake,
+            BlackjackOutcome::Blackjack => self.blackjack_winnings(stake),
+            BlackjackOutcome::Surrender => self.surrender_loss(stake),
+            BlackjackOutcome::Push => 0,
+        }
+    }
+}
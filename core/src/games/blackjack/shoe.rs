@@ -0,0 +1,72 @@
+use crate::games::blackjack::rules::BlackjackRules;
+use crate::games::playing_cards::{PlayingCard, PlayingCardDeck};
+use crate::games::rng::GameRng;
+
+/// The cards of one or more decks the dealer draws from.
+///
+/// Once the cut card is reached, which happens after the penetration of the rules is dealt, the
+/// shoe is refilled with all decks and reshuffled before the next card is drawn. Every shuffle
+/// uses the generator of the shoe, so the seed decides every card that is dealt.
+///
+/// A table keeps dealing from the same shoe across games, so it is only shuffled at the cut card.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BlackjackShoe {
+    pub deck: PlayingCardDeck,
+    decks: u8,
+    reshuffle_at: usize,
+    rng: GameRng,
+}
+
+impl BlackjackShoe {
+    pub fn new(rules: &BlackjackRules, rng: GameRng) -> Self {
+        let mut shoe = Self {
+            deck: PlayingCardDeck::default(),
+            decks: rules.decks.max(1),
+            reshuffle_at: rules.reshuffle_at(),
+            rng,
+        };
+        shoe.reshuffle();
+        shoe
+    }
+
+    /// A shoe which deals the given cards in order and only reshuffles once they run out.
+    pub fn from_deck(deck: PlayingCardDeck) -> Self {
+        Self {
+            deck,
+            decks: 1,
+            reshuffle_at: 0,
+            rng: GameRng::new(),
+        }
+    }
+
+    /// Whether the shoe was filled for the given rules, a shoe of other rules is not dealt from.
+    pub fn fits(&self, rules: &BlackjackRules) -> bool {
+        self.decks == rules.decks.max(1) && self.reshuffle_at == rules.reshuffle_at()
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    pub fn draw(&mut self) -> Option<PlayingCard> {
+        if self.deck.count() <= self.reshuffle_at {
+            self.reshuffle();
+        }
+        self.deck.draw_top()
+    }
+
+    pub fn reshuffle(&mut self) {
+        let mut deck = PlayingCardDeck::default();
+        for _ in 0..self.decks {
+            deck.add_multiple_bottom(&PlayingCardDeck::new().iter_cards().collect::<Vec<_>>());
+        }
+        deck.shuffle(&mut self.rng);
+        self.deck = deck;
+    }
+}
+
+impl Default for BlackjackShoe {
+    fn default() -> Self {
+        Self::new(&BlackjackRules::default(), GameRng::new())
+    }
+}
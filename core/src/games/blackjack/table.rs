@@ -0,0 +1,286 @@
+use crate::error::CoreResult;
+use crate::games::blackjack::{BlackjackGame, BlackjackHand, BlackjackOutcome};
+use crate::games::playing_cards::PlayingCard;
+use crate::rendering::o2d::prelude::{
+    LayerO2D, O2DRenderable, O2DRenderer, Object2D, PositionO2D, SpriteId, TextVisual, CARD_HEIGHT,
+    CARD_WIDTH,
+};
+use image::Rgba;
+use std::collections::HashMap;
+use std::io::Cursor;
+
+const TILE_SIZE: u8 = 4;
+const MIN_WIDTH: u8 = 56;
+const PADDING: u8 = 3;
+const HAND_WIDTH: u8 = 26;
+const CARD_TILES_X: u8 = CARD_WIDTH / TILE_SIZE;
+const CARD_TILES_Y: u8 = CARD_HEIGHT / TILE_SIZE;
+const CARD_STEP: u8 = 4;
+const DEALER_SECTION: u8 = 20;
+const PLAYER_SECTION: u8 = 24;
+const MAX_NAME_LENGTH: usize = 24;
+
+const FELT: Rgba<u8> = Rgba([39, 94, 62, 255]);
+const TEXT: Rgba<u8> = Rgba([236, 232, 220, 255]);
+const TEXT_MUTED: Rgba<u8> = Rgba([160, 190, 168, 255]);
+const TEXT_ACTIVE: Rgba<u8> = Rgba([224, 180, 84, 255]);
+const TEXT_BUST: Rgba<u8> = Rgba([214, 96, 88, 255]);
+
+#[derive(Debug, Clone, Copy)]
+enum TableLayer {
+    Felt,
+    Text,
+    Card(u8),
+}
+
+impl LayerO2D for TableLayer {
+    fn get_z_index(&self) -> u8 {
+        match self {
+            Self::Felt => 0,
+            Self::Text => 1,
+            Self::Card(index) => 2u8.saturating_add(*index),
+        }
+    }
+}
+
+/// The blackjack table as everyone sees it, with the dealer's hole card hidden until the end.
+pub struct BlackjackTable<'a> {
+    pub game: &'a BlackjackGame,
+    /// Display names by player id, the id is shown for players without one.
+    pub names: &'a HashMap<String, String>,
+}
+
+impl<'a> BlackjackTable<'a> {
+    pub fn new(game: &'a BlackjackGame, names: &'a HashMap<String, String>) -> Self {
+        Self { game, names }
+    }
+
+    pub fn render_png(&self, o2d: &O2DRenderer) -> CoreResult<Vec<u8>> {
+        let image = o2d.render(
+            std::slice::from_ref(self),
+            self.height(),
+            self.width(),
+            TILE_SIZE,
+        )?;
+        let scaled = image::imageops::resize(
+            &image,
+            image.width() * 2,
+            image.height() * 2,
+            image::imageops::FilterType::Nearest,
+        );
+        drop(image);
+
+        let mut bytes = Cursor::new(Vec::new());
+        scaled.write_to(&mut bytes, image::ImageFormat::Png)?;
+
+        let opts = oxipng::Options::default();
+        let optimized = oxipng::optimize_from_memory(&bytes.into_inner(), &opts)?;
+
+        Ok(optimized)
+    }
+
+    /// Split hands are laid out next to each other, so the widest player decides the width.
+    fn width(&self) -> u8 {
+        let hands = self
+            .game
+            .iter_players()
+            .map(|(_, player)| player.hands.len() as u8)
+            .max()
+            .unwrap_or(1);
+        MIN_WIDTH.max(PADDING * 2 + HAND_WIDTH * hands)
+    }
+
+    fn height(&self) -> u8 {
+        let players = self.game.iter_players().count() as u8;
+        PADDING * 2 + DEALER_SECTION + players.saturating_mul(PLAYER_SECTION)
+    }
+
+    /// Text is drawn upwards from its position, so `tile_y` is the bottom of the line.
+    fn text(text: impl Into<String>, color: Rgba<u8>, tile_x: u8, tile_y: u8) -> Object2D {
+        Object2D::text(
+            TextVisual::new(text, color),
+            PositionO2D::from_tile_xy_layer(tile_x, tile_y, TableLayer::Text),
+        )
+    }
+
+    /// Lays out a hand from left to right, cards without a face are drawn with their back up.
+    /// Larger hands are stacked tighter so that they stay within their column.
+    fn cards(cards: &[Option<PlayingCard>], tile_x: u8, top: u8) -> Vec<Object2D> {
+        let step = match cards.len() {
+            0 | 1 => CARD_STEP,
+            count => CARD_STEP
+                .min((HAND_WIDTH - 2 - CARD_TILES_X) / (count - 1) as u8)
+                .max(1),
+        };
+
+        // Sprites are anchored at their bottom tile.
+        let tile_y = top + CARD_TILES_Y - 1;
+        cards
+            .iter()
+            .enumerate()
+            .map(|(index, card)| {
+                let sprite = match card {
+                    Some(card) => SpriteId::PlayingCard(*card),
+                    None => SpriteId::PlayingCardBack,
+                };
+                let tile_x = tile_x.saturating_add(step.saturating_mul(index as u8));
+                Object2D::sprite(
+                    sprite,
+                    PositionO2D::from_tile_xy_layer(tile_x, tile_y, TableLayer::Card(index as u8)),
+                )
+            })
+            .collect()
+    }
+
+    fn dealer_objects(&self, top: u8) -> Vec<Object2D> {
+        let dealer = &self.game.dealer;
+        let (label, cards) = if self.game.is_over() {
+            let cards = dealer.deck().iter_cards().map(Some).collect::<Vec<_>>();
+            (format!("DEALER {}", dealer.score()), cards)
+        } else {
+            let up_card = self.game.dealer_up_card();
+            let score = up_card.map(|card| card.score()).unwrap_or(0);
+            (format!("DEALER {score}+?"), vec![up_card, None])
+        };
+
+        let mut objects = vec![Self::text(label, TEXT, PADDING, top + 3)];
+        objects.extend(Self::cards(&cards, PADDING, top + 4));
+        objects
+    }
+
+    fn hand_label(
+        &self,
+        is_current: bool,
+        hand: &BlackjackHand,
+        outcome: Option<&BlackjackOutcome>,
+    ) -> String {
+        let status = match outcome {
+            Some(BlackjackOutcome::Win) => "WON",
+            Some(BlackjackOutcome::Blackjack) => "BLACKJACK",
+            Some(BlackjackOutcome::Loss) => "LOST",
+            Some(BlackjackOutcome::Push) => "PUSH",
+            Some(BlackjackOutcome::Surrender) => "SURRENDERED",
+            None if hand.surrendered => "SURRENDERED",
+            None if hand.is_bust() => "BUST",
+            None if hand.doubled => "DOUBLED",
+            None if hand.standing => "STANDING",
+            None => "",
+        };
+
+        let marker = if is_current { "→ " } else { "" };
+        format!("{marker}{} {status}", hand.score())
+            .trim_end()
+            .to_string()
+    }
+
+    fn player_objects(
+        &self,
+        id: &str,
+        outcomes: Option<&Vec<BlackjackOutcome>>,
+        top: u8,
+    ) -> Vec<Object2D> {
+        let Some(player) = self.game.players.get(id) else {
+            return Vec::new();
+        };
+
+        let is_to_play = self.game.is_to_play(id);
+        let name: String = self
+            .names
+            .get(id)
+            .map(String::as_str)
+            .unwrap_or(id)
+            .chars()
+            .take(MAX_NAME_LENGTH)
+            .collect();
+        let name = if player.insured {
+            format!("{name} INSURED")
+        } else {
+            name
+        };
+        let name_color = if is_to_play { TEXT_ACTIVE } else { TEXT };
+
+        let mut objects = vec![Self::text(name, name_color, PADDING, top + 3)];
+        for (index, hand) in player.hands.iter().enumerate() {
+            let tile_x = PADDING + HAND_WIDTH * index as u8;
+            let is_current = is_to_play && player.current_hand == index;
+            let outcome = outcomes.and_then(|outcomes| outcomes.get(index));
+            let color = if is_current {
+                TEXT_ACTIVE
+            } else if hand.is_bust() {
+                TEXT_BUST
+            } else {
+                TEXT_MUTED
+            };
+
+            let label = self.hand_label(is_current, hand, outcome);
+            objects.push(Self::text(label, color, tile_x, top + 7));
+
+            let cards = hand.deck().iter_cards().map(Some).collect::<Vec<_>>();
+            objects.extend(Self::cards(&cards, tile_x, top + 8));
+        }
+        objects
+    }
+}
+
+impl O2DRenderable for BlackjackTable<'_> {
+    fn to_objects(&self) -> Vec<Object2D> {
+        let mut objects = Vec::new();
+
+        for x in 0..self.width() {
+            for y in 0..self.height() {
+                objects.push(Object2D::color(
+                    FELT,
+                    PositionO2D::from_tile_xy_layer(x, y, TableLayer::Felt),
+                ));
+            }
+        }
+
+        objects.extend(self.dealer_objects(PADDING));
+
+        let outcomes = self
+            .game
+            .get_outcomes()
+            .unwrap_or_default()
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        let mut top = PADDING + DEALER_SECTION;
+        for (id, _) in self.game.iter_players() {
+            objects.extend(self.player_objects(id, outcomes.get(id), top));
+            top = top.saturating_add(PLAYER_SECTION);
+        }
+
+        objects
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rendering::o2d::prelude::VisualO2D;
+
+    fn count_card_backs(table: &BlackjackTable) -> usize {
+        table
+            .to_objects()
+            .iter()
+            .filter(|object| matches!(object.visual, VisualO2D::Sprite(SpriteId::PlayingCardBack)))
+            .count()
+    }
+
+    #[test]
+    fn test_hole_card_hidden_until_over() {
+        let mut game = BlackjackGame::new();
+        game.register_player("1");
+        game.start_game();
+        let names = HashMap::new();
+
+        if !game.is_over() {
+            assert_eq!(count_card_backs(&BlackjackTable::new(&game, &names)), 1);
+        }
+
+        while let Some(player) = game.current_player.clone() {
+            game.play(&player, crate::games::blackjack::BlackjackMove::Stand);
+        }
+        assert!(game.is_over());
+        assert_eq!(count_card_backs(&BlackjackTable::new(&game, &names)), 0);
+    }
+}
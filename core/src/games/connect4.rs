@@ -1,17 +1,31 @@
+use crate::games::connect4::difficulty::Connect4Difficulty;
 use crate::games::connect4::state::Connect4State;
+use crate::games::record::MoveLog;
+use std::time::Instant;
 use uuid::Uuid;
 
 pub mod ai;
 pub mod board;
+pub mod book;
 pub mod coords;
+pub mod difficulty;
+pub mod solver;
 pub mod state;
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct Connect4Game {
     pub id: Uuid,
     pub player_1: String,
     pub player_2: String,
     pub state: Connect4State,
+    pub moves: Vec<u8>,
+    #[serde(skip)]
+    pub last_move: Option<Instant>,
+    pub wager: Option<u32>,
+    pub forfeited_by: Option<bool>,
+    pub ai_player: Option<String>,
+    pub difficulty: Connect4Difficulty,
+    pub log: MoveLog<u8>,
 }
 
 impl Connect4Game {
@@ -30,6 +44,41 @@ impl Connect4Game {
             player_1,
             player_2,
             state: Connect4State::default(),
+            moves: Vec::new(),
+            last_move: None,
+            wager: None,
+            forfeited_by: None,
+            ai_player: None,
+            difficulty: Connect4Difficulty::default(),
+            log: MoveLog::new(),
+        }
+    }
+
+    /// Marks the given player as controlled by the AI.
+    pub fn with_ai(mut self, ai_player: impl Into<String>, difficulty: Connect4Difficulty) -> Self {
+        self.ai_player = Some(ai_player.into());
+        self.difficulty = difficulty;
+        self
+    }
+
+    pub fn is_ai(&self, player_id: impl AsRef<str>) -> bool {
+        self.ai_player.as_deref() == Some(player_id.as_ref())
+    }
+
+    pub fn is_ai_to_play(&self) -> bool {
+        self.is_ai(self.player_to_play())
+    }
+
+    pub fn with_wager(mut self, wager: u32) -> Self {
+        self.wager = Some(wager);
+        self
+    }
+
+    pub fn player_to_play(&self) -> &str {
+        if self.state.is_1_to_play {
+            &self.player_1
+        } else {
+            &self.player_2
         }
     }
 
@@ -39,34 +88,47 @@ impl Connect4Game {
             return false;
         }
 
-        if is_player_1 != self.state.is_1_to_play {
+        if is_player_1 != self.state.is_1_to_play || self.is_over() || !self.state.can_play(col) {
             return false;
         }
 
-        self.state.play(col);
+        self.apply(col);
         true
     }
 
     #[tracing::instrument(level = "trace", skip_all)]
     pub fn play_ai(&mut self) {
-        let focus = rand::random();
-
-        let depth = match focus {
-            0.0..=0.2 => 1,
-            0.2..=0.50 => 2,
-            0.5..=0.8 => 3,
-            _ => 4,
-        };
+        if self.is_over() {
+            return;
+        }
 
-        let Some(best_move) = ai::Connect4AI::best_move(&self.state, depth) else {
+        let Some(best_move) = ai::Connect4AI::best_move(&self.state, self.difficulty) else {
             return;
         };
 
-        self.state.play(best_move);
+        self.apply(best_move);
+    }
+
+    /// Ends the game in favour of the player who is not to play, e.g. when the player to play
+    /// ran out of time.
+    pub fn forfeit(&mut self) {
+        if !self.is_over() {
+            self.forfeited_by = Some(self.state.is_1_to_play);
+        }
+    }
+
+    fn apply(&mut self, col: u8) {
+        let player = self.player_to_play().to_string();
+        self.state.play(col);
+        self.moves.push(col);
+        self.log.push(player, col);
+        self.last_move = Some(Instant::now());
     }
 
     pub fn winner(&self) -> Option<bool> {
-        if self.state.board.has_won(true) {
+        if let Some(forfeited_by) = self.forfeited_by {
+            Some(!forfeited_by)
+        } else if self.state.board.has_won(true) {
             Some(true)
         } else if self.state.board.has_won(false) {
             Some(false)
@@ -74,4 +136,26 @@ impl Connect4Game {
             None
         }
     }
+
+    pub fn winner_id(&self) -> Option<&str> {
+        match self.winner()? {
+            true => Some(&self.player_1),
+            false => Some(&self.player_2),
+        }
+    }
+
+    pub fn loser_id(&self) -> Option<&str> {
+        match self.winner()? {
+            true => Some(&self.player_2),
+            false => Some(&self.player_1),
+        }
+    }
+
+    pub fn is_draw(&self) -> bool {
+        self.winner().is_none() && self.state.is_full()
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.winner().is_some() || self.state.is_full()
+    }
 }
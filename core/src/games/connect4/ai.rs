@@ -1,36 +1,245 @@
+use crate::games::connect4::book;
+use crate::games::connect4::difficulty::Connect4Difficulty;
+use crate::games::connect4::solver::{CELLS, Connect4Solver, Position, TranspositionTable};
 use crate::games::connect4::state::Connect4State;
-use minimax::{Evaluation, Game, Negamax, Strategy};
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
-pub struct Connect4AI;
+type Evaluation = i32;
 
-impl Connect4AI {
-    pub fn best_move(state: &Connect4State, depth: u8) -> Option<u8> {
-        Negamax::new(Self, depth).choose_move(state)
-    }
+const WIN_SCORE: Evaluation = 10_000;
+
+/// At most this many solvers are kept for reuse, every one of them holds a large table.
+const POOLED_SOLVERS: usize = 4;
+
+/// Exact solvers are reused across moves and games, so that their tables are only allocated once
+/// and the positions solved for earlier moves speed up the later ones. Every move takes a solver
+/// out of the pool, so concurrent games never wait for each other.
+static SOLVERS: Mutex<Vec<Connect4Solver>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, Copy)]
+enum Bound {
+    Exact,
+    Lower,
+    Upper,
 }
 
-impl minimax::Evaluator for Connect4AI {
-    type G = Connect4State;
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    depth: u8,
+    score: Evaluation,
+    bound: Bound,
+    best_move: u8,
+}
 
-    fn evaluate(&self, state: &<Self::G as Game>::S) -> Evaluation {
-        let my_board = if state.is_1_to_play {
-            state.board.player_1.value()
-        } else {
-            state.board.player_2.value()
+/// Heuristic alpha-beta search with iterative deepening.
+///
+/// Every finished iteration stores its results in a transposition table keyed on
+/// [`Position::key`], which is derived from both players' board masks, so the next iteration
+/// can try the best move of the previous one first. Moves are otherwise ordered by the threats
+/// they create and then from the centre outwards. Once the time budget is used up the best move
+/// of the deepest finished iteration is played.
+pub struct Connect4AI {
+    table: HashMap<u64, Entry>,
+    deadline: Option<Instant>,
+    aborted: bool,
+    pub nodes: u64,
+}
+
+impl Connect4AI {
+    pub fn best_move(state: &Connect4State, difficulty: Connect4Difficulty) -> Option<u8> {
+        let position = Position::from(state);
+        if position.possible() == 0 {
+            return None;
+        }
+
+        if difficulty.uses_opening_book()
+            && let Some(col) = book::lookup(&position)
+        {
+            return Some(col);
+        }
+
+        if let Some(budget) = difficulty.solver_budget()
+            && let Some(col) = Self::solve(&position, budget)
+        {
+            return Some(col);
+        }
+
+        let mut ai = Self {
+            table: HashMap::new(),
+            deadline: difficulty
+                .search_budget()
+                .map(|budget| Instant::now() + budget),
+            aborted: false,
+            nodes: 0,
         };
-        let opp_board = if state.is_1_to_play {
-            state.board.player_2.value()
+        ai.search(&position, difficulty.max_depth())
+            .map(|(col, _)| col)
+    }
+
+    /// Plays the move that keeps the best outcome, or nothing if solving takes longer than the
+    /// budget.
+    fn solve(position: &Position, budget: Duration) -> Option<u8> {
+        let solver = SOLVERS.lock().unwrap_or_else(PoisonError::into_inner).pop();
+        let mut solver = solver
+            .unwrap_or_else(|| Connect4Solver::new(TranspositionTable::LARGE))
+            .with_deadline(Instant::now() + budget);
+        let col = solver.best_move_weak(position).map(|(col, _)| col);
+
+        let mut solvers = SOLVERS.lock().unwrap_or_else(PoisonError::into_inner);
+        if solvers.len() < POOLED_SOLVERS {
+            solvers.push(solver);
+        }
+        col
+    }
+
+    /// Searches the position to at most `max_depth` moves and returns the best column with its
+    /// score from the view of the player to play.
+    pub fn search(&mut self, position: &Position, max_depth: u8) -> Option<(u8, Evaluation)> {
+        let remaining = (CELLS - position.moves) as u8;
+        let mut best = None;
+
+        for depth in 1..=max_depth.min(remaining) {
+            let score = self.negamax(position, depth, -WIN_SCORE, WIN_SCORE);
+            if self.aborted {
+                break;
+            }
+
+            if let Some(entry) = self.table.get(&position.key()) {
+                best = Some((entry.best_move, score));
+            }
+
+            if score.abs() >= WIN_SCORE - CELLS as Evaluation {
+                break;
+            }
+        }
+
+        best.or_else(|| {
+            let (moves, len) = position.ordered_moves(position.possible());
+            (len > 0).then_some((moves[0].0, 0))
+        })
+    }
+
+    fn negamax(
+        &mut self,
+        position: &Position,
+        depth: u8,
+        mut alpha: Evaluation,
+        mut beta: Evaluation,
+    ) -> Evaluation {
+        self.nodes += 1;
+        if self.nodes & 0xFFF == 0
+            && let Some(deadline) = self.deadline
+            && Instant::now() >= deadline
+        {
+            self.aborted = true;
+        }
+        if self.aborted {
+            return 0;
+        }
+
+        if position.can_win_next() {
+            let score = WIN_SCORE - position.moves as Evaluation - 1;
+            let col = (0..7)
+                .find(|col| position.is_winning_move(*col))
+                .unwrap_or(0);
+            self.store(position, u8::MAX, score, Bound::Exact, col);
+            return score;
+        }
+
+        let next = position.non_losing_moves();
+        if next == 0 {
+            let score = -(WIN_SCORE - position.moves as Evaluation - 2);
+            let (moves, _) = position.ordered_moves(position.possible());
+            self.store(position, u8::MAX, score, Bound::Exact, moves[0].0);
+            return score;
+        }
+
+        if position.moves >= CELLS - 2 {
+            return 0;
+        }
+
+        if depth == 0 {
+            return evaluate(position);
+        }
+
+        let original_alpha = alpha;
+        let mut hint = None;
+        if let Some(entry) = self.table.get(&position.key()) {
+            hint = Some(entry.best_move);
+            if entry.depth >= depth {
+                match entry.bound {
+                    Bound::Exact => return entry.score,
+                    Bound::Lower => alpha = alpha.max(entry.score),
+                    Bound::Upper => beta = beta.min(entry.score),
+                }
+                if alpha >= beta {
+                    return entry.score;
+                }
+            }
+        }
+
+        let (mut moves, len) = position.ordered_moves(next);
+        if let Some(hint) = hint
+            && let Some(index) = moves[..len].iter().position(|(col, _)| *col == hint)
+        {
+            moves[..=index].rotate_right(1);
+        }
+
+        let mut best_score = -WIN_SCORE;
+        let mut best_move = moves[0].0;
+        for (col, bit) in &moves[..len] {
+            let mut child = *position;
+            child.play_bit(*bit);
+
+            let score = -self.negamax(&child, depth - 1, -beta, -alpha);
+            if self.aborted {
+                return 0;
+            }
+
+            if score > best_score {
+                best_score = score;
+                best_move = *col;
+            }
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        let bound = if best_score <= original_alpha {
+            Bound::Upper
+        } else if best_score >= beta {
+            Bound::Lower
         } else {
-            state.board.player_1.value()
+            Bound::Exact
         };
+        self.store(position, depth, best_score, bound, best_move);
 
-        let my_score = evaluate_position(my_board, opp_board);
-        let opp_score = evaluate_position(opp_board, my_board);
+        best_score
+    }
 
-        my_score - opp_score
+    fn store(&mut self, position: &Position, depth: u8, score: Evaluation, bound: Bound, col: u8) {
+        self.table.insert(
+            position.key(),
+            Entry {
+                depth,
+                score,
+                bound,
+                best_move: col,
+            },
+        );
     }
 }
 
+fn evaluate(position: &Position) -> Evaluation {
+    let current = position.current;
+    let opponent = position.current ^ position.mask;
+
+    evaluate_position(current, opponent) - evaluate_position(opponent, current)
+}
+
 fn evaluate_position(player: u64, opponent: u64) -> Evaluation {
     let mut score: Evaluation = 0;
 
@@ -70,3 +279,117 @@ fn count_line_threats(player: u64, empty: u64, shift: u32) -> u32 {
 
     threats.count_ones()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::connect4::solver::Connect4Solver;
+
+    fn state(moves: &str) -> Connect4State {
+        let mut state = Connect4State::default();
+        for col in moves.bytes() {
+            state.play(col - b'1');
+        }
+        state
+    }
+
+    #[test]
+    fn test_takes_immediate_win() {
+        // Player 1 completes the bottom row in column 4.
+        let state = state("152535");
+        for difficulty in Connect4Difficulty::ALL {
+            assert_eq!(
+                Connect4AI::best_move(&state, difficulty),
+                Some(3),
+                "{difficulty}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_blocks_immediate_threat() {
+        // Player 2 has to block the bottom row of player 1 in column 4.
+        let state = state("15253");
+        for difficulty in Connect4Difficulty::ALL {
+            assert_eq!(
+                Connect4AI::best_move(&state, difficulty),
+                Some(3),
+                "{difficulty}"
+            );
+        }
+    }
+
+    /// Plays every reply of the opponent and asserts that Perfect wins all of them.
+    fn assert_perfect_wins(state: Connect4State) {
+        let perfect_is_1 = state.is_1_to_play;
+        let col = Connect4AI::best_move(&state, Connect4Difficulty::Perfect).unwrap();
+        let mut state = state;
+        state.play(col);
+        if state.board.has_won(perfect_is_1) {
+            return;
+        }
+        assert!(!state.is_full(), "drawn a won position");
+
+        for reply in 0..7 {
+            if state.can_play(reply) {
+                let mut next = state;
+                next.play(reply);
+                assert!(!next.board.has_won(!perfect_is_1), "lost a won position");
+                assert_perfect_wins(next);
+            }
+        }
+    }
+
+    #[test]
+    fn test_perfect_never_loses_won_position() {
+        for moves in [
+            "7422341735647741166133573473242566",
+            "7422341735647741166133573473242",
+        ] {
+            let state = state(moves);
+            let mut solver = Connect4Solver::new(TranspositionTable::SMALL);
+            assert_eq!(
+                solver.solve_weak(&Position::from(&state)),
+                Some(1),
+                "{moves}"
+            );
+            assert_perfect_wins(state);
+        }
+    }
+
+    #[test]
+    fn test_search_finds_forced_win() {
+        // Player 1 wins by creating two threats on the bottom row at once.
+        let position = Position::from(&state("2737"));
+        let mut ai = Connect4AI {
+            table: HashMap::new(),
+            deadline: None,
+            aborted: false,
+            nodes: 0,
+        };
+
+        let (col, score) = ai.search(&position, 4).unwrap();
+        assert_eq!(col, 3);
+        assert!(score > WIN_SCORE - CELLS as Evaluation);
+    }
+
+    #[test]
+    fn test_plays_full_games() {
+        for difficulty in [Connect4Difficulty::Easy, Connect4Difficulty::Medium] {
+            let mut state = Connect4State::default();
+            for _ in 0..CELLS {
+                let position = Position::from(&state);
+                if position.possible() == 0
+                    || state.board.has_won(true)
+                    || state.board.has_won(false)
+                {
+                    break;
+                }
+
+                let col = Connect4AI::best_move(&state, difficulty).unwrap();
+                assert!(state.can_play(col));
+                state.play(col);
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::games::connect4::coords::Connect4Coords;
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, serde::Serialize, serde::Deserialize)]
 pub struct Connect4Board {
     pub player_1: Connect4BoardMask,
     pub player_2: Connect4BoardMask,
@@ -31,7 +31,7 @@ impl Connect4Board {
     }
 }
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, serde::Serialize, serde::Deserialize)]
 pub struct Connect4BoardMask(u64);
 
 impl Connect4BoardMask {
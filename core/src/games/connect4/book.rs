@@ -0,0 +1,1178 @@
+//! Opening book for the first eight moves of a game, where the solver would take far too long.
+//!
+//! Only positions which can be reached while the AI follows the book are stored, for both the
+//! AI moving first and second. Mirrored positions are looked up through their mirrored key.
+//! The entries are generated with `cargo run --release --example connect4_book`.
+
+use crate::games::connect4::solver::Position;
+
+/// Book entries as `(Position::key, column)`, sorted by key.
+pub const BOOK: &[(u64, u8)] = &[
+    (0x0, 3),
+    (0x1, 3),
+    (0x80, 2),
+    (0x4000, 3),
+    (0x8081, 3),
+    (0x8180, 1),
+    (0x8581, 3),
+    (0x8980, 2),
+    (0x10080, 2),
+    (0x10580, 2),
+    (0x18981, 3),
+    (0x19180, 3),
+    (0x28980, 2),
+    (0x30081, 3),
+    (0x30180, 3),
+    (0x30581, 3),
+    (0x30980, 3),
+    (0x50080, 1),
+    (0x50281, 1),
+    (0x50480, 3),
+    (0x50580, 2),
+    (0x90280, 1),
+    (0x200000, 3),
+    (0x208080, 3),
+    (0x208580, 3),
+    (0x218980, 3),
+    (0x230080, 3),
+    (0x230580, 3),
+    (0x250280, 3),
+    (0x400001, 3),
+    (0x400003, 3),
+    (0x400080, 1),
+    (0x400081, 3),
+    (0x400281, 2),
+    (0x400480, 3),
+    (0x404000, 5),
+    (0x404001, 3),
+    (0x404080, 3),
+    (0x404280, 4),
+    (0x408083, 3),
+    (0x408181, 1),
+    (0x408283, 2),
+    (0x408481, 3),
+    (0x408583, 3),
+    (0x408981, 3),
+    (0x40c000, 2),
+    (0x410081, 3),
+    (0x410281, 2),
+    (0x410581, 2),
+    (0x418287, 0),
+    (0x418483, 3),
+    (0x428283, 3),
+    (0x42c001, 3),
+    (0x42c080, 3),
+    (0x430083, 3),
+    (0x430181, 3),
+    (0x430283, 2),
+    (0x430380, 1),
+    (0x430481, 3),
+    (0x44c000, 3),
+    (0x450081, 3),
+    (0x450180, 3),
+    (0x450281, 2),
+    (0x800000, 3),
+    (0x800001, 3),
+    (0x800280, 3),
+    (0x804000, 3),
+    (0x808081, 3),
+    (0x808281, 3),
+    (0x808581, 2),
+    (0x818283, 3),
+    (0x82c000, 3),
+    (0x830081, 2),
+    (0x830180, 3),
+    (0x830281, 3),
+    (0xa00001, 3),
+    (0xa00080, 2),
+    (0xa04000, 4),
+    (0xa08081, 3),
+    (0xa08180, 3),
+    (0xa08581, 3),
+    (0xa08980, 3),
+    (0xa10080, 3),
+    (0xa10580, 3),
+    (0xa30081, 3),
+    (0xa30180, 3),
+    (0xa50080, 3),
+    (0xc00003, 3),
+    (0xc00007, 0),
+    (0xc00027, 3),
+    (0xc00081, 3),
+    (0xc00083, 3),
+    (0xc00097, 3),
+    (0xc00181, 3),
+    (0xc00481, 3),
+    (0xc00880, 3),
+    (0xc04001, 3),
+    (0xc04003, 3),
+    (0xc04017, 3),
+    (0xc04081, 3),
+    (0xc04180, 3),
+    (0xc04480, 3),
+    (0xc08087, 0),
+    (0xc08183, 3),
+    (0xc08483, 3),
+    (0xc08881, 3),
+    (0xc0c001, 3),
+    (0xc0c080, 3),
+    (0xc10083, 3),
+    (0xc10181, 3),
+    (0xc10481, 3),
+    (0xc20081, 3),
+    (0xc2c003, 3),
+    (0xc2c081, 3),
+    (0xc2c180, 3),
+    (0xc4c001, 3),
+    (0xc4c080, 3),
+    (0xc8c000, 3),
+    (0x1200000, 3),
+    (0x1208080, 3),
+    (0x1208580, 3),
+    (0x1230080, 3),
+    (0x1400001, 3),
+    (0x1400003, 3),
+    (0x1400017, 3),
+    (0x1400081, 3),
+    (0x1400480, 3),
+    (0x1404001, 3),
+    (0x1404080, 3),
+    (0x1408083, 3),
+    (0x1408481, 2),
+    (0x1410081, 2),
+    (0x142c001, 3),
+    (0x142c080, 3),
+    (0x144c000, 3),
+    (0x1800001, 3),
+    (0x1800003, 3),
+    (0x1800080, 3),
+    (0x1800081, 3),
+    (0x1800281, 3),
+    (0x1800480, 3),
+    (0x1804000, 3),
+    (0x1804001, 3),
+    (0x1804080, 3),
+    (0x1804280, 3),
+    (0x1808083, 3),
+    (0x1808181, 3),
+    (0x1808283, 3),
+    (0x1808481, 3),
+    (0x180c000, 3),
+    (0x1810081, 3),
+    (0x1810281, 3),
+    (0x182c001, 3),
+    (0x182c080, 3),
+    (0x184c000, 3),
+    (0x1a00003, 3),
+    (0x1a00081, 2),
+    (0x1a04001, 1),
+    (0x1a04103, 3),
+    (0x1a04201, 3),
+    (0x1a08083, 3),
+    (0x1a08181, 3),
+    (0x1a08380, 1),
+    (0x1a0c101, 3),
+    (0x1a10081, 3),
+    (0x1a10180, 3),
+    (0x1a20080, 3),
+    (0x2800000, 3),
+    (0x2800001, 3),
+    (0x2800280, 3),
+    (0x2804000, 3),
+    (0x2808081, 1),
+    (0x2808281, 3),
+    (0x282c000, 3),
+    (0x2a00001, 2),
+    (0x2a04101, 3),
+    (0x2a08003, 3),
+    (0x2a08081, 3),
+    (0x2a08180, 3),
+    (0x2a10001, 3),
+    (0x2a10080, 3),
+    (0x2c00003, 3),
+    (0x2c00081, 3),
+    (0x2c00083, 3),
+    (0x2c00181, 4),
+    (0x2c00481, 3),
+    (0x2c00880, 3),
+    (0x2c04001, 3),
+    (0x2c04003, 3),
+    (0x2c04081, 3),
+    (0x2c04180, 3),
+    (0x2c04480, 3),
+    (0x2c0c001, 3),
+    (0x2c0c080, 3),
+    (0x3200001, 3),
+    (0x3200080, 2),
+    (0x3204000, 4),
+    (0x3208081, 3),
+    (0x3208180, 1),
+    (0x3210080, 2),
+    (0x3400003, 3),
+    (0x3400007, 0),
+    (0x3400081, 3),
+    (0x3400083, 3),
+    (0x3400181, 4),
+    (0x3400481, 3),
+    (0x3400880, 3),
+    (0x3404001, 3),
+    (0x3404003, 3),
+    (0x3404081, 3),
+    (0x3404180, 1),
+    (0x3404480, 3),
+    (0x340c001, 2),
+    (0x340c080, 2),
+    (0x3800003, 3),
+    (0x3800007, 0),
+    (0x3800081, 3),
+    (0x3800083, 3),
+    (0x3800180, 3),
+    (0x3800181, 3),
+    (0x3800283, 3),
+    (0x3800481, 3),
+    (0x3800880, 3),
+    (0x3804001, 3),
+    (0x3804003, 3),
+    (0x3804080, 3),
+    (0x3804081, 3),
+    (0x3804180, 3),
+    (0x3804281, 3),
+    (0x3804480, 3),
+    (0x380c000, 3),
+    (0x380c001, 3),
+    (0x380c080, 3),
+    (0x380c280, 3),
+    (0x381c000, 2),
+    (0x4a08001, 3),
+    (0x5200000, 3),
+    (0x5208080, 2),
+    (0x5400001, 4),
+    (0x5400003, 4),
+    (0x5400081, 3),
+    (0x5400480, 3),
+    (0x5404001, 2),
+    (0x5404080, 3),
+    (0x5800001, 2),
+    (0x5800003, 3),
+    (0x5800080, 3),
+    (0x5800081, 3),
+    (0x5800281, 3),
+    (0x5800480, 3),
+    (0x5804000, 2),
+    (0x5804001, 3),
+    (0x5804080, 3),
+    (0x5804280, 3),
+    (0x5808003, 3),
+    (0x5808081, 3),
+    (0x580c000, 3),
+    (0x5810001, 2),
+    (0x5814001, 3),
+    (0x5814080, 2),
+    (0x5824000, 6),
+    (0x5a00003, 3),
+    (0x6800001, 2),
+    (0x6800003, 3),
+    (0x6800080, 1),
+    (0x6800081, 1),
+    (0x6800281, 3),
+    (0x6800480, 1),
+    (0x6804000, 2),
+    (0x6804001, 3),
+    (0x6804080, 1),
+    (0x6804280, 3),
+    (0x6808003, 3),
+    (0x6808081, 1),
+    (0x680c000, 3),
+    (0x6810001, 2),
+    (0x6814001, 3),
+    (0x6814080, 3),
+    (0x6824000, 2),
+    (0x6c00007, 0),
+    (0x6c00083, 3),
+    (0x6c00181, 3),
+    (0x6c04003, 3),
+    (0x6c04081, 3),
+    (0x6c0c001, 3),
+    (0x7200003, 5),
+    (0x7200081, 2),
+    (0x7204001, 1),
+    (0x7400007, 0),
+    (0x7400083, 3),
+    (0x7400181, 4),
+    (0x7404003, 2),
+    (0x7404081, 2),
+    (0x740c001, 2),
+    (0x9808001, 2),
+    (0x9814000, 2),
+    (0xa800000, 2),
+    (0xa800001, 5),
+    (0xa800280, 4),
+    (0xa804000, 5),
+    (0xa808001, 2),
+    (0xa808080, 5),
+    (0xa810000, 2),
+    (0xa814000, 2),
+    (0xac00003, 2),
+    (0xac00081, 2),
+    (0xac04001, 2),
+    (0xb200001, 2),
+    (0xb400003, 2),
+    (0xb400081, 2),
+    (0xb404001, 2),
+    (0xb800003, 2),
+    (0xb800081, 2),
+    (0xb800180, 2),
+    (0xb804001, 2),
+    (0xb804080, 2),
+    (0xb80c000, 5),
+    (0xd200001, 1),
+    (0xd200080, 2),
+    (0xd204000, 2),
+    (0xd800081, 2),
+    (0xd800180, 6),
+    (0xd804080, 4),
+    (0x10008080, 2),
+    (0x10008580, 2),
+    (0x10018081, 2),
+    (0x10018180, 2),
+    (0x10018581, 3),
+    (0x10018980, 2),
+    (0x10028080, 2),
+    (0x10028580, 2),
+    (0x10030080, 2),
+    (0x10030580, 2),
+    (0x10050280, 4),
+    (0x10058081, 4),
+    (0x10058180, 4),
+    (0x10068081, 2),
+    (0x10068180, 1),
+    (0x10070081, 3),
+    (0x10070180, 3),
+    (0x100a8080, 2),
+    (0x100b0080, 3),
+    (0x10218080, 3),
+    (0x10218580, 3),
+    (0x10268080, 3),
+    (0x10270080, 3),
+    (0x10400001, 3),
+    (0x10400280, 3),
+    (0x10404000, 3),
+    (0x10408081, 4),
+    (0x10408281, 3),
+    (0x10408581, 3),
+    (0x10418283, 3),
+    (0x1042c000, 3),
+    (0x10430081, 3),
+    (0x10430180, 3),
+    (0x10430281, 2),
+    (0x10a08080, 3),
+    (0x10a08580, 3),
+    (0x10a18081, 3),
+    (0x10a18180, 3),
+    (0x10a28080, 3),
+    (0x10a30080, 3),
+    (0x10c00001, 3),
+    (0x10c00003, 3),
+    (0x10c00017, 3),
+    (0x10c00081, 3),
+    (0x10c00281, 3),
+    (0x10c00480, 3),
+    (0x10c04001, 3),
+    (0x10c04080, 3),
+    (0x10c04280, 3),
+    (0x10c08083, 3),
+    (0x10c08283, 3),
+    (0x10c08481, 3),
+    (0x10c0c000, 3),
+    (0x10c10081, 3),
+    (0x10c10281, 3),
+    (0x10c2c001, 3),
+    (0x10c2c080, 3),
+    (0x10c4c000, 3),
+    (0x11218080, 3),
+    (0x11400001, 3),
+    (0x11400280, 3),
+    (0x11404000, 3),
+    (0x11408281, 2),
+    (0x1142c000, 3),
+    (0x11800001, 3),
+    (0x11800280, 3),
+    (0x11804000, 3),
+    (0x11808081, 3),
+    (0x11808281, 3),
+    (0x1182c000, 3),
+    (0x11a00001, 3),
+    (0x11a04101, 5),
+    (0x11a08081, 3),
+    (0x11a08180, 3),
+    (0x11a10080, 3),
+    (0x12a08001, 3),
+    (0x12a08080, 3),
+    (0x12c00001, 3),
+    (0x12c00003, 3),
+    (0x12c00081, 3),
+    (0x12c00281, 3),
+    (0x12c00480, 3),
+    (0x12c04001, 3),
+    (0x12c04080, 3),
+    (0x12c04280, 3),
+    (0x12c0c000, 3),
+    (0x13208080, 3),
+    (0x13400001, 3),
+    (0x13400003, 3),
+    (0x13400081, 3),
+    (0x13400281, 3),
+    (0x13400480, 3),
+    (0x13404001, 3),
+    (0x13404080, 3),
+    (0x13404280, 3),
+    (0x1340c000, 2),
+    (0x13800001, 3),
+    (0x13800003, 3),
+    (0x13800080, 3),
+    (0x13800081, 3),
+    (0x13800281, 3),
+    (0x13800480, 3),
+    (0x13804000, 3),
+    (0x13804001, 3),
+    (0x13804080, 3),
+    (0x13804280, 3),
+    (0x1380c000, 3),
+    (0x15400001, 3),
+    (0x15400280, 1),
+    (0x15404000, 2),
+    (0x15800001, 3),
+    (0x15800280, 3),
+    (0x15804000, 3),
+    (0x15808001, 4),
+    (0x15814000, 2),
+    (0x15a00001, 2),
+    (0x16800001, 3),
+    (0x16800280, 4),
+    (0x16804000, 3),
+    (0x16808001, 4),
+    (0x16814000, 3),
+    (0x16c00003, 3),
+    (0x16c00081, 3),
+    (0x16c04001, 3),
+    (0x17200001, 2),
+    (0x17400003, 4),
+    (0x17400081, 3),
+    (0x17404001, 3),
+    (0x1a808000, 4),
+    (0x1ac00001, 2),
+    (0x1b400001, 2),
+    (0x1b800001, 2),
+    (0x1b800080, 2),
+    (0x1b804000, 2),
+    (0x1d800080, 2),
+    (0x20404281, 3),
+    (0x20404480, 3),
+    (0x2040c280, 2),
+    (0x2042c281, 2),
+    (0x2042c480, 3),
+    (0x2044c280, 2),
+    (0x20804280, 3),
+    (0x2082c280, 3),
+    (0x20a04001, 1),
+    (0x20a04080, 0),
+    (0x20a04084, 3),
+    (0x20a04103, 3),
+    (0x20a04182, 3),
+    (0x20a04201, 3),
+    (0x20a0c000, 3),
+    (0x20a0c082, 3),
+    (0x20a0c101, 3),
+    (0x20c04283, 3),
+    (0x20c04481, 3),
+    (0x20c04880, 3),
+    (0x20c0c281, 3),
+    (0x20c0c480, 3),
+    (0x21204000, 3),
+    (0x21204082, 3),
+    (0x21204101, 3),
+    (0x21404281, 3),
+    (0x21404480, 3),
+    (0x21804281, 3),
+    (0x21804480, 3),
+    (0x2180c280, 3),
+    (0x21a0c001, 1),
+    (0x21a0c080, 0),
+    (0x21a1c000, 2),
+    (0x22804280, 3),
+    (0x22a0c000, 2),
+    (0x23204001, 1),
+    (0x23204080, 0),
+    (0x2320c000, 2),
+    (0x25204000, 4),
+    (0x25400003, 3),
+    (0x25400081, 4),
+    (0x25404001, 6),
+    (0x29400001, 2),
+    (0x30018080, 4),
+    (0x30018580, 4),
+    (0x30068080, 4),
+    (0x30070080, 3),
+    (0x30a18080, 3),
+    (0x30c00001, 3),
+    (0x30c00280, 3),
+    (0x30c08281, 3),
+    (0x30c2c000, 3),
+    (0x31a08080, 3),
+    (0x32c00001, 3),
+    (0x32c00280, 3),
+    (0x33400001, 4),
+    (0x33400280, 3),
+    (0x33800001, 3),
+    (0x33800280, 3),
+    (0x36c00001, 3),
+    (0x37400001, 4),
+    (0x40404280, 3),
+    (0x4042c280, 3),
+    (0x40a04000, 3),
+    (0x40a04082, 3),
+    (0x40a04101, 3),
+    (0x40c04281, 3),
+    (0x40c04480, 3),
+    (0x40c0c280, 3),
+    (0x41404280, 3),
+    (0x41804280, 3),
+    (0x41a04001, 1),
+    (0x41a04080, 0),
+    (0x41a0c000, 3),
+    (0x42a04000, 3),
+    (0x43204000, 4),
+    (0x45400001, 2),
+    (0x50408083, 3),
+    (0x50408181, 1),
+    (0x50410081, 3),
+    (0x50808081, 3),
+    (0x80c04280, 3),
+    (0x81a04000, 3),
+    (0x90408081, 3),
+    (0xb0018081, 2),
+    (0xb0018180, 2),
+    (0xb0028080, 2),
+    (0xb0218080, 3),
+    (0x130018080, 2),
+    (0x800008080, 2),
+    (0x800008580, 2),
+    (0x800018081, 3),
+    (0x800018180, 2),
+    (0x800018581, 3),
+    (0x800018980, 3),
+    (0x800028080, 2),
+    (0x800028580, 2),
+    (0x800030080, 3),
+    (0x800030580, 3),
+    (0x800050280, 3),
+    (0x800058180, 2),
+    (0x800068081, 2),
+    (0x800068180, 1),
+    (0x8000a8080, 2),
+    (0x800218080, 3),
+    (0x800218580, 3),
+    (0x800268080, 3),
+    (0x800400001, 3),
+    (0x800400280, 3),
+    (0x800404000, 3),
+    (0x800408081, 3),
+    (0x800408281, 2),
+    (0x800408581, 3),
+    (0x800418083, 3),
+    (0x800418181, 2),
+    (0x800418283, 2),
+    (0x800418481, 3),
+    (0x800428081, 3),
+    (0x800428281, 3),
+    (0x80042c000, 3),
+    (0x800430081, 3),
+    (0x800430180, 3),
+    (0x800430281, 2),
+    (0x800450080, 3),
+    (0x800818081, 3),
+    (0x800818281, 3),
+    (0x800830080, 2),
+    (0x800a08080, 3),
+    (0x800a08580, 3),
+    (0x800a18081, 3),
+    (0x800a18180, 3),
+    (0x800a28080, 3),
+    (0x800a30080, 3),
+    (0x800c00001, 3),
+    (0x800c00003, 3),
+    (0x800c00017, 3),
+    (0x800c00081, 3),
+    (0x800c00281, 3),
+    (0x800c00480, 3),
+    (0x800c04001, 3),
+    (0x800c04080, 3),
+    (0x800c04280, 3),
+    (0x800c08083, 3),
+    (0x800c08181, 3),
+    (0x800c08481, 3),
+    (0x800c0c000, 3),
+    (0x800c10081, 3),
+    (0x800c2c001, 3),
+    (0x800c2c080, 3),
+    (0x800c4c000, 3),
+    (0x801218080, 3),
+    (0x801400001, 3),
+    (0x801400280, 3),
+    (0x801404000, 3),
+    (0x801408081, 3),
+    (0x80142c000, 3),
+    (0x801800001, 3),
+    (0x801800280, 3),
+    (0x801804000, 3),
+    (0x801808081, 3),
+    (0x801808281, 3),
+    (0x80182c000, 3),
+    (0x801a00001, 3),
+    (0x801a04101, 4),
+    (0x801a08081, 3),
+    (0x801a08180, 3),
+    (0x801a10080, 3),
+    (0x802a08001, 2),
+    (0x802a08080, 3),
+    (0x802c00001, 3),
+    (0x802c00003, 3),
+    (0x802c00081, 3),
+    (0x802c00281, 3),
+    (0x802c00480, 3),
+    (0x802c04001, 3),
+    (0x802c04080, 3),
+    (0x802c04280, 3),
+    (0x802c0c000, 2),
+    (0x803208080, 3),
+    (0x803400001, 3),
+    (0x803400003, 3),
+    (0x803400081, 3),
+    (0x803400281, 3),
+    (0x803400480, 3),
+    (0x803404001, 3),
+    (0x803404080, 3),
+    (0x803404280, 3),
+    (0x80340c000, 2),
+    (0x803800001, 3),
+    (0x803800003, 3),
+    (0x803800080, 3),
+    (0x803800081, 3),
+    (0x803800281, 3),
+    (0x803800480, 3),
+    (0x803804001, 3),
+    (0x803804080, 3),
+    (0x803804280, 3),
+    (0x80380c000, 3),
+    (0x805400001, 3),
+    (0x805400280, 1),
+    (0x805404000, 3),
+    (0x805800001, 3),
+    (0x805800280, 3),
+    (0x805804000, 3),
+    (0x805808001, 3),
+    (0x805814000, 3),
+    (0x805a00001, 2),
+    (0x806800001, 5),
+    (0x806800280, 5),
+    (0x806804000, 2),
+    (0x806808001, 5),
+    (0x806814000, 3),
+    (0x806c00003, 3),
+    (0x806c00081, 3),
+    (0x806c04001, 3),
+    (0x807200001, 2),
+    (0x807400003, 3),
+    (0x807400081, 3),
+    (0x807404001, 3),
+    (0x80a808000, 2),
+    (0x80ac00001, 2),
+    (0x80b400001, 2),
+    (0x80b800001, 2),
+    (0x80b800080, 2),
+    (0x80d800080, 1),
+    (0x810018080, 2),
+    (0x810018580, 2),
+    (0x810058080, 4),
+    (0x810068080, 6),
+    (0x810070080, 3),
+    (0x810418081, 3),
+    (0x810418281, 3),
+    (0x810430080, 3),
+    (0x810a18080, 6),
+    (0x810c00001, 3),
+    (0x810c00280, 3),
+    (0x810c08081, 3),
+    (0x810c08281, 3),
+    (0x810c2c000, 3),
+    (0x811a08080, 6),
+    (0x812c00001, 3),
+    (0x812c00280, 3),
+    (0x813400001, 3),
+    (0x813400280, 3),
+    (0x813800001, 3),
+    (0x813800280, 3),
+    (0x816c00001, 3),
+    (0x817400001, 3),
+    (0x820404280, 2),
+    (0x820414281, 3),
+    (0x820414480, 3),
+    (0x820424280, 3),
+    (0x82042c280, 2),
+    (0x820814280, 2),
+    (0x820a04082, 3),
+    (0x820a04101, 3),
+    (0x820c04281, 3),
+    (0x820c04480, 3),
+    (0x821804280, 3),
+    (0x825400001, 4),
+    (0x840414280, 2),
+    (0x840c04280, 3),
+    (0x850408081, 3),
+    (0x8b0018080, 3),
+    (0x1000404001, 3),
+    (0x1000404080, 3),
+    (0x100040c000, 2),
+    (0x100042c001, 3),
+    (0x100042c080, 3),
+    (0x100044c000, 3),
+    (0x1000804000, 3),
+    (0x100082c000, 3),
+    (0x1000c04003, 3),
+    (0x1000c04081, 3),
+    (0x1000c04180, 3),
+    (0x1000c0c001, 3),
+    (0x1000c0c080, 3),
+    (0x1000c2c003, 3),
+    (0x1000c2c081, 3),
+    (0x1000c2c180, 3),
+    (0x1000c4c001, 3),
+    (0x1000c4c080, 3),
+    (0x1000c8c000, 3),
+    (0x1001404001, 3),
+    (0x1001404080, 3),
+    (0x100142c001, 3),
+    (0x100142c080, 3),
+    (0x100144c000, 3),
+    (0x1001804001, 3),
+    (0x1001804080, 3),
+    (0x100180c000, 3),
+    (0x100182c001, 3),
+    (0x100182c080, 3),
+    (0x100184c000, 3),
+    (0x1002804000, 3),
+    (0x100282c000, 2),
+    (0x1002c04003, 3),
+    (0x1002c04081, 3),
+    (0x1002c04180, 3),
+    (0x1002c0c001, 3),
+    (0x1002c0c080, 3),
+    (0x1003404003, 3),
+    (0x1003404081, 3),
+    (0x1003404180, 3),
+    (0x100340c001, 3),
+    (0x100340c080, 2),
+    (0x1003804003, 3),
+    (0x1003804081, 3),
+    (0x1003804180, 3),
+    (0x100380c001, 3),
+    (0x100380c080, 3),
+    (0x100381c000, 2),
+    (0x1005404001, 1),
+    (0x1005404080, 2),
+    (0x1005804001, 3),
+    (0x1005804080, 3),
+    (0x100580c000, 2),
+    (0x1006804001, 3),
+    (0x1006804080, 2),
+    (0x100680c000, 6),
+    (0x100a804000, 2),
+    (0x1010404000, 3),
+    (0x101042c000, 3),
+    (0x1010c04001, 3),
+    (0x1010c04080, 3),
+    (0x1010c0c000, 3),
+    (0x1010c2c001, 3),
+    (0x1010c2c080, 3),
+    (0x1010c4c000, 3),
+    (0x1011404000, 3),
+    (0x101142c000, 3),
+    (0x1011804000, 3),
+    (0x101182c000, 4),
+    (0x1012c04001, 3),
+    (0x1012c04080, 3),
+    (0x1012c0c000, 3),
+    (0x1013404001, 3),
+    (0x1013404080, 3),
+    (0x101340c000, 2),
+    (0x1013804001, 3),
+    (0x1013804080, 3),
+    (0x101380c000, 3),
+    (0x1015404000, 1),
+    (0x1015804000, 3),
+    (0x1016804000, 2),
+    (0x1030c04000, 3),
+    (0x1030c2c000, 3),
+    (0x1032c04000, 3),
+    (0x1033404000, 3),
+    (0x1033804000, 3),
+    (0x1800018080, 2),
+    (0x1800018580, 2),
+    (0x1800058080, 5),
+    (0x1800068080, 1),
+    (0x1800418081, 3),
+    (0x1800418281, 3),
+    (0x1800430080, 2),
+    (0x1800a18080, 3),
+    (0x1800c00001, 3),
+    (0x1800c00280, 3),
+    (0x1800c04000, 3),
+    (0x1800c08081, 3),
+    (0x1800c2c000, 3),
+    (0x1801a08080, 3),
+    (0x1802c00001, 2),
+    (0x1802c00280, 3),
+    (0x1802c04000, 3),
+    (0x1803400001, 5),
+    (0x1803400280, 3),
+    (0x1803404000, 5),
+    (0x1803800001, 3),
+    (0x1803800280, 3),
+    (0x1803804000, 3),
+    (0x1806c00001, 3),
+    (0x1807400001, 3),
+    (0x1820414280, 3),
+    (0x2000404000, 6),
+    (0x200042c000, 4),
+    (0x2000c04001, 3),
+    (0x2000c04080, 3),
+    (0x2000c2c001, 3),
+    (0x2000c2c080, 3),
+    (0x2000c4c000, 3),
+    (0x2001804000, 3),
+    (0x200182c000, 6),
+    (0x2002c04001, 3),
+    (0x2002c04080, 3),
+    (0x2003404001, 3),
+    (0x2003404080, 3),
+    (0x2003804001, 3),
+    (0x2003804080, 3),
+    (0x200380c000, 3),
+    (0x2005804000, 2),
+    (0x2006804000, 2),
+    (0x2010c04000, 3),
+    (0x2010c2c000, 3),
+    (0x2012c04000, 3),
+    (0x2013404000, 3),
+    (0x2013804000, 3),
+    (0x4003804000, 3),
+    (0x40000008080, 2),
+    (0x40000008580, 3),
+    (0x40000018081, 3),
+    (0x40000018180, 2),
+    (0x40000018980, 3),
+    (0x40000028080, 2),
+    (0x40000030080, 3),
+    (0x40000030580, 3),
+    (0x40000050280, 3),
+    (0x40000058180, 2),
+    (0x40000068081, 3),
+    (0x40000068180, 1),
+    (0x400000a8080, 3),
+    (0x40000218080, 3),
+    (0x40000268080, 3),
+    (0x40000400001, 3),
+    (0x40000400280, 3),
+    (0x40000408081, 3),
+    (0x40000408281, 3),
+    (0x40000408581, 3),
+    (0x40000408980, 3),
+    (0x40000410580, 3),
+    (0x40000418083, 3),
+    (0x40000418181, 2),
+    (0x40000418283, 3),
+    (0x40000428081, 3),
+    (0x4000042c000, 3),
+    (0x40000430081, 3),
+    (0x40000430180, 3),
+    (0x40000430281, 2),
+    (0x40000450080, 3),
+    (0x40000808580, 2),
+    (0x40000818081, 3),
+    (0x40000830080, 3),
+    (0x40000a08080, 3),
+    (0x40000a08580, 3),
+    (0x40000a18081, 3),
+    (0x40000a18180, 3),
+    (0x40000a28080, 3),
+    (0x40000a30080, 3),
+    (0x40000c00001, 3),
+    (0x40000c00003, 3),
+    (0x40000c00017, 3),
+    (0x40000c00081, 3),
+    (0x40000c00281, 3),
+    (0x40000c00480, 3),
+    (0x40000c04001, 3),
+    (0x40000c04280, 3),
+    (0x40000c08083, 3),
+    (0x40000c08181, 3),
+    (0x40000c08283, 3),
+    (0x40000c08481, 3),
+    (0x40000c10081, 3),
+    (0x40000c10281, 3),
+    (0x40000c2c001, 3),
+    (0x40000c2c080, 3),
+    (0x40000c4c000, 3),
+    (0x40001218080, 3),
+    (0x40001400001, 3),
+    (0x40001400280, 3),
+    (0x40001408081, 3),
+    (0x40001408281, 2),
+    (0x4000142c000, 3),
+    (0x40001800001, 3),
+    (0x40001800280, 3),
+    (0x40001808081, 3),
+    (0x40001808281, 3),
+    (0x4000182c000, 3),
+    (0x40001a00001, 3),
+    (0x40001a04101, 3),
+    (0x40001a08081, 3),
+    (0x40001a08180, 3),
+    (0x40001a10080, 3),
+    (0x40002a08001, 2),
+    (0x40002a08080, 3),
+    (0x40002c00001, 3),
+    (0x40002c00003, 3),
+    (0x40002c00081, 2),
+    (0x40002c00281, 3),
+    (0x40002c00480, 3),
+    (0x40002c04001, 3),
+    (0x40002c04280, 3),
+    (0x40003208080, 3),
+    (0x40003400001, 3),
+    (0x40003400003, 3),
+    (0x40003400081, 3),
+    (0x40003400281, 3),
+    (0x40003400480, 3),
+    (0x40003404001, 3),
+    (0x40003404280, 3),
+    (0x40003800001, 3),
+    (0x40003800003, 3),
+    (0x40003800081, 3),
+    (0x40003800281, 3),
+    (0x40003800480, 3),
+    (0x40003804001, 3),
+    (0x40003804280, 3),
+    (0x40005400001, 3),
+    (0x40005400280, 4),
+    (0x40005800001, 3),
+    (0x40005800280, 3),
+    (0x40005808001, 3),
+    (0x40005814000, 2),
+    (0x40005a00001, 3),
+    (0x40006800001, 3),
+    (0x40006800280, 2),
+    (0x40006808001, 2),
+    (0x40006814000, 2),
+    (0x40006c00003, 3),
+    (0x40006c00081, 3),
+    (0x40006c04001, 3),
+    (0x40007200001, 3),
+    (0x40007400003, 3),
+    (0x40007400081, 3),
+    (0x40007404001, 3),
+    (0x4000a808000, 2),
+    (0x4000ac00001, 2),
+    (0x4000b400001, 2),
+    (0x4000b800001, 2),
+    (0x4000d800080, 2),
+    (0x40010018080, 2),
+    (0x40010018580, 3),
+    (0x40010058080, 4),
+    (0x40010068080, 5),
+    (0x40010070080, 3),
+    (0x40010408580, 3),
+    (0x40010418081, 3),
+    (0x40010430080, 3),
+    (0x40010a18080, 5),
+    (0x40010c00280, 3),
+    (0x40010c08081, 3),
+    (0x40010c08281, 3),
+    (0x40010c2c000, 3),
+    (0x40011a08080, 5),
+    (0x40012c00280, 3),
+    (0x40013400280, 3),
+    (0x40013800280, 3),
+    (0x40020404280, 2),
+    (0x40020414281, 3),
+    (0x40020414480, 3),
+    (0x40020424280, 3),
+    (0x4002042c280, 2),
+    (0x40020814280, 3),
+    (0x40020a04000, 3),
+    (0x40020a04082, 3),
+    (0x40020a04101, 3),
+    (0x40020c04281, 3),
+    (0x40020c04480, 3),
+    (0x40021804280, 3),
+    (0x40021a04001, 1),
+    (0x40021a04080, 0),
+    (0x40021a0c000, 3),
+    (0x40023204000, 3),
+    (0x40025400001, 2),
+    (0x40040414280, 2),
+    (0x40040c04280, 3),
+    (0x40041a04000, 3),
+    (0x40050408081, 3),
+    (0x400b0018080, 2),
+    (0x40800018080, 3),
+    (0x40800018580, 3),
+    (0x40800068080, 4),
+    (0x40800408580, 3),
+    (0x40800418081, 3),
+    (0x40800418180, 2),
+    (0x40800418281, 3),
+    (0x40800428080, 3),
+    (0x40800430080, 3),
+    (0x40800818080, 3),
+    (0x40800a18080, 4),
+    (0x40800c00280, 3),
+    (0x40800c08081, 3),
+    (0x40800c08281, 3),
+    (0x40800c2c000, 3),
+    (0x40801a08080, 4),
+    (0x40802c00280, 3),
+    (0x40803400280, 3),
+    (0x40803800280, 3),
+    (0x40810418080, 3),
+    (0x40820414280, 3),
+    (0x41000404000, 5),
+    (0x4100042c000, 5),
+    (0x41000c04001, 3),
+    (0x41000c04080, 3),
+    (0x41000c2c001, 3),
+    (0x41000c2c080, 3),
+    (0x41000c4c000, 3),
+    (0x41001804000, 3),
+    (0x4100182c000, 5),
+    (0x41002c04001, 3),
+    (0x41002c04080, 3),
+    (0x41003404001, 3),
+    (0x41003404080, 3),
+    (0x41003804001, 3),
+    (0x41003804080, 3),
+    (0x4100380c000, 3),
+    (0x41005804000, 2),
+    (0x41006804000, 2),
+    (0x41010c04000, 3),
+    (0x41010c2c000, 3),
+    (0x41012c04000, 3),
+    (0x41013404000, 3),
+    (0x41013804000, 3),
+    (0x41800418080, 3),
+    (0x42003804000, 3),
+    (0x4202042c000, 4),
+    (0x43000404001, 3),
+    (0x43000404080, 3),
+    (0x4300040c000, 2),
+    (0x4300042c001, 3),
+    (0x4300042c080, 3),
+    (0x4300044c000, 3),
+    (0x43000804000, 3),
+    (0x4300082c000, 3),
+    (0x43000c04003, 3),
+    (0x43000c04081, 3),
+    (0x43000c04180, 3),
+    (0x43000c0c001, 3),
+    (0x43000c0c080, 3),
+    (0x43001404001, 3),
+    (0x43001404080, 3),
+    (0x43001804001, 3),
+    (0x43001804080, 3),
+    (0x4300180c000, 3),
+    (0x43002804000, 3),
+    (0x43010404000, 3),
+    (0x4301042c000, 3),
+    (0x43010c04001, 3),
+    (0x43010c04080, 3),
+    (0x43010c0c000, 3),
+    (0x43011404000, 3),
+    (0x43011804000, 3),
+    (0x43030c04000, 3),
+    (0x45000404000, 3),
+    (0x4500042c000, 3),
+    (0x45000c04001, 3),
+    (0x45000c04080, 3),
+    (0x45000c0c000, 3),
+    (0x45001404000, 3),
+    (0x45001804000, 3),
+    (0x45010c04000, 3),
+    (0x49000c04000, 3),
+    (0x82010404000, 2),
+    (0x82010414001, 2),
+    (0x82010414080, 2),
+    (0x82010424000, 4),
+    (0x82010814000, 3),
+    (0x82030414000, 6),
+    (0x84010414000, 5),
+    (0xc0000018080, 3),
+    (0xc0000068080, 3),
+    (0xc0000408580, 3),
+    (0xc0000418081, 3),
+    (0xc0000418180, 3),
+    (0xc0000428080, 3),
+    (0xc0000430080, 2),
+    (0xc0000818080, 3),
+    (0xc0000a18080, 3),
+    (0xc0000c00280, 3),
+    (0xc0000c08081, 3),
+    (0xc0000c08281, 3),
+    (0xc0000c2c000, 3),
+    (0xc0001a08080, 3),
+    (0xc0002c00280, 3),
+    (0xc0003400280, 3),
+    (0xc0003800280, 3),
+    (0xc0010418080, 3),
+    (0xc0020414280, 3),
+    (0xc0021a04000, 3),
+    (0xc0800418080, 3),
+    (0xc1003804000, 3),
+    (0xc3000404000, 2),
+    (0xc3000414001, 3),
+    (0xc3000414080, 2),
+    (0xc3000424000, 5),
+    (0xc300042c000, 2),
+    (0xc3000814000, 3),
+    (0xc3000c04001, 3),
+    (0xc3000c04080, 3),
+    (0xc3001804000, 3),
+    (0xc3010414000, 3),
+    (0xc3010c04000, 3),
+    (0xc5000414000, 2),
+    (0xc5000c04000, 3),
+    (0x102010414000, 4),
+    (0x1c0000418080, 6),
+    (0x1c3000414000, 6),
+];
+
+pub fn lookup(position: &Position) -> Option<u8> {
+    if let Ok(index) = BOOK.binary_search_by_key(&position.key(), |(key, _)| *key) {
+        return Some(BOOK[index].1);
+    }
+
+    let index = BOOK
+        .binary_search_by_key(&position.mirrored_key(), |(key, _)| *key)
+        .ok()?;
+    Some(6 - BOOK[index].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_book_is_sorted() {
+        assert!(BOOK.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(BOOK.iter().all(|(_, col)| *col < 7));
+    }
+}
@@ -0,0 +1,65 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use std::fmt::Display;
+use std::time::Duration;
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    TryFromPrimitive,
+    IntoPrimitive,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[repr(i16)]
+pub enum Connect4Difficulty {
+    Easy = 0,
+    #[default]
+    Medium = 1,
+    Hard = 2,
+    Perfect = 3,
+}
+
+impl Connect4Difficulty {
+    pub const ALL: [Self; 4] = [Self::Easy, Self::Medium, Self::Hard, Self::Perfect];
+
+    /// The deepest the heuristic search looks ahead, in moves.
+    pub fn max_depth(&self) -> u8 {
+        match self {
+            Self::Easy => 1,
+            Self::Medium => 4,
+            Self::Hard | Self::Perfect => 42,
+        }
+    }
+
+    /// How long the heuristic search may deepen before it has to play the best move found.
+    pub fn search_budget(&self) -> Option<Duration> {
+        match self {
+            Self::Easy | Self::Medium => None,
+            Self::Hard | Self::Perfect => Some(Duration::from_millis(1000)),
+        }
+    }
+
+    /// How long the exact solver may run before falling back to the heuristic search. Past the
+    /// opening book it rarely needs more than a fraction of this.
+    pub fn solver_budget(&self) -> Option<Duration> {
+        match self {
+            Self::Easy | Self::Medium | Self::Hard => None,
+            Self::Perfect => Some(Duration::from_millis(5000)),
+        }
+    }
+
+    pub fn uses_opening_book(&self) -> bool {
+        matches!(self, Self::Perfect)
+    }
+}
+
+impl Display for Connect4Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
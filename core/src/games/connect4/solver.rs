@@ -0,0 +1,485 @@
+//! Exact Connect Four solver.
+//!
+//! The solver works on the same column-major bitboards as [`Connect4BoardMask`]: every column
+//! takes 7 bits, 6 for the cells and one sentinel bit on top. Scores are given from the view of
+//! the player to play: a positive score is a win, a negative score a loss, and the further the
+//! score is from zero the sooner the game ends (`22 - moves played by the winner`).
+//!
+//! [`Connect4BoardMask`]: crate::games::connect4::board::Connect4BoardMask
+
+use crate::games::connect4::state::Connect4State;
+use std::time::Instant;
+
+pub const WIDTH: u32 = 7;
+pub const HEIGHT: u32 = 6;
+pub const CELLS: u32 = WIDTH * HEIGHT;
+pub const MIN_SCORE: i32 = -(CELLS as i32) / 2 + 3;
+pub const MAX_SCORE: i32 = (CELLS as i32 + 1) / 2 - 3;
+
+/// Columns ordered from the centre outwards, centre columns take part in more lines.
+pub const COLUMN_ORDER: [u8; 7] = [3, 2, 4, 1, 5, 0, 6];
+
+const BOTTOM_MASK: u64 = 0b0000001_0000001_0000001_0000001_0000001_0000001_0000001;
+const BOARD_MASK: u64 = BOTTOM_MASK * ((1 << HEIGHT) - 1);
+
+#[inline(always)]
+fn top_mask(col: u8) -> u64 {
+    1 << (HEIGHT - 1 + col as u32 * (HEIGHT + 1))
+}
+
+#[inline(always)]
+fn column_mask(col: u8) -> u64 {
+    ((1 << HEIGHT) - 1) << (col as u32 * (HEIGHT + 1))
+}
+
+/// Returns every empty cell which would complete a line of four for `position`.
+fn winning_cells(position: u64, mask: u64) -> u64 {
+    let mut cells = (position << 1) & (position << 2) & (position << 3);
+
+    for shift in [HEIGHT + 1, HEIGHT, HEIGHT + 2] {
+        let mut pair = (position << shift) & (position << (2 * shift));
+        cells |= pair & (position << (3 * shift));
+        cells |= pair & (position >> shift);
+        pair = (position >> shift) & (position >> (2 * shift));
+        cells |= pair & (position << shift);
+        cells |= pair & (position >> (3 * shift));
+    }
+
+    cells & (BOARD_MASK ^ mask)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    /// Stones of the player to play.
+    pub current: u64,
+    /// Stones of both players.
+    pub mask: u64,
+    pub moves: u32,
+}
+
+impl From<&Connect4State> for Position {
+    fn from(state: &Connect4State) -> Self {
+        let player_1 = state.board.player_1.value();
+        let player_2 = state.board.player_2.value();
+        let mask = player_1 | player_2;
+
+        Self {
+            current: if state.is_1_to_play {
+                player_1
+            } else {
+                player_2
+            },
+            mask,
+            moves: mask.count_ones(),
+        }
+    }
+}
+
+impl Position {
+    /// Unique key of the position. Adding the mask sets the bit above the top stone of every
+    /// column, so the stones of the player to play and the heights can both be read from it.
+    #[inline(always)]
+    pub fn key(&self) -> u64 {
+        self.current + self.mask
+    }
+
+    /// The key of the position mirrored along the centre column.
+    pub fn mirrored_key(&self) -> u64 {
+        let mut key = 0;
+        let full = self.key();
+        for col in 0..WIDTH {
+            let column = (full >> (col * (HEIGHT + 1))) & 0x7F;
+            key |= column << ((WIDTH - 1 - col) * (HEIGHT + 1));
+        }
+        key
+    }
+
+    #[inline(always)]
+    pub fn can_play(&self, col: u8) -> bool {
+        self.mask & top_mask(col) == 0
+    }
+
+    #[inline(always)]
+    pub fn move_bit(&self, col: u8) -> u64 {
+        (self.mask + (1 << (col as u32 * (HEIGHT + 1)))) & column_mask(col)
+    }
+
+    #[inline(always)]
+    pub fn play_bit(&mut self, bit: u64) {
+        self.current ^= self.mask;
+        self.mask |= bit;
+        self.moves += 1;
+    }
+
+    pub fn play(&mut self, col: u8) {
+        self.play_bit(self.move_bit(col));
+    }
+
+    #[inline(always)]
+    pub fn possible(&self) -> u64 {
+        (self.mask + BOTTOM_MASK) & BOARD_MASK
+    }
+
+    #[inline(always)]
+    pub fn is_winning_move(&self, col: u8) -> bool {
+        winning_cells(self.current, self.mask) & self.possible() & column_mask(col) != 0
+    }
+
+    #[inline(always)]
+    pub fn can_win_next(&self) -> bool {
+        winning_cells(self.current, self.mask) & self.possible() != 0
+    }
+
+    /// Returns the moves which do not hand the opponent an immediate win. If the opponent
+    /// threatens to win in two places at once, there are none.
+    pub fn non_losing_moves(&self) -> u64 {
+        let mut possible = self.possible();
+        let opponent_wins = winning_cells(self.current ^ self.mask, self.mask);
+        let forced = possible & opponent_wins;
+
+        if forced != 0 {
+            if forced & (forced - 1) != 0 {
+                return 0;
+            }
+            possible = forced;
+        }
+
+        possible & !(opponent_wins >> 1)
+    }
+
+    /// The number of winning cells the player to play would have after playing `bit`.
+    #[inline(always)]
+    pub fn move_score(&self, bit: u64) -> u32 {
+        winning_cells(self.current | bit, self.mask).count_ones()
+    }
+
+    /// Returns the playable moves in `moves`, best candidates first. Candidates are ordered by
+    /// the threats they create and then from the centre outwards.
+    pub fn ordered_moves(&self, moves: u64) -> ([(u8, u64); 7], usize) {
+        let mut ordered = [(0, 0); 7];
+        let mut scores = [0; 7];
+        let mut len = 0;
+
+        for col in COLUMN_ORDER {
+            let bit = moves & column_mask(col);
+            if bit == 0 {
+                continue;
+            }
+
+            let score = self.move_score(bit);
+            let mut index = len;
+            while index > 0 && scores[index - 1] < score {
+                ordered[index] = ordered[index - 1];
+                scores[index] = scores[index - 1];
+                index -= 1;
+            }
+            ordered[index] = (col, bit);
+            scores[index] = score;
+            len += 1;
+        }
+
+        (ordered, len)
+    }
+}
+
+/// Fixed size transposition table storing upper bounds of solved positions.
+///
+/// Entries are keyed on [`Position::key`], which is derived from both board masks. Only the lower
+/// 32 bits of the key are stored; as the table size is a prime larger than 2^17 and keys fit into
+/// 49 bits, the slot index together with the partial key still identifies a position uniquely.
+pub struct TranspositionTable {
+    keys: Vec<u32>,
+    values: Vec<i8>,
+}
+
+impl TranspositionTable {
+    pub const SMALL: usize = 1_048_573;
+    pub const LARGE: usize = 8_388_617;
+
+    pub fn new(size: usize) -> Self {
+        Self {
+            keys: vec![0; size],
+            values: vec![0; size],
+        }
+    }
+
+    #[inline(always)]
+    fn index(&self, key: u64) -> usize {
+        (key % self.keys.len() as u64) as usize
+    }
+
+    pub fn put(&mut self, key: u64, value: i8) {
+        let index = self.index(key);
+        self.keys[index] = key as u32;
+        self.values[index] = value;
+    }
+
+    pub fn get(&self, key: u64) -> Option<i8> {
+        let index = self.index(key);
+        (self.keys[index] == key as u32 && self.values[index] != 0).then_some(self.values[index])
+    }
+}
+
+pub struct Connect4Solver {
+    table: TranspositionTable,
+    deadline: Option<Instant>,
+    aborted: bool,
+    pub nodes: u64,
+}
+
+impl Connect4Solver {
+    pub fn new(table_size: usize) -> Self {
+        Self {
+            table: TranspositionTable::new(table_size),
+            deadline: None,
+            aborted: false,
+            nodes: 0,
+        }
+    }
+
+    /// Stops solving once the deadline has passed, solving then returns `None`. A solver which
+    /// ran out of time can be reused with a new deadline, its table only holds finished results.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self.aborted = false;
+        self
+    }
+
+    /// Returns the exact score of the position.
+    pub fn solve(&mut self, position: &Position) -> Option<i32> {
+        if position.can_win_next() {
+            return Some((CELLS as i32 + 1 - position.moves as i32) / 2);
+        }
+
+        let mut min = -(CELLS as i32 - position.moves as i32) / 2;
+        let mut max = (CELLS as i32 + 1 - position.moves as i32) / 2;
+
+        // Narrow the window with null window searches, probing close to zero first since
+        // those searches are the cheapest.
+        while min < max {
+            let mut med = min + (max - min) / 2;
+            if med <= 0 && min / 2 < med {
+                med = min / 2;
+            } else if med >= 0 && max / 2 > med {
+                med = max / 2;
+            }
+
+            let score = self.negamax(position, med, med + 1);
+            if self.aborted {
+                return None;
+            }
+
+            if score <= med {
+                max = score;
+            } else {
+                min = score;
+            }
+        }
+
+        Some(min)
+    }
+
+    /// Returns only whether the position is won (1), drawn (0) or lost (-1), which needs far
+    /// fewer nodes than [`Self::solve`].
+    pub fn solve_weak(&mut self, position: &Position) -> Option<i32> {
+        if position.can_win_next() {
+            return Some(1);
+        }
+
+        let score = self.negamax(position, -1, 1);
+        (!self.aborted).then_some(score.signum())
+    }
+
+    /// Returns the best column to play together with its exact score.
+    pub fn best_move(&mut self, position: &Position) -> Option<(u8, i32)> {
+        let mut best: Option<(u8, i32)> = None;
+
+        for col in COLUMN_ORDER {
+            if !position.can_play(col) {
+                continue;
+            }
+
+            if position.is_winning_move(col) {
+                return Some((col, (CELLS as i32 + 1 - position.moves as i32) / 2));
+            }
+
+            let mut next = *position;
+            next.play(col);
+            let score = -self.solve(&next)?;
+
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((col, score));
+            }
+        }
+
+        best
+    }
+
+    /// Returns a column which keeps the best achievable outcome, together with that outcome
+    /// (1 win, 0 draw, -1 loss). Unlike [`Self::best_move`] only the outcome of every move is
+    /// solved and not how fast it is reached, which is a lot cheaper.
+    pub fn best_move_weak(&mut self, position: &Position) -> Option<(u8, i32)> {
+        let mut best: Option<(u8, i32)> = None;
+
+        for col in COLUMN_ORDER {
+            if !position.can_play(col) {
+                continue;
+            }
+
+            if position.is_winning_move(col) {
+                return Some((col, 1));
+            }
+
+            let mut next = *position;
+            next.play(col);
+            let score = -self.solve_weak(&next)?;
+            if score > 0 {
+                return Some((col, score));
+            }
+
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((col, score));
+            }
+        }
+
+        best
+    }
+
+    fn negamax(&mut self, position: &Position, mut alpha: i32, mut beta: i32) -> i32 {
+        self.nodes += 1;
+        if self.nodes & 0xFFF == 0
+            && let Some(deadline) = self.deadline
+            && Instant::now() >= deadline
+        {
+            self.aborted = true;
+        }
+        if self.aborted {
+            return 0;
+        }
+
+        let next = position.non_losing_moves();
+        if next == 0 {
+            return -(CELLS as i32 - position.moves as i32) / 2;
+        }
+
+        if position.moves >= CELLS - 2 {
+            return 0;
+        }
+
+        let min = -(CELLS as i32 - 2 - position.moves as i32) / 2;
+        if alpha < min {
+            alpha = min;
+            if alpha >= beta {
+                return alpha;
+            }
+        }
+
+        let mut max = (CELLS as i32 - 1 - position.moves as i32) / 2;
+        if let Some(value) = self.table.get(position.key()) {
+            max = value as i32 + MIN_SCORE - 1;
+        }
+        if beta > max {
+            beta = max;
+            if alpha >= beta {
+                return beta;
+            }
+        }
+
+        let (moves, len) = position.ordered_moves(next);
+        for (_, bit) in &moves[..len] {
+            let mut child = *position;
+            child.play_bit(*bit);
+
+            let score = -self.negamax(&child, -beta, -alpha);
+            if score >= beta {
+                return score;
+            }
+            if score > alpha {
+                alpha = score;
+            }
+        }
+
+        if !self.aborted {
+            self.table
+                .put(position.key(), (alpha - MIN_SCORE + 1) as i8);
+        }
+        alpha
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(moves: &str) -> Position {
+        let mut position = Position::from(&Connect4State::default());
+        for col in moves.bytes() {
+            position.play(col - b'1');
+        }
+        position
+    }
+
+    #[test]
+    fn test_solve_known_positions() {
+        // Positions and scores from the test sets of Pascal Pons' solver, columns are 1-based.
+        let cases = [
+            ("2252576253462244111563365343671351441", -1),
+            ("7422341735647741166133573473242566", 1),
+            ("23163416124767223154467471272416755633", 0),
+        ];
+
+        for (moves, expected) in cases {
+            let mut solver = Connect4Solver::new(TranspositionTable::SMALL);
+            assert_eq!(solver.solve(&position(moves)), Some(expected), "{moves}");
+
+            let mut solver = Connect4Solver::new(TranspositionTable::SMALL);
+            assert_eq!(
+                solver.solve_weak(&position(moves)),
+                Some(expected.signum()),
+                "{moves}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_best_move_keeps_win() {
+        let position = position("7422341735647741166133573473242566");
+        let mut solver = Connect4Solver::new(TranspositionTable::SMALL);
+
+        let (col, score) = solver.best_move(&position).unwrap();
+        assert_eq!(score, 1);
+
+        let mut next = position;
+        next.play(col);
+        assert_eq!(solver.solve(&next), Some(-1));
+    }
+
+    #[test]
+    fn test_non_losing_moves() {
+        // The opponent threatens to complete the bottom row in column 4.
+        let single = position("1525363");
+        assert_eq!(single.non_losing_moves(), single.move_bit(3));
+
+        // Two threats at once cannot both be blocked.
+        let double = position("27374");
+        assert_eq!(double.non_losing_moves(), 0);
+    }
+
+    #[test]
+    fn test_mirrored_key() {
+        let left = position("1123");
+        let right = position("7765");
+        assert_eq!(left.mirrored_key(), right.key());
+        assert_eq!(right.mirrored_key(), left.key());
+
+        let symmetric = position("4444");
+        assert_eq!(symmetric.mirrored_key(), symmetric.key());
+    }
+
+    #[test]
+    fn test_deadline_aborts() {
+        let mut solver =
+            Connect4Solver::new(TranspositionTable::SMALL).with_deadline(Instant::now());
+        assert_eq!(solver.solve(&position("")), None);
+    }
+}
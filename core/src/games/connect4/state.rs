@@ -1,7 +1,6 @@
 use crate::games::connect4::board::Connect4Board;
-use minimax::Winner;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
 pub struct Connect4State {
     pub is_1_to_play: bool,
     pub board: Connect4Board,
@@ -12,6 +11,14 @@ impl Connect4State {
         self.board.play(col, self.is_1_to_play);
         self.is_1_to_play = !self.is_1_to_play;
     }
+
+    pub fn can_play(&self, col: u8) -> bool {
+        col < 7 && self.board.heights[col as usize] < 6
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.board.heights.iter().all(|height| *height >= 6)
+    }
 }
 
 impl Default for Connect4State {
@@ -22,38 +29,3 @@ impl Default for Connect4State {
         }
     }
 }
-
-impl minimax::Game for Connect4State {
-    type S = Self;
-    type M = u8;
-
-    fn generate_moves(state: &Self::S, moves: &mut Vec<Self::M>) {
-        moves.clear();
-        for col in 0..7 {
-            if state.board.heights[col as usize] < 6 {
-                moves.push(col);
-            }
-        }
-    }
-
-    fn apply(state: &mut Self::S, m: Self::M) -> Option<Self::S> {
-        let mut new_state = *state;
-        new_state.play(m);
-        Some(new_state)
-    }
-
-    fn get_winner(state: &Self::S) -> Option<Winner> {
-        let just_moved = !state.is_1_to_play;
-        if state.board.has_won(just_moved) {
-            Some(Winner::PlayerJustMoved)
-        } else {
-            let mut moves = Vec::new();
-            Self::generate_moves(state, &mut moves);
-            if moves.is_empty() {
-                Some(Winner::Draw)
-            } else {
-                None
-            }
-        }
-    }
-}
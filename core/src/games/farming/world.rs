@@ -3,11 +3,13 @@ use crate::games::farming::day_night::day_night_color;
 use crate::games::farming::hemisphere::Hemisphere;
 use crate::games::farming::season::Season;
 use crate::games::farming::tile::{FarmTile, TileContext};
+use crate::games::rng::GameRng;
 use crate::rendering::o2d::prelude::{O2DRenderer, Object2D};
 use crate::types::grid::cardinal::Cardinal;
 use crate::types::grid::Grid;
 use chrono_tz::Tz;
 use image::RgbaImage;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashSet, VecDeque};
 use std::io::Cursor;
@@ -40,17 +42,20 @@ impl FarmWorld {
         }
     }
 
+    /// A world of randomly scattered water, the same seed gives the same world.
     pub fn new_random(
         width: u8,
         height: u8,
         water_chance: f32,
         hemisphere: Hemisphere,
         tz: Tz,
+        seed: u64,
     ) -> Self {
+        let mut rng = GameRng::seeded(seed);
         let mut tiles = Vec::with_capacity(width as usize * height as usize);
         for _ in 0..width {
             for _ in 0..height {
-                if rand::random::<f32>() < water_chance {
+                if rng.random::<f32>() < water_chance {
                     tiles.push(FarmTile::default())
                 } else {
                     tiles.push(FarmTile::new_land());
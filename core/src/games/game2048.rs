@@ -0,0 +1,240 @@
+use crate::games::rng::GameRng;
+use crate::types::grid::cardinal::Cardinal;
+use crate::types::grid::Grid;
+use chrono::{DateTime, Utc};
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+pub mod image;
+
+pub const SIZE: u8 = 4;
+/// The exponent of the tile that wins the game, play can continue after it.
+pub const WINNING_EXPONENT: u8 = 11;
+
+/// 2048 on a 4x4 grid. Tiles are stored as the exponent of their value, `0` being empty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Game2048 {
+    pub grid: Grid<u8>,
+    pub score: u32,
+    pub moves: u32,
+    pub started_at: DateTime<Utc>,
+    rng: GameRng,
+}
+
+impl Game2048 {
+    pub fn new() -> Self {
+        Self::with_rng(GameRng::new())
+    }
+
+    pub fn seeded(seed: u64) -> Self {
+        Self::with_rng(GameRng::seeded(seed))
+    }
+
+    fn with_rng(rng: GameRng) -> Self {
+        let tiles = vec![0; SIZE as usize * SIZE as usize];
+        let mut game = Self {
+            grid: Grid::new(tiles, SIZE, SIZE),
+            score: 0,
+            moves: 0,
+            started_at: Utc::now(),
+            rng,
+        };
+        game.spawn_tile();
+        game.spawn_tile();
+        game
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    pub fn value(exponent: u8) -> u32 {
+        if exponent == 0 {
+            0
+        } else {
+            1 << exponent
+        }
+    }
+
+    pub fn highest_tile(&self) -> u32 {
+        Self::value(self.grid.iter_tiles().copied().max().unwrap_or(0))
+    }
+
+    pub fn has_won(&self) -> bool {
+        self.grid
+            .iter_tiles()
+            .any(|exponent| *exponent >= WINNING_EXPONENT)
+    }
+
+    /// The game is over once the grid is full and no two neighboring tiles can merge.
+    pub fn is_over(&self) -> bool {
+        !self.grid.iter_tiles_coords().any(|(exponent, x, y)| {
+            *exponent == 0
+                || Cardinal::iter_main().any(|cardinal| {
+                    self.grid
+                        .check_neighbor(cardinal, x, y, |neighbor| neighbor == exponent)
+                })
+        })
+    }
+
+    pub fn can_slide(&self, direction: Cardinal) -> bool {
+        self.clone().slide_tiles(direction)
+    }
+
+    /// Slides every tile towards one of the main directions, merging equal tiles that meet once
+    /// per move. A new tile spawns after every move that changed the grid.
+    ///
+    /// Returns false if nothing moved.
+    pub fn slide(&mut self, direction: Cardinal) -> bool {
+        if !self.slide_tiles(direction) {
+            return false;
+        }
+        self.moves += 1;
+        self.spawn_tile();
+        true
+    }
+
+    fn slide_tiles(&mut self, direction: Cardinal) -> bool {
+        let mut changed = false;
+        for line in 0..SIZE {
+            // The coordinates of the line, starting at the edge the tiles slide towards.
+            let coords = (0..SIZE)
+                .map(|step| match direction {
+                    Cardinal::North => (line, step),
+                    Cardinal::South => (line, SIZE - 1 - step),
+                    Cardinal::West => (step, line),
+                    _ => (SIZE - 1 - step, line),
+                })
+                .collect::<Vec<_>>();
+
+            let tiles = coords
+                .iter()
+                .filter_map(|(x, y)| self.grid.get_tile(*x, *y).copied())
+                .filter(|exponent| *exponent != 0)
+                .collect::<Vec<_>>();
+
+            let mut merged = Vec::with_capacity(SIZE as usize);
+            let mut index = 0;
+            while index < tiles.len() {
+                if tiles.get(index + 1) == Some(&tiles[index]) {
+                    let exponent = tiles[index] + 1;
+                    self.score += Self::value(exponent);
+                    merged.push(exponent);
+                    index += 2;
+                } else {
+                    merged.push(tiles[index]);
+                    index += 1;
+                }
+            }
+
+            for (step, (x, y)) in coords.into_iter().enumerate() {
+                let exponent = merged.get(step).copied().unwrap_or(0);
+                if let Some(tile) = self.grid.get_tile_mut(x, y)
+                    && *tile != exponent
+                {
+                    *tile = exponent;
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+
+    /// Spawns a 2, or a 4 one in ten times, on a random empty tile.
+    fn spawn_tile(&mut self) {
+        let empty = self
+            .grid
+            .iter_tiles_coords()
+            .filter(|(exponent, _, _)| **exponent == 0)
+            .map(|(_, x, y)| (x, y))
+            .collect::<Vec<_>>();
+        let Some((x, y)) = empty.choose(&mut self.rng).copied() else {
+            return;
+        };
+
+        let exponent = if self.rng.random_range(0..10) == 0 {
+            2
+        } else {
+            1
+        };
+        self.grid.apply_at(x, y, |tile| *tile = exponent);
+    }
+}
+
+impl Default for Game2048 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game_with(rows: [[u8; 4]; 4]) -> Game2048 {
+        let mut game = Game2048::seeded(1);
+        game.grid = Grid::new(rows.concat(), SIZE, SIZE);
+        game
+    }
+
+    fn row(game: &Game2048, y: u8) -> Vec<u8> {
+        (0..SIZE)
+            .map(|x| *game.grid.get_tile(x, y).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_starts_with_two_tiles() {
+        let game = Game2048::seeded(9);
+        let tiles = game.grid.iter_tiles().filter(|tile| **tile != 0).count();
+        assert_eq!(tiles, 2);
+        assert_eq!(game.score, 0);
+    }
+
+    #[test]
+    fn test_tiles_merge_once_per_move() {
+        let mut game = game_with([[1, 1, 1, 1], [2, 1, 1, 0], [0, 0, 0, 0], [0, 0, 0, 0]]);
+        assert!(game.slide_tiles(Cardinal::West));
+        assert_eq!(row(&game, 0), vec![2, 2, 0, 0]);
+        assert_eq!(row(&game, 1), vec![2, 2, 0, 0]);
+        assert_eq!(game.score, 4 + 4 + 4);
+
+        let mut game = game_with([[1, 1, 1, 0], [0; 4], [0; 4], [0; 4]]);
+        game.slide_tiles(Cardinal::East);
+        assert_eq!(row(&game, 0), vec![0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_slide_vertically_and_spawn() {
+        let mut game = game_with([[1, 0, 0, 0], [0; 4], [1, 0, 0, 0], [2, 0, 0, 0]]);
+        assert!(game.slide(Cardinal::South));
+        assert_eq!(*game.grid.get_tile(0, 3).unwrap(), 2);
+        assert_eq!(*game.grid.get_tile(0, 2).unwrap(), 2);
+        assert_eq!(game.moves, 1);
+
+        let tiles = game.grid.iter_tiles().filter(|tile| **tile != 0).count();
+        assert_eq!(tiles, 3);
+    }
+
+    #[test]
+    fn test_blocked_move_and_game_over() {
+        let mut game = game_with([[1, 2, 1, 2], [2, 1, 2, 1], [1, 2, 1, 2], [2, 1, 2, 1]]);
+        assert!(!game.can_slide(Cardinal::North));
+        assert!(!game.slide(Cardinal::West));
+        assert!(game.is_over());
+
+        let game = game_with([[1, 2, 1, 2], [2, 1, 2, 1], [1, 2, 1, 2], [2, 1, 2, 2]]);
+        assert!(!game.is_over());
+        assert!(game.can_slide(Cardinal::East));
+    }
+
+    #[test]
+    fn test_winning_tile() {
+        let mut game = game_with([[10, 10, 0, 0], [0; 4], [0; 4], [0; 4]]);
+        assert!(!game.has_won());
+        game.slide(Cardinal::West);
+        assert!(game.has_won());
+        assert_eq!(game.highest_tile(), 2048);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::error::CoreResult;
+use crate::games::game2048::{Game2048, SIZE};
+use crate::rendering::o2d::prelude::{
+    LayerO2D, O2DRenderable, O2DRenderer, Object2D, PositionO2D, TextVisual,
+};
+use image::Rgba;
+use std::io::Cursor;
+
+const TILE_SIZE: u8 = 4;
+/// Every tile of the game is drawn as a square of 8x8 image tiles, wide enough for five digits.
+const CELL: u8 = 8;
+const GAP: u8 = 1;
+const BOARD_TILES: u8 = SIZE * CELL + (SIZE + 1) * GAP;
+const CHAR_WIDTH: u32 = 6;
+const GLYPH_HEIGHT: u32 = 7;
+/// Text is drawn upwards from its position, the top of its glyphs being this many pixels above.
+const GLYPH_TOP: u32 = 9;
+
+const BOARD: Rgba<u8> = Rgba([44, 41, 56, 255]);
+const EMPTY: Rgba<u8> = Rgba([58, 54, 70, 255]);
+const TEXT_DARK: Rgba<u8> = Rgba([35, 32, 45, 255]);
+const TEXT_LIGHT: Rgba<u8> = Rgba([246, 242, 232, 255]);
+
+#[derive(Debug, Clone, Copy)]
+enum BoardLayer {
+    Tiles,
+    Text,
+}
+
+impl LayerO2D for BoardLayer {
+    fn get_z_index(&self) -> u8 {
+        match self {
+            Self::Tiles => 0,
+            Self::Text => 1,
+        }
+    }
+}
+
+pub struct Game2048Image<'a> {
+    pub game: &'a Game2048,
+}
+
+impl<'a> Game2048Image<'a> {
+    pub fn new(game: &'a Game2048) -> Self {
+        Self { game }
+    }
+
+    pub fn render_png(&self, o2d: &O2DRenderer) -> CoreResult<Vec<u8>> {
+        let image = o2d.render(
+            std::slice::from_ref(self),
+            BOARD_TILES,
+            BOARD_TILES,
+            TILE_SIZE,
+        )?;
+        let scaled = image::imageops::resize(
+            &image,
+            image.width() * 3,
+            image.height() * 3,
+            image::imageops::FilterType::Nearest,
+        );
+        drop(image);
+
+        let mut bytes = Cursor::new(Vec::new());
+        scaled.write_to(&mut bytes, image::ImageFormat::Png)?;
+
+        let opts = oxipng::Options::default();
+        let optimized = oxipng::optimize_from_memory(&bytes.into_inner(), &opts)?;
+
+        Ok(optimized)
+    }
+
+    /// The colors of a tile and its value, getting warmer the higher the value.
+    fn tile_colors(exponent: u8) -> (Rgba<u8>, Rgba<u8>) {
+        match exponent {
+            1 => (Rgba([238, 228, 218, 255]), TEXT_DARK),
+            2 => (Rgba([237, 224, 200, 255]), TEXT_DARK),
+            3 => (Rgba([242, 177, 121, 255]), TEXT_LIGHT),
+            4 => (Rgba([245, 149, 99, 255]), TEXT_LIGHT),
+            5 => (Rgba([246, 124, 95, 255]), TEXT_LIGHT),
+            6 => (Rgba([246, 94, 59, 255]), TEXT_LIGHT),
+            7 => (Rgba([237, 207, 114, 255]), TEXT_LIGHT),
+            8 => (Rgba([237, 204, 97, 255]), TEXT_LIGHT),
+            9 => (Rgba([237, 200, 80, 255]), TEXT_LIGHT),
+            10 => (Rgba([237, 197, 63, 255]), TEXT_LIGHT),
+            11 => (Rgba([237, 194, 46, 255]), TEXT_LIGHT),
+            _ => (Rgba([60, 58, 50, 255]), TEXT_LIGHT),
+        }
+    }
+
+    fn cell(exponent: u8, x: u8, y: u8) -> Vec<Object2D> {
+        let mut objects = Vec::new();
+        let tile_x = GAP + x * (CELL + GAP);
+        let tile_y = GAP + y * (CELL + GAP);
+        let (background, text) = if exponent == 0 {
+            (EMPTY, TEXT_LIGHT)
+        } else {
+            Self::tile_colors(exponent)
+        };
+
+        for dx in 0..CELL {
+            for dy in 0..CELL {
+                objects.push(Object2D::color(
+                    background,
+                    PositionO2D::from_tile_xy_layer(tile_x + dx, tile_y + dy, BoardLayer::Tiles),
+                ));
+            }
+        }
+
+        if exponent != 0 {
+            let value = Game2048::value(exponent).to_string();
+            let tile_size = TILE_SIZE as u32;
+            let cell_size = (CELL * TILE_SIZE) as u32;
+            let text_width = value.len() as u32 * CHAR_WIDTH - 1;
+            let pixel_x = tile_x as u32 * tile_size + (cell_size - text_width) / 2;
+            let pixel_y = tile_y as u32 * tile_size + (cell_size - GLYPH_HEIGHT) / 2 + GLYPH_TOP;
+            objects.push(Object2D::text(
+                TextVisual::new(value, text),
+                PositionO2D::from_tile_xy_layer(
+                    (pixel_x / tile_size) as u8,
+                    (pixel_y / tile_size) as u8,
+                    BoardLayer::Text,
+                )
+                .with_offsets((pixel_x % tile_size) as i8, (pixel_y % tile_size) as i8),
+            ));
+        }
+
+        objects
+    }
+}
+
+impl O2DRenderable for Game2048Image<'_> {
+    fn to_objects(&self) -> Vec<Object2D> {
+        let mut objects = Vec::new();
+
+        for x in 0..BOARD_TILES {
+            for y in 0..BOARD_TILES {
+                let in_gap = x % (CELL + GAP) == 0 || y % (CELL + GAP) == 0;
+                if in_gap {
+                    objects.push(Object2D::color(
+                        BOARD,
+                        PositionO2D::from_tile_xy_layer(x, y, BoardLayer::Tiles),
+                    ));
+                }
+            }
+        }
+
+        for (exponent, x, y) in self.game.grid.iter_tiles_coords() {
+            objects.extend(Self::cell(*exponent, x, y));
+        }
+
+        objects
+    }
+}
@@ -0,0 +1,182 @@
+//! Provably fair lottery draws.
+//!
+//! When a round opens a random 32 byte seed is generated and only its SHA-256 hash is published.
+//! After the draw the seed is revealed, anyone can then check that it matches the published hash
+//! and recompute the winning ticket: a `ChaCha8Rng` seeded with the seed picks
+//! `random_range(0..total_tickets)`, tickets are numbered in order of purchase.
+
+use crate::database::entity::{lottery_round, lottery_ticket};
+use crate::error::{CoreError, CoreResult};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+pub type LotterySeed = [u8; 32];
+
+pub fn generate_seed() -> LotterySeed {
+    rand::rng().random()
+}
+
+/// The reservation of a ticket purchase, which is committed when the round is drawn.
+pub fn reference_id(ticket_id: Uuid) -> String {
+    format!("lottery-{ticket_id}")
+}
+
+pub fn seed_hash(seed: &LotterySeed) -> String {
+    hex::encode(Sha256::digest(seed))
+}
+
+pub fn decode_seed(seed: impl AsRef<str>) -> Option<LotterySeed> {
+    hex::decode(seed.as_ref()).ok()?.try_into().ok()
+}
+
+/// The 0-based index of the winning ticket, returns `None` if no tickets were bought.
+pub fn winning_ticket(seed: &LotterySeed, total_tickets: u64) -> Option<u64> {
+    if total_tickets == 0 {
+        return None;
+    }
+    let mut rng = ChaCha8Rng::from_seed(*seed);
+    Some(rng.random_range(0..total_tickets))
+}
+
+/// The part of the pot which is removed from circulation instead of being paid out.
+pub fn sink_amount(pot: i64, sink_percent: i16) -> i64 {
+    pot.max(0) * sink_percent.clamp(0, 100) as i64 / 100
+}
+
+/// The outcome of drawing a round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LotteryResult {
+    pub total_tickets: u64,
+    pub winning_ticket: Option<u64>,
+    pub winner_id: Option<String>,
+    pub pot: i64,
+    pub sink: i64,
+    pub payout: i64,
+    /// What is carried over to the next round, the whole pot if nobody won.
+    pub next_jackpot: i64,
+}
+
+/// Draws the winner among the given tickets, which have to be in order of purchase.
+/// Fails if the seed of the round is not a valid seed, instead of drawing with another one.
+pub fn draw(
+    round: &lottery_round::Model,
+    tickets: &[lottery_ticket::Model],
+    sink_percent: i16,
+) -> CoreResult<LotteryResult> {
+    let seed = decode_seed(&round.seed).ok_or(CoreError::InvalidLotterySeed(round.id))?;
+
+    let revenue: i64 = tickets.iter().map(|ticket| ticket.cost).sum();
+    let total_tickets: u64 = tickets
+        .iter()
+        .map(|ticket| ticket.tickets.max(0) as u64)
+        .sum();
+    let pot = round.carried_over.saturating_add(revenue);
+
+    let winning_ticket = winning_ticket(&seed, total_tickets);
+    let winner_id = winning_ticket.and_then(|index| {
+        let mut end = 0;
+        tickets.iter().find_map(|ticket| {
+            end += ticket.tickets.max(0) as u64;
+            (index < end).then(|| ticket.user_id.clone())
+        })
+    });
+
+    let (sink, payout, next_jackpot) = if winner_id.is_some() {
+        let sink = sink_amount(pot, sink_percent);
+        (sink, pot - sink, 0)
+    } else {
+        (0, 0, pot)
+    };
+
+    Ok(LotteryResult {
+        total_tickets,
+        winning_ticket,
+        winner_id,
+        pot,
+        sink,
+        payout,
+        next_jackpot,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round(seed: &LotterySeed, carried_over: i64) -> lottery_round::Model {
+        let now = chrono::Utc::now().naive_utc();
+        lottery_round::Model {
+            id: Uuid::new_v4(),
+            guild_id: "1".to_string(),
+            seed: hex::encode(seed),
+            seed_hash: seed_hash(seed),
+            ticket_price: 10,
+            carried_over,
+            pot: None,
+            sink: None,
+            winner_id: None,
+            winning_ticket: None,
+            draws_at: now,
+            drawn_at: None,
+            created_at: now,
+        }
+    }
+
+    fn ticket(user_id: &str, tickets: i32) -> lottery_ticket::Model {
+        lottery_ticket::Model {
+            id: Uuid::new_v4(),
+            round_id: Uuid::nil(),
+            user_id: user_id.to_string(),
+            tickets,
+            cost: tickets as i64 * 10,
+            created_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+
+    #[test]
+    fn test_draw_pays_the_owner_of_the_winning_ticket() {
+        let tickets = [ticket("a", 3), ticket("b", 1), ticket("c", 6)];
+        for byte in 0..20 {
+            let seed = [byte; 32];
+            let result = draw(&round(&seed, 50), &tickets, 10).unwrap();
+
+            let index = winning_ticket(&seed, 10).unwrap();
+            let owner = match index {
+                0..3 => "a",
+                3 => "b",
+                _ => "c",
+            };
+            assert_eq!(result.winning_ticket, Some(index));
+            assert_eq!(result.winner_id.as_deref(), Some(owner));
+            assert_eq!(result.total_tickets, 10);
+            assert_eq!(result.pot, 150);
+            assert_eq!(result.sink, 15);
+            assert_eq!(result.payout, 135);
+            assert_eq!(result.next_jackpot, 0);
+        }
+    }
+
+    #[test]
+    fn test_draw_without_tickets_carries_the_pot_over() {
+        let result = draw(&round(&generate_seed(), 70), &[], 10).unwrap();
+        assert_eq!(result.winner_id, None);
+        assert_eq!(result.winning_ticket, None);
+        assert_eq!((result.pot, result.sink, result.payout), (70, 0, 0));
+        assert_eq!(result.next_jackpot, 70);
+    }
+
+    #[test]
+    fn test_draw_rejects_an_invalid_seed() {
+        let mut round = round(&generate_seed(), 0);
+        round.seed = "not a seed".to_string();
+        assert!(matches!(
+            draw(&round, &[ticket("a", 1)], 10),
+            Err(CoreError::InvalidLotterySeed(_))
+        ));
+
+        round.seed = hex::encode([0; 16]);
+        assert!(draw(&round, &[ticket("a", 1)], 10).is_err());
+    }
+}
@@ -0,0 +1,357 @@
+use crate::games::minesweeper::difficulty::MinesweeperDifficulty;
+use crate::games::rng::GameRng;
+use crate::types::grid::cardinal::Cardinal;
+use crate::types::grid::Grid;
+use chrono::{DateTime, Utc};
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use strum::IntoEnumIterator;
+
+pub mod difficulty;
+pub mod image;
+
+/// The letter a column is labeled with, boards are at most 25 tiles wide.
+pub fn column_label(x: u8) -> char {
+    (b'A' + x) as char
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MinesweeperTileState {
+    #[default]
+    Hidden,
+    Flagged,
+    Revealed,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MinesweeperTile {
+    pub mine: bool,
+    /// How many of the eight surrounding tiles are mines.
+    pub adjacent_mines: u8,
+    pub state: MinesweeperTileState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MinesweeperStatus {
+    Playing,
+    Won,
+    /// The tile of the mine that was revealed.
+    Lost {
+        x: u8,
+        y: u8,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinesweeperGame {
+    pub difficulty: MinesweeperDifficulty,
+    pub grid: Grid<MinesweeperTile>,
+    pub status: MinesweeperStatus,
+    /// Set on the first reveal, which is when the mines are placed.
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    rng: GameRng,
+}
+
+impl MinesweeperGame {
+    pub fn new(difficulty: MinesweeperDifficulty) -> Self {
+        let (width, height) = (difficulty.width(), difficulty.height());
+        let tiles = vec![MinesweeperTile::default(); width as usize * height as usize];
+        Self {
+            difficulty,
+            grid: Grid::new(tiles, width, height),
+            status: MinesweeperStatus::Playing,
+            started_at: None,
+            finished_at: None,
+            rng: GameRng::new(),
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = GameRng::seeded(seed);
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.status != MinesweeperStatus::Playing
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.status == MinesweeperStatus::Won
+    }
+
+    /// The mines minus the flags placed, which goes negative if too many tiles are flagged.
+    pub fn mines_left(&self) -> i32 {
+        let flags = self
+            .grid
+            .iter_tiles()
+            .filter(|tile| tile.state == MinesweeperTileState::Flagged)
+            .count();
+        self.difficulty.mines() as i32 - flags as i32
+    }
+
+    /// The time from the first reveal until the game ended, or until `now` while it is running.
+    pub fn elapsed_ms(&self, now: DateTime<Utc>) -> i64 {
+        let Some(started_at) = self.started_at else {
+            return 0;
+        };
+        let until = self.finished_at.unwrap_or(now);
+        (until - started_at).num_milliseconds().max(0)
+    }
+
+    /// Reveals a tile, and every tile around it if it has no mines next to it. Revealing an
+    /// already revealed tile whose mines are all flagged reveals its other neighbors.
+    ///
+    /// Returns false if nothing changed.
+    pub fn reveal(&mut self, x: u8, y: u8) -> bool {
+        if self.is_over() {
+            return false;
+        }
+        let Some(tile) = self.grid.get_tile(x, y).copied() else {
+            return false;
+        };
+        if tile.state == MinesweeperTileState::Flagged {
+            return false;
+        }
+
+        if self.started_at.is_none() {
+            self.place_mines(x, y);
+            self.started_at = Some(Utc::now());
+        }
+
+        let changed = if tile.state == MinesweeperTileState::Revealed {
+            self.chord(x, y, tile.adjacent_mines)
+        } else {
+            self.flood_reveal(x, y);
+            true
+        };
+
+        if changed {
+            self.update_status();
+        }
+        changed
+    }
+
+    /// Flags a hidden tile, or removes the flag of a flagged one.
+    pub fn toggle_flag(&mut self, x: u8, y: u8) -> bool {
+        if self.is_over() {
+            return false;
+        }
+        let Some(tile) = self.grid.get_tile_mut(x, y) else {
+            return false;
+        };
+
+        tile.state = match tile.state {
+            MinesweeperTileState::Hidden => MinesweeperTileState::Flagged,
+            MinesweeperTileState::Flagged => MinesweeperTileState::Hidden,
+            MinesweeperTileState::Revealed => return false,
+        };
+        true
+    }
+
+    fn neighbors(&self, x: u8, y: u8) -> Vec<(u8, u8)> {
+        Cardinal::iter()
+            .filter_map(|cardinal| self.grid.get_neighbor_coordinates(cardinal, x, y))
+            .collect()
+    }
+
+    /// Places the mines anywhere but around the first tile revealed, so that the first reveal
+    /// always opens an area instead of a single number.
+    fn place_mines(&mut self, first_x: u8, first_y: u8) {
+        let mut safe = self.neighbors(first_x, first_y);
+        safe.push((first_x, first_y));
+
+        let mut candidates = self
+            .grid
+            .iter_tiles_coords()
+            .map(|(_, x, y)| (x, y))
+            .filter(|coords| !safe.contains(coords))
+            .collect::<Vec<_>>();
+        candidates.shuffle(&mut self.rng);
+
+        for (x, y) in candidates
+            .into_iter()
+            .take(self.difficulty.mines() as usize)
+        {
+            self.grid.apply_at(x, y, |tile| tile.mine = true);
+            for (nx, ny) in self.neighbors(x, y) {
+                self.grid.apply_at(nx, ny, |tile| tile.adjacent_mines += 1);
+            }
+        }
+    }
+
+    fn flood_reveal(&mut self, x: u8, y: u8) {
+        let mut queue = VecDeque::from([(x, y)]);
+        while let Some((x, y)) = queue.pop_front() {
+            let Some(tile) = self.grid.get_tile_mut(x, y) else {
+                continue;
+            };
+            if tile.state != MinesweeperTileState::Hidden {
+                continue;
+            }
+            tile.state = MinesweeperTileState::Revealed;
+
+            if tile.mine {
+                self.status = MinesweeperStatus::Lost { x, y };
+            } else if tile.adjacent_mines == 0 {
+                queue.extend(self.neighbors(x, y));
+            }
+        }
+    }
+
+    fn chord(&mut self, x: u8, y: u8, adjacent_mines: u8) -> bool {
+        let neighbors = self.neighbors(x, y);
+        let flags = neighbors
+            .iter()
+            .filter(|(nx, ny)| {
+                self.grid
+                    .get_tile(*nx, *ny)
+                    .is_some_and(|tile| tile.state == MinesweeperTileState::Flagged)
+            })
+            .count();
+        if adjacent_mines == 0 || flags != adjacent_mines as usize {
+            return false;
+        }
+
+        let mut changed = false;
+        for (nx, ny) in neighbors {
+            if self
+                .grid
+                .get_tile(nx, ny)
+                .is_some_and(|tile| tile.state == MinesweeperTileState::Hidden)
+            {
+                self.flood_reveal(nx, ny);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    fn update_status(&mut self) {
+        if self.status == MinesweeperStatus::Playing
+            && self
+                .grid
+                .iter_tiles()
+                .all(|tile| tile.mine || tile.state == MinesweeperTileState::Revealed)
+        {
+            self.status = MinesweeperStatus::Won;
+            for tile in self.grid.iter_tiles_mut() {
+                if tile.mine {
+                    tile.state = MinesweeperTileState::Flagged;
+                }
+            }
+        }
+
+        if self.is_over() {
+            self.finished_at = Some(Utc::now());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mine_coords(game: &MinesweeperGame) -> Vec<(u8, u8)> {
+        game.grid
+            .iter_tiles_coords()
+            .filter(|(tile, _, _)| tile.mine)
+            .map(|(_, x, y)| (x, y))
+            .collect()
+    }
+
+    #[test]
+    fn test_first_reveal_is_safe() {
+        for seed in 0..50 {
+            let mut game = MinesweeperGame::new(MinesweeperDifficulty::Expert).with_seed(seed);
+            assert!(game.reveal(0, 0));
+            assert_eq!(game.status, MinesweeperStatus::Playing);
+            assert_eq!(mine_coords(&game).len(), 80);
+
+            let first = game.grid.get_tile(0, 0).unwrap();
+            assert_eq!(first.adjacent_mines, 0);
+        }
+    }
+
+    #[test]
+    fn test_flood_fill_stops_at_numbers() {
+        let mut game = MinesweeperGame::new(MinesweeperDifficulty::Beginner).with_seed(3);
+        game.reveal(4, 4);
+
+        for (tile, x, y) in game.grid.iter_tiles_coords() {
+            if tile.state != MinesweeperTileState::Revealed {
+                continue;
+            }
+            assert!(!tile.mine);
+            // Every revealed tile is either the start or next to a revealed empty tile.
+            let opened_by_neighbor = game.neighbors(x, y).iter().any(|(nx, ny)| {
+                let neighbor = game.grid.get_tile(*nx, *ny).unwrap();
+                neighbor.state == MinesweeperTileState::Revealed && neighbor.adjacent_mines == 0
+            });
+            assert!((x, y) == (4, 4) || opened_by_neighbor);
+        }
+    }
+
+    #[test]
+    fn test_flags_block_reveal_and_chord() {
+        let mut game = MinesweeperGame::new(MinesweeperDifficulty::Beginner).with_seed(11);
+        game.reveal(0, 0);
+
+        let (mine_x, mine_y) = mine_coords(&game)[0];
+        assert!(game.toggle_flag(mine_x, mine_y));
+        assert!(!game.reveal(mine_x, mine_y));
+        assert_eq!(game.mines_left(), 9);
+
+        // A number with its mines flagged reveals the rest of its neighbors.
+        let numbered = game.neighbors(mine_x, mine_y).into_iter().find(|(x, y)| {
+            let tile = game.grid.get_tile(*x, *y).unwrap();
+            !tile.mine && tile.adjacent_mines == 1
+        });
+        if let Some((x, y)) = numbered {
+            game.grid
+                .apply_at(x, y, |tile| tile.state = MinesweeperTileState::Revealed);
+            game.reveal(x, y);
+            assert_eq!(game.status, MinesweeperStatus::Playing);
+            assert!(game.neighbors(x, y).iter().all(|(nx, ny)| {
+                let tile = game.grid.get_tile(*nx, *ny).unwrap();
+                tile.mine || tile.state == MinesweeperTileState::Revealed
+            }));
+        }
+    }
+
+    #[test]
+    fn test_win_and_loss() {
+        let mut game = MinesweeperGame::new(MinesweeperDifficulty::Beginner).with_seed(5);
+        game.reveal(0, 0);
+        let mines = mine_coords(&game);
+        let safe = game
+            .grid
+            .iter_tiles_coords()
+            .filter(|(tile, _, _)| !tile.mine)
+            .map(|(_, x, y)| (x, y))
+            .collect::<Vec<_>>();
+
+        let mut lost = game.clone();
+        assert!(lost.reveal(mines[0].0, mines[0].1));
+        assert_eq!(
+            lost.status,
+            MinesweeperStatus::Lost {
+                x: mines[0].0,
+                y: mines[0].1
+            }
+        );
+        assert!(!lost.reveal(safe[0].0, safe[0].1));
+
+        for (x, y) in safe {
+            game.reveal(x, y);
+        }
+        assert!(game.is_won());
+        assert_eq!(game.mines_left(), 0);
+        assert!(game.finished_at.is_some());
+    }
+}
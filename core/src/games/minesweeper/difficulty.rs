@@ -0,0 +1,56 @@
+use crate::types::puzzle_kind::PuzzleKind;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Boards are at most 25 tiles wide and high, as tiles are picked by column and row from select
+/// menus of up to 25 options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MinesweeperDifficulty {
+    Beginner,
+    Intermediate,
+    Expert,
+}
+
+impl MinesweeperDifficulty {
+    pub fn width(&self) -> u8 {
+        match self {
+            Self::Beginner => 9,
+            Self::Intermediate => 16,
+            Self::Expert => 24,
+        }
+    }
+
+    pub fn height(&self) -> u8 {
+        match self {
+            Self::Beginner => 9,
+            Self::Intermediate => 16,
+            Self::Expert => 16,
+        }
+    }
+
+    pub fn mines(&self) -> u16 {
+        match self {
+            Self::Beginner => 10,
+            Self::Intermediate => 40,
+            Self::Expert => 80,
+        }
+    }
+
+    pub fn puzzle_kind(&self) -> PuzzleKind {
+        match self {
+            Self::Beginner => PuzzleKind::MinesweeperBeginner,
+            Self::Intermediate => PuzzleKind::MinesweeperIntermediate,
+            Self::Expert => PuzzleKind::MinesweeperExpert,
+        }
+    }
+}
+
+impl Display for MinesweeperDifficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Beginner => write!(f, "Beginner"),
+            Self::Intermediate => write!(f, "Intermediate"),
+            Self::Expert => write!(f, "Expert"),
+        }
+    }
+}
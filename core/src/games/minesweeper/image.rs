@@ -0,0 +1,251 @@
+use crate::error::CoreResult;
+use crate::games::minesweeper::{
+    column_label, MinesweeperGame, MinesweeperStatus, MinesweeperTile, MinesweeperTileState,
+};
+use crate::rendering::o2d::prelude::{
+    LayerO2D, O2DRenderable, O2DRenderer, Object2D, PositionO2D, TextVisual,
+};
+use image::Rgba;
+use std::io::Cursor;
+
+const TILE_SIZE: u8 = 4;
+/// Every board tile is drawn as a square of 3x3 image tiles.
+const CELL: u8 = 3;
+const PADDING: u8 = 1;
+const LABELS: u8 = 3;
+const BOARD_START: u8 = PADDING + LABELS;
+const CHAR_WIDTH: u32 = 6;
+const GLYPH_HEIGHT: u32 = 7;
+/// Text is drawn upwards from its position, the top of its glyphs being this many pixels above.
+const GLYPH_TOP: u32 = 9;
+
+const BACKGROUND: Rgba<u8> = Rgba([35, 32, 45, 255]);
+const HIDDEN: Rgba<u8> = Rgba([96, 120, 160, 255]);
+const HIDDEN_ALT: Rgba<u8> = Rgba([88, 111, 150, 255]);
+const REVEALED: Rgba<u8> = Rgba([58, 54, 70, 255]);
+const REVEALED_ALT: Rgba<u8> = Rgba([52, 48, 63, 255]);
+const EXPLODED: Rgba<u8> = Rgba([170, 58, 58, 255]);
+const FLAG: Rgba<u8> = Rgba([232, 84, 72, 255]);
+const MINE: Rgba<u8> = Rgba([18, 16, 22, 255]);
+const SELECTED: Rgba<u8> = Rgba([224, 180, 84, 255]);
+const TEXT_MUTED: Rgba<u8> = Rgba([140, 134, 150, 255]);
+
+#[derive(Debug, Clone, Copy)]
+enum BoardLayer {
+    Tiles,
+    Markers,
+    Text,
+}
+
+impl LayerO2D for BoardLayer {
+    fn get_z_index(&self) -> u8 {
+        match self {
+            Self::Tiles => 0,
+            Self::Markers => 1,
+            Self::Text => 2,
+        }
+    }
+}
+
+/// A Minesweeper board with its column letters and row numbers. The column and row picked by
+/// the player are highlighted, the mines are shown once the game is lost.
+pub struct MinesweeperImage<'a> {
+    pub game: &'a MinesweeperGame,
+    pub selected_column: Option<u8>,
+    pub selected_row: Option<u8>,
+}
+
+impl<'a> MinesweeperImage<'a> {
+    pub fn new(
+        game: &'a MinesweeperGame,
+        selected_column: Option<u8>,
+        selected_row: Option<u8>,
+    ) -> Self {
+        Self {
+            game,
+            selected_column,
+            selected_row,
+        }
+    }
+
+    pub fn render_png(&self, o2d: &O2DRenderer) -> CoreResult<Vec<u8>> {
+        let width = BOARD_START + self.game.grid.width() * CELL + PADDING;
+        let height = BOARD_START + self.game.grid.height() * CELL + PADDING;
+        let image = o2d.render(std::slice::from_ref(self), height, width, TILE_SIZE)?;
+        let scaled = image::imageops::resize(
+            &image,
+            image.width() * 3,
+            image.height() * 3,
+            image::imageops::FilterType::Nearest,
+        );
+        drop(image);
+
+        let mut bytes = Cursor::new(Vec::new());
+        scaled.write_to(&mut bytes, image::ImageFormat::Png)?;
+
+        let opts = oxipng::Options::default();
+        let optimized = oxipng::optimize_from_memory(&bytes.into_inner(), &opts)?;
+
+        Ok(optimized)
+    }
+
+    /// Text whose glyphs start at the given pixel, centered vertically within a board tile.
+    fn text(text: impl Into<String>, color: Rgba<u8>, pixel_x: u32, cell_top: u32) -> Object2D {
+        let tile_size = TILE_SIZE as u32;
+        let cell_size = (CELL * TILE_SIZE) as u32;
+        let pixel_y = cell_top + (cell_size - GLYPH_HEIGHT) / 2 + GLYPH_TOP;
+        Object2D::text(
+            TextVisual::new(text, color),
+            PositionO2D::from_tile_xy_layer(
+                (pixel_x / tile_size) as u8,
+                (pixel_y / tile_size) as u8,
+                BoardLayer::Text,
+            )
+            .with_offsets((pixel_x % tile_size) as i8, (pixel_y % tile_size) as i8),
+        )
+    }
+
+    fn cell_pixels(x: u8, y: u8) -> (u32, u32) {
+        let tile_size = TILE_SIZE as u32;
+        (
+            (BOARD_START + x * CELL) as u32 * tile_size,
+            (BOARD_START + y * CELL) as u32 * tile_size,
+        )
+    }
+
+    fn number_color(adjacent_mines: u8) -> Rgba<u8> {
+        match adjacent_mines {
+            1 => Rgba([110, 170, 240, 255]),
+            2 => Rgba([120, 200, 120, 255]),
+            3 => Rgba([236, 110, 100, 255]),
+            4 => Rgba([176, 128, 236, 255]),
+            5 => Rgba([236, 164, 84, 255]),
+            6 => Rgba([90, 200, 200, 255]),
+            7 => Rgba([236, 232, 220, 255]),
+            _ => Rgba([160, 156, 170, 255]),
+        }
+    }
+
+    fn labels(&self) -> Vec<Object2D> {
+        let mut objects = Vec::new();
+        let tile_size = TILE_SIZE as u32;
+        let cell_size = (CELL * TILE_SIZE) as u32;
+
+        for x in 0..self.game.grid.width() {
+            let color = if self.selected_column == Some(x) {
+                SELECTED
+            } else {
+                TEXT_MUTED
+            };
+            let (pixel_x, _) = Self::cell_pixels(x, 0);
+            let pixel_x = pixel_x + (cell_size - (CHAR_WIDTH - 1)) / 2;
+            objects.push(Self::text(
+                column_label(x).to_string(),
+                color,
+                pixel_x,
+                PADDING as u32 * tile_size,
+            ));
+        }
+
+        for y in 0..self.game.grid.height() {
+            let color = if self.selected_row == Some(y) {
+                SELECTED
+            } else {
+                TEXT_MUTED
+            };
+            let label = (y + 1).to_string();
+            let text_width = label.len() as u32 * CHAR_WIDTH;
+            let (_, pixel_y) = Self::cell_pixels(0, y);
+            objects.push(Self::text(
+                label,
+                color,
+                BOARD_START as u32 * tile_size - text_width - 1,
+                pixel_y,
+            ));
+        }
+
+        objects
+    }
+
+    fn cell(&self, tile: &MinesweeperTile, x: u8, y: u8) -> Vec<Object2D> {
+        let mut objects = Vec::new();
+        let exploded = self.game.status == (MinesweeperStatus::Lost { x, y });
+        let even = (x + y).is_multiple_of(2);
+        let background = match tile.state {
+            _ if exploded => EXPLODED,
+            MinesweeperTileState::Revealed if even => REVEALED,
+            MinesweeperTileState::Revealed => REVEALED_ALT,
+            _ if even => HIDDEN,
+            _ => HIDDEN_ALT,
+        };
+
+        let tile_x = BOARD_START + x * CELL;
+        let tile_y = BOARD_START + y * CELL;
+        for dx in 0..CELL {
+            for dy in 0..CELL {
+                objects.push(Object2D::color(
+                    background,
+                    PositionO2D::from_tile_xy_layer(tile_x + dx, tile_y + dy, BoardLayer::Tiles),
+                ));
+            }
+        }
+
+        let center = PositionO2D::from_tile_xy_layer(tile_x + 1, tile_y + 1, BoardLayer::Markers);
+        let lost = matches!(self.game.status, MinesweeperStatus::Lost { .. });
+        match tile.state {
+            MinesweeperTileState::Flagged => objects.push(Object2D::color(FLAG, center)),
+            _ if tile.mine && lost => objects.push(Object2D::color(MINE, center)),
+            MinesweeperTileState::Revealed if tile.adjacent_mines > 0 => {
+                let (pixel_x, pixel_y) = Self::cell_pixels(x, y);
+                let cell_size = (CELL * TILE_SIZE) as u32;
+                objects.push(Self::text(
+                    tile.adjacent_mines.to_string(),
+                    Self::number_color(tile.adjacent_mines),
+                    pixel_x + (cell_size - (CHAR_WIDTH - 1)) / 2,
+                    pixel_y,
+                ));
+            }
+            _ => {}
+        }
+
+        let selected = self.selected_column == Some(x) && self.selected_row == Some(y);
+        if selected {
+            for (dx, dy) in [(0, 0), (CELL - 1, 0), (0, CELL - 1), (CELL - 1, CELL - 1)] {
+                objects.push(Object2D::color(
+                    SELECTED,
+                    PositionO2D::from_tile_xy_layer(tile_x + dx, tile_y + dy, BoardLayer::Markers),
+                ));
+            }
+        }
+
+        objects
+    }
+}
+
+impl O2DRenderable for MinesweeperImage<'_> {
+    fn to_objects(&self) -> Vec<Object2D> {
+        let mut objects = Vec::new();
+
+        let width = BOARD_START + self.game.grid.width() * CELL + PADDING;
+        let height = BOARD_START + self.game.grid.height() * CELL + PADDING;
+        for x in 0..width {
+            for y in 0..height {
+                let on_board = (BOARD_START..width - PADDING).contains(&x)
+                    && (BOARD_START..height - PADDING).contains(&y);
+                if !on_board {
+                    objects.push(Object2D::color(
+                        BACKGROUND,
+                        PositionO2D::from_tile_xy_layer(x, y, BoardLayer::Tiles),
+                    ));
+                }
+            }
+        }
+
+        objects.extend(self.labels());
+        for (tile, x, y) in self.game.grid.iter_tiles_coords() {
+            objects.extend(self.cell(tile, x, y));
+        }
+
+        objects
+    }
+}
@@ -0,0 +1,57 @@
+use crate::games::blackjack::BlackjackGame;
+use crate::games::connect4::Connect4Game;
+use crate::games::rps::tournament::RPSTournament;
+use crate::games::rps::RPSGame;
+use crate::games::turn_based::TurnBasedWagers;
+use crate::types::active_game_kind::ActiveGameKind;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// A game whose state is stored while it is running, so that it can be voided and its wagers
+/// refunded if the bot restarts before it is over.
+pub trait PersistentGame: Serialize + DeserializeOwned {
+    const KIND: ActiveGameKind;
+
+    fn id(&self) -> Uuid;
+}
+
+impl PersistentGame for BlackjackGame {
+    const KIND: ActiveGameKind = ActiveGameKind::Blackjack;
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl PersistentGame for Connect4Game {
+    const KIND: ActiveGameKind = ActiveGameKind::Connect4;
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl PersistentGame for RPSGame {
+    const KIND: ActiveGameKind = ActiveGameKind::RockPaperScissors;
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl PersistentGame for RPSTournament {
+    const KIND: ActiveGameKind = ActiveGameKind::RockPaperScissorsTournament;
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl PersistentGame for TurnBasedWagers {
+    const KIND: ActiveGameKind = ActiveGameKind::TurnBased;
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
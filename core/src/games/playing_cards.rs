@@ -9,6 +9,7 @@ use strum::IntoEnumIterator;
     Copy,
     PartialEq,
     Eq,
+    Hash,
     sea_orm::EnumIter,
     TryFromPrimitive,
     serde::Serialize,
@@ -128,6 +129,16 @@ impl PlayingCard {
         }
     }
 
+    /// The rank of the card regardless of its suit, from 0 for a two up to 12 for an ace.
+    pub fn rank(&self) -> u8 {
+        *self as u8 % 13
+    }
+
+    /// The suit of the card, from 0 to 3 for spades, hearts, clubs and diamonds.
+    pub fn suit(&self) -> u8 {
+        *self as u8 / 13
+    }
+
     pub fn is_ace(&self) -> bool {
         matches!(
             self,
@@ -151,9 +162,9 @@ impl PlayingCardDeck {
         }
     }
 
-    pub fn new_shuffled() -> Self {
+    pub fn new_shuffled<R: Rng>(rng: &mut R) -> Self {
         let mut deck = Self::new();
-        deck.shuffle(&mut rand::rng());
+        deck.shuffle(rng);
         deck
     }
 
@@ -0,0 +1,748 @@
+use crate::games::playing_cards::{PlayingCard, PlayingCardDeck};
+use crate::games::poker::hand::{evaluate, PokerHandRank};
+use crate::games::poker::pot::build_pots;
+use crate::games::rng::GameRng;
+use crate::games::turn_based::{TurnBasedGame, TurnOutcome};
+use uuid::Uuid;
+
+pub mod hand;
+pub mod pot;
+
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum PokerAction {
+    Fold,
+    Check,
+    Call,
+    /// Raises the bet of the current betting round to the given amount.
+    Raise(i64),
+    AllIn,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub enum PokerStreet {
+    PreFlop,
+    Flop,
+    Turn,
+    River,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PokerSeat {
+    pub id: String,
+    /// The chips in front of the player, backed by the Citrine of their buy-in.
+    pub stack: i64,
+    pub hole_cards: Vec<PlayingCard>,
+    /// The chips put in during the current betting round.
+    pub bet: i64,
+    /// The chips put in during the current hand.
+    pub contributed: i64,
+    /// Players without chips sit out the remaining hands.
+    pub in_hand: bool,
+    pub folded: bool,
+    pub acted: bool,
+}
+
+impl PokerSeat {
+    fn new(id: String, stack: i64) -> Self {
+        Self {
+            id,
+            stack,
+            hole_cards: Vec::new(),
+            bet: 0,
+            contributed: 0,
+            in_hand: false,
+            folded: false,
+            acted: false,
+        }
+    }
+
+    /// Still competing for the pot.
+    pub fn is_live(&self) -> bool {
+        self.in_hand && !self.folded
+    }
+
+    pub fn is_all_in(&self) -> bool {
+        self.is_live() && self.stack == 0
+    }
+
+    pub fn can_act(&self) -> bool {
+        self.is_live() && self.stack > 0
+    }
+
+    /// Puts chips into the pot, as many as the player has left at most.
+    fn put_in(&mut self, amount: i64) {
+        let amount = amount.min(self.stack);
+        self.stack -= amount;
+        self.bet += amount;
+        self.contributed += amount;
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PokerPotResult {
+    pub amount: i64,
+    pub winners: Vec<String>,
+}
+
+/// How a hand ended, kept around so that it can be shown while the next hand is played.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PokerHandResult {
+    pub number: u32,
+    pub community: Vec<PlayingCard>,
+    /// The hands shown at the showdown, empty if everyone else folded.
+    pub shown: Vec<(String, Vec<PlayingCard>, PokerHandRank)>,
+    pub pots: Vec<PokerPotResult>,
+}
+
+/// A Texas Hold'em table played for a number of hands, every player buys in for the same amount
+/// of chips and leaves with what is left in front of them.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PokerGame {
+    pub id: Uuid,
+    pub seats: Vec<PokerSeat>,
+    pub buy_in: i64,
+    pub small_blind: i64,
+    pub big_blind: i64,
+    /// The table closes after this many hands, or once a single player has all the chips.
+    pub max_hands: u32,
+    pub hand_number: u32,
+    pub dealer: usize,
+    pub street: PokerStreet,
+    pub community: Vec<PlayingCard>,
+    deck: PlayingCardDeck,
+    pub to_act: Option<usize>,
+    /// The highest bet of the current betting round.
+    pub current_bet: i64,
+    /// How much a raise has to add to the current bet at least, the size of the last raise.
+    pub min_raise: i64,
+    pub last_hand: Option<PokerHandResult>,
+    pub finished: bool,
+    /// Shuffles the deck of every hand.
+    rng: GameRng,
+}
+
+impl PokerGame {
+    pub fn new(players: &[String], buy_in: i64, big_blind: i64, max_hands: u32) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            seats: players
+                .iter()
+                .map(|id| PokerSeat::new(id.clone(), buy_in))
+                .collect(),
+            buy_in,
+            small_blind: (big_blind / 2).max(1),
+            big_blind,
+            max_hands,
+            hand_number: 0,
+            // The button moves before every hand, so that the first player deals first.
+            dealer: players.len().saturating_sub(1),
+            street: PokerStreet::PreFlop,
+            community: Vec::new(),
+            deck: PlayingCardDeck::new(),
+            to_act: None,
+            current_bet: 0,
+            min_raise: big_blind,
+            last_hand: None,
+            finished: false,
+            rng: GameRng::new(),
+        }
+    }
+
+    /// Shuffles every hand with the given seed, which together with the actions taken
+    /// reproduces the table exactly.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = GameRng::seeded(seed);
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    pub fn seat(&self, id: &str) -> Option<&PokerSeat> {
+        self.seats.iter().find(|seat| seat.id == id)
+    }
+
+    pub fn seat_to_act(&self) -> Option<&PokerSeat> {
+        self.to_act.map(|index| &self.seats[index])
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.finished
+    }
+
+    /// All chips put in during the current hand.
+    pub fn pot(&self) -> i64 {
+        self.seats.iter().map(|seat| seat.contributed).sum()
+    }
+
+    pub fn to_call(&self, seat: &PokerSeat) -> i64 {
+        (self.current_bet - seat.bet).clamp(0, seat.stack)
+    }
+
+    pub fn min_raise_to(&self) -> i64 {
+        self.current_bet + self.min_raise
+    }
+
+    pub fn start_hand(&mut self) {
+        let deck = PlayingCardDeck::new_shuffled(&mut self.rng);
+        self.deal_hand(deck);
+    }
+
+    /// Moves the button, posts the blinds and deals the hole cards from the given deck.
+    pub fn deal_hand(&mut self, deck: PlayingCardDeck) {
+        self.deck = deck;
+        self.hand_number += 1;
+        self.community.clear();
+        self.street = PokerStreet::PreFlop;
+        for seat in &mut self.seats {
+            seat.in_hand = seat.stack > 0;
+            seat.hole_cards.clear();
+            seat.bet = 0;
+            seat.contributed = 0;
+            seat.folded = false;
+            seat.acted = false;
+        }
+
+        let players = self.seats.iter().filter(|seat| seat.in_hand).count();
+        if players < MIN_PLAYERS {
+            self.finished = true;
+            self.to_act = None;
+            return;
+        }
+
+        self.dealer = self.next_seat(self.dealer, |seat| seat.in_hand);
+        // Heads-up, the dealer posts the small blind and acts first before the flop.
+        let small_blind = if players == 2 {
+            self.dealer
+        } else {
+            self.next_seat(self.dealer, |seat| seat.in_hand)
+        };
+        let big_blind = self.next_seat(small_blind, |seat| seat.in_hand);
+        self.seats[small_blind].put_in(self.small_blind);
+        self.seats[big_blind].put_in(self.big_blind);
+        self.current_bet = self.big_blind;
+        self.min_raise = self.big_blind;
+
+        for _ in 0..2 {
+            let mut index = self.dealer;
+            for _ in 0..players {
+                index = self.next_seat(index, |seat| seat.in_hand);
+                if let Some(card) = self.deck.draw_top() {
+                    self.seats[index].hole_cards.push(card);
+                }
+            }
+        }
+
+        self.after_action(big_blind);
+    }
+
+    pub fn play(&mut self, player: &str, action: PokerAction) -> bool {
+        let Some(index) = self.to_act.filter(|_| !self.finished) else {
+            return false;
+        };
+        if self.seats[index].id != player {
+            return false;
+        }
+
+        let to_call = self.current_bet - self.seats[index].bet;
+        match action {
+            PokerAction::Fold => self.seats[index].folded = true,
+            PokerAction::Check if to_call > 0 => return false,
+            PokerAction::Check => {}
+            PokerAction::Call if to_call <= 0 => return false,
+            PokerAction::Call => self.seats[index].put_in(to_call),
+            PokerAction::Raise(amount) => {
+                if !self.raise_to(index, amount) {
+                    return false;
+                }
+            }
+            PokerAction::AllIn => {
+                let (bet, stack) = (self.seats[index].bet, self.seats[index].stack);
+                if bet + stack > self.current_bet {
+                    self.raise_to(index, bet + stack);
+                } else {
+                    self.seats[index].put_in(stack);
+                }
+            }
+        }
+
+        self.seats[index].acted = true;
+        self.after_action(index);
+        true
+    }
+
+    /// Checks if possible and folds otherwise.
+    pub fn time_out(&mut self, player: &str) {
+        let Some(seat) = self.seat_to_act().filter(|seat| seat.id == player) else {
+            return;
+        };
+
+        if self.to_call(seat) == 0 {
+            self.play(player, PokerAction::Check);
+        } else {
+            self.play(player, PokerAction::Fold);
+        }
+    }
+
+    /// Only an all-in may raise by less than the last raise, which does not reopen the betting
+    /// for players who already acted.
+    fn raise_to(&mut self, index: usize, amount: i64) -> bool {
+        let (bet, stack) = (self.seats[index].bet, self.seats[index].stack);
+        let raise = amount - self.current_bet;
+        if raise <= 0 || amount > bet + stack || (raise < self.min_raise && amount < bet + stack) {
+            return false;
+        }
+
+        self.seats[index].put_in(amount - bet);
+        self.current_bet = amount;
+        if raise >= self.min_raise {
+            self.min_raise = raise;
+            for seat in &mut self.seats {
+                seat.acted = false;
+            }
+        }
+        true
+    }
+
+    fn needs_action(&self, seat: &PokerSeat) -> bool {
+        seat.can_act() && (!seat.acted || seat.bet < self.current_bet)
+    }
+
+    fn after_action(&mut self, index: usize) {
+        if self.seats.iter().filter(|seat| seat.is_live()).count() == 1 {
+            self.finish_hand();
+            return;
+        }
+
+        let next = self.next_seat(index, |seat| self.needs_action(seat));
+        if self.needs_action(&self.seats[next]) {
+            self.to_act = Some(next);
+        } else {
+            self.next_street();
+        }
+    }
+
+    /// Deals the next community cards, straight to the showdown if fewer than two players can
+    /// still bet.
+    fn next_street(&mut self) {
+        loop {
+            for seat in &mut self.seats {
+                seat.bet = 0;
+                seat.acted = false;
+            }
+            self.current_bet = 0;
+            self.min_raise = self.big_blind;
+
+            let (street, cards) = match self.street {
+                PokerStreet::PreFlop => (PokerStreet::Flop, 3),
+                PokerStreet::Flop => (PokerStreet::Turn, 1),
+                PokerStreet::Turn => (PokerStreet::River, 1),
+                PokerStreet::River => {
+                    self.finish_hand();
+                    return;
+                }
+            };
+            self.street = street;
+            self.community
+                .extend((0..cards).filter_map(|_| self.deck.draw_top()));
+
+            if self.seats.iter().filter(|seat| seat.can_act()).count() >= 2 {
+                self.to_act = Some(self.next_seat(self.dealer, PokerSeat::can_act));
+                return;
+            }
+        }
+    }
+
+    /// Awards the pots, then deals the next hand unless the table closes.
+    fn finish_hand(&mut self) {
+        self.to_act = None;
+
+        let live = self
+            .seats
+            .iter()
+            .enumerate()
+            .filter(|(_, seat)| seat.is_live())
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        let showdown = live.len() > 1;
+        if showdown {
+            while self.community.len() < 5 {
+                let Some(card) = self.deck.draw_top() else {
+                    break;
+                };
+                self.community.push(card);
+            }
+        }
+
+        let ranks = self
+            .seats
+            .iter()
+            .map(|seat| {
+                let cards = [seat.hole_cards.as_slice(), self.community.as_slice()].concat();
+                (showdown && seat.is_live()).then(|| evaluate(&cards))
+            })
+            .collect::<Vec<_>>();
+
+        let contributions = self
+            .seats
+            .iter()
+            .map(|seat| (seat.contributed, !seat.is_live()))
+            .collect::<Vec<_>>();
+        let mut pots = Vec::new();
+        for pot in build_pots(&contributions) {
+            let best = pot.eligible.iter().filter_map(|&index| ranks[index]).max();
+            let winners = self
+                .seat_order()
+                .into_iter()
+                .filter(|index| pot.eligible.contains(index) && ranks[*index] == best)
+                .collect::<Vec<_>>();
+
+            // Chips that cannot be split go to the first winners after the button.
+            let share = pot.amount / winners.len() as i64;
+            let remainder = pot.amount % winners.len() as i64;
+            for (position, &index) in winners.iter().enumerate() {
+                let extra = if (position as i64) < remainder { 1 } else { 0 };
+                self.seats[index].stack += share + extra;
+            }
+
+            pots.push(PokerPotResult {
+                amount: pot.amount,
+                winners: winners
+                    .iter()
+                    .map(|&index| self.seats[index].id.clone())
+                    .collect(),
+            });
+        }
+
+        for seat in &mut self.seats {
+            seat.bet = 0;
+            seat.contributed = 0;
+        }
+
+        let shown = live
+            .iter()
+            .filter_map(|&index| {
+                let seat = &self.seats[index];
+                ranks[index].map(|rank| (seat.id.clone(), seat.hole_cards.clone(), rank))
+            })
+            .collect();
+        self.last_hand = Some(PokerHandResult {
+            number: self.hand_number,
+            community: self.community.clone(),
+            shown,
+            pots,
+        });
+
+        let with_chips = self.seats.iter().filter(|seat| seat.stack > 0).count();
+        if self.hand_number >= self.max_hands || with_chips < MIN_PLAYERS {
+            self.finished = true;
+        } else {
+            self.start_hand();
+        }
+    }
+
+    /// The seats in the order they act after the flop, starting left of the button.
+    fn seat_order(&self) -> Vec<usize> {
+        (1..=self.seats.len())
+            .map(|offset| (self.dealer + offset) % self.seats.len())
+            .collect()
+    }
+
+    /// The first seat after the given one that matches, the given seat itself if none does.
+    fn next_seat(&self, from: usize, matches: impl Fn(&PokerSeat) -> bool) -> usize {
+        (1..=self.seats.len())
+            .map(|offset| (from + offset) % self.seats.len())
+            .find(|&index| matches(&self.seats[index]))
+            .unwrap_or(from)
+    }
+}
+
+impl TurnBasedGame for PokerGame {
+    type Move = PokerAction;
+
+    fn players(&self) -> Vec<String> {
+        self.seats.iter().map(|seat| seat.id.clone()).collect()
+    }
+
+    fn current_player(&self) -> Option<String> {
+        self.seat_to_act()
+            .filter(|_| !self.finished)
+            .map(|seat| seat.id.clone())
+    }
+
+    fn play(&mut self, player: &str, move_: PokerAction) -> bool {
+        PokerGame::play(self, player, move_)
+    }
+
+    fn time_out(&mut self, player: &str) {
+        PokerGame::time_out(self, player)
+    }
+
+    fn is_over(&self) -> bool {
+        self.finished
+    }
+
+    fn outcomes(&self) -> Vec<(String, TurnOutcome)> {
+        if !self.finished {
+            return Vec::new();
+        }
+
+        self.seats
+            .iter()
+            .map(|seat| {
+                let outcome = match seat.stack.cmp(&self.buy_in) {
+                    std::cmp::Ordering::Greater => TurnOutcome::Win,
+                    std::cmp::Ordering::Less => TurnOutcome::Loss,
+                    std::cmp::Ordering::Equal => TurnOutcome::Draw,
+                };
+                (seat.id.clone(), outcome)
+            })
+            .collect()
+    }
+
+    /// Everyone leaves with the chips in front of them, the stake is the buy-in.
+    fn settlement(&self, _stake: i64) -> Vec<(String, i64)> {
+        self.seats
+            .iter()
+            .map(|seat| (seat.id.clone(), seat.stack - self.buy_in))
+            .collect()
+    }
+
+    /// The hand in progress is called off, everyone leaves with the chips in front of them and
+    /// what they put into the hand.
+    fn abandoned_settlement(&self, _stake: i64) -> Vec<(String, i64)> {
+        self.seats
+            .iter()
+            .map(|seat| {
+                let chips = seat.stack + seat.contributed;
+                (seat.id.clone(), chips - self.buy_in)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses cards like `As Td 2c`.
+    fn cards(text: &str) -> Vec<PlayingCard> {
+        text.split_whitespace()
+            .map(|card| {
+                let mut chars = card.chars();
+                let rank = "23456789TJQKA".find(chars.next().unwrap()).unwrap() as u8;
+                let suit = "shcd".find(chars.next().unwrap()).unwrap() as u8;
+                PlayingCard::try_from(suit * 13 + rank).unwrap()
+            })
+            .collect()
+    }
+
+    /// A deck that deals the given cards first, one card per player at a time starting left of
+    /// the button, then the board.
+    fn deck(text: &str) -> PlayingCardDeck {
+        let mut deck = PlayingCardDeck::new();
+        deck.add_multiple_top(&cards(text));
+        deck
+    }
+
+    fn table(players: usize, max_hands: u32) -> PokerGame {
+        let players = (0..players).map(|id| id.to_string()).collect::<Vec<_>>();
+        PokerGame::new(&players, 100, 2, max_hands)
+    }
+
+    fn chips(game: &PokerGame) -> Vec<i64> {
+        game.seats
+            .iter()
+            .map(|seat| seat.stack + seat.contributed)
+            .collect()
+    }
+
+    #[test]
+    fn test_heads_up_order() {
+        let mut game = table(2, 1);
+        game.deal_hand(deck("2c 3c 4c 5c 7s 8s 9s Jd Qd"));
+        assert_eq!((game.seats[0].bet, game.seats[1].bet), (1, 2));
+        assert_eq!(game.to_act, Some(0));
+
+        assert!(!game.play("0", PokerAction::Check));
+        assert!(!game.play("1", PokerAction::Check));
+        assert!(game.play("0", PokerAction::Call));
+        assert!(game.play("1", PokerAction::Check));
+
+        assert_eq!(game.street, PokerStreet::Flop);
+        assert_eq!(game.community.len(), 3);
+        assert_eq!(game.to_act, Some(1));
+    }
+
+    #[test]
+    fn test_raises() {
+        let mut game = table(3, 1);
+        game.deal_hand(PlayingCardDeck::new());
+        assert_eq!(game.to_act, Some(0));
+
+        assert!(!game.play("0", PokerAction::Raise(3)));
+        assert!(game.play("0", PokerAction::Raise(6)));
+        assert_eq!(game.min_raise_to(), 10);
+        assert!(game.play("1", PokerAction::Raise(20)));
+        assert!(game.play("2", PokerAction::Fold));
+        assert!(game.play("0", PokerAction::Call));
+
+        assert_eq!(game.street, PokerStreet::Flop);
+        assert_eq!(game.pot(), 42);
+        assert_eq!(game.to_act, Some(1));
+    }
+
+    #[test]
+    fn test_everyone_folds() {
+        let mut game = table(3, 1);
+        game.deal_hand(PlayingCardDeck::new());
+        game.play("0", PokerAction::Fold);
+        game.play("1", PokerAction::Fold);
+
+        assert!(game.is_over());
+        let result = game.last_hand.as_ref().unwrap();
+        assert!(result.shown.is_empty());
+        assert_eq!(result.pots[0].winners, vec!["2"]);
+        assert_eq!(chips(&game), vec![100, 99, 101]);
+        assert_eq!(
+            game.settlement(100),
+            vec![
+                ("0".to_string(), 0),
+                ("1".to_string(), -1),
+                ("2".to_string(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_abandoned_table_calls_off_the_hand() {
+        let mut game = table(3, 2);
+        game.deal_hand(PlayingCardDeck::new());
+        game.play("0", PokerAction::Fold);
+        game.play("1", PokerAction::Fold);
+        assert!(!game.is_over());
+
+        // The second hand is dealt right away and called off after the first raise.
+        assert_eq!(game.hand_number, 2);
+        let raiser = game.seat_to_act().unwrap().id.clone();
+        assert!(game.play(&raiser, PokerAction::Raise(6)));
+        assert!(game.pot() > 0);
+
+        let settlement = game.abandoned_settlement(100);
+        assert_eq!(settlement.iter().map(|(_, amount)| amount).sum::<i64>(), 0);
+        assert_eq!(
+            settlement,
+            vec![
+                ("0".to_string(), 0),
+                ("1".to_string(), -1),
+                ("2".to_string(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_side_pots() {
+        let mut game = table(3, 1);
+        game.seats[0].stack = 20;
+        game.seats[1].stack = 50;
+        // Dealt to the blinds first: kings, seven-deuce, then aces to the button.
+        game.deal_hand(deck("Ks 2c As Kh 7d Ah 3s 8d 9c Jh 4d"));
+
+        assert!(game.play("0", PokerAction::AllIn));
+        assert!(game.play("1", PokerAction::AllIn));
+        assert!(game.play("2", PokerAction::Call));
+
+        assert!(game.is_over());
+        assert_eq!(game.community.len(), 5);
+        assert_eq!(
+            game.seats.iter().map(|seat| seat.stack).collect::<Vec<_>>(),
+            vec![60, 60, 50]
+        );
+
+        let result = game.last_hand.as_ref().unwrap();
+        assert_eq!(result.shown.len(), 3);
+        let pots = result
+            .pots
+            .iter()
+            .map(|pot| (pot.amount, pot.winners.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pots,
+            vec![(60, vec!["0".to_string()]), (60, vec!["1".to_string()])]
+        );
+    }
+
+    #[test]
+    fn test_split_pot() {
+        let mut game = table(2, 1);
+        // Both play the straight on the board.
+        game.deal_hand(deck("2c 2d 3h 3s 8s 9d Tc Jh Qd"));
+        game.play("0", PokerAction::Call);
+        while let Some(seat) = game.seat_to_act() {
+            let id = seat.id.clone();
+            game.play(&id, PokerAction::Check);
+        }
+
+        assert_eq!(game.last_hand.as_ref().unwrap().pots[0].winners.len(), 2);
+        assert_eq!(chips(&game), vec![100, 100]);
+    }
+
+    #[test]
+    fn test_seed_replays_table() {
+        let play = |seed| {
+            let mut game = table(4, 5).with_seed(seed);
+            game.start_hand();
+            let mut boards = Vec::new();
+            while let Some(seat) = game.seat_to_act() {
+                let id = seat.id.clone();
+                let action = match game.to_call(seat) {
+                    0 => PokerAction::Check,
+                    _ => PokerAction::Call,
+                };
+                assert!(game.play(&id, action));
+                match &game.last_hand {
+                    Some(result) if result.number as usize > boards.len() => {
+                        boards.push(result.community.clone())
+                    }
+                    _ => {}
+                }
+            }
+            (boards, chips(&game))
+        };
+
+        assert_eq!(play(40), play(40));
+        assert_ne!(play(40).0, play(41).0);
+    }
+
+    #[test]
+    fn test_next_hand_moves_button() {
+        let mut game = table(3, 5);
+        game.deal_hand(PlayingCardDeck::new());
+        game.play("0", PokerAction::Fold);
+        game.play("1", PokerAction::Fold);
+
+        assert!(!game.is_over());
+        assert_eq!(game.hand_number, 2);
+        assert_eq!(game.dealer, 1);
+        assert_eq!(game.seat_to_act().map(|seat| seat.id.as_str()), Some("1"));
+        assert_eq!(chips(&game).iter().sum::<i64>(), 300);
+    }
+
+    #[test]
+    fn test_time_out_folds_facing_a_bet() {
+        let mut game = table(2, 1);
+        game.deal_hand(PlayingCardDeck::new());
+        game.time_out("1");
+        assert_eq!(game.to_act, Some(0));
+
+        game.time_out("0");
+        assert!(game.is_over());
+        assert_eq!(game.outcomes()[1], ("1".to_string(), TurnOutcome::Win));
+    }
+}
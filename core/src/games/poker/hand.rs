@@ -0,0 +1,289 @@
+use crate::games::playing_cards::PlayingCard;
+
+const ACE: u8 = 12;
+/// Ace, two, three, four and five, the lowest straight.
+const WHEEL: u16 = 0b1_0000_0000_1111;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum PokerHandCategory {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+}
+
+impl PokerHandCategory {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::HighCard => "High Card",
+            Self::OnePair => "One Pair",
+            Self::TwoPair => "Two Pair",
+            Self::ThreeOfAKind => "Three of a Kind",
+            Self::Straight => "Straight",
+            Self::Flush => "Flush",
+            Self::FullHouse => "Full House",
+            Self::FourOfAKind => "Four of a Kind",
+            Self::StraightFlush => "Straight Flush",
+        }
+    }
+}
+
+/// The value of the best five cards of a hand. Hands compare by their category first and then
+/// by the ranks that decide between hands of the same category.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct PokerHandRank {
+    pub category: PokerHandCategory,
+    /// Ranks from 0 for a two up to 12 for an ace in the order they are compared, e.g. the
+    /// rank of the pair followed by the kickers. Unused ranks are 0.
+    pub ranks: [u8; 5],
+}
+
+impl PokerHandRank {
+    fn new(category: PokerHandCategory, ranks: &[u8]) -> Self {
+        let mut padded = [0; 5];
+        padded[..ranks.len()].copy_from_slice(ranks);
+        Self {
+            category,
+            ranks: padded,
+        }
+    }
+}
+
+/// Ranks the best five card hand out of five to seven cards.
+pub fn evaluate(cards: &[PlayingCard]) -> PokerHandRank {
+    let mut counts = [0u8; 13];
+    let mut suits = [0u16; 4];
+    for card in cards {
+        counts[card.rank() as usize] += 1;
+        suits[card.suit() as usize] |= 1 << card.rank();
+    }
+
+    // Out of seven cards, five of a suit leave too few cards for four of a kind or a full house,
+    // so a flush is the best hand unless it is a straight flush.
+    if let Some(&flush) = suits.iter().find(|mask| mask.count_ones() >= 5) {
+        return match straight_high(flush) {
+            Some(high) => PokerHandRank::new(PokerHandCategory::StraightFlush, &[high]),
+            None => PokerHandRank::new(PokerHandCategory::Flush, &top_ranks(flush, 5)),
+        };
+    }
+
+    let ranks = suits.iter().fold(0, |ranks, mask| ranks | mask);
+    let [(count_1, rank_1), (count_2, rank_2)] = largest_groups(&counts);
+    let without = |excluded: &[u8]| {
+        excluded
+            .iter()
+            .fold(ranks, |ranks, rank| ranks & !(1 << rank))
+    };
+
+    if count_1 == 4 {
+        let kicker = top_ranks(without(&[rank_1]), 1);
+        return PokerHandRank::new(PokerHandCategory::FourOfAKind, &[rank_1, kicker[0]]);
+    }
+    if count_1 == 3 && count_2 >= 2 {
+        return PokerHandRank::new(PokerHandCategory::FullHouse, &[rank_1, rank_2]);
+    }
+    if let Some(high) = straight_high(ranks) {
+        return PokerHandRank::new(PokerHandCategory::Straight, &[high]);
+    }
+
+    // The remaining cards are filled up with the highest kickers, which also covers the rank of
+    // a third pair.
+    let (category, decisive, kickers) = match (count_1, count_2) {
+        (3, _) => (PokerHandCategory::ThreeOfAKind, vec![rank_1], 2),
+        (2, 2) => (PokerHandCategory::TwoPair, vec![rank_1, rank_2], 1),
+        (2, _) => (PokerHandCategory::OnePair, vec![rank_1], 3),
+        _ => (PokerHandCategory::HighCard, vec![], 5),
+    };
+    let ranks = [decisive.clone(), top_ranks(without(&decisive), kickers)].concat();
+    PokerHandRank::new(category, &ranks)
+}
+
+/// The two largest groups of cards of the same rank as count and rank, larger and then higher
+/// groups first.
+fn largest_groups(counts: &[u8; 13]) -> [(u8, u8); 2] {
+    let mut groups = [(0, 0); 2];
+    for rank in (0..13u8).rev() {
+        let group = (counts[rank as usize], rank);
+        if group.0 > groups[0].0 {
+            groups = [group, groups[0]];
+        } else if group.0 > groups[1].0 {
+            groups[1] = group;
+        }
+    }
+    groups
+}
+
+/// The highest rank of a straight within the ranks, five for the wheel.
+fn straight_high(ranks: u16) -> Option<u8> {
+    (4..=ACE)
+        .rev()
+        .find(|high| {
+            let straight = 0b1_1111 << (high - 4);
+            ranks & straight == straight
+        })
+        .or((ranks & WHEEL == WHEEL).then_some(3))
+}
+
+fn top_ranks(ranks: u16, count: usize) -> Vec<u8> {
+    (0..13u8)
+        .rev()
+        .filter(|rank| ranks & (1 << rank) != 0)
+        .take(count)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::SliceRandom;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::HashSet;
+    use strum::IntoEnumIterator;
+
+    /// Parses cards like `As Td 2c`.
+    fn cards(text: &str) -> Vec<PlayingCard> {
+        text.split_whitespace()
+            .map(|card| {
+                let mut chars = card.chars();
+                let rank = "23456789TJQKA".find(chars.next().unwrap()).unwrap() as u8;
+                let suit = "shcd".find(chars.next().unwrap()).unwrap() as u8;
+                PlayingCard::try_from(suit * 13 + rank).unwrap()
+            })
+            .collect()
+    }
+
+    fn rank(text: &str) -> PokerHandRank {
+        evaluate(&cards(text))
+    }
+
+    /// Calls the closure with every combination of the given size out of the deck.
+    fn for_each_combination(size: usize, mut f: impl FnMut(&[PlayingCard])) {
+        let deck = PlayingCard::iter().collect::<Vec<_>>();
+        let mut indices = (0..size).collect::<Vec<_>>();
+        let mut hand = vec![deck[0]; size];
+
+        loop {
+            for (card, index) in hand.iter_mut().zip(&indices) {
+                *card = deck[*index];
+            }
+            f(&hand);
+
+            let Some(position) = (0..size)
+                .rev()
+                .find(|&i| indices[i] < deck.len() - size + i)
+            else {
+                return;
+            };
+            indices[position] += 1;
+            for i in position + 1..size {
+                indices[i] = indices[i - 1] + 1;
+            }
+        }
+    }
+
+    fn category_counts(size: usize) -> ([u64; 9], HashSet<PokerHandRank>) {
+        let mut counts = [0; 9];
+        let mut distinct = HashSet::new();
+        for_each_combination(size, |hand| {
+            let rank = evaluate(hand);
+            counts[rank.category as usize] += 1;
+            if size == 5 {
+                distinct.insert(rank);
+            }
+        });
+        (counts, distinct)
+    }
+
+    #[test]
+    fn test_every_five_card_hand() {
+        let (counts, distinct) = category_counts(5);
+        assert_eq!(
+            counts,
+            [1_302_540, 1_098_240, 123_552, 54_912, 10_200, 5_108, 3_744, 624, 40]
+        );
+        // Every distinct value a five card hand can have.
+        assert_eq!(distinct.len(), 7_462);
+    }
+
+    #[test]
+    #[ignore = "evaluates all 133 million hands, run with --release"]
+    fn test_every_seven_card_hand() {
+        let (counts, _) = category_counts(7);
+        assert_eq!(
+            counts,
+            [
+                23_294_460, 58_627_800, 31_433_400, 6_461_620, 6_180_020, 4_047_644, 3_473_184,
+                224_848, 41_584
+            ]
+        );
+    }
+
+    #[test]
+    fn test_seven_cards_rank_as_best_five() {
+        let mut rng = ChaCha8Rng::seed_from_u64(40);
+        let mut deck = PlayingCard::iter().collect::<Vec<_>>();
+
+        for _ in 0..20_000 {
+            deck.shuffle(&mut rng);
+            let hand = &deck[..7];
+
+            let mut best = None;
+            for skip_1 in 0..7 {
+                for skip_2 in skip_1 + 1..7 {
+                    let five = (0..7)
+                        .filter(|&i| i != skip_1 && i != skip_2)
+                        .map(|i| hand[i])
+                        .collect::<Vec<_>>();
+                    best = best.max(Some(evaluate(&five)));
+                }
+            }
+
+            assert_eq!(Some(evaluate(hand)), best, "{hand:?}");
+        }
+    }
+
+    #[test]
+    fn test_straights() {
+        assert_eq!(rank("As 2d 3c 4h 5s").category, PokerHandCategory::Straight);
+        assert!(rank("As 2d 3c 4h 5s") < rank("2d 3c 4h 5s 6s"));
+        assert!(rank("Ts Jd Qc Kh As") > rank("9s Ts Jd Qc Kh"));
+        assert_eq!(rank("Qs Kd Ac 2h 3s").category, PokerHandCategory::HighCard);
+
+        let steel_wheel = rank("Ah 2h 3h 4h 5h Kh 9c");
+        assert_eq!(steel_wheel.category, PokerHandCategory::StraightFlush);
+        assert!(steel_wheel < rank("2h 3h 4h 5h 6h"));
+    }
+
+    #[test]
+    fn test_best_five_of_seven() {
+        // Two three of a kinds make a full house of the higher one.
+        assert_eq!(rank("Ks Kd Kc 5h 5s 5d 2c"), rank("Ks Kd Kc 5h 5s"));
+        // A third pair only counts as a kicker.
+        assert_eq!(rank("Ks Kd 7c 7h 3s 3d Qc"), rank("Ks Kd 7c 7h Qc"));
+        assert_eq!(rank("Ks Kd 7c 7h Qs Qd 2c"), rank("Ks Kd Qs Qd 7c"));
+        // A flush beats the straight in the same hand.
+        assert_eq!(
+            rank("4h 5h 6h 7c 8h Kh 2s").category,
+            PokerHandCategory::Flush
+        );
+    }
+
+    #[test]
+    fn test_kickers_decide() {
+        assert!(rank("As Ad Kc 9h 4s") > rank("Ah Ac Qc Jh Ts"));
+        assert!(rank("Ks Kd 4c 4h As") > rank("Kh Kc 4s 4d Qs"));
+        assert_eq!(rank("Ks Kd 4c 4h As"), rank("Kh Kc 4s 4d Ac"));
+        assert!(rank("7s 7d 7c Ah 2s") > rank("7s 7d 7c Kh Qs"));
+        assert!(rank("Ah Jh 8h 5h 3h") > rank("Ad Jd 8d 5d 2d"));
+    }
+}
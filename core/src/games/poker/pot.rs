@@ -0,0 +1,99 @@
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PokerPot {
+    pub amount: i64,
+    /// The seats that can win the pot, those that are still in the hand and covered its level.
+    pub eligible: Vec<usize>,
+}
+
+/// Splits what every seat put into the hand into the main pot and the side pots, the main pot
+/// first. Takes the contribution of every seat and whether it folded, folded seats pay into the
+/// pots but cannot win them.
+pub fn build_pots(contributions: &[(i64, bool)]) -> Vec<PokerPot> {
+    let mut levels = contributions
+        .iter()
+        .filter(|(amount, folded)| !folded && *amount > 0)
+        .map(|(amount, _)| *amount)
+        .collect::<Vec<_>>();
+    levels.sort_unstable();
+    levels.dedup();
+
+    let mut pots = Vec::new();
+    let mut previous = 0;
+    for level in levels {
+        let amount = contributions
+            .iter()
+            .map(|(amount, _)| (*amount).min(level) - (*amount).min(previous))
+            .sum();
+        let eligible = contributions
+            .iter()
+            .enumerate()
+            .filter(|(_, (amount, folded))| !folded && *amount >= level)
+            .map(|(seat, _)| seat)
+            .collect();
+        pots.push(PokerPot { amount, eligible });
+        previous = level;
+    }
+
+    // Folded seats that put in more than anyone still in the hand lose the rest to the last pot.
+    let excess = contributions
+        .iter()
+        .map(|(amount, _)| (*amount - previous).max(0))
+        .sum::<i64>();
+    if let Some(last) = pots.last_mut() {
+        last.amount += excess;
+    }
+
+    pots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_pot() {
+        let pots = build_pots(&[(10, false), (10, false), (4, true)]);
+        assert_eq!(
+            pots,
+            vec![PokerPot {
+                amount: 24,
+                eligible: vec![0, 1]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_side_pots() {
+        let pots = build_pots(&[(20, false), (50, false), (100, false), (30, true)]);
+        assert_eq!(
+            pots,
+            vec![
+                PokerPot {
+                    amount: 80,
+                    eligible: vec![0, 1, 2]
+                },
+                PokerPot {
+                    amount: 70,
+                    eligible: vec![1, 2]
+                },
+                PokerPot {
+                    amount: 50,
+                    eligible: vec![2]
+                },
+            ]
+        );
+        assert_eq!(pots.iter().map(|pot| pot.amount).sum::<i64>(), 200);
+    }
+
+    #[test]
+    fn test_folded_excess_goes_to_last_pot() {
+        let pots = build_pots(&[(40, true), (25, false), (25, false)]);
+        assert_eq!(
+            pots,
+            vec![PokerPot {
+                amount: 90,
+                eligible: vec![1, 2]
+            }]
+        );
+    }
+}
@@ -0,0 +1,269 @@
+//! Player ratings across games.
+//!
+//! Ratings follow Glicko-2: besides the rating itself every player has a deviation, how sure the
+//! rating is, and a volatility, how consistently they play. New players start at 1500 with a high
+//! deviation, so their first games move their rating a lot, which settles as they play more.
+
+use crate::games::connect4::Connect4Game;
+use crate::games::persistence::PersistentGame;
+use crate::games::rps::state::RPSState;
+use crate::games::rps::RPSGame;
+use crate::types::rated_game_kind::RatedGameKind;
+use std::f64::consts::PI;
+
+pub mod chart;
+pub mod matchmaking;
+
+/// Converts between the Glicko scale shown to players and the Glicko-2 scale used internally.
+const SCALE: f64 = 173.7178;
+/// Constrains how much the volatility changes over time.
+const TAU: f64 = 0.5;
+const CONVERGENCE: f64 = 0.000001;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Glicko2Rating {
+    pub rating: f64,
+    pub deviation: f64,
+    pub volatility: f64,
+}
+
+impl Default for Glicko2Rating {
+    fn default() -> Self {
+        Self {
+            rating: 1500.0,
+            deviation: 350.0,
+            volatility: 0.06,
+        }
+    }
+}
+
+impl Glicko2Rating {
+    fn mu(&self) -> f64 {
+        (self.rating - 1500.0) / SCALE
+    }
+
+    fn phi(&self) -> f64 {
+        self.deviation / SCALE
+    }
+
+    /// The chance of winning against the given opponent, a draw counting as half a win.
+    pub fn expected_score(&self, opponent: &Self) -> f64 {
+        expected(self.mu(), opponent.mu(), opponent.phi())
+    }
+
+    /// The rating after a rating period in which the given games were played, each with the
+    /// opponent's rating before the period and the score of `1.0` for a win, `0.5` for a draw and
+    /// `0.0` for a loss.
+    pub fn update(&self, results: &[(Self, f64)]) -> Self {
+        let (mu, phi, sigma) = (self.mu(), self.phi(), self.volatility);
+        if results.is_empty() {
+            return Self {
+                deviation: (phi.powi(2) + sigma.powi(2)).sqrt() * SCALE,
+                ..*self
+            };
+        }
+
+        let mut variance_inv = 0.0;
+        let mut improvement = 0.0;
+        for (opponent, score) in results {
+            let g = g(opponent.phi());
+            let e = expected(mu, opponent.mu(), opponent.phi());
+            variance_inv += g.powi(2) * e * (1.0 - e);
+            improvement += g * (score - e);
+        }
+        let variance = 1.0 / variance_inv;
+        let delta = variance * improvement;
+
+        let sigma = new_volatility(phi, sigma, variance, delta);
+        let phi_star = (phi.powi(2) + sigma.powi(2)).sqrt();
+        let phi = 1.0 / (1.0 / phi_star.powi(2) + 1.0 / variance).sqrt();
+        let mu = mu + phi.powi(2) * improvement;
+
+        Self {
+            rating: mu * SCALE + 1500.0,
+            deviation: phi * SCALE,
+            volatility: sigma,
+        }
+    }
+}
+
+fn g(phi: f64) -> f64 {
+    1.0 / (1.0 + 3.0 * phi.powi(2) / PI.powi(2)).sqrt()
+}
+
+fn expected(mu: f64, opponent_mu: f64, opponent_phi: f64) -> f64 {
+    1.0 / (1.0 + (-g(opponent_phi) * (mu - opponent_mu)).exp())
+}
+
+/// Finds the new volatility with the Illinois algorithm as given in the Glicko-2 paper.
+fn new_volatility(phi: f64, sigma: f64, variance: f64, delta: f64) -> f64 {
+    let a = sigma.powi(2).ln();
+    let f = |x: f64| {
+        let ex = x.exp();
+        let denominator = phi.powi(2) + variance + ex;
+        ex * (delta.powi(2) - denominator) / (2.0 * denominator.powi(2)) - (x - a) / TAU.powi(2)
+    };
+
+    let mut upper = a;
+    let mut lower = if delta.powi(2) > phi.powi(2) + variance {
+        (delta.powi(2) - phi.powi(2) - variance).ln()
+    } else {
+        let mut k = 1.0;
+        while f(a - k * TAU) < 0.0 {
+            k += 1.0;
+        }
+        a - k * TAU
+    };
+
+    let (mut f_upper, mut f_lower) = (f(upper), f(lower));
+    while (lower - upper).abs() > CONVERGENCE {
+        let next = upper + (upper - lower) * f_upper / (f_lower - f_upper);
+        let f_next = f(next);
+        if f_next * f_lower <= 0.0 {
+            upper = lower;
+            f_upper = f_lower;
+        } else {
+            f_upper /= 2.0;
+        }
+        lower = next;
+        f_lower = f_next;
+    }
+
+    (upper / 2.0).exp()
+}
+
+/// The result of a finished game between two players, as far as their ratings are concerned.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RatedResult {
+    pub player_1: String,
+    pub player_2: String,
+    /// `1.0` if the first player won, `0.5` for a draw and `0.0` if the second player won.
+    pub score_1: f64,
+}
+
+/// A game whose results between two players change their ratings.
+pub trait RatedGame: PersistentGame {
+    const RATED_KIND: RatedGameKind;
+
+    /// The result of the game, `None` while it is running or if it is not rated, e.g. against
+    /// the bot.
+    fn rated_result(&self) -> Option<RatedResult>;
+}
+
+impl RatedGame for Connect4Game {
+    const RATED_KIND: RatedGameKind = RatedGameKind::Connect4;
+
+    fn rated_result(&self) -> Option<RatedResult> {
+        if self.ai_player.is_some() || !self.is_over() {
+            return None;
+        }
+
+        let score_1 = match self.winner_id() {
+            Some(winner) if winner == self.player_1 => 1.0,
+            Some(_) => 0.0,
+            None => 0.5,
+        };
+        Some(RatedResult {
+            player_1: self.player_1.clone(),
+            player_2: self.player_2.clone(),
+            score_1,
+        })
+    }
+}
+
+impl RatedGame for RPSGame {
+    const RATED_KIND: RatedGameKind = RatedGameKind::RockPaperScissors;
+
+    fn rated_result(&self) -> Option<RatedResult> {
+        if self.against_bot() {
+            return None;
+        }
+
+        let score_1 = match self.state() {
+            RPSState::Winner1 => 1.0,
+            RPSState::Winner2 => 0.0,
+            RPSState::Draw => 0.5,
+            RPSState::WaitingForBoth | RPSState::WaitingFor1 | RPSState::WaitingFor2 => {
+                return None
+            }
+        };
+        Some(RatedResult {
+            player_1: self.user_1.id.clone(),
+            player_2: self.user_2.id.clone(),
+            score_1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rating(rating: f64, deviation: f64) -> Glicko2Rating {
+        Glicko2Rating {
+            rating,
+            deviation,
+            volatility: 0.06,
+        }
+    }
+
+    #[test]
+    fn test_paper_example() {
+        // The worked example from Glickman's "Example of the Glicko-2 system".
+        let player = rating(1500.0, 200.0);
+        let updated = player.update(&[
+            (rating(1400.0, 30.0), 1.0),
+            (rating(1550.0, 100.0), 0.0),
+            (rating(1700.0, 300.0), 0.0),
+        ]);
+
+        assert!((updated.rating - 1464.06).abs() < 0.01);
+        assert!((updated.deviation - 151.52).abs() < 0.01);
+        assert!((updated.volatility - 0.05999).abs() < 0.00001);
+    }
+
+    #[test]
+    fn test_winner_gains_what_loser_loses() {
+        let player_1 = Glicko2Rating::default();
+        let player_2 = Glicko2Rating::default();
+
+        let winner = player_1.update(&[(player_2, 1.0)]);
+        let loser = player_2.update(&[(player_1, 0.0)]);
+        assert!(winner.rating > 1500.0);
+        assert!((winner.rating - 1500.0 + loser.rating - 1500.0).abs() < 0.001);
+        assert!(winner.deviation < player_1.deviation);
+    }
+
+    #[test]
+    fn test_draw_between_equals_keeps_rating() {
+        let player = rating(1600.0, 80.0);
+        let updated = player.update(&[(player, 0.5)]);
+        assert!((updated.rating - 1600.0).abs() < 0.001);
+        assert_eq!(player.expected_score(&player), 0.5);
+    }
+
+    #[test]
+    fn test_inactivity_grows_deviation() {
+        let player = rating(1600.0, 80.0);
+        let updated = player.update(&[]);
+        assert_eq!(updated.rating, 1600.0);
+        assert!(updated.deviation > 80.0);
+    }
+
+    #[test]
+    fn test_connect4_result() {
+        let mut game = Connect4Game::new("1", "2");
+        for col in [3, 3, 4, 4, 5, 5, 6] {
+            let player = game.player_to_play().to_string();
+            game.play(&player, col);
+        }
+
+        // The players are ordered by id, so "2" moves first and wins.
+        let result = game.rated_result().unwrap();
+        assert_eq!(result.player_1, "2");
+        assert_eq!(result.score_1, 1.0);
+
+        let against_ai = Connect4Game::new("1", "2").with_ai("2", Default::default());
+        assert_eq!(against_ai.rated_result(), None);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::error::CoreResult;
+use crate::rendering::o2d::prelude::{
+    O2DRenderable, O2DRenderer, Object2D, PositionO2D, TextVisual,
+};
+use image::Rgba;
+use std::io::Cursor;
+
+const TILE_SIZE: u8 = 4;
+const WIDTH: u8 = 60;
+const HEIGHT: u8 = 30;
+const PADDING: u8 = 2;
+const PLOT_TOP: u8 = 9;
+const PLOT_BOTTOM: u8 = HEIGHT - PADDING - 1;
+const CHAR_WIDTH: u32 = 6;
+const MAX_NAME_LENGTH: usize = 24;
+
+const BACKGROUND: Rgba<u8> = Rgba([35, 32, 45, 255]);
+const PLOT_BACKGROUND: Rgba<u8> = Rgba([44, 41, 56, 255]);
+const LINE: Rgba<u8> = Rgba([224, 180, 84, 255]);
+const AREA: Rgba<u8> = Rgba([88, 76, 68, 255]);
+const TEXT: Rgba<u8> = Rgba([217, 211, 217, 255]);
+const TEXT_MUTED: Rgba<u8> = Rgba([140, 134, 150, 255]);
+
+/// A player's rating in one game over their most recent games.
+pub struct RatingChart {
+    pub name: String,
+    pub game: String,
+    /// The ratings from oldest to newest.
+    pub ratings: Vec<f64>,
+}
+
+impl RatingChart {
+    pub fn render_png(&self, o2d: &O2DRenderer) -> CoreResult<Vec<u8>> {
+        let image = o2d.render(std::slice::from_ref(self), HEIGHT, WIDTH, TILE_SIZE)?;
+        let scaled = image::imageops::resize(
+            &image,
+            image.width() * 3,
+            image.height() * 3,
+            image::imageops::FilterType::Nearest,
+        );
+        drop(image);
+
+        let mut bytes = Cursor::new(Vec::new());
+        scaled.write_to(&mut bytes, image::ImageFormat::Png)?;
+
+        let opts = oxipng::Options::default();
+        let optimized = oxipng::optimize_from_memory(&bytes.into_inner(), &opts)?;
+
+        Ok(optimized)
+    }
+
+    fn left_text(text: impl Into<String>, color: Rgba<u8>, tile_y: u8) -> Object2D {
+        Object2D::text(
+            TextVisual::new(text, color),
+            PositionO2D::from_tile_xy(PADDING, tile_y),
+        )
+    }
+
+    fn right_text(text: impl Into<String>, color: Rgba<u8>, tile_y: u8) -> Object2D {
+        let text = text.into();
+        let text_width = text.chars().count() as u32 * CHAR_WIDTH;
+        let x = ((WIDTH - PADDING) as u32 * TILE_SIZE as u32).saturating_sub(text_width);
+        Object2D::text(
+            TextVisual::new(text, color),
+            PositionO2D::from_tile_xy((x / TILE_SIZE as u32) as u8, tile_y)
+                .with_offsets((x % TILE_SIZE as u32) as i8, 0),
+        )
+    }
+
+    /// The row of every column of the plot, stretching or sampling the ratings to fit its width.
+    fn plot_rows(&self, min: f64, max: f64) -> Vec<u8> {
+        let columns = (WIDTH - PADDING * 2) as usize;
+        let rows = (PLOT_BOTTOM - PLOT_TOP) as f64;
+        let range = (max - min).max(1.0);
+
+        (0..columns)
+            .map(|column| {
+                let index = column * self.ratings.len() / columns;
+                let fraction = (self.ratings[index] - min) / range;
+                PLOT_BOTTOM - (fraction * rows).round() as u8
+            })
+            .collect()
+    }
+}
+
+impl O2DRenderable for RatingChart {
+    fn to_objects(&self) -> Vec<Object2D> {
+        let mut objects = Vec::new();
+
+        for x in 0..WIDTH {
+            for y in 0..HEIGHT {
+                let in_plot = (PADDING..WIDTH - PADDING).contains(&x)
+                    && (PLOT_TOP..=PLOT_BOTTOM).contains(&y);
+                let color = if in_plot { PLOT_BACKGROUND } else { BACKGROUND };
+                objects.push(Object2D::color(color, PositionO2D::from_tile_xy(x, y)));
+            }
+        }
+
+        let name: String = self.name.chars().take(MAX_NAME_LENGTH).collect();
+        objects.push(Self::left_text(name, TEXT, 1));
+        objects.push(Self::left_text(self.game.to_uppercase(), TEXT_MUTED, 5));
+
+        let Some(current) = self.ratings.last() else {
+            objects.push(Self::right_text("NO GAMES", TEXT_MUTED, 1));
+            return objects;
+        };
+        objects.push(Self::right_text(format!("{current:.0}"), LINE, 1));
+
+        let min = self.ratings.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = self
+            .ratings
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        objects.push(Self::right_text(
+            format!("{min:.0}-{max:.0}"),
+            TEXT_MUTED,
+            5,
+        ));
+
+        for (column, row) in self.plot_rows(min, max).into_iter().enumerate() {
+            let x = PADDING + column as u8;
+            objects.push(Object2D::color(LINE, PositionO2D::from_tile_xy(x, row)));
+            for y in row + 1..=PLOT_BOTTOM {
+                objects.push(Object2D::color(AREA, PositionO2D::from_tile_xy(x, y)));
+            }
+        }
+
+        objects
+    }
+}
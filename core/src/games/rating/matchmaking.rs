@@ -0,0 +1,146 @@
+use crate::types::rated_game_kind::RatedGameKind;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// How far apart two ratings may be for a match right away.
+const BASE_WINDOW: f64 = 100.0;
+/// How much further apart the ratings may be for every second a player has been waiting.
+const WINDOW_PER_SECOND: f64 = 2.0;
+const MAX_WINDOW: f64 = 600.0;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueuedPlayer {
+    pub user_id: String,
+    pub rating: f64,
+    pub joined_at: DateTime<Utc>,
+}
+
+impl QueuedPlayer {
+    /// How far apart an opponent's rating may be, which widens the longer the player waits.
+    fn window(&self, now: DateTime<Utc>) -> f64 {
+        let waited = (now - self.joined_at).num_seconds().max(0) as f64;
+        (BASE_WINDOW + waited * WINDOW_PER_SECOND).min(MAX_WINDOW)
+    }
+}
+
+/// Players of a guild waiting for an opponent of a similar rating, per game.
+#[derive(Default)]
+pub struct MatchmakingQueue {
+    queues: Mutex<HashMap<(String, RatedGameKind), Vec<QueuedPlayer>>>,
+}
+
+impl MatchmakingQueue {
+    /// Pairs the player with the closest rated player waiting within their window, who leaves
+    /// the queue. Without one, the player is queued until matched or they leave.
+    pub fn join(
+        &self,
+        guild_id: impl Into<String>,
+        kind: RatedGameKind,
+        player: QueuedPlayer,
+        now: DateTime<Utc>,
+    ) -> Option<QueuedPlayer> {
+        let mut queues = self.queues.lock().unwrap();
+        let queue = queues.entry((guild_id.into(), kind)).or_default();
+        queue.retain(|queued| queued.user_id != player.user_id);
+
+        let opponent = queue
+            .iter()
+            .enumerate()
+            .map(|(index, queued)| (index, (queued.rating - player.rating).abs(), queued))
+            .filter(|(_, distance, queued)| *distance <= queued.window(now).max(player.window(now)))
+            .min_by(|(_, a, _), (_, b, _)| a.total_cmp(b))
+            .map(|(index, _, _)| index);
+
+        match opponent {
+            Some(index) => Some(queue.remove(index)),
+            None => {
+                queue.push(player);
+                None
+            }
+        }
+    }
+
+    /// Whether the player is still waiting, which is no longer the case once they were matched.
+    pub fn is_waiting(&self, guild_id: &str, kind: RatedGameKind, user_id: &str) -> bool {
+        self.queues
+            .lock()
+            .unwrap()
+            .get(&(guild_id.to_string(), kind))
+            .is_some_and(|queue| queue.iter().any(|queued| queued.user_id == user_id))
+    }
+
+    /// Removes the player from the queue, returns false if they were not waiting anymore.
+    pub fn leave(&self, guild_id: &str, kind: RatedGameKind, user_id: &str) -> bool {
+        let mut queues = self.queues.lock().unwrap();
+        let Some(queue) = queues.get_mut(&(guild_id.to_string(), kind)) else {
+            return false;
+        };
+
+        let before = queue.len();
+        queue.retain(|queued| queued.user_id != user_id);
+        before != queue.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn player(user_id: &str, rating: f64, joined_at: DateTime<Utc>) -> QueuedPlayer {
+        QueuedPlayer {
+            user_id: user_id.to_string(),
+            rating,
+            joined_at,
+        }
+    }
+
+    #[test]
+    fn test_pairs_closest_rating() {
+        let queue = MatchmakingQueue::default();
+        let now = Utc::now();
+        let kind = RatedGameKind::Connect4;
+
+        assert_eq!(queue.join("g", kind, player("1", 1500.0, now), now), None);
+        assert_eq!(queue.join("g", kind, player("2", 1650.0, now), now), None);
+        let opponent = queue.join("g", kind, player("3", 1600.0, now), now);
+        assert_eq!(opponent.unwrap().user_id, "2");
+        assert!(queue.is_waiting("g", kind, "1"));
+        assert!(!queue.is_waiting("g", kind, "2"));
+    }
+
+    #[test]
+    fn test_window_widens_while_waiting() {
+        let queue = MatchmakingQueue::default();
+        let now = Utc::now();
+        let kind = RatedGameKind::RockPaperScissors;
+
+        assert_eq!(queue.join("g", kind, player("1", 1200.0, now), now), None);
+        assert_eq!(queue.join("g", kind, player("2", 1450.0, now), now), None);
+
+        // After two minutes "2" accepts opponents 340 points away, "1" is still too far off.
+        let later = now + Duration::seconds(120);
+        let opponent = queue.join("g", kind, player("3", 1700.0, later), later);
+        assert_eq!(opponent.unwrap().user_id, "2");
+        assert!(queue.leave("g", kind, "1"));
+        assert!(!queue.leave("g", kind, "1"));
+    }
+
+    #[test]
+    fn test_queues_are_per_guild_and_game() {
+        let queue = MatchmakingQueue::default();
+        let now = Utc::now();
+
+        queue.join("g1", RatedGameKind::Connect4, player("1", 1500.0, now), now);
+        let other_guild = queue.join("g2", RatedGameKind::Connect4, player("2", 1500.0, now), now);
+        let other_game = queue.join(
+            "g1",
+            RatedGameKind::RockPaperScissors,
+            player("3", 1500.0, now),
+            now,
+        );
+        assert_eq!(other_guild, None);
+        assert_eq!(other_game, None);
+    }
+}
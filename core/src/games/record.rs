@@ -0,0 +1,367 @@
+//! Records of finished games.
+//!
+//! Games log every move as it is played. Once a game is over, its setup, seed and move log are
+//! stored as a record, from which any position of the game can be rebuilt by replaying the
+//! moves from the start.
+
+use crate::database::entity::user;
+use crate::games::blackjack::rules::BlackjackRules;
+use crate::games::blackjack::shoe::BlackjackShoe;
+use crate::games::blackjack::{BlackjackGame, BlackjackMove};
+use crate::games::connect4::difficulty::Connect4Difficulty;
+use crate::games::connect4::Connect4Game;
+use crate::games::persistence::PersistentGame;
+use crate::games::rps::choice::RPSChoice;
+use crate::games::rps::state::RPSState;
+use crate::games::rps::RPSGame;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveLog<M> {
+    pub started_at: DateTime<Utc>,
+    pub moves: Vec<LoggedMove<M>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoggedMove<M> {
+    pub player: String,
+    pub action: M,
+    /// Milliseconds since the game started.
+    pub at_ms: u64,
+}
+
+impl<M> MoveLog<M> {
+    pub fn new() -> Self {
+        Self {
+            started_at: Utc::now(),
+            moves: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, player: impl Into<String>, action: M) {
+        let elapsed = Utc::now() - self.started_at;
+        self.moves.push(LoggedMove {
+            player: player.into(),
+            action,
+            at_ms: elapsed.num_milliseconds().max(0) as u64,
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+}
+
+impl<M> Default for MoveLog<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A game which can be stored once it is over and replayed move by move.
+pub trait ReplayableGame: PersistentGame {
+    type Move: Clone + Serialize + DeserializeOwned;
+    /// Everything besides the seed and the moves that is needed to set the game up again.
+    type Setup: Serialize + DeserializeOwned;
+
+    fn setup(&self) -> Self::Setup;
+
+    fn seed(&self) -> Option<u64> {
+        None
+    }
+
+    fn players(&self) -> Vec<String>;
+
+    fn log(&self) -> &MoveLog<Self::Move>;
+
+    /// The result of every player, e.g. `Win` or `Loss, Push` for one per hand.
+    fn outcomes(&self) -> Vec<(String, String)>;
+
+    /// Sets the game up again and plays the given moves, which rebuilds the game as it was
+    /// after the last of them.
+    fn replay(setup: Self::Setup, seed: Option<u64>, moves: &[LoggedMove<Self::Move>]) -> Self;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlackjackSetup {
+    pub rules: BlackjackRules,
+    /// The players in their turn order.
+    pub players: Vec<String>,
+    pub wager: Option<u32>,
+    /// The shoe the game was dealt from, if it is the shoe of a table which outlives the game.
+    #[serde(default)]
+    pub shoe: Option<BlackjackShoe>,
+}
+
+impl ReplayableGame for BlackjackGame {
+    type Move = BlackjackMove;
+    type Setup = BlackjackSetup;
+
+    fn setup(&self) -> BlackjackSetup {
+        BlackjackSetup {
+            rules: self.rules,
+            players: self.turn_order.clone(),
+            wager: self.wager,
+            shoe: self.carried_shoe.clone(),
+        }
+    }
+
+    /// The seed of a table's shoe is never revealed, as it would tell every card the table deals
+    /// next. Such games are replayed from the shoe in their setup instead.
+    fn seed(&self) -> Option<u64> {
+        self.carried_shoe
+            .is_none()
+            .then(|| BlackjackGame::seed(self))
+    }
+
+    fn players(&self) -> Vec<String> {
+        self.turn_order.clone()
+    }
+
+    fn log(&self) -> &MoveLog<BlackjackMove> {
+        &self.log
+    }
+
+    fn outcomes(&self) -> Vec<(String, String)> {
+        self.get_outcomes()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(player, outcomes)| {
+                let outcomes = outcomes
+                    .iter()
+                    .map(|outcome| outcome.to_string())
+                    .collect::<Vec<_>>();
+                (player, outcomes.join(", "))
+            })
+            .collect()
+    }
+
+    fn replay(
+        setup: BlackjackSetup,
+        seed: Option<u64>,
+        moves: &[LoggedMove<BlackjackMove>],
+    ) -> Self {
+        let mut game = BlackjackGame::new().with_rules(setup.rules);
+        if let Some(shoe) = setup.shoe {
+            game = game.with_shoe(shoe);
+        } else if let Some(seed) = seed {
+            game = game.with_seed(seed);
+        }
+        game.wager = setup.wager;
+        for player in &setup.players {
+            game.register_player(player);
+        }
+
+        game.start_game();
+        for logged in moves {
+            game.play(&logged.player, logged.action);
+        }
+        game
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Connect4Setup {
+    pub player_1: String,
+    pub player_2: String,
+    pub ai_player: Option<String>,
+    pub difficulty: Connect4Difficulty,
+    pub wager: Option<u32>,
+}
+
+impl ReplayableGame for Connect4Game {
+    type Move = u8;
+    type Setup = Connect4Setup;
+
+    fn setup(&self) -> Connect4Setup {
+        Connect4Setup {
+            player_1: self.player_1.clone(),
+            player_2: self.player_2.clone(),
+            ai_player: self.ai_player.clone(),
+            difficulty: self.difficulty,
+            wager: self.wager,
+        }
+    }
+
+    fn players(&self) -> Vec<String> {
+        vec![self.player_1.clone(), self.player_2.clone()]
+    }
+
+    fn log(&self) -> &MoveLog<u8> {
+        &self.log
+    }
+
+    fn outcomes(&self) -> Vec<(String, String)> {
+        [&self.player_1, &self.player_2]
+            .into_iter()
+            .map(|player| {
+                let outcome = match self.winner_id() {
+                    Some(winner) if winner == player => "Win",
+                    Some(_) if self.forfeited_by.is_some() => "Forfeit",
+                    Some(_) => "Loss",
+                    None => "Draw",
+                };
+                (player.clone(), outcome.to_string())
+            })
+            .collect()
+    }
+
+    fn replay(setup: Connect4Setup, _seed: Option<u64>, moves: &[LoggedMove<u8>]) -> Self {
+        let mut game = Connect4Game::new(setup.player_1, setup.player_2);
+        if let Some(ai_player) = setup.ai_player {
+            game = game.with_ai(ai_player, setup.difficulty);
+        }
+        game.wager = setup.wager;
+
+        for logged in moves {
+            game.play(&logged.player, logged.action);
+        }
+        game
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RPSSetup {
+    pub user_1: user::Model,
+    pub user_2: user::Model,
+    pub best_of: u8,
+    pub sudden_death: bool,
+    pub wager: Option<u32>,
+}
+
+impl ReplayableGame for RPSGame {
+    type Move = RPSChoice;
+    type Setup = RPSSetup;
+
+    fn setup(&self) -> RPSSetup {
+        RPSSetup {
+            user_1: self.user_1.clone(),
+            user_2: self.user_2.clone(),
+            best_of: self.best_of,
+            sudden_death: self.sudden_death,
+            wager: self.wager,
+        }
+    }
+
+    fn seed(&self) -> Option<u64> {
+        self.against_bot().then(|| RPSGame::seed(self))
+    }
+
+    fn players(&self) -> Vec<String> {
+        vec![self.user_1.id.clone(), self.user_2.id.clone()]
+    }
+
+    fn log(&self) -> &MoveLog<RPSChoice> {
+        &self.log
+    }
+
+    fn outcomes(&self) -> Vec<(String, String)> {
+        let (outcome_1, outcome_2) = match self.state() {
+            RPSState::Winner1 => ("Win", "Loss"),
+            RPSState::Winner2 => ("Loss", "Win"),
+            _ => ("Draw", "Draw"),
+        };
+        vec![
+            (self.user_1.id.clone(), outcome_1.to_string()),
+            (self.user_2.id.clone(), outcome_2.to_string()),
+        ]
+    }
+
+    /// The bot's throws are part of the moves, so the series replays without the bot.
+    fn replay(setup: RPSSetup, seed: Option<u64>, moves: &[LoggedMove<RPSChoice>]) -> Self {
+        let mut game = RPSGame::new(setup.user_1, setup.user_2)
+            .with_best_of(setup.best_of)
+            .with_sudden_death(setup.sudden_death);
+        if let Some(seed) = seed {
+            game = game.with_seed(seed);
+        }
+        game.wager = setup.wager;
+        game.locked_1 = true;
+        game.locked_2 = true;
+
+        for logged in moves {
+            game.play(&logged.player, logged.action);
+        }
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moves_are_logged_in_order() {
+        let mut log = MoveLog::new();
+        log.push("1", 3);
+        log.push("2", 4);
+
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.moves[1].player, "2");
+        assert_eq!(log.moves[1].action, 4);
+        assert!(log.moves[0].at_ms <= log.moves[1].at_ms);
+    }
+
+    #[test]
+    fn test_blackjack_replays_from_record() {
+        let mut game = BlackjackGame::new().with_seed(42);
+        game.register_player("1");
+        game.register_player("2");
+        game.start_game();
+        while let Some(player) = game.current_player.clone() {
+            let score = game.players[&player]
+                .current_hand()
+                .map_or(21, |hand| hand.score());
+            let move_ = if score < 15 {
+                BlackjackMove::Hit
+            } else {
+                BlackjackMove::Stand
+            };
+            game.play(&player, move_);
+        }
+
+        let replayed =
+            BlackjackGame::replay(game.setup(), ReplayableGame::seed(&game), &game.log.moves);
+        assert!(replayed.is_over());
+        assert_eq!(replayed.outcomes(), game.outcomes());
+        assert_eq!(
+            replayed.dealer.deck.iter_cards().collect::<Vec<_>>(),
+            game.dealer.deck.iter_cards().collect::<Vec<_>>()
+        );
+
+        // Every position in between can be rebuilt from the first moves.
+        let first = BlackjackGame::replay(
+            game.setup(),
+            ReplayableGame::seed(&game),
+            &game.log.moves[..1],
+        );
+        assert_eq!(first.log.len(), 1);
+    }
+
+    #[test]
+    fn test_connect4_replays_from_record() {
+        let mut game = Connect4Game::new("1", "2");
+        for col in [3, 3, 4, 4, 5, 5, 6] {
+            let player = game.player_to_play().to_string();
+            assert!(game.play(&player, col));
+        }
+        assert!(game.is_over());
+
+        let replayed = Connect4Game::replay(game.setup(), None, &game.log.moves);
+        assert_eq!(replayed.moves, game.moves);
+        assert_eq!(replayed.winner_id(), game.winner_id());
+        assert_eq!(
+            game.outcomes(),
+            vec![
+                ("2".to_string(), "Win".to_string()),
+                ("1".to_string(), "Loss".to_string())
+            ]
+        );
+    }
+}
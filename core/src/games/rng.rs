@@ -0,0 +1,112 @@
+//! Randomness of games.
+//!
+//! Every game that needs randomness owns a [`GameRng`], a `ChaCha8Rng` seeded with a seed stored
+//! on the game. Given the seed and the moves that were played, a game can be replayed and
+//! audited exactly, and tests can fix the seed to get the same cards every time.
+
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(from = "GameRngState", into = "GameRngState")]
+pub struct GameRng {
+    seed: u64,
+    rng: ChaCha8Rng,
+}
+
+/// What is persisted of a [`GameRng`]: its seed and how many words it has generated since,
+/// which no game comes close to running out of in 64 bits.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct GameRngState {
+    seed: u64,
+    word_pos: u64,
+}
+
+impl GameRng {
+    /// A generator with a random seed.
+    pub fn new() -> Self {
+        Self::seeded(rand::random())
+    }
+
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.rng.fill_bytes(dst)
+    }
+}
+
+impl From<GameRngState> for GameRng {
+    fn from(state: GameRngState) -> Self {
+        let mut rng = Self::seeded(state.seed);
+        rng.rng.set_word_pos(state.word_pos as u128);
+        rng
+    }
+}
+
+impl From<GameRng> for GameRngState {
+    fn from(rng: GameRng) -> Self {
+        Self {
+            seed: rng.seed,
+            word_pos: rng.rng.get_word_pos() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_same_seed_same_numbers() {
+        let mut rng_1 = GameRng::seeded(41);
+        let mut rng_2 = GameRng::seeded(41);
+        for _ in 0..100 {
+            assert_eq!(rng_1.random::<u64>(), rng_2.random::<u64>());
+        }
+        assert_ne!(
+            GameRng::seeded(41).random::<u64>(),
+            GameRng::seeded(42).random::<u64>()
+        );
+    }
+
+    #[test]
+    fn test_persisted_rng_continues() {
+        let mut rng = GameRng::seeded(7);
+        for _ in 0..13 {
+            rng.next_u32();
+        }
+
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restored: GameRng = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.seed(), 7);
+        for _ in 0..100 {
+            assert_eq!(rng.next_u32(), restored.next_u32());
+        }
+    }
+}
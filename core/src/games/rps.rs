@@ -1,71 +1,241 @@
+use crate::achievements::AchievementEvent;
 use crate::database::entity::user;
 use crate::error::CoreResult;
+use crate::games::record::MoveLog;
+use crate::games::rng::GameRng;
+use crate::games::rps::bot::RPSBot;
 use crate::games::rps::choice::RPSChoice;
 use crate::games::rps::state::RPSState;
 use crate::NeobabuCore;
+use uuid::Uuid;
 
+pub mod bot;
 pub mod choice;
 pub mod state;
 pub mod stats;
+pub mod tournament;
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RPSRound {
+    pub choice_1: RPSChoice,
+    pub choice_2: RPSChoice,
+}
+
+impl RPSRound {
+    pub fn state(&self) -> RPSState {
+        if self.choice_1.beats(self.choice_2) {
+            RPSState::Winner1
+        } else if self.choice_2.beats(self.choice_1) {
+            RPSState::Winner2
+        } else {
+            RPSState::Draw
+        }
+    }
+}
+
+/// A best-of series, a single throw being a series of one.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct RPSGame {
+    pub id: Uuid,
     pub user_1: user::Model,
     pub user_2: user::Model,
+    /// The choices of the round being played, cleared once both have chosen.
     pub choice_1: Option<RPSChoice>,
     pub choice_2: Option<RPSChoice>,
+    pub best_of: u8,
+    pub rounds: Vec<RPSRound>,
+    /// Series that are tied after all rounds continue until somebody wins a round.
+    pub sudden_death: bool,
+    pub wager: Option<u32>,
+    /// Set if the second player is the bot, whose side of a wager is covered by the house.
+    pub bot: Option<RPSBot>,
+    /// Whether each player has locked their wager, which happens with their first throw.
+    pub locked_1: bool,
+    pub locked_2: bool,
+    /// What the bot throws at random comes from here.
+    rng: GameRng,
+    pub log: MoveLog<RPSChoice>,
 }
 
 impl RPSGame {
     pub fn new(user_1: user::Model, user_2: user::Model) -> Self {
         Self {
+            id: Uuid::new_v4(),
             user_1,
             user_2,
             choice_1: None,
             choice_2: None,
+            best_of: 1,
+            rounds: Vec::new(),
+            sudden_death: false,
+            wager: None,
+            bot: None,
+            locked_1: false,
+            locked_2: false,
+            rng: GameRng::new(),
+            log: MoveLog::new(),
         }
     }
 
-    pub fn state(&self) -> RPSState {
-        if self.choice_1.is_none() && self.choice_2.is_none() {
-            return RPSState::WaitingForBoth;
-        };
+    pub fn with_best_of(mut self, best_of: u8) -> Self {
+        self.best_of = best_of.max(1);
+        self
+    }
 
-        let Some(choice_1) = self.choice_1 else {
-            return RPSState::WaitingFor1;
-        };
+    pub fn with_sudden_death(mut self, sudden_death: bool) -> Self {
+        self.sudden_death = sudden_death;
+        self
+    }
 
-        let Some(choice_2) = self.choice_2 else {
-            return RPSState::WaitingFor2;
-        };
+    pub fn with_wager(mut self, wager: u32) -> Self {
+        self.wager = Some(wager);
+        self
+    }
+
+    pub fn with_bot(mut self, bot: RPSBot) -> Self {
+        self.bot = Some(bot);
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = GameRng::seeded(seed);
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    pub fn against_bot(&self) -> bool {
+        self.bot.is_some()
+    }
+
+    pub fn is_bot(&self, id: impl AsRef<str>) -> bool {
+        self.against_bot() && id.as_ref() == self.user_2.id
+    }
 
-        if choice_1 == choice_2 {
-            return RPSState::Draw;
+    /// Lets the bot throw for the round being played, it only knows the rounds before.
+    pub fn play_bot(&mut self) {
+        let Some(bot) = &self.bot else {
+            return;
         };
+        if self.choice_2.is_some() || self.state().is_finished() {
+            return;
+        }
 
-        let is_user_1_winner = matches!(
-            (choice_1, choice_2),
-            (RPSChoice::Rock, RPSChoice::Scissors)
-                | (RPSChoice::Paper, RPSChoice::Rock)
-                | (RPSChoice::Scissors, RPSChoice::Paper)
-        );
+        let recent = self
+            .rounds
+            .iter()
+            .map(|round| round.choice_1)
+            .collect::<Vec<_>>();
+        let choice = bot.choose_with(&recent, &mut self.rng);
+        self.choice_2 = Some(choice);
+        self.log.push(self.user_2.id.clone(), choice);
+    }
 
-        if is_user_1_winner {
-            RPSState::Winner1
+    /// Whether the player may throw, which requires their wager to be locked if there is one.
+    pub fn is_locked(&self, id: impl AsRef<str>) -> bool {
+        let id = id.as_ref();
+        if self.wager.is_none() || self.is_bot(id) {
+            return true;
+        }
+
+        (id == self.user_1.id && self.locked_1) || (id == self.user_2.id && self.locked_2)
+    }
+
+    pub fn lock(&mut self, id: impl AsRef<str>) {
+        if id.as_ref() == self.user_1.id {
+            self.locked_1 = true;
+        } else if id.as_ref() == self.user_2.id {
+            self.locked_2 = true;
+        }
+    }
+
+    pub fn is_series(&self) -> bool {
+        self.best_of > 1
+    }
+
+    pub fn wins_needed(&self) -> u8 {
+        self.best_of / 2 + 1
+    }
+
+    pub fn wins_1(&self) -> u8 {
+        self.count_rounds(RPSState::Winner1)
+    }
+
+    pub fn wins_2(&self) -> u8 {
+        self.count_rounds(RPSState::Winner2)
+    }
+
+    fn count_rounds(&self, state: RPSState) -> u8 {
+        self.rounds
+            .iter()
+            .filter(|round| round.state() == state)
+            .count() as u8
+    }
+
+    pub fn last_round(&self) -> Option<&RPSRound> {
+        self.rounds.last()
+    }
+
+    /// The number of the round being played, or of the last one once the series is over.
+    pub fn round_number(&self) -> usize {
+        if self.state().is_finished() {
+            self.rounds.len()
         } else {
-            RPSState::Winner2
+            self.rounds.len() + 1
+        }
+    }
+
+    pub fn state(&self) -> RPSState {
+        let wins_1 = self.wins_1();
+        let wins_2 = self.wins_2();
+        if wins_1 >= self.wins_needed() {
+            return RPSState::Winner1;
+        }
+        if wins_2 >= self.wins_needed() {
+            return RPSState::Winner2;
+        }
+
+        if self.rounds.len() >= self.best_of as usize {
+            if wins_1 > wins_2 {
+                return RPSState::Winner1;
+            } else if wins_2 > wins_1 {
+                return RPSState::Winner2;
+            } else if !self.sudden_death {
+                return RPSState::Draw;
+            }
+        }
+
+        match (self.choice_1, self.choice_2) {
+            (None, None) => RPSState::WaitingForBoth,
+            (None, Some(_)) => RPSState::WaitingFor1,
+            (Some(_), None) => RPSState::WaitingFor2,
+            // Both choices are moved into a round as soon as the second one is made.
+            (Some(_), Some(_)) => RPSState::WaitingForBoth,
         }
     }
 
     pub fn play(&mut self, id: impl AsRef<str>, choice: RPSChoice) -> bool {
+        if self.state().is_finished() || !self.is_locked(&id) {
+            return false;
+        }
+
         if id.as_ref() == self.user_1.id && self.choice_1.is_none() {
             self.choice_1 = Some(choice);
-            true
         } else if id.as_ref() == self.user_2.id && self.choice_2.is_none() {
             self.choice_2 = Some(choice);
-            true
         } else {
-            false
+            return false;
+        }
+        self.log.push(id.as_ref(), choice);
+
+        if let (Some(choice_1), Some(choice_2)) = (self.choice_1, self.choice_2) {
+            self.rounds.push(RPSRound { choice_1, choice_2 });
+            self.choice_1 = None;
+            self.choice_2 = None;
         }
+        true
     }
 
     pub async fn register_end(&self, core: &NeobabuCore) -> CoreResult<()> {
@@ -93,20 +263,131 @@ impl RPSGame {
             }
         };
 
-        if let Some(choice_1) = self.choice_1 {
+        for round in &self.rounds {
             core.services
                 .rps
-                .register_choice(&self.user_1, choice_1)
+                .register_choice(&self.user_1, round.choice_1)
                 .await?;
-        };
-
-        if let Some(choice_2) = self.choice_2 {
             core.services
                 .rps
-                .register_choice(&self.user_2, choice_2)
+                .register_choice(&self.user_2, round.choice_2)
                 .await?;
+        }
+
+        for user in [&self.user_1, &self.user_2] {
+            if self.is_bot(&user.id) {
+                continue;
+            }
+            let stats = core.services.rps.get_stats(user).await?;
+            core.services
+                .achievement
+                .track_or_log(user, AchievementEvent::RockPaperScissorsFinished(stats))
+                .await;
+        }
+
+        core.services.game_record.save(self).await?;
+        core.services.game_rating.record(self).await?;
+        core.services.rps.settle_wagers(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::rps::bot::RPSBotMode;
+
+    fn user(id: &str) -> user::Model {
+        user::Model {
+            id: id.to_string(),
+            username: None,
+            avatar_hash: None,
+            encrypted_oauth_token: None,
+            permissions: 0,
+            preferred_timezone: None,
+            created_at: Default::default(),
+            updated_at: Default::default(),
+        }
+    }
+
+    fn series(best_of: u8) -> RPSGame {
+        RPSGame::new(user("1"), user("2")).with_best_of(best_of)
+    }
+
+    fn play_round(game: &mut RPSGame, choice_1: RPSChoice, choice_2: RPSChoice) {
+        assert!(game.play("1", choice_1));
+        assert!(game.play("2", choice_2));
+    }
+
+    #[test]
+    fn test_single_round() {
+        let mut game = series(1);
+        assert!(game.play("2", RPSChoice::Rock));
+        assert_eq!(game.state(), RPSState::WaitingFor1);
+        assert!(!game.play("2", RPSChoice::Paper));
+        assert!(game.play("1", RPSChoice::Paper));
+        assert_eq!(game.state(), RPSState::Winner1);
+        assert!(!game.play("1", RPSChoice::Rock));
+    }
+
+    #[test]
+    fn test_series_ends_at_majority() {
+        let mut game = series(5);
+        play_round(&mut game, RPSChoice::Rock, RPSChoice::Paper);
+        play_round(&mut game, RPSChoice::Rock, RPSChoice::Rock);
+        play_round(&mut game, RPSChoice::Scissors, RPSChoice::Paper);
+        assert!(game.state().is_ongoing());
+        play_round(&mut game, RPSChoice::Paper, RPSChoice::Scissors);
+        play_round(&mut game, RPSChoice::Paper, RPSChoice::Scissors);
+        assert_eq!(game.state(), RPSState::Winner2);
+        assert_eq!((game.wins_1(), game.wins_2()), (1, 3));
+    }
+
+    #[test]
+    fn test_series_decided_by_wins_after_draws() {
+        let mut game = series(3);
+        play_round(&mut game, RPSChoice::Rock, RPSChoice::Rock);
+        play_round(&mut game, RPSChoice::Rock, RPSChoice::Scissors);
+        play_round(&mut game, RPSChoice::Paper, RPSChoice::Paper);
+        assert_eq!(game.state(), RPSState::Winner1);
+    }
+
+    #[test]
+    fn test_wager_must_be_locked() {
+        let mut game = series(1).with_wager(10).with_bot(RPSBot::default());
+        assert!(!game.play("1", RPSChoice::Rock));
+        game.lock("1");
+        assert!(game.play("1", RPSChoice::Rock));
+        assert!(game.play("2", RPSChoice::Paper));
+        assert_eq!(game.state(), RPSState::Winner2);
+    }
+
+    #[test]
+    fn test_seeded_bot_replays() {
+        let play = |seed| {
+            let mut game = series(7)
+                .with_bot(RPSBot::new(RPSBotMode::Fair))
+                .with_seed(seed);
+            while !game.state().is_finished() {
+                game.play_bot();
+                assert!(game.play("1", RPSChoice::Rock));
+            }
+            game.rounds
         };
 
-        Ok(())
+        assert_eq!(play(37), play(37));
+        assert_ne!(play(37), play(38));
+    }
+
+    #[test]
+    fn test_tied_series() {
+        let mut game = series(1);
+        play_round(&mut game, RPSChoice::Rock, RPSChoice::Rock);
+        assert_eq!(game.state(), RPSState::Draw);
+
+        let mut game = series(1).with_sudden_death(true);
+        play_round(&mut game, RPSChoice::Rock, RPSChoice::Rock);
+        assert!(game.state().is_ongoing());
+        play_round(&mut game, RPSChoice::Rock, RPSChoice::Paper);
+        assert_eq!(game.state(), RPSState::Winner2);
     }
 }
@@ -0,0 +1,182 @@
+use crate::games::rps::choice::RPSChoice;
+use rand::prelude::IndexedRandom;
+use rand::Rng;
+
+/// How many of a player's throws are remembered across games.
+pub const HISTORY_LENGTH: usize = 64;
+/// The longest sequence of throws the bot looks for in a player's history.
+const MAX_ORDER: usize = 3;
+/// How much the player's overall frequencies count, compared to a single matched sequence.
+const FREQUENCY_WEIGHT: f64 = 3.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum RPSBotMode {
+    /// Learns from the player and counters what they are most likely to throw.
+    #[default]
+    Adaptive,
+    /// Throws at random, so that nobody can be exploited by or exploit the bot.
+    Fair,
+}
+
+/// The bot opponent, which predicts the next throw of a player from how often they throw each
+/// choice overall and from what they threw after their last few throws before.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct RPSBot {
+    pub mode: RPSBotMode,
+    /// How often the player threw rock, paper and scissors over all their games.
+    pub frequencies: [u64; 3],
+    /// The player's last throws before this game, oldest first.
+    pub history: Vec<RPSChoice>,
+}
+
+impl RPSBot {
+    pub fn new(mode: RPSBotMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_frequencies(mut self, frequencies: [u64; 3]) -> Self {
+        self.frequencies = frequencies;
+        self
+    }
+
+    pub fn with_history(mut self, history: Vec<RPSChoice>) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Chooses a throw given what the player has thrown so far in this game.
+    pub fn choose_with(&self, recent: &[RPSChoice], rng: &mut impl Rng) -> RPSChoice {
+        if self.mode == RPSBotMode::Fair {
+            return *RPSChoice::ALL.choose(rng).unwrap_or(&RPSChoice::Rock);
+        }
+
+        let prediction = self.predict(recent);
+        let expected = |choice: &RPSChoice| {
+            RPSChoice::ALL
+                .iter()
+                .map(|other| {
+                    if choice.beats(*other) {
+                        prediction[other.index()]
+                    } else if other.beats(*choice) {
+                        -prediction[other.index()]
+                    } else {
+                        0.0
+                    }
+                })
+                .sum::<f64>()
+        };
+
+        let best = RPSChoice::ALL
+            .iter()
+            .map(expected)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let candidates = RPSChoice::ALL
+            .iter()
+            .filter(|choice| expected(choice) >= best - f64::EPSILON)
+            .copied()
+            .collect::<Vec<_>>();
+        *candidates.choose(rng).unwrap_or(&RPSChoice::Rock)
+    }
+
+    /// The likelihood of the player's next throw being rock, paper or scissors.
+    pub fn predict(&self, recent: &[RPSChoice]) -> [f64; 3] {
+        let mut weights = [1.0; 3];
+
+        let total = self.frequencies.iter().sum::<u64>();
+        if total > 0 {
+            for (weight, count) in weights.iter_mut().zip(self.frequencies) {
+                *weight += FREQUENCY_WEIGHT * count as f64 / total as f64;
+            }
+        }
+
+        // Every earlier occurrence of the last throws votes for what followed it, longer matches
+        // are more telling and weigh more.
+        let sequence = self
+            .history
+            .iter()
+            .chain(recent)
+            .copied()
+            .collect::<Vec<_>>();
+        for order in 1..=MAX_ORDER.min(sequence.len()) {
+            let context = &sequence[sequence.len() - order..];
+            for window in sequence.windows(order + 1) {
+                if &window[..order] == context {
+                    weights[window[order].index()] += order as f64;
+                }
+            }
+        }
+
+        let sum = weights.iter().sum::<f64>();
+        weights.map(|weight| weight / sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Plays the bot against a player and returns its wins and losses.
+    fn simulate(
+        bot: &RPSBot,
+        rounds: usize,
+        mut player: impl FnMut(usize, &mut ChaCha8Rng) -> RPSChoice,
+    ) -> (usize, usize) {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let mut recent = Vec::new();
+        let (mut wins, mut losses) = (0, 0);
+
+        for round in 0..rounds {
+            let bot_choice = bot.choose_with(&recent, &mut rng);
+            let player_choice = player(round, &mut rng);
+            if bot_choice.beats(player_choice) {
+                wins += 1;
+            } else if player_choice.beats(bot_choice) {
+                losses += 1;
+            }
+            recent.push(player_choice);
+        }
+
+        (wins, losses)
+    }
+
+    fn biased(rng: &mut ChaCha8Rng) -> RPSChoice {
+        match rng.random_range(0..10) {
+            0..5 => RPSChoice::Rock,
+            5..8 => RPSChoice::Paper,
+            _ => RPSChoice::Scissors,
+        }
+    }
+
+    #[test]
+    fn test_beats_biased_player() {
+        let bot = RPSBot::new(RPSBotMode::Adaptive);
+        let (wins, losses) = simulate(&bot, 1000, |_, rng| biased(rng));
+        assert!(wins > losses + 150, "won {wins}, lost {losses}");
+    }
+
+    #[test]
+    fn test_beats_repeating_sequence() {
+        let bot = RPSBot::new(RPSBotMode::Adaptive);
+        let (wins, losses) = simulate(&bot, 300, |round, _| RPSChoice::ALL[round % 3]);
+        assert!(wins > 250, "won {wins}, lost {losses}");
+    }
+
+    #[test]
+    fn test_uses_frequencies_before_first_throw() {
+        let bot = RPSBot::new(RPSBotMode::Adaptive).with_frequencies([0, 0, 40]);
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        assert_eq!(bot.choose_with(&[], &mut rng), RPSChoice::Rock);
+    }
+
+    #[test]
+    fn test_fair_mode_ignores_player() {
+        let bot = RPSBot::new(RPSBotMode::Fair).with_frequencies([0, 0, 40]);
+        let (wins, losses) = simulate(&bot, 1000, |_, _| RPSChoice::Scissors);
+        assert!(wins.abs_diff(losses) < 100, "won {wins}, lost {losses}");
+    }
+}
@@ -1,7 +1,8 @@
 use rand::prelude::IndexedRandom;
+use rand::Rng;
 use std::fmt::Display;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum RPSChoice {
     Rock,
     Paper,
@@ -9,12 +10,47 @@ pub enum RPSChoice {
 }
 
 impl RPSChoice {
-    pub fn random() -> Self {
-        let choice = [Self::Rock, Self::Paper, Self::Scissors]
-            .choose(&mut rand::rng())
-            .copied();
+    pub const ALL: [Self; 3] = [Self::Rock, Self::Paper, Self::Scissors];
+
+    pub fn index(&self) -> usize {
+        match self {
+            Self::Rock => 0,
+            Self::Paper => 1,
+            Self::Scissors => 2,
+        }
+    }
+
+    /// Single letter form, used to store sequences of throws.
+    pub fn as_char(&self) -> char {
+        match self {
+            Self::Rock => 'R',
+            Self::Paper => 'P',
+            Self::Scissors => 'S',
+        }
+    }
+
+    pub fn from_char(char: char) -> Option<Self> {
+        match char {
+            'R' => Some(Self::Rock),
+            'P' => Some(Self::Paper),
+            'S' => Some(Self::Scissors),
+            _ => None,
+        }
+    }
+
+    pub fn random<R: Rng>(rng: &mut R) -> Self {
+        let choice = Self::ALL.choose(rng).copied();
         choice.unwrap_or(Self::Rock)
     }
+
+    pub fn beats(&self, other: Self) -> bool {
+        matches!(
+            (self, other),
+            (Self::Rock, Self::Scissors)
+                | (Self::Paper, Self::Rock)
+                | (Self::Scissors, Self::Paper)
+        )
+    }
 }
 
 impl Display for RPSChoice {
@@ -1,3 +1,4 @@
+#[derive(Debug, Clone)]
 pub struct RPSStats {
     pub wins: u64,
     pub losses: u64,
@@ -5,6 +6,9 @@ pub struct RPSStats {
     pub rock: u64,
     pub paper: u64,
     pub scissors: u64,
+    pub citrine_wagered: i64,
+    pub citrine_won: i64,
+    pub citrine_lost: i64,
 }
 
 impl RPSStats {
@@ -15,4 +19,8 @@ impl RPSStats {
     pub fn win_rate(&self) -> f64 {
         self.wins as f64 / self.total_played() as f64
     }
+
+    pub fn net_gain(&self) -> i64 {
+        self.citrine_won.saturating_sub(self.citrine_lost)
+    }
 }
@@ -0,0 +1,326 @@
+use crate::database::entity::user;
+use crate::games::rps::state::RPSState;
+use crate::games::rps::RPSGame;
+use uuid::Uuid;
+
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 16;
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct RPSMatch {
+    pub player_1: Option<String>,
+    pub player_2: Option<String>,
+    pub winner: Option<String>,
+    pub wins_1: u8,
+    pub wins_2: u8,
+    pub forfeited: bool,
+}
+
+impl RPSMatch {
+    /// A first round match against a missing opponent, the present player advances right away.
+    pub fn is_bye(&self) -> bool {
+        self.player_1.is_none() || self.player_2.is_none()
+    }
+
+    pub fn is_playable(&self) -> bool {
+        self.winner.is_none() && self.player_1.is_some() && self.player_2.is_some()
+    }
+}
+
+/// A single elimination bracket of best-of series, played one match at a time.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct RPSTournament {
+    pub id: Uuid,
+    pub host_id: String,
+    pub best_of: u8,
+    /// Signed up players, ordered by seed once the tournament has started.
+    pub players: Vec<user::Model>,
+    /// The matches of every round, with the final as the last round.
+    pub bracket: Vec<Vec<RPSMatch>>,
+    /// The match being played and its position in the bracket.
+    pub current: Option<(usize, usize, RPSGame)>,
+}
+
+impl RPSTournament {
+    pub fn new(host: user::Model, best_of: u8) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            host_id: host.id.clone(),
+            best_of: best_of.max(1),
+            players: vec![host],
+            bracket: Vec::new(),
+            current: None,
+        }
+    }
+
+    pub fn join(&mut self, user: user::Model) -> bool {
+        if self.has_started()
+            || self.players.len() >= MAX_PLAYERS
+            || self.players.iter().any(|player| player.id == user.id)
+        {
+            return false;
+        }
+
+        self.players.push(user);
+        true
+    }
+
+    pub fn can_start(&self) -> bool {
+        !self.has_started() && self.players.len() >= MIN_PLAYERS
+    }
+
+    pub fn has_started(&self) -> bool {
+        !self.bracket.is_empty()
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    pub fn winner(&self) -> Option<&str> {
+        self.bracket.last()?.first()?.winner.as_deref()
+    }
+
+    pub fn player(&self, id: &str) -> Option<&user::Model> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
+    pub fn current_game(&self) -> Option<&RPSGame> {
+        self.current.as_ref().map(|(_, _, game)| game)
+    }
+
+    pub fn current_game_mut(&mut self) -> Option<&mut RPSGame> {
+        self.current.as_mut().map(|(_, _, game)| game)
+    }
+
+    /// Builds the bracket from the players in seed order, the best seeds get the byes and only
+    /// meet each other in the late rounds. Starts the first match.
+    pub fn start(&mut self) -> bool {
+        if !self.can_start() {
+            return false;
+        }
+
+        let size = self.players.len().next_power_of_two();
+        let positions = seed_positions(size);
+        let first_round = positions
+            .chunks(2)
+            .map(|seeds| {
+                let player_1 = self.players.get(seeds[0]).map(|user| user.id.clone());
+                let player_2 = self.players.get(seeds[1]).map(|user| user.id.clone());
+                let winner = match (&player_1, &player_2) {
+                    (Some(player), None) | (None, Some(player)) => Some(player.clone()),
+                    _ => None,
+                };
+                RPSMatch {
+                    player_1,
+                    player_2,
+                    winner,
+                    ..Default::default()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.bracket.push(first_round);
+        let mut matches = size / 2;
+        while matches > 1 {
+            matches /= 2;
+            self.bracket.push(vec![RPSMatch::default(); matches]);
+        }
+
+        self.advance();
+        true
+    }
+
+    /// Records the current match once its series is over and moves on to the next one.
+    /// Returns the finished series.
+    pub fn finish_current(&mut self) -> Option<RPSGame> {
+        let (round, index, game) = self.current.take()?;
+        let winner = match game.state() {
+            RPSState::Winner1 => game.user_1.id.clone(),
+            RPSState::Winner2 => game.user_2.id.clone(),
+            _ => {
+                self.current = Some((round, index, game));
+                return None;
+            }
+        };
+
+        let result = &mut self.bracket[round][index];
+        result.winner = Some(winner);
+        result.wins_1 = game.wins_1();
+        result.wins_2 = game.wins_2();
+
+        self.advance();
+        Some(game)
+    }
+
+    /// Eliminates a player of the current match without a result, e.g. for not playing in time.
+    /// Returns the winner and the loser.
+    pub fn forfeit_current(&mut self, loser_id: &str) -> Option<(user::Model, user::Model)> {
+        let (round, index, game) = self.current.take()?;
+        let (winner, loser) = if game.user_1.id == loser_id {
+            (game.user_2, game.user_1)
+        } else {
+            (game.user_1, game.user_2)
+        };
+
+        let result = &mut self.bracket[round][index];
+        result.winner = Some(winner.id.clone());
+        result.forfeited = true;
+
+        self.advance();
+        Some((winner, loser))
+    }
+
+    /// Moves winners into the next round and sets up the next playable match.
+    fn advance(&mut self) {
+        for round in 1..self.bracket.len() {
+            for index in 0..self.bracket[round].len() {
+                let player_1 = self.bracket[round - 1][index * 2].winner.clone();
+                let player_2 = self.bracket[round - 1][index * 2 + 1].winner.clone();
+                let next = &mut self.bracket[round][index];
+                next.player_1 = player_1;
+                next.player_2 = player_2;
+            }
+        }
+
+        if self.current.is_some() {
+            return;
+        }
+
+        let next = self
+            .bracket
+            .iter()
+            .enumerate()
+            .find_map(|(round, matches)| {
+                matches
+                    .iter()
+                    .position(RPSMatch::is_playable)
+                    .map(|index| (round, index))
+            });
+        let Some((round, index)) = next else {
+            return;
+        };
+
+        let result = &self.bracket[round][index];
+        let user_1 = result.player_1.as_deref().and_then(|id| self.player(id));
+        let user_2 = result.player_2.as_deref().and_then(|id| self.player(id));
+        if let (Some(user_1), Some(user_2)) = (user_1, user_2) {
+            let game = RPSGame::new(user_1.clone(), user_2.clone())
+                .with_best_of(self.best_of)
+                .with_sudden_death(true);
+            self.current = Some((round, index, game));
+        }
+    }
+}
+
+/// The seeds in bracket order, so that seed 1 and 2 can only meet in the final.
+fn seed_positions(size: usize) -> Vec<usize> {
+    let mut positions = vec![0];
+    while positions.len() < size {
+        let count = positions.len() * 2;
+        positions = positions
+            .iter()
+            .flat_map(|&seed| [seed, count - 1 - seed])
+            .collect();
+    }
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::rps::choice::RPSChoice;
+
+    fn user(id: &str) -> user::Model {
+        user::Model {
+            id: id.to_string(),
+            username: None,
+            avatar_hash: None,
+            encrypted_oauth_token: None,
+            permissions: 0,
+            preferred_timezone: None,
+            created_at: Default::default(),
+            updated_at: Default::default(),
+        }
+    }
+
+    fn tournament(players: usize) -> RPSTournament {
+        let mut tournament = RPSTournament::new(user("0"), 1);
+        for id in 1..players {
+            assert!(tournament.join(user(&id.to_string())));
+        }
+        tournament
+    }
+
+    /// Lets the first player of every match win.
+    fn play_current(tournament: &mut RPSTournament) -> RPSGame {
+        let game = tournament.current_game_mut().unwrap();
+        let (id_1, id_2) = (game.user_1.id.clone(), game.user_2.id.clone());
+        game.play(id_1, RPSChoice::Rock);
+        game.play(id_2, RPSChoice::Scissors);
+        tournament.finish_current().unwrap()
+    }
+
+    #[test]
+    fn test_seed_positions() {
+        assert_eq!(seed_positions(2), vec![0, 1]);
+        assert_eq!(seed_positions(4), vec![0, 3, 1, 2]);
+        assert_eq!(seed_positions(8), vec![0, 7, 3, 4, 1, 6, 2, 5]);
+    }
+
+    #[test]
+    fn test_join() {
+        let mut tournament = tournament(1);
+        assert!(!tournament.can_start());
+        assert!(!tournament.join(user("0")));
+        assert!(tournament.join(user("1")));
+        assert!(tournament.can_start());
+    }
+
+    #[test]
+    fn test_byes_go_to_top_seeds() {
+        let mut tournament = tournament(5);
+        assert!(tournament.start());
+        assert_eq!(tournament.bracket.len(), 3);
+
+        let byes = tournament.bracket[0]
+            .iter()
+            .filter(|result| result.is_bye())
+            .map(|result| result.winner.clone().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(byes, vec!["0", "1", "2"]);
+
+        let game = tournament.current_game().unwrap();
+        assert_eq!(
+            (game.user_1.id.as_str(), game.user_2.id.as_str()),
+            ("3", "4")
+        );
+    }
+
+    #[test]
+    fn test_plays_until_winner() {
+        let mut tournament = tournament(6);
+        tournament.start();
+
+        let mut played = 0;
+        while !tournament.is_over() {
+            play_current(&mut tournament);
+            played += 1;
+        }
+
+        assert_eq!(played, 5);
+        assert_eq!(tournament.winner(), Some("0"));
+        assert!(tournament.current_game().is_none());
+    }
+
+    #[test]
+    fn test_forfeit_advances_opponent() {
+        let mut tournament = tournament(2);
+        tournament.start();
+
+        let (winner, loser) = tournament.forfeit_current("0").unwrap();
+        assert_eq!((winner.id.as_str(), loser.id.as_str()), ("1", "0"));
+        assert_eq!(tournament.winner(), Some("1"));
+        assert!(tournament.bracket[0][0].forfeited);
+    }
+}
@@ -0,0 +1,212 @@
+use crate::games::connect4::Connect4Game;
+use uuid::Uuid;
+
+pub mod lobby;
+
+/// The wagers held by a running turn-based game. Turn-based games are stored as just their wagers,
+/// as there is nothing but the stakes to refund if they are voided.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TurnBasedWagers {
+    pub id: Uuid,
+    pub reference_id: String,
+    /// The players whose stakes are held under the reference.
+    pub players: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TurnOutcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+/// A multiplayer game where players take turns, which is all a game has to implement to be
+/// played through a lobby with wagers. Players are identified by their user id.
+pub trait TurnBasedGame: Send + Sync {
+    type Move: Send;
+
+    /// Every seated player, in turn order.
+    fn players(&self) -> Vec<String>;
+
+    /// The player whose turn it is, none once the game is over.
+    fn current_player(&self) -> Option<String>;
+
+    /// Applies the move if it is the player's turn and the move is allowed.
+    fn play(&mut self, player: &str, move_: Self::Move) -> bool;
+
+    /// Ends the turn of a player who ran out of time, e.g. by folding or forfeiting.
+    fn time_out(&mut self, player: &str);
+
+    fn is_over(&self) -> bool;
+
+    /// The outcome for every player, empty until the game is over.
+    fn outcomes(&self) -> Vec<(String, TurnOutcome)>;
+
+    fn is_to_play(&self, player: &str) -> bool {
+        self.current_player()
+            .is_some_and(|current| current == player)
+    }
+
+    /// How much every player wins or loses, given the stake every player put up. By default the
+    /// winners split the stakes of the losers.
+    fn settlement(&self, stake: i64) -> Vec<(String, i64)> {
+        split_stakes(&self.outcomes(), stake)
+    }
+
+    /// How much every player wins or loses if the game is called off before it is over, e.g.
+    /// because it ran out of time. By default everyone gets their stake back.
+    fn abandoned_settlement(&self, _stake: i64) -> Vec<(String, i64)> {
+        self.players().into_iter().map(|id| (id, 0)).collect()
+    }
+}
+
+/// Winners split the stakes of the losers evenly, the first winners get what cannot be split.
+/// Draws and games without a winner change nothing.
+pub fn split_stakes(outcomes: &[(String, TurnOutcome)], stake: i64) -> Vec<(String, i64)> {
+    let winners = outcomes
+        .iter()
+        .filter(|(_, outcome)| *outcome == TurnOutcome::Win)
+        .count() as i64;
+    let losers = outcomes
+        .iter()
+        .filter(|(_, outcome)| *outcome == TurnOutcome::Loss)
+        .count() as i64;
+
+    if winners == 0 {
+        return outcomes.iter().map(|(id, _)| (id.clone(), 0)).collect();
+    }
+
+    let pot = stake * losers;
+    let mut remainder = pot % winners;
+    outcomes
+        .iter()
+        .map(|(id, outcome)| {
+            let amount = match outcome {
+                TurnOutcome::Win => {
+                    let extra = if remainder > 0 { 1 } else { 0 };
+                    remainder -= extra;
+                    pot / winners + extra
+                }
+                TurnOutcome::Loss => -stake,
+                TurnOutcome::Draw => 0,
+            };
+            (id.clone(), amount)
+        })
+        .collect()
+}
+
+impl TurnBasedGame for Connect4Game {
+    type Move = u8;
+
+    fn players(&self) -> Vec<String> {
+        vec![self.player_1.clone(), self.player_2.clone()]
+    }
+
+    fn current_player(&self) -> Option<String> {
+        if self.is_over() {
+            None
+        } else {
+            Some(self.player_to_play().to_string())
+        }
+    }
+
+    fn play(&mut self, player: &str, move_: u8) -> bool {
+        Connect4Game::play(self, player, move_)
+    }
+
+    fn time_out(&mut self, player: &str) {
+        if self.is_to_play(player) {
+            self.forfeit();
+        }
+    }
+
+    fn is_over(&self) -> bool {
+        Connect4Game::is_over(self)
+    }
+
+    fn outcomes(&self) -> Vec<(String, TurnOutcome)> {
+        if !self.is_over() {
+            return Vec::new();
+        }
+
+        self.players()
+            .into_iter()
+            .map(|id| {
+                let outcome = match self.winner_id() {
+                    Some(winner) if winner == id => TurnOutcome::Win,
+                    Some(_) => TurnOutcome::Loss,
+                    None => TurnOutcome::Draw,
+                };
+                (id, outcome)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcomes(outcomes: &[TurnOutcome]) -> Vec<(String, TurnOutcome)> {
+        outcomes
+            .iter()
+            .enumerate()
+            .map(|(index, outcome)| (index.to_string(), *outcome))
+            .collect()
+    }
+
+    fn amounts(settlement: Vec<(String, i64)>) -> Vec<i64> {
+        settlement.into_iter().map(|(_, amount)| amount).collect()
+    }
+
+    #[test]
+    fn test_split_stakes() {
+        let settlement = split_stakes(&outcomes(&[TurnOutcome::Win, TurnOutcome::Loss]), 10);
+        assert_eq!(amounts(settlement), vec![10, -10]);
+
+        let settlement = split_stakes(
+            &outcomes(&[
+                TurnOutcome::Loss,
+                TurnOutcome::Win,
+                TurnOutcome::Draw,
+                TurnOutcome::Win,
+                TurnOutcome::Loss,
+                TurnOutcome::Loss,
+            ]),
+            5,
+        );
+        assert_eq!(amounts(settlement), vec![-5, 8, 0, 7, -5, -5]);
+    }
+
+    #[test]
+    fn test_split_stakes_without_winner() {
+        let settlement = split_stakes(&outcomes(&[TurnOutcome::Draw, TurnOutcome::Draw]), 10);
+        assert_eq!(amounts(settlement), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_connect4_time_out_forfeits() {
+        let mut game = Connect4Game::new("1", "2");
+        let first = game.current_player().unwrap();
+        let second = game.players().into_iter().find(|id| *id != first).unwrap();
+        assert!(TurnBasedGame::play(&mut game, &first, 3));
+        assert_eq!(game.current_player(), Some(second.clone()));
+
+        game.time_out(&first);
+        assert!(!TurnBasedGame::is_over(&game));
+
+        game.time_out(&second);
+        let outcome_of = |id: &str| {
+            game.outcomes()
+                .into_iter()
+                .find(|(player, _)| player == id)
+                .map(|(_, outcome)| outcome)
+        };
+        assert_eq!(outcome_of(&first), Some(TurnOutcome::Win));
+        assert_eq!(outcome_of(&second), Some(TurnOutcome::Loss));
+
+        let settlement = game.settlement(25);
+        assert!(settlement.contains(&(first, 25)));
+        assert!(settlement.contains(&(second, -25)));
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LobbyError {
+    AlreadyStarted,
+    AlreadyJoined,
+    Full,
+    NotJoined,
+    NotHost,
+    NotReady,
+}
+
+/// Seats players before a game starts. Players join and mark themselves ready, the host starts
+/// the game once enough players are ready. Anyone else can watch as a spectator.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Lobby {
+    pub host_id: String,
+    /// Seated players in the order they joined, which becomes the turn order.
+    pub players: Vec<String>,
+    pub ready: HashSet<String>,
+    pub spectators: Vec<String>,
+    pub min_players: usize,
+    pub max_players: usize,
+    pub started: bool,
+}
+
+impl Lobby {
+    pub fn new(host_id: impl Into<String>, min_players: usize, max_players: usize) -> Self {
+        let host_id = host_id.into();
+        Self {
+            players: vec![host_id.clone()],
+            host_id,
+            ready: HashSet::new(),
+            spectators: Vec::new(),
+            min_players,
+            max_players,
+            started: false,
+        }
+    }
+
+    pub fn is_player(&self, id: &str) -> bool {
+        self.players.iter().any(|player| player == id)
+    }
+
+    pub fn is_spectator(&self, id: &str) -> bool {
+        self.spectators.iter().any(|spectator| spectator == id)
+    }
+
+    pub fn is_ready(&self, id: &str) -> bool {
+        self.ready.contains(id)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.players.len() >= self.max_players
+    }
+
+    /// Seats the player, moving them out of the spectators if they were watching.
+    pub fn join(&mut self, id: &str) -> Result<(), LobbyError> {
+        if self.started {
+            return Err(LobbyError::AlreadyStarted);
+        }
+        if self.is_player(id) {
+            return Err(LobbyError::AlreadyJoined);
+        }
+        if self.is_full() {
+            return Err(LobbyError::Full);
+        }
+
+        self.spectators.retain(|spectator| spectator != id);
+        self.players.push(id.to_string());
+        Ok(())
+    }
+
+    /// Unseats the player, the next player becomes host if the host leaves.
+    pub fn leave(&mut self, id: &str) -> Result<(), LobbyError> {
+        if self.started {
+            return Err(LobbyError::AlreadyStarted);
+        }
+        if !self.is_player(id) {
+            return Err(LobbyError::NotJoined);
+        }
+
+        self.players.retain(|player| player != id);
+        self.ready.remove(id);
+        if self.host_id == id
+            && let Some(next_host) = self.players.first()
+        {
+            self.host_id = next_host.clone();
+        }
+        Ok(())
+    }
+
+    /// Toggles whether the player is ready, returns whether they are ready now.
+    pub fn toggle_ready(&mut self, id: &str) -> Result<bool, LobbyError> {
+        if self.started {
+            return Err(LobbyError::AlreadyStarted);
+        }
+        if !self.is_player(id) {
+            return Err(LobbyError::NotJoined);
+        }
+
+        if self.ready.remove(id) {
+            Ok(false)
+        } else {
+            self.ready.insert(id.to_string());
+            Ok(true)
+        }
+    }
+
+    /// Spectators see the game but cannot play, seated players give up their seat to watch.
+    pub fn spectate(&mut self, id: &str) -> Result<(), LobbyError> {
+        if self.is_player(id) {
+            self.leave(id)?;
+        }
+        if !self.is_spectator(id) {
+            self.spectators.push(id.to_string());
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    pub fn can_start(&self) -> bool {
+        !self.started
+            && self.players.len() >= self.min_players
+            && self
+                .players
+                .iter()
+                .all(|player| self.ready.contains(player))
+    }
+
+    pub fn start(&mut self, id: &str) -> Result<(), LobbyError> {
+        if self.started {
+            return Err(LobbyError::AlreadyStarted);
+        }
+        if id != self.host_id {
+            return Err(LobbyError::NotHost);
+        }
+        if !self.can_start() {
+            return Err(LobbyError::NotReady);
+        }
+
+        self.started = true;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_and_leave() {
+        let mut lobby = Lobby::new("1", 2, 3);
+        assert_eq!(lobby.join("1"), Err(LobbyError::AlreadyJoined));
+        assert_eq!(lobby.join("2"), Ok(()));
+        assert_eq!(lobby.join("3"), Ok(()));
+        assert_eq!(lobby.join("4"), Err(LobbyError::Full));
+
+        assert_eq!(lobby.leave("1"), Ok(()));
+        assert_eq!(lobby.host_id, "2");
+        assert_eq!(lobby.leave("1"), Err(LobbyError::NotJoined));
+    }
+
+    #[test]
+    fn test_start_requires_everyone_ready() {
+        let mut lobby = Lobby::new("1", 2, 4);
+        assert_eq!(lobby.toggle_ready("1"), Ok(true));
+        assert_eq!(lobby.start("1"), Err(LobbyError::NotReady));
+
+        lobby.join("2").unwrap();
+        assert!(!lobby.can_start());
+        assert_eq!(lobby.toggle_ready("2"), Ok(true));
+        assert_eq!(lobby.start("2"), Err(LobbyError::NotHost));
+        assert_eq!(lobby.start("1"), Ok(()));
+        assert_eq!(lobby.join("3"), Err(LobbyError::AlreadyStarted));
+    }
+
+    #[test]
+    fn test_spectate() {
+        let mut lobby = Lobby::new("1", 2, 4);
+        lobby.join("2").unwrap();
+        lobby.toggle_ready("2").unwrap();
+
+        lobby.spectate("2").unwrap();
+        assert!(lobby.is_spectator("2"));
+        assert!(!lobby.is_player("2"));
+        assert!(!lobby.is_ready("2"));
+
+        lobby.join("2").unwrap();
+        assert!(!lobby.is_spectator("2"));
+    }
+}
@@ -30,6 +30,7 @@ impl<'a> RequestBuilder<'a> {
         self
     }
 
+    #[allow(dead_code)]
     pub fn header(mut self, key: impl IntoHeaderName, value: impl AsRef<str>) -> CoreResult<Self> {
         let header_value: HeaderValue = value.as_ref().parse()?;
         self.headers.insert(key, header_value);
@@ -43,6 +44,7 @@ impl<'a> RequestBuilder<'a> {
         self
     }
 
+    #[allow(dead_code)]
     pub fn path(mut self, path: impl AsRef<str>) -> Self {
         self.url.set_path(path.as_ref());
         self
@@ -7,6 +7,9 @@ use tracing::{error, info, warn};
 
 mod apod;
 mod birthday_notification;
+mod birthday_role_removal;
+mod leveling_flush;
+mod lottery_draw;
 mod youtube_update;
 
 pub struct Scheduler {
@@ -36,10 +39,20 @@ impl Scheduler {
         self.schedule_job("apod", "0 * * * * *", apod::run).await?;
         self.schedule_job(
             "birthday_notification",
-            "0 5 9 * * *",
+            "0 */5 * * * *",
             birthday_notification::run,
         )
         .await?;
+        self.schedule_job(
+            "birthday_role_removal",
+            "45 * * * * *",
+            birthday_role_removal::run,
+        )
+        .await?;
+        self.schedule_job("leveling_flush", "15 * * * * *", leveling_flush::run)
+            .await?;
+        self.schedule_job("lottery_draw", "0 */5 * * * *", lottery_draw::run)
+            .await?;
         self.schedule_job("youtube_update", "30 * * * * *", youtube_update::run)
             .await?;
         info!("Jobs successfully scheduled");
@@ -1,23 +1,28 @@
+use crate::birthday::announcement::pick_template;
+use crate::birthday::due::DueBirthday;
 use crate::database::entity::user_birthday;
-use crate::error::{CoreError, CoreResult};
+use crate::error::CoreResult;
 use crate::events::birthday_notification::BirthdayNotification;
 use crate::events::CoreEvent;
-use crate::utils::dates::upcoming_date_time;
+use crate::utils::dates::end_of_local_day;
 use crate::NeobabuCore;
-use chrono::{DateTime, Datelike};
+use chrono::{DateTime, Duration, NaiveDate};
 use futures::StreamExt;
-use sea_orm::{IntoActiveModel, Set};
 use tracing::{error, info};
 
 pub async fn run(core: NeobabuCore) -> CoreResult<()> {
-    let today = chrono::Utc::now();
+    let now = chrono::Utc::now();
 
-    let mut user_birthdays = core.stores.user_birthday.stream_all().await?;
+    let mut user_birthdays = core
+        .stores
+        .user_birthday
+        .stream_due(now.naive_utc())
+        .await?;
     let mut count: u32 = 0;
     while let Some(user_birthday) = user_birthdays.next().await {
         let user_birthday = user_birthday?;
         let user_id = user_birthday.user_id.clone();
-        if let Err(err) = handle_user_birthday(&core, user_birthday, today).await {
+        if let Err(err) = handle_user_birthday(&core, user_birthday, now).await {
             error!(
                 "Failed to handle user birthday for user '{}': {err}",
                 user_id
@@ -27,7 +32,9 @@ pub async fn run(core: NeobabuCore) -> CoreResult<()> {
         }
     }
 
-    info!("Successfully processed {count} birthdays");
+    if count > 0 {
+        info!("Successfully processed {count} birthdays");
+    }
 
     Ok(())
 }
@@ -35,21 +42,51 @@ pub async fn run(core: NeobabuCore) -> CoreResult<()> {
 async fn handle_user_birthday(
     core: &NeobabuCore,
     user_birthday: user_birthday::Model,
-    today: DateTime<chrono::Utc>,
+    now: DateTime<chrono::Utc>,
 ) -> CoreResult<()> {
-    let next_birthday = user_birthday.next_birthday.and_utc();
-    if next_birthday > today {
-        return Ok(());
-    }
-
-    let is_belated = next_birthday.date_naive() != today.date_naive();
-    let age = user_birthday
-        .year
-        .map(|year| (today.year() - year as i32) as u8);
+    let user = core
+        .stores
+        .user
+        .fetch_or_create(&user_birthday.user_id)
+        .await?;
+    let timezone = user.timezone().unwrap_or(chrono_tz::UTC);
+    let DueBirthday {
+        date,
+        age,
+        is_belated,
+    } = DueBirthday::new(&user_birthday, timezone, now);
+    let age = age.filter(|_| !user_birthday.hide_age);
 
     core.event_bus
         .send(CoreEvent::birthday_dm(&user_birthday.user_id, is_belated));
 
+    if !user_birthday.opt_out_announcements {
+        let role_expires_at = role_expires_at(date, timezone, now);
+        announce_in_guilds(core, &user_birthday, age, is_belated, role_expires_at).await?;
+    }
+
+    let next_birthday = core.services.birthday.upcoming_birthday(
+        &user,
+        user_birthday.day,
+        user_birthday.month,
+        now,
+    )?;
+    core.stores
+        .user_birthday
+        .set_next_birthday(&user_birthday.user_id, next_birthday.naive_utc())
+        .await?;
+
+    Ok(())
+}
+
+/// Announces the birthday in the user's guilds, skipping the ones they opted out of.
+async fn announce_in_guilds(
+    core: &NeobabuCore,
+    user_birthday: &user_birthday::Model,
+    age: Option<u8>,
+    is_belated: bool,
+    role_expires_at: DateTime<chrono::Utc>,
+) -> CoreResult<()> {
     let mut user_guilds = core
         .stores
         .user_guild
@@ -71,29 +108,53 @@ async fn handle_user_birthday(
             continue;
         }
 
-        let Some(channel_id) = guild_birthday.notification_channel_id else {
+        if guild_birthday.notification_channel_id.is_none()
+            && guild_birthday.notification_role_id.is_none()
+        {
             continue;
-        };
+        }
+
+        if core
+            .stores
+            .birthday_opt_out
+            .exists(&user_birthday.user_id, &user_guild.guild_id)
+            .await?
+        {
+            continue;
+        }
 
+        let template = pick_template(
+            &guild_birthday.announcement_templates(),
+            &guild_birthday.belated_templates(),
+            is_belated,
+            age.is_some(),
+        );
         let event = BirthdayNotification {
             user_id: user_birthday.user_id.to_string(),
             guild_id: user_guild.guild_id,
-            channel_id,
+            channel_id: guild_birthday.notification_channel_id,
+            role_id: guild_birthday.notification_role_id,
+            role_expires_at: role_expires_at.naive_utc(),
             age,
             is_belated,
+            template,
+            image_url: guild_birthday.announcement_image_url,
         };
         core.event_bus.send(CoreEvent::birthday_notification(event));
     }
-    drop(user_guilds);
-
-    let next_birthday = upcoming_date_time(user_birthday.day as u32, user_birthday.month as u32)
-        .ok_or(CoreError::invalid_birthday("Invalid date."))?;
-    let mut active_user_birthday = user_birthday.into_active_model();
-    active_user_birthday.next_birthday = Set(next_birthday.naive_utc());
-    core.stores
-        .user_birthday
-        .update(active_user_birthday)
-        .await?;
 
     Ok(())
 }
+
+/// The birthday role is kept until the birthday ends in the user's timezone,
+/// or for a full day if that moment already passed.
+fn role_expires_at(
+    birthday: NaiveDate,
+    timezone: chrono_tz::Tz,
+    now: DateTime<chrono::Utc>,
+) -> DateTime<chrono::Utc> {
+    match end_of_local_day(birthday, timezone) {
+        Some(end) if end > now => end,
+        _ => now + Duration::hours(24),
+    }
+}
@@ -0,0 +1,42 @@
+use crate::error::CoreResult;
+use crate::events::birthday_role_expired::BirthdayRoleExpired;
+use crate::events::CoreEvent;
+use crate::NeobabuCore;
+use tracing::{error, info};
+
+pub async fn run(core: NeobabuCore) -> CoreResult<()> {
+    let now = chrono::Utc::now().naive_utc();
+
+    let expired = core.stores.birthday_role.find_expired(now).await?;
+    if expired.is_empty() {
+        return Ok(());
+    }
+
+    let mut count: u32 = 0;
+    for birthday_role in expired {
+        if let Err(err) = core
+            .stores
+            .birthday_role
+            .delete(&birthday_role.user_id, &birthday_role.guild_id)
+            .await
+        {
+            error!(
+                "Failed to remove birthday role of user '{}' in guild '{}': {err}",
+                birthday_role.user_id, birthday_role.guild_id
+            );
+            continue;
+        }
+
+        core.event_bus
+            .send(CoreEvent::birthday_role_expired(BirthdayRoleExpired {
+                user_id: birthday_role.user_id,
+                guild_id: birthday_role.guild_id,
+                role_id: birthday_role.role_id,
+            }));
+        count += 1;
+    }
+
+    info!("Removed {count} expired birthday roles");
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use crate::error::CoreResult;
+use crate::events::CoreEvent;
+use crate::NeobabuCore;
+
+pub async fn run(core: NeobabuCore) -> CoreResult<()> {
+    let level_ups = core.services.leveling.flush().await?;
+    for level_up in level_ups {
+        core.event_bus.send(CoreEvent::level_up(level_up));
+    }
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use crate::error::CoreResult;
+use crate::events::CoreEvent;
+use crate::NeobabuCore;
+use futures::StreamExt;
+use tracing::{error, info};
+
+pub async fn run(core: NeobabuCore) -> CoreResult<()> {
+    let now = chrono::Utc::now().naive_utc();
+
+    let mut lotteries = core.stores.guild_lottery.stream_all_enabled().await?;
+    while let Some(settings) = lotteries.next().await {
+        let settings = settings?;
+        let round = match core.services.lottery.open_round(&settings).await {
+            Ok(round) => round,
+            Err(err) => {
+                error!(
+                    "Failed to open lottery round for guild '{}': {err}",
+                    settings.guild_id
+                );
+                continue;
+            }
+        };
+
+        if round.draws_at > now {
+            continue;
+        }
+
+        match core.services.lottery.draw(&settings, &round).await {
+            Ok(Some(drawn)) => {
+                info!(
+                    "Drew lottery round '{}' for guild '{}'",
+                    drawn.round_id, drawn.guild_id
+                );
+                core.event_bus.send(CoreEvent::lottery_drawn(drawn));
+            }
+            Ok(None) => info!("Lottery round '{}' was already drawn", round.id),
+            Err(err) => error!(
+                "Failed to draw lottery for guild '{}': {err}",
+                settings.guild_id
+            ),
+        }
+    }
+
+    // Tickets of disabled lotteries are never drawn, so they are refunded instead.
+    for round in core.stores.lottery_round.find_open_of_disabled().await? {
+        match core
+            .services
+            .lottery
+            .refund_open_round(&round.guild_id)
+            .await
+        {
+            Ok(refunded) => info!(
+                "Refunded {refunded} ticket purchases of disabled lottery in guild '{}'",
+                round.guild_id
+            ),
+            Err(err) => error!(
+                "Failed to refund lottery tickets for guild '{}': {err}",
+                round.guild_id
+            ),
+        }
+    }
+
+    Ok(())
+}
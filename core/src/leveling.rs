@@ -0,0 +1,3 @@
+pub mod activity;
+pub mod progress;
+pub mod rank_card;
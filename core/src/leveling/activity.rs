@@ -0,0 +1,228 @@
+use rand::Rng;
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+pub const XP_COOLDOWN: Duration = Duration::from_secs(60);
+pub const MIN_MESSAGE_LENGTH: usize = 3;
+pub const XP_MIN: i64 = 15;
+pub const XP_MAX: i64 = 25;
+
+/// Activity that was recorded in memory but not yet written to the database.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PendingActivity {
+    pub messages: i64,
+    pub guild_xp: i64,
+    pub global_messages: i64,
+    pub global_xp: i64,
+}
+
+impl PendingActivity {
+    /// The part of the activity which is written to the guild's levels.
+    pub fn guild_part(&self) -> Self {
+        Self {
+            messages: self.messages,
+            guild_xp: self.guild_xp,
+            ..Default::default()
+        }
+    }
+
+    /// The part of the activity which is written to the global levels.
+    pub fn global_part(&self) -> Self {
+        Self {
+            global_messages: self.global_messages,
+            global_xp: self.global_xp,
+            ..Default::default()
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.messages += other.messages;
+        self.guild_xp += other.guild_xp;
+        self.global_messages += other.global_messages;
+        self.global_xp += other.global_xp;
+    }
+}
+
+#[derive(Default)]
+struct UserTracker {
+    guild_awards: HashMap<String, Instant>,
+    global_award: Option<Instant>,
+    last_message_hash: Option<u64>,
+}
+
+#[derive(Default)]
+struct ActivityBufferInner {
+    trackers: HashMap<String, UserTracker>,
+    pending: HashMap<(String, String), PendingActivity>,
+}
+
+/// Collects message activity in memory so the database is only hit once per flush.
+///
+/// Anti-spam rules:
+/// - XP is only awarded once per [`XP_COOLDOWN`] per guild, and once per cooldown globally
+/// - Messages shorter than [`MIN_MESSAGE_LENGTH`] don't award XP
+/// - Repeating the same message back to back doesn't award XP
+#[derive(Default)]
+pub struct ActivityBuffer {
+    inner: Mutex<ActivityBufferInner>,
+}
+
+impl ActivityBuffer {
+    pub fn record(&self, user_id: &str, guild_id: &str, content: &str) {
+        let now = Instant::now();
+        let content = content.trim();
+
+        let mut hasher = DefaultHasher::new();
+        content.to_lowercase().hash(&mut hasher);
+        let content_hash = hasher.finish();
+
+        let mut inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+        let tracker = inner.trackers.entry(user_id.to_string()).or_default();
+
+        let is_repeated = tracker.last_message_hash == Some(content_hash);
+        tracker.last_message_hash = Some(content_hash);
+        let eligible = !is_repeated && content.chars().count() >= MIN_MESSAGE_LENGTH;
+
+        let awards_guild = eligible
+            && tracker
+                .guild_awards
+                .get(guild_id)
+                .is_none_or(|last| now.duration_since(*last) >= XP_COOLDOWN);
+        let awards_global = eligible
+            && tracker
+                .global_award
+                .is_none_or(|last| now.duration_since(last) >= XP_COOLDOWN);
+
+        let xp = rand::rng().random_range(XP_MIN..=XP_MAX);
+        if awards_guild {
+            tracker.guild_awards.insert(guild_id.to_string(), now);
+        }
+        if awards_global {
+            tracker.global_award = Some(now);
+        }
+
+        let pending = inner
+            .pending
+            .entry((user_id.to_string(), guild_id.to_string()))
+            .or_default();
+        pending.messages += 1;
+        pending.global_messages += 1;
+        if awards_guild {
+            pending.guild_xp += xp;
+        }
+        if awards_global {
+            pending.global_xp += xp;
+        }
+    }
+
+    pub fn pending_for(&self, user_id: &str, guild_id: &str) -> PendingActivity {
+        let inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+        inner
+            .pending
+            .get(&(user_id.to_string(), guild_id.to_string()))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn pending_global_xp(&self, user_id: &str) -> i64 {
+        let inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+        inner
+            .pending
+            .iter()
+            .filter(|((pending_user_id, _), _)| pending_user_id == user_id)
+            .map(|(_, pending)| pending.global_xp)
+            .sum()
+    }
+
+    /// Takes all pending activity out of the buffer and forgets trackers which are past their cooldown.
+    pub fn drain(&self) -> HashMap<(String, String), PendingActivity> {
+        let now = Instant::now();
+        let mut inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+
+        inner.trackers.retain(|_, tracker| {
+            tracker
+                .guild_awards
+                .retain(|_, last| now.duration_since(*last) < XP_COOLDOWN);
+            let global_active = tracker
+                .global_award
+                .is_some_and(|last| now.duration_since(last) < XP_COOLDOWN);
+            global_active || !tracker.guild_awards.is_empty()
+        });
+
+        std::mem::take(&mut inner.pending)
+    }
+
+    /// Puts activity which could not be written back into the buffer, so the next flush retries it.
+    pub fn restore(&self, user_id: &str, guild_id: &str, activity: PendingActivity) {
+        let mut inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+        inner
+            .pending
+            .entry((user_id.to_string(), guild_id.to_string()))
+            .or_default()
+            .merge(activity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_awards_xp_once_per_cooldown() {
+        let buffer = ActivityBuffer::default();
+        buffer.record("1", "a", "hello there");
+        buffer.record("1", "a", "anyone around?");
+
+        let first = buffer.pending_for("1", "a");
+        assert_eq!(first.messages, 2);
+        assert!((XP_MIN..=XP_MAX).contains(&first.guild_xp));
+        assert_eq!(first.global_xp, first.guild_xp);
+
+        // Another guild has its own cooldown, the global one is still running.
+        buffer.record("1", "b", "hello again");
+        let second = buffer.pending_for("1", "b");
+        assert!((XP_MIN..=XP_MAX).contains(&second.guild_xp));
+        assert_eq!(second.global_xp, 0);
+        assert_eq!(buffer.pending_global_xp("1"), first.global_xp);
+
+        // Other users are not affected.
+        buffer.record("2", "a", "hello there");
+        assert!(buffer.pending_for("2", "a").guild_xp > 0);
+    }
+
+    #[test]
+    fn test_ignores_short_and_repeated_messages() {
+        let buffer = ActivityBuffer::default();
+        buffer.record("1", "a", " ok ");
+        buffer.record("1", "b", "spam");
+        buffer.record("1", "c", "SPAM");
+
+        assert_eq!(buffer.pending_for("1", "a").guild_xp, 0);
+        assert!(buffer.pending_for("1", "b").guild_xp > 0);
+        let repeated = buffer.pending_for("1", "c");
+        assert_eq!(repeated.messages, 1);
+        assert_eq!(repeated.guild_xp, 0);
+    }
+
+    #[test]
+    fn test_restores_drained_activity() {
+        let buffer = ActivityBuffer::default();
+        buffer.record("1", "a", "hello there");
+        let drained = buffer.drain();
+        let activity = drained[&("1".to_string(), "a".to_string())];
+        assert_eq!(buffer.pending_for("1", "a").messages, 0);
+
+        // The cooldown survives the drain, so only the message is counted.
+        buffer.record("1", "a", "still here");
+        buffer.restore("1", "a", activity.guild_part());
+        buffer.restore("1", "a", activity.global_part());
+
+        let pending = buffer.pending_for("1", "a");
+        assert_eq!(pending.messages, 2);
+        assert_eq!(pending.global_messages, 2);
+        assert_eq!(pending.guild_xp, activity.guild_xp);
+        assert_eq!(pending.global_xp, activity.global_xp);
+    }
+}
@@ -0,0 +1,106 @@
+/// Citrine granted per reached global level, multiplied by the level itself.
+pub const CITRINE_PER_LEVEL: i64 = 10;
+
+/// XP required to advance from `level` to `level + 1`.
+pub fn xp_for_level(level: i32) -> i64 {
+    let level = level.max(0) as i64;
+    5 * level * level + 50 * level + 100
+}
+
+/// Total XP required to reach `level` starting at level 0.
+pub fn total_xp_for_level(level: i32) -> i64 {
+    (0..level.max(0)).map(xp_for_level).sum()
+}
+
+/// Citrine rewarded for advancing from `from_level` to `to_level`.
+pub fn citrine_reward(from_level: i32, to_level: i32) -> i64 {
+    ((from_level + 1)..=to_level)
+        .map(|level| level as i64 * CITRINE_PER_LEVEL)
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelProgress {
+    pub level: i32,
+    pub total_xp: i64,
+    pub level_xp: i64,
+    pub level_xp_required: i64,
+}
+
+impl LevelProgress {
+    pub fn from_xp(total_xp: i64) -> Self {
+        let mut level = 0;
+        let mut remaining = total_xp.max(0);
+        while remaining >= xp_for_level(level) {
+            remaining -= xp_for_level(level);
+            level += 1;
+        }
+
+        Self {
+            level,
+            total_xp,
+            level_xp: remaining,
+            level_xp_required: xp_for_level(level),
+        }
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.level_xp_required <= 0 {
+            return 0.0;
+        }
+        (self.level_xp as f32 / self.level_xp_required as f32).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xp_curve() {
+        assert_eq!(xp_for_level(0), 100);
+        assert_eq!(xp_for_level(1), 155);
+        assert_eq!(xp_for_level(10), 1100);
+        assert_eq!(xp_for_level(-1), 100);
+
+        assert_eq!(total_xp_for_level(0), 0);
+        assert_eq!(total_xp_for_level(3), 100 + 155 + 220);
+        assert!((1..50).all(|level| xp_for_level(level) > xp_for_level(level - 1)));
+    }
+
+    #[test]
+    fn test_progress_within_level() {
+        let start = LevelProgress::from_xp(0);
+        assert_eq!(
+            (start.level, start.level_xp, start.level_xp_required),
+            (0, 0, 100)
+        );
+        assert_eq!(start.fraction(), 0.0);
+
+        let halfway = LevelProgress::from_xp(50);
+        assert_eq!((halfway.level, halfway.level_xp), (0, 50));
+        assert_eq!(halfway.fraction(), 0.5);
+
+        let reached = LevelProgress::from_xp(100);
+        assert_eq!(
+            (reached.level, reached.level_xp, reached.level_xp_required),
+            (1, 0, 155)
+        );
+
+        let xp = total_xp_for_level(12) + 7;
+        let progress = LevelProgress::from_xp(xp);
+        assert_eq!(progress.level, 12);
+        assert_eq!(progress.total_xp, xp);
+        assert_eq!(progress.level_xp, 7);
+        assert_eq!(progress.level_xp_required, xp_for_level(12));
+
+        assert_eq!(LevelProgress::from_xp(-20).level, 0);
+    }
+
+    #[test]
+    fn test_citrine_reward() {
+        assert_eq!(citrine_reward(0, 1), 10);
+        assert_eq!(citrine_reward(0, 3), 10 + 20 + 30);
+        assert_eq!(citrine_reward(4, 4), 0);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::error::CoreResult;
+use crate::leveling::progress::LevelProgress;
+use crate::rendering::o2d::prelude::{
+    O2DRenderable, O2DRenderer, Object2D, PositionO2D, TextVisual,
+};
+use image::Rgba;
+use std::io::Cursor;
+
+const TILE_SIZE: u8 = 4;
+const WIDTH: u8 = 50;
+const HEIGHT: u8 = 20;
+const PADDING: u8 = 2;
+const CHAR_WIDTH: u32 = 6;
+const MAX_NAME_LENGTH: usize = 24;
+
+const BACKGROUND: Rgba<u8> = Rgba([35, 32, 45, 255]);
+const BAR_EMPTY: Rgba<u8> = Rgba([60, 56, 74, 255]);
+const BAR_GUILD: Rgba<u8> = Rgba([172, 181, 101, 255]);
+const BAR_GLOBAL: Rgba<u8> = Rgba([224, 180, 84, 255]);
+const TEXT: Rgba<u8> = Rgba([217, 211, 217, 255]);
+const TEXT_MUTED: Rgba<u8> = Rgba([140, 134, 150, 255]);
+
+pub struct RankCard {
+    pub name: String,
+    pub rank: Option<u64>,
+    pub guild: LevelProgress,
+    pub global: LevelProgress,
+}
+
+impl RankCard {
+    pub fn render_png(&self, o2d: &O2DRenderer) -> CoreResult<Vec<u8>> {
+        let image = o2d.render(std::slice::from_ref(self), HEIGHT, WIDTH, TILE_SIZE)?;
+        let scaled = image::imageops::resize(
+            &image,
+            image.width() * 3,
+            image.height() * 3,
+            image::imageops::FilterType::Nearest,
+        );
+        drop(image);
+
+        let mut bytes = Cursor::new(Vec::new());
+        scaled.write_to(&mut bytes, image::ImageFormat::Png)?;
+
+        let opts = oxipng::Options::default();
+        let optimized = oxipng::optimize_from_memory(&bytes.into_inner(), &opts)?;
+
+        Ok(optimized)
+    }
+
+    fn left_text(text: impl Into<String>, color: Rgba<u8>, tile_y: u8) -> Object2D {
+        Object2D::text(
+            TextVisual::new(text, color),
+            PositionO2D::from_tile_xy(PADDING, tile_y),
+        )
+    }
+
+    fn right_text(text: impl Into<String>, color: Rgba<u8>, tile_y: u8) -> Object2D {
+        let text = text.into();
+        let text_width = text.chars().count() as u32 * CHAR_WIDTH;
+        let x = ((WIDTH - PADDING) as u32 * TILE_SIZE as u32).saturating_sub(text_width);
+        Object2D::text(
+            TextVisual::new(text, color),
+            PositionO2D::from_tile_xy((x / TILE_SIZE as u32) as u8, tile_y)
+                .with_offsets((x % TILE_SIZE as u32) as i8, 0),
+        )
+    }
+
+    fn progress_bar(progress: &LevelProgress, color: Rgba<u8>, tile_y: u8) -> Vec<Object2D> {
+        let length = WIDTH - PADDING * 2;
+        let filled = (progress.fraction() * length as f32).round() as u8;
+        (0..length)
+            .map(|i| {
+                let color = if i < filled { color } else { BAR_EMPTY };
+                Object2D::color(color, PositionO2D::from_tile_xy(PADDING + i, tile_y))
+            })
+            .collect()
+    }
+
+    fn progress_section(
+        label: &str,
+        progress: &LevelProgress,
+        color: Rgba<u8>,
+        tile_y: u8,
+    ) -> Vec<Object2D> {
+        let mut objects = vec![
+            Self::left_text(format!("{label} LV {}", progress.level), TEXT, tile_y),
+            Self::right_text(
+                format!("{}/{} XP", progress.level_xp, progress.level_xp_required),
+                TEXT_MUTED,
+                tile_y,
+            ),
+        ];
+        objects.extend(Self::progress_bar(progress, color, tile_y + 1));
+        objects
+    }
+}
+
+impl O2DRenderable for RankCard {
+    fn to_objects(&self) -> Vec<Object2D> {
+        let mut objects = Vec::new();
+
+        for x in 0..WIDTH {
+            for y in 0..HEIGHT {
+                objects.push(Object2D::color(BACKGROUND, PositionO2D::from_tile_xy(x, y)));
+            }
+        }
+
+        let name: String = self.name.chars().take(MAX_NAME_LENGTH).collect();
+        objects.push(Self::left_text(name, TEXT, 5));
+        if let Some(rank) = self.rank {
+            objects.push(Self::right_text(format!("#{rank}"), BAR_GUILD, 5));
+        }
+
+        objects.extend(Self::progress_section("SERVER", &self.guild, BAR_GUILD, 10));
+        objects.extend(Self::progress_section(
+            "GLOBAL",
+            &self.global,
+            BAR_GLOBAL,
+            16,
+        ));
+
+        objects
+    }
+}
@@ -4,6 +4,8 @@ use crate::error::CoreResult;
 use crate::integrations::apis::Apis;
 use std::sync::Arc;
 
+pub mod achievements;
+pub mod birthday;
 pub mod config;
 pub mod cryptor;
 pub mod database;
@@ -13,6 +15,7 @@ pub mod games;
 pub mod integrations;
 pub mod inventory;
 pub mod jobs;
+pub mod leveling;
 pub mod rendering;
 pub mod services;
 pub mod stores;
@@ -36,7 +39,7 @@ impl NeobabuCore {
         let db = Database::initialize(&config).await?;
         let event_bus = events::CoreEventBus::initialize();
         let stores = stores::Stores::initialize(&db);
-        let services = services::Services::initialize(&apis, &stores);
+        let services = services::Services::initialize(&apis, &config, &event_bus, &stores);
         Ok(Self {
             apis,
             config,
@@ -22,6 +22,7 @@ pub enum AtlasId {
     FarmingIcons,
     FarmingObjects,
     FarmingTileset,
+    PlayingCards,
 }
 
 impl AtlasId {
@@ -31,6 +32,7 @@ impl AtlasId {
             Self::FarmingIcons => include_bytes!("../../../assets/farming_icons.png"),
             Self::FarmingObjects => include_bytes!("../../../assets/farming_objects.png"),
             Self::FarmingTileset => include_bytes!("../../../assets/farming_tileset.png"),
+            Self::PlayingCards => include_bytes!("../../../assets/playing_cards.png"),
         }
     }
 
@@ -7,6 +7,7 @@ pub struct AtlasCache {
     pub farming_icons: Atlas,
     pub farming_objects: Atlas,
     pub farming_tileset: Atlas,
+    pub playing_cards: Atlas,
 }
 
 impl AtlasCache {
@@ -16,6 +17,7 @@ impl AtlasCache {
             farming_icons: AtlasId::FarmingIcons.load()?,
             farming_objects: AtlasId::FarmingObjects.load()?,
             farming_tileset: AtlasId::FarmingTileset.load()?,
+            playing_cards: AtlasId::PlayingCards.load()?,
         };
         Ok(Arc::new(cache))
     }
@@ -26,6 +28,7 @@ impl AtlasCache {
             AtlasId::FarmingIcons => &self.farming_icons,
             AtlasId::FarmingObjects => &self.farming_objects,
             AtlasId::FarmingTileset => &self.farming_tileset,
+            AtlasId::PlayingCards => &self.playing_cards,
         })
     }
 }
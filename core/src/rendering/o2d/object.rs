@@ -1,5 +1,6 @@
 use crate::rendering::o2d::object::position::PositionO2D;
 use crate::rendering::o2d::object::visual::{TextVisual, VisualO2D};
+use crate::rendering::o2d::sprite::SpriteId;
 
 pub mod grid;
 pub mod layer;
@@ -20,6 +21,20 @@ impl Object2D {
         }
     }
 
+    pub fn color(color: image::Rgba<u8>, position: PositionO2D) -> Self {
+        Self {
+            visual: VisualO2D::Color(color),
+            position,
+        }
+    }
+
+    pub fn sprite(sprite_id: SpriteId, position: PositionO2D) -> Self {
+        Self {
+            visual: VisualO2D::Sprite(sprite_id),
+            position,
+        }
+    }
+
     pub fn text(text: TextVisual, position: PositionO2D) -> Self {
         Self {
             visual: VisualO2D::Text(text),
@@ -28,6 +28,7 @@ impl O2DGrid {
         self.grid.values().flatten()
     }
 
+    #[allow(dead_code)]
     pub fn add_object(&mut self, object: Object2D) {
         let key = (object.position.tile_x, object.position.tile_y);
         self.grid.entry(key).or_default().push(object);
@@ -37,6 +38,7 @@ impl O2DGrid {
         self.grid.get(&(tile_x, tile_y)).map(|v| v.as_slice())
     }
 
+    #[allow(dead_code)]
     pub fn get_tile_at(&self, tile_x: u8, tile_y: u8, tileset_id: TilesetId) -> Option<&Object2D> {
         self.get_at(tile_x, tile_y).and_then(|objects| {
             objects
@@ -45,6 +47,7 @@ impl O2DGrid {
         })
     }
 
+    #[allow(dead_code)]
     pub fn has_tile_at(&self, tile_x: u8, tile_y: u8, tileset_id: TilesetId) -> bool {
         if let Some(objects) = self.get_at(tile_x, tile_y) {
             objects
@@ -5,5 +5,5 @@ pub use super::object::{
     Object2D,
 };
 pub use super::render::{O2DRenderable, O2DRenderer};
-pub use super::sprite::SpriteId;
+pub use super::sprite::{SpriteId, CARD_HEIGHT, CARD_WIDTH};
 pub use super::tileset::TilesetId;
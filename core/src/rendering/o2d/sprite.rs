@@ -1,5 +1,10 @@
+use crate::games::playing_cards::PlayingCard;
 use crate::rendering::o2d::atlas::AtlasId;
 
+/// Size of a single card in the playing card atlas.
+pub const CARD_WIDTH: u8 = 32;
+pub const CARD_HEIGHT: u8 = 48;
+
 #[derive(Debug, Clone, Copy)]
 pub struct Rect {
     pub x: u32,
@@ -42,6 +47,8 @@ pub enum SpriteId {
     Carrot3,
     Carrot4,
     Carrot5,
+    PlayingCard(PlayingCard),
+    PlayingCardBack,
     SoilHole1,
     SoilHole2,
     SoilHole1Watered,
@@ -78,6 +85,18 @@ impl SpriteId {
                 rect: Rect::from_tiled(4, 0, 16, 32),
                 y_sort_offset: 0,
             },
+            // The atlas has a row per suit in the order of `PlayingCard`, from 2 to ace,
+            // followed by a row of card backs.
+            Self::PlayingCard(card) => Sprite {
+                atlas_id: AtlasId::PlayingCards,
+                rect: Rect::from_tiled(card.rank(), *card as u8 / 13, CARD_WIDTH, CARD_HEIGHT),
+                y_sort_offset: 0,
+            },
+            Self::PlayingCardBack => Sprite {
+                atlas_id: AtlasId::PlayingCards,
+                rect: Rect::from_tiled(0, 4, CARD_WIDTH, CARD_HEIGHT),
+                y_sort_offset: 0,
+            },
             Self::SoilHole1 => Sprite {
                 atlas_id: AtlasId::FarmingTileset,
                 rect: Rect::from_tiled(12, 0, 16, 16),
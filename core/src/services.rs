@@ -1,37 +1,79 @@
+use crate::config::Config;
+use crate::events::CoreEventBus;
 use crate::integrations::apis::Apis;
 use crate::stores::Stores;
 use std::sync::Arc;
 
+mod achievement;
+mod active_game;
 mod apod;
 mod birthday;
 mod blackjack;
+mod connect4;
 mod economy;
 mod farming;
+mod game_rating;
+mod game_record;
+mod leveling;
+mod lottery;
+mod puzzle;
 mod rock_paper_scissors;
 mod user;
+mod wager;
 mod youtube;
 
 pub struct Services {
+    pub achievement: Arc<achievement::AchievementService>,
+    pub active_game: Arc<active_game::ActiveGameService>,
     pub apod: Arc<apod::ApodService>,
     pub birthday: Arc<birthday::BirthdayService>,
     pub blackjack: Arc<blackjack::BlackjackService>,
+    pub connect4: Arc<connect4::Connect4Service>,
     pub economy: Arc<economy::EconomyService>,
     pub farming: Arc<farming::FarmingService>,
+    pub game_rating: Arc<game_rating::GameRatingService>,
+    pub game_record: Arc<game_record::GameRecordService>,
+    pub leveling: Arc<leveling::LevelingService>,
+    pub lottery: Arc<lottery::LotteryService>,
+    pub puzzle: Arc<puzzle::PuzzleService>,
     pub rps: Arc<rock_paper_scissors::RockPaperScissorsService>,
     pub user: Arc<user::UserService>,
+    pub wager: Arc<wager::WagerService>,
     pub youtube: Arc<youtube::YoutubeService>,
 }
 
 impl Services {
-    pub fn initialize(apis: &Arc<Apis>, stores: &Arc<Stores>) -> Arc<Self> {
+    pub fn initialize(
+        apis: &Arc<Apis>,
+        config: &Arc<Config>,
+        event_bus: &Arc<CoreEventBus>,
+        stores: &Arc<Stores>,
+    ) -> Arc<Self> {
+        let achievement = achievement::AchievementService::initialize(event_bus, stores);
+        let blackjack = blackjack::BlackjackService::initialize(stores, &achievement);
+        let connect4 = connect4::Connect4Service::initialize(stores, &achievement);
+        let farming = farming::FarmingService::initialize(stores, &achievement);
+        let rps = rock_paper_scissors::RockPaperScissorsService::initialize(stores);
+        let wager = wager::WagerService::initialize(stores);
         Arc::new(Self {
+            achievement,
+            active_game: active_game::ActiveGameService::initialize(
+                stores, &blackjack, &connect4, &rps, &wager,
+            ),
             apod: apod::ApodService::initialize(apis, stores),
-            birthday: birthday::BirthdayService::initialize(stores),
-            blackjack: blackjack::BlackjackService::initialize(stores),
+            birthday: birthday::BirthdayService::initialize(config, stores),
+            blackjack,
+            connect4,
             economy: economy::EconomyService::initialize(stores),
-            farming: farming::FarmingService::initialize(stores),
-            rps: rock_paper_scissors::RockPaperScissorsService::initialize(stores),
+            farming,
+            game_rating: game_rating::GameRatingService::initialize(stores),
+            game_record: game_record::GameRecordService::initialize(stores),
+            leveling: leveling::LevelingService::initialize(stores),
+            lottery: lottery::LotteryService::initialize(stores),
+            puzzle: puzzle::PuzzleService::initialize(stores),
+            rps,
             user: user::UserService::initialize(stores),
+            wager,
             youtube: youtube::YoutubeService::initialize(apis, stores),
         })
     }
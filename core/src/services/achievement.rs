@@ -0,0 +1,76 @@
+use crate::achievements::{Achievement, AchievementEvent};
+use crate::database::entity::user;
+use crate::error::CoreResult;
+use crate::events::achievement_unlocked::AchievementUnlocked;
+use crate::events::{CoreEvent, CoreEventBus};
+use std::collections::HashSet;
+use std::sync::Arc;
+use strum::IntoEnumIterator;
+use tracing::error;
+
+pub struct AchievementService {
+    event_bus: Arc<CoreEventBus>,
+    stores: Arc<crate::stores::Stores>,
+}
+
+impl AchievementService {
+    pub fn initialize(
+        event_bus: &Arc<CoreEventBus>,
+        stores: &Arc<crate::stores::Stores>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            event_bus: event_bus.clone(),
+            stores: stores.clone(),
+        })
+    }
+
+    /// Unlocks every achievement whose condition the event meets and that the user does not have
+    /// yet, pays out its reward and announces it. Returns the newly unlocked achievements.
+    pub async fn track(
+        &self,
+        user: &user::Model,
+        event: AchievementEvent,
+    ) -> CoreResult<Vec<Achievement>> {
+        let unlocked = self
+            .stores
+            .achievement_unlock
+            .find_by_user_id(&user.id)
+            .await?
+            .iter()
+            .filter_map(|unlock| unlock.achievement())
+            .collect::<HashSet<_>>();
+
+        let mut newly_unlocked = Vec::new();
+        for achievement in Achievement::iter() {
+            if unlocked.contains(&achievement) || !achievement.is_met(&event) {
+                continue;
+            }
+
+            let unlocked = self
+                .stores
+                .achievement_unlock
+                .unlock(&self.stores.economy, user, achievement)
+                .await?;
+            if !unlocked {
+                continue;
+            }
+
+            self.event_bus
+                .send(CoreEvent::achievement_unlocked(AchievementUnlocked {
+                    user_id: user.id.clone(),
+                    achievement,
+                }));
+            newly_unlocked.push(achievement);
+        }
+
+        Ok(newly_unlocked)
+    }
+
+    /// Like [`Self::track`], but a failure is only logged. Used after games, where it must not
+    /// keep the wagers from being settled.
+    pub async fn track_or_log(&self, user: &user::Model, event: AchievementEvent) {
+        if let Err(err) = self.track(user, event).await {
+            error!("Failed to track achievements of user '{}': {err}", user.id);
+        }
+    }
+}
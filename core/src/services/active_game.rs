@@ -0,0 +1,89 @@
+use crate::database::entity::active_game;
+use crate::error::CoreResult;
+use crate::games::persistence::PersistentGame;
+use crate::games::turn_based::TurnBasedWagers;
+use crate::services::blackjack::BlackjackService;
+use crate::services::connect4::Connect4Service;
+use crate::services::rock_paper_scissors::RockPaperScissorsService;
+use crate::services::wager::WagerService;
+use crate::types::active_game_kind::ActiveGameKind;
+use std::sync::Arc;
+use tracing::warn;
+
+pub struct ActiveGameService {
+    stores: Arc<crate::stores::Stores>,
+    blackjack: Arc<BlackjackService>,
+    connect4: Arc<Connect4Service>,
+    rps: Arc<RockPaperScissorsService>,
+    wager: Arc<WagerService>,
+}
+
+impl ActiveGameService {
+    pub fn initialize(
+        stores: &Arc<crate::stores::Stores>,
+        blackjack: &Arc<BlackjackService>,
+        connect4: &Arc<Connect4Service>,
+        rps: &Arc<RockPaperScissorsService>,
+        wager: &Arc<WagerService>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            stores: stores.clone(),
+            blackjack: blackjack.clone(),
+            connect4: connect4.clone(),
+            rps: rps.clone(),
+            wager: wager.clone(),
+        })
+    }
+
+    /// Stores the current state of a running game together with the message it is played in.
+    pub async fn save<G: PersistentGame>(
+        &self,
+        game: &G,
+        channel_id: impl AsRef<str>,
+        message_id: impl AsRef<str>,
+    ) -> CoreResult<()> {
+        let state = serde_json::to_value(game)?;
+        self.stores
+            .active_game
+            .save(game.id(), G::KIND, channel_id, message_id, state)
+            .await
+    }
+
+    /// Forgets a game once it is over and its wagers are settled.
+    pub async fn remove<G: PersistentGame>(&self, game: &G) -> CoreResult<()> {
+        self.stores.active_game.delete(game.id()).await?;
+        Ok(())
+    }
+
+    /// Voids every game which was still running when the bot stopped and refunds its wagers.
+    /// The voided games are returned, so that their messages can be updated. Games whose refund
+    /// failed are kept and retried on the next start.
+    pub async fn void_all(&self) -> CoreResult<Vec<active_game::Model>> {
+        let mut voided = Vec::new();
+        for game in self.stores.active_game.find_all().await? {
+            if let Err(err) = self.refund(&game).await {
+                warn!("Failed to refund the wagers of game '{}': {err}", game.id);
+                continue;
+            }
+            self.stores.active_game.delete(game.id).await?;
+            voided.push(game);
+        }
+
+        Ok(voided)
+    }
+
+    async fn refund(&self, game: &active_game::Model) -> CoreResult<()> {
+        match game.kind() {
+            Some(ActiveGameKind::Blackjack) => self.blackjack.abandon_game(&game.game()?).await,
+            Some(ActiveGameKind::Connect4) => self.connect4.cancel_wagers(&game.game()?).await,
+            Some(ActiveGameKind::RockPaperScissors) => self.rps.cancel_wagers(&game.game()?).await,
+            Some(ActiveGameKind::TurnBased) => {
+                let wagers: TurnBasedWagers = game.game()?;
+                self.wager
+                    .cancel_all(&wagers.reference_id, &wagers.players)
+                    .await
+            }
+            Some(ActiveGameKind::RockPaperScissorsTournament) | None => Ok(()),
+        }
+    }
+}
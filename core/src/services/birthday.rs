@@ -1,8 +1,11 @@
+use crate::birthday::calendar::BirthdayCalendar;
+use crate::config::Config;
 use crate::database::entity::{user, user_birthday};
 use crate::error::{CoreError, CoreResult};
 use crate::stores::Stores;
-use crate::utils::dates::upcoming_date_time;
-use chrono::Duration;
+use crate::utils::dates::upcoming_birthday;
+use chrono::{DateTime, Duration, Utc};
+use futures::StreamExt;
 use sea_orm::{IntoActiveModel, Set};
 use std::ops::Add;
 use std::sync::Arc;
@@ -10,12 +13,14 @@ use std::sync::Arc;
 const BIRTHDAY_UPDATE_TIMEOUT_HOURS: i64 = 24 * 270;
 
 pub struct BirthdayService {
+    config: Arc<Config>,
     stores: Arc<Stores>,
 }
 
 impl BirthdayService {
-    pub fn initialize(stores: &Arc<Stores>) -> Arc<Self> {
+    pub fn initialize(config: &Arc<Config>, stores: &Arc<Stores>) -> Arc<Self> {
         Arc::new(Self {
+            config: config.clone(),
             stores: stores.clone(),
         })
     }
@@ -55,8 +60,7 @@ impl BirthdayService {
         year: Option<i16>,
     ) -> CoreResult<()> {
         self.validate_birthday(day, month, year)?;
-        let upcoming_birthday = upcoming_date_time(day as u32, month as u32)
-            .ok_or(CoreError::invalid_birthday("Invalid date."))?;
+        let upcoming_birthday = self.upcoming_birthday(user, day, month, Utc::now())?;
 
         if let Some(user_birthday) = self.stores.user_birthday.find_by_user_id(&user.id).await? {
             if !self.can_update(&user_birthday) {
@@ -83,4 +87,60 @@ impl BirthdayService {
 
         Ok(())
     }
+
+    /// The next time the birthday is announced, at the configured hour in the user's timezone or UTC.
+    pub fn upcoming_birthday(
+        &self,
+        user: &user::Model,
+        day: i16,
+        month: i16,
+        now: DateTime<Utc>,
+    ) -> CoreResult<DateTime<Utc>> {
+        upcoming_birthday(
+            day as u32,
+            month as u32,
+            self.config.birthday_notification_hour(),
+            user.timezone().unwrap_or(chrono_tz::UTC),
+            now,
+        )
+        .ok_or(CoreError::invalid_birthday("Invalid date."))
+    }
+
+    /// Moves the next announcement to the user's current timezone, e.g. after it changed.
+    pub async fn reschedule(&self, user: &user::Model) -> CoreResult<()> {
+        let Some(user_birthday) = self.stores.user_birthday.find_by_user_id(&user.id).await? else {
+            return Ok(());
+        };
+
+        let next_birthday =
+            self.upcoming_birthday(user, user_birthday.day, user_birthday.month, Utc::now())?;
+        self.stores
+            .user_birthday
+            .set_next_birthday(&user.id, next_birthday.naive_utc())
+            .await?;
+
+        Ok(())
+    }
+
+    /// The birthdays of the guild's members celebrated in the given month.
+    pub async fn calendar(
+        &self,
+        guild_id: impl AsRef<str>,
+        year: i32,
+        month: u32,
+    ) -> CoreResult<BirthdayCalendar> {
+        let mut calendar =
+            BirthdayCalendar::new(year, month).ok_or(CoreError::InvalidMonth(month))?;
+
+        let mut user_birthdays = self
+            .stores
+            .user_birthday
+            .stream_by_guild(guild_id, None, None)
+            .await?;
+        while let Some(user_birthday) = user_birthdays.next().await {
+            calendar.add(&user_birthday?);
+        }
+
+        Ok(calendar)
+    }
 }
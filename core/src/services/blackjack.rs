@@ -1,29 +1,174 @@
-use crate::database::entity::user;
+use crate::achievements::AchievementEvent;
+use crate::database::entity::{guild_blackjack, user};
 use crate::error::CoreResult;
-use crate::games::blackjack::{BlackjackGame, BlackjackOutcome};
+use crate::games::blackjack::shoe::BlackjackShoe;
+use crate::games::blackjack::{BlackjackGame, BlackjackHand, BlackjackMove, BlackjackOutcome};
+use crate::games::rng::GameRng;
+use crate::services::achievement::AchievementService;
 use crate::types::currency::Currency;
 use sea_orm::{IntoActiveModel, Set};
 use std::sync::Arc;
 use std::time::Duration;
 
 pub struct BlackjackService {
+    achievement: Arc<AchievementService>,
     stores: Arc<crate::stores::Stores>,
 }
 
 impl BlackjackService {
-    pub fn initialize(stores: &Arc<crate::stores::Stores>) -> Arc<Self> {
+    pub fn initialize(
+        stores: &Arc<crate::stores::Stores>,
+        achievement: &Arc<AchievementService>,
+    ) -> Arc<Self> {
         Arc::new(Self {
+            achievement: achievement.clone(),
             stores: stores.clone(),
         })
     }
 
+    /// Sets up a game with the house rules of the guild, dealt from the shoe its table left off
+    /// with. A fresh shoe is used if there is none or the rules have changed since. The shoe is
+    /// put back on the table with [`Self::return_shoe`] however the game ends.
+    pub async fn new_game(&self, settings: &guild_blackjack::Model) -> CoreResult<BlackjackGame> {
+        let rules = settings.preset().rules();
+        let game = BlackjackGame::new().with_rules(rules);
+        let shoe = self
+            .stores
+            .guild_blackjack
+            .take_shoe(&settings.guild_id)
+            .await?
+            .and_then(|shoe| serde_json::from_value::<BlackjackShoe>(shoe).ok())
+            .filter(|shoe| shoe.fits(&rules))
+            .unwrap_or_else(|| BlackjackShoe::new(&rules, GameRng::new()));
+
+        let mut game = game.with_shoe(shoe);
+        game.guild_id = Some(settings.guild_id.clone());
+        Ok(game)
+    }
+
+    /// Puts the shoe of a game back on the table of its guild for the next game.
+    pub async fn return_shoe(&self, game: &BlackjackGame) -> CoreResult<()> {
+        let Some(guild_id) = &game.guild_id else {
+            return Ok(());
+        };
+
+        self.stores
+            .guild_blackjack
+            .save_shoe(guild_id, serde_json::to_value(&game.shoe)?)
+            .await
+    }
+
+    /// Ends a game which cannot be finished anymore, e.g. because it timed out or was voided.
+    /// Its wagers are released and its shoe is put back on the table.
+    pub async fn abandon_game(&self, game: &BlackjackGame) -> CoreResult<()> {
+        self.cancel_wagers(game).await?;
+        self.return_shoe(game).await
+    }
+
+    /// Every hand is wagered under its own reference, so that it can be settled separately.
+    pub fn reference_id(game: &BlackjackGame, hand: usize) -> String {
+        if hand == 0 {
+            format!("bj-{}", game.id)
+        } else {
+            format!("bj-{}-{hand}", game.id)
+        }
+    }
+
+    pub fn insurance_reference_id(game: &BlackjackGame) -> String {
+        format!("bj-{}-insurance", game.id)
+    }
+
+    /// Plays a move for the given user and reserves the additional wager of a double down,
+    /// split or insurance. Returns false if the move is not possible or cannot be afforded.
+    pub async fn play(
+        &self,
+        game: &mut BlackjackGame,
+        user: &user::Model,
+        move_: BlackjackMove,
+    ) -> CoreResult<bool> {
+        if !game.can_play(&user.id, move_) {
+            return Ok(false);
+        }
+
+        let (Some(wager), Some(player)) = (game.wager, game.players.get(&user.id)) else {
+            return Ok(game.play(&user.id, move_));
+        };
+
+        let reserved = match move_ {
+            BlackjackMove::Hit | BlackjackMove::Stand | BlackjackMove::Surrender => true,
+            BlackjackMove::DoubleDown => {
+                self.stores
+                    .economy
+                    .extend(
+                        Self::reference_id(game, player.current_hand),
+                        user,
+                        Currency::Citrine,
+                        wager as i64,
+                    )
+                    .await?
+            }
+            BlackjackMove::Split => {
+                self.stores
+                    .economy
+                    .reserve(
+                        Self::reference_id(game, player.hands.len()),
+                        Duration::from_mins(20),
+                        user,
+                        Currency::Citrine,
+                        wager as i64,
+                    )
+                    .await?
+            }
+            BlackjackMove::Insurance => {
+                self.stores
+                    .economy
+                    .reserve(
+                        Self::insurance_reference_id(game),
+                        Duration::from_mins(20),
+                        user,
+                        Currency::Citrine,
+                        game.insurance_cost().unwrap_or_default() as i64,
+                    )
+                    .await?
+            }
+        };
+
+        Ok(reserved && game.play(&user.id, move_))
+    }
+
     pub async fn resolve_game(&self, game: &BlackjackGame) -> CoreResult<()> {
         let Some(outcomes) = game.get_outcomes() else {
             return Ok(());
         };
 
-        for (id, outcome) in outcomes {
-            self.resolve_player(game, id, outcome).await?;
+        for (id, outcomes) in outcomes {
+            self.resolve_player(game, id, outcomes).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Releases every wager held for the game, e.g. when it is voided before it is over.
+    pub async fn cancel_wagers(&self, game: &BlackjackGame) -> CoreResult<()> {
+        if game.wager.is_none() {
+            return Ok(());
+        }
+
+        for (player_id, player) in &game.players {
+            let user = self.stores.user.fetch_or_create(player_id).await?;
+            for index in 0..player.hands.len() {
+                self.stores
+                    .economy
+                    .cancel(Self::reference_id(game, index), &user, Currency::Citrine)
+                    .await?;
+            }
+
+            if player.insured {
+                self.stores
+                    .economy
+                    .cancel(Self::insurance_reference_id(game), &user, Currency::Citrine)
+                    .await?;
+            }
         }
 
         Ok(())
@@ -33,35 +178,65 @@ impl BlackjackService {
         &self,
         game: &BlackjackGame,
         player_id: String,
-        outcome: BlackjackOutcome,
+        outcomes: Vec<BlackjackOutcome>,
     ) -> CoreResult<()> {
         let Some(player) = game.players.get(&player_id) else {
             return Ok(());
         };
 
         let user = self.stores.user.fetch_or_create(&player_id).await?;
+        for (index, (hand, outcome)) in player.hands.iter().zip(outcomes).enumerate() {
+            self.resolve_hand(game, &user, index, hand, outcome).await?;
+        }
+
+        if let Some(outcome) = game.get_insurance_outcome(&player_id) {
+            self.resolve_insurance(game, &user, outcome).await?;
+        }
+
         let bj_user = self.stores.bj_user.fetch_or_create(&user).await?;
+        self.achievement
+            .track_or_log(&user, AchievementEvent::BlackjackResolved(bj_user))
+            .await;
+
+        Ok(())
+    }
+
+    /// Every hand counts as a game of its own in the stats, a surrendered hand as a loss.
+    async fn resolve_hand(
+        &self,
+        game: &BlackjackGame,
+        user: &user::Model,
+        index: usize,
+        hand: &BlackjackHand,
+        outcome: BlackjackOutcome,
+    ) -> CoreResult<()> {
+        let bj_user = self.stores.bj_user.fetch_or_create(user).await?;
         let mut active = bj_user.clone().into_active_model();
+        let reference_id = Self::reference_id(game, index);
+        let stake = game.wager.map(|wager| hand.stake(wager) as i64);
+        let settlement = game
+            .wager
+            .map(|wager| game.rules.settlement(hand.stake(wager), &outcome) as i64);
 
         active.dealer_score_total = Set(bj_user
             .dealer_score_total
             .saturating_add(game.dealer.score() as i64));
 
-        if player.standing {
+        if hand.standing && !hand.is_bust() {
             active.times_final_stand = Set(bj_user.times_final_stand.saturating_add(1));
             active.final_stand_score_total = Set(bj_user
                 .final_stand_score_total
-                .saturating_add(player.score() as i64));
+                .saturating_add(hand.score() as i64));
         }
 
-        if player.is_bust() {
+        if hand.is_bust() {
             active.times_final_hit = Set(bj_user.times_final_hit.saturating_add(1));
             active.final_hit_score_total = Set(bj_user
                 .final_hit_score_total
-                .saturating_add(player.score() as i64))
+                .saturating_add(hand.score() as i64))
         }
 
-        if player.score() == 21 {
+        if hand.score() == 21 {
             active.blackjack_count = Set(bj_user.blackjack_count.saturating_add(1));
             active.blackjack_streak = Set(bj_user.blackjack_streak.saturating_add(1));
             if bj_user.blackjack_streak >= bj_user.longest_blackjack_streak {
@@ -72,7 +247,7 @@ impl BlackjackService {
         }
 
         match outcome {
-            BlackjackOutcome::Win => {
+            BlackjackOutcome::Win | BlackjackOutcome::Blackjack => {
                 active.wins = Set(bj_user.wins.saturating_add(1));
                 active.win_streak = Set(bj_user.win_streak.saturating_add(1));
                 if bj_user.win_streak >= bj_user.longest_win_streak {
@@ -85,23 +260,22 @@ impl BlackjackService {
                     active.draw_streak = Set(0);
                 }
 
-                if let Some(wager) = game.wager {
+                if let (Some(stake), Some(winnings)) = (stake, settlement) {
                     active.total_citrine_wagered =
-                        Set(bj_user.total_citrine_wagered.saturating_add(wager as i64));
+                        Set(bj_user.total_citrine_wagered.saturating_add(stake));
                     active.total_citrine_won =
-                        Set(bj_user.total_citrine_won.saturating_add(wager as i64));
-                    let reference_id = format!("bj-{}", game.id);
+                        Set(bj_user.total_citrine_won.saturating_add(winnings));
                     self.stores
                         .economy
-                        .cancel(&reference_id, &user, Currency::Citrine)
+                        .cancel(&reference_id, user, Currency::Citrine)
                         .await?;
                     self.stores
                         .economy
-                        .add(&user, Currency::Citrine, wager as i64)
+                        .add(user, Currency::Citrine, winnings)
                         .await?;
                 }
             }
-            BlackjackOutcome::Loss => {
+            BlackjackOutcome::Loss | BlackjackOutcome::Surrender => {
                 active.losses = Set(bj_user.losses.saturating_add(1));
                 active.loss_streak = Set(bj_user.loss_streak.saturating_add(1));
                 if bj_user.loss_streak >= bj_user.longest_loss_streak {
@@ -114,15 +288,14 @@ impl BlackjackService {
                     active.draw_streak = Set(0);
                 }
 
-                if let Some(wager) = game.wager {
+                if let (Some(stake), Some(loss)) = (stake, settlement) {
                     active.total_citrine_wagered =
-                        Set(bj_user.total_citrine_wagered.saturating_add(wager as i64));
+                        Set(bj_user.total_citrine_wagered.saturating_add(stake));
                     active.total_citrine_lost =
-                        Set(bj_user.total_citrine_lost.saturating_add(wager as i64));
-                    let reference_id = format!("bj-{}", game.id);
+                        Set(bj_user.total_citrine_lost.saturating_add(loss));
                     self.stores
                         .economy
-                        .commit(&reference_id, &user, Currency::Citrine)
+                        .commit_partial(&reference_id, user, Currency::Citrine, loss)
                         .await?;
                 }
             }
@@ -139,13 +312,12 @@ impl BlackjackService {
                     active.loss_streak = Set(0);
                 }
 
-                if let Some(wager) = game.wager {
+                if let Some(stake) = stake {
                     active.total_citrine_wagered =
-                        Set(bj_user.total_citrine_wagered.saturating_add(wager as i64));
-                    let reference_id = format!("bj-{}", game.id);
+                        Set(bj_user.total_citrine_wagered.saturating_add(stake));
                     self.stores
                         .economy
-                        .cancel(&reference_id, &user, Currency::Citrine)
+                        .cancel(&reference_id, user, Currency::Citrine)
                         .await?;
                 }
             }
@@ -156,23 +328,61 @@ impl BlackjackService {
         Ok(())
     }
 
+    /// Insurance pays 2:1 if the dealer has a blackjack. It is not counted as a game.
+    async fn resolve_insurance(
+        &self,
+        game: &BlackjackGame,
+        user: &user::Model,
+        outcome: BlackjackOutcome,
+    ) -> CoreResult<()> {
+        let Some(cost) = game.insurance_cost().map(|cost| cost as i64) else {
+            return Ok(());
+        };
+
+        let bj_user = self.stores.bj_user.fetch_or_create(user).await?;
+        let mut active = bj_user.clone().into_active_model();
+        let reference_id = Self::insurance_reference_id(game);
+
+        active.total_citrine_wagered = Set(bj_user.total_citrine_wagered.saturating_add(cost));
+        if outcome == BlackjackOutcome::Win {
+            let payout = cost.saturating_mul(2);
+            active.total_citrine_won = Set(bj_user.total_citrine_won.saturating_add(payout));
+            self.stores
+                .economy
+                .cancel(&reference_id, user, Currency::Citrine)
+                .await?;
+            self.stores
+                .economy
+                .add(user, Currency::Citrine, payout)
+                .await?;
+        } else {
+            active.total_citrine_lost = Set(bj_user.total_citrine_lost.saturating_add(cost));
+            self.stores
+                .economy
+                .commit(&reference_id, user, Currency::Citrine)
+                .await?;
+        }
+
+        self.stores.bj_user.update(active).await?;
+
+        Ok(())
+    }
+
     pub async fn register_user(
         &self,
         game: &mut BlackjackGame,
         user: &user::Model,
-        max: usize,
     ) -> CoreResult<()> {
-        if game.players.contains_key(&user.id) || game.players.len() >= max {
+        if game.players.contains_key(&user.id) || game.is_full() {
             return Ok(());
         };
 
         if let Some(wager) = game.wager {
-            let reference_id = format!("bj-{}", game.id);
             let reservation_successful = self
                 .stores
                 .economy
                 .reserve(
-                    reference_id,
+                    Self::reference_id(game, 0),
                     Duration::from_mins(20),
                     user,
                     Currency::Citrine,
@@ -0,0 +1,190 @@
+use crate::achievements::AchievementEvent;
+use crate::database::entity::user;
+use crate::error::CoreResult;
+use crate::games::connect4::Connect4Game;
+use crate::services::achievement::AchievementService;
+use crate::types::currency::Currency;
+use sea_orm::{IntoActiveModel, Set};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub struct Connect4Service {
+    achievement: Arc<AchievementService>,
+    stores: Arc<crate::stores::Stores>,
+}
+
+impl Connect4Service {
+    pub fn initialize(
+        stores: &Arc<crate::stores::Stores>,
+        achievement: &Arc<AchievementService>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            achievement: achievement.clone(),
+            stores: stores.clone(),
+        })
+    }
+
+    pub fn reference_id(game: &Connect4Game) -> String {
+        format!("c4-{}", game.id)
+    }
+
+    /// Holds the wager of the given player until the game is resolved or cancelled.
+    /// Returns false if the player cannot cover the wager.
+    pub async fn reserve_wager(&self, game: &Connect4Game, user: &user::Model) -> CoreResult<bool> {
+        let Some(wager) = game.wager else {
+            return Ok(true);
+        };
+
+        self.stores
+            .economy
+            .reserve(
+                Self::reference_id(game),
+                Duration::from_hours(1),
+                user,
+                Currency::Citrine,
+                wager as i64,
+            )
+            .await
+    }
+
+    /// Releases all wagers held for the game, e.g. when a challenge is declined.
+    pub async fn cancel_wagers(&self, game: &Connect4Game) -> CoreResult<()> {
+        if game.wager.is_none() {
+            return Ok(());
+        }
+
+        let reference_id = Self::reference_id(game);
+        for player_id in [&game.player_1, &game.player_2] {
+            if game.is_ai(player_id) {
+                continue;
+            }
+
+            let user = self.stores.user.fetch_or_create(player_id).await?;
+            self.stores
+                .economy
+                .cancel(&reference_id, &user, Currency::Citrine)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Records the stats of a finished game and settles its wagers.
+    pub async fn resolve_game(&self, game: &Connect4Game) -> CoreResult<()> {
+        if !game.is_over() {
+            return Ok(());
+        }
+
+        self.record_stats(game).await?;
+        self.settle_wagers(game).await
+    }
+
+    /// The loser's stake is taken and the winner is paid out the same amount if it could be
+    /// collected, a draw releases both stakes. Only games between players are wagered, the house
+    /// never pays out, so anything held in a game against the AI is released.
+    async fn settle_wagers(&self, game: &Connect4Game) -> CoreResult<()> {
+        let Some(wager) = game.wager else {
+            return Ok(());
+        };
+
+        let (Some(winner_id), Some(loser_id)) = (game.winner_id(), game.loser_id()) else {
+            return self.cancel_wagers(game).await;
+        };
+        if game.ai_player.is_some() {
+            return self.cancel_wagers(game).await;
+        }
+
+        let reference_id = Self::reference_id(game);
+
+        let loser = self.stores.user.fetch_or_create(loser_id).await?;
+        let collected = self
+            .stores
+            .economy
+            .commit(&reference_id, &loser, Currency::Citrine)
+            .await?;
+
+        let winner = self.stores.user.fetch_or_create(winner_id).await?;
+        self.stores
+            .economy
+            .cancel(&reference_id, &winner, Currency::Citrine)
+            .await?;
+        if collected {
+            self.stores
+                .economy
+                .add(&winner, Currency::Citrine, wager as i64)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn record_stats(&self, game: &Connect4Game) -> CoreResult<()> {
+        let winner = game.winner();
+        let moves = game.moves.len() as i64;
+
+        for (player_id, is_player_1) in [(&game.player_1, true), (&game.player_2, false)] {
+            if game.is_ai(player_id) {
+                continue;
+            }
+
+            let user = self.stores.user.fetch_or_create(player_id).await?;
+            let outcome = winner.map(|winner| winner == is_player_1);
+
+            let c4_user = self.stores.connect4_user.fetch_or_create(&user).await?;
+            let mut active = c4_user.clone().into_active_model();
+            active.moves_total = Set(c4_user.moves_total.saturating_add(moves));
+            match outcome {
+                Some(true) => active.wins = Set(c4_user.wins.saturating_add(1)),
+                Some(false) => active.losses = Set(c4_user.losses.saturating_add(1)),
+                None => active.draws = Set(c4_user.draws.saturating_add(1)),
+            }
+            let stats = self.stores.connect4_user.update(active).await?;
+
+            if game.ai_player.is_some() {
+                let c4_ai = self
+                    .stores
+                    .connect4_ai
+                    .fetch_or_create(&user, game.difficulty)
+                    .await?;
+                let mut active = c4_ai.clone().into_active_model();
+                match outcome {
+                    Some(true) => active.wins = Set(c4_ai.wins.saturating_add(1)),
+                    Some(false) => active.losses = Set(c4_ai.losses.saturating_add(1)),
+                    None => active.draws = Set(c4_ai.draws.saturating_add(1)),
+                }
+                self.stores.connect4_ai.update(active).await?;
+            }
+
+            let defeated_ai =
+                (game.ai_player.is_some() && outcome == Some(true)).then_some(game.difficulty);
+            self.achievement
+                .track_or_log(
+                    &user,
+                    AchievementEvent::Connect4Finished { stats, defeated_ai },
+                )
+                .await;
+        }
+
+        if game.ai_player.is_none() {
+            let user_1 = self.stores.user.fetch_or_create(&game.player_1).await?;
+            let user_2 = self.stores.user.fetch_or_create(&game.player_2).await?;
+            let games = self
+                .stores
+                .connect4_games
+                .fetch_or_create(&user_1, &user_2)
+                .await?;
+
+            let mut active = games.clone().into_active_model();
+            match game.winner_id() {
+                Some(winner_id) if winner_id == games.user_id1 => {
+                    active.wins1 = Set(games.wins1.saturating_add(1))
+                }
+                Some(_) => active.wins2 = Set(games.wins2.saturating_add(1)),
+                None => active.draws = Set(games.draws.saturating_add(1)),
+            }
+            self.stores.connect4_games.update(active).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
+use crate::achievements::AchievementEvent;
 use crate::database::entity::{farming_world, user};
 use crate::error::{CoreError, CoreResult};
 use crate::games::farming::hemisphere::Hemisphere;
 use crate::games::farming::procedural::ProceduralWorld;
+use crate::services::achievement::AchievementService;
 use crate::stores::Stores;
 use futures::StreamExt;
 use fuzzy_matcher::skim::SkimMatcherV2;
@@ -11,12 +13,14 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 pub struct FarmingService {
+    achievement: Arc<AchievementService>,
     stores: Arc<Stores>,
 }
 
 impl FarmingService {
-    pub fn initialize(stores: &Arc<Stores>) -> Arc<Self> {
+    pub fn initialize(stores: &Arc<Stores>, achievement: &Arc<AchievementService>) -> Arc<Self> {
         Arc::new(Self {
+            achievement: achievement.clone(),
             stores: stores.clone(),
         })
     }
@@ -64,7 +68,17 @@ impl FarmingService {
             ..Default::default()
         };
 
-        self.stores.farming_world.insert(new).await
+        let world = self.stores.farming_world.insert(new).await?;
+        self.achievement
+            .track(
+                user,
+                AchievementEvent::FarmWorldCreated {
+                    worlds: world_count + 1,
+                },
+            )
+            .await?;
+
+        Ok(world)
     }
 
     pub async fn fuzzy_search_worlds(
@@ -0,0 +1,110 @@
+use crate::database::entity::user;
+use crate::error::CoreResult;
+use crate::games::rating::matchmaking::{MatchmakingQueue, QueuedPlayer};
+use crate::games::rating::{Glicko2Rating, RatedGame};
+use crate::types::rated_game_kind::RatedGameKind;
+use chrono::Utc;
+use std::sync::Arc;
+
+pub struct GameRatingService {
+    stores: Arc<crate::stores::Stores>,
+    queue: MatchmakingQueue,
+}
+
+impl GameRatingService {
+    pub fn initialize(stores: &Arc<crate::stores::Stores>) -> Arc<Self> {
+        Arc::new(Self {
+            stores: stores.clone(),
+            queue: MatchmakingQueue::default(),
+        })
+    }
+
+    /// Updates the ratings of both players of a finished game, every game is its own rating
+    /// period. Games that are not rated, like those against the bot, are ignored.
+    pub async fn record<G: RatedGame>(&self, game: &G) -> CoreResult<()> {
+        let Some(result) = game.rated_result() else {
+            return Ok(());
+        };
+
+        let user_1 = self.stores.user.fetch_or_create(&result.player_1).await?;
+        let user_2 = self.stores.user.fetch_or_create(&result.player_2).await?;
+        let rating_1 = self
+            .stores
+            .game_rating
+            .fetch_or_create(&user_1, G::RATED_KIND)
+            .await?;
+        let rating_2 = self
+            .stores
+            .game_rating
+            .fetch_or_create(&user_2, G::RATED_KIND)
+            .await?;
+
+        let (glicko_1, glicko_2) = (rating_1.glicko(), rating_2.glicko());
+        let updated_1 = glicko_1.update(&[(glicko_2, result.score_1)]);
+        let updated_2 = glicko_2.update(&[(glicko_1, 1.0 - result.score_1)]);
+
+        self.stores
+            .game_rating
+            .update(rating_1, updated_1, game.id())
+            .await?;
+        self.stores
+            .game_rating
+            .update(rating_2, updated_2, game.id())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Looks for a player of a similar rating waiting in the guild and returns their id. If there
+    /// is none, the user is queued until someone joins or they leave.
+    pub async fn join_queue(
+        &self,
+        guild_id: impl Into<String>,
+        kind: RatedGameKind,
+        user: &user::Model,
+    ) -> CoreResult<Option<String>> {
+        let rating = self.find_glicko(&user.id, kind).await?;
+
+        let now = Utc::now();
+        let player = QueuedPlayer {
+            user_id: user.id.clone(),
+            rating: rating.rating,
+            joined_at: now,
+        };
+        Ok(self
+            .queue
+            .join(guild_id, kind, player, now)
+            .map(|opponent| opponent.user_id))
+    }
+
+    pub fn is_queued(&self, guild_id: &str, kind: RatedGameKind, user_id: &str) -> bool {
+        self.queue.is_waiting(guild_id, kind, user_id)
+    }
+
+    /// Returns false if the user was matched in the meantime.
+    pub fn leave_queue(&self, guild_id: &str, kind: RatedGameKind, user_id: &str) -> bool {
+        self.queue.leave(guild_id, kind, user_id)
+    }
+
+    /// The chance of the first player beating the second, as their ratings are now.
+    pub async fn expected_score(
+        &self,
+        kind: RatedGameKind,
+        user_id_1: &str,
+        user_id_2: &str,
+    ) -> CoreResult<f64> {
+        let rating_1 = self.find_glicko(user_id_1, kind).await?;
+        let rating_2 = self.find_glicko(user_id_2, kind).await?;
+        Ok(rating_1.expected_score(&rating_2))
+    }
+
+    async fn find_glicko(&self, user_id: &str, kind: RatedGameKind) -> CoreResult<Glicko2Rating> {
+        Ok(self
+            .stores
+            .game_rating
+            .find_by_id(user_id, kind)
+            .await?
+            .map(|rating| rating.glicko())
+            .unwrap_or_default())
+    }
+}
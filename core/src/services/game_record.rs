@@ -0,0 +1,57 @@
+use crate::database::entity::game_record;
+use crate::error::CoreResult;
+use crate::games::record::ReplayableGame;
+use sea_orm::Set;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct GameRecordService {
+    stores: Arc<crate::stores::Stores>,
+}
+
+impl GameRecordService {
+    pub fn initialize(stores: &Arc<crate::stores::Stores>) -> Arc<Self> {
+        Arc::new(Self {
+            stores: stores.clone(),
+        })
+    }
+
+    /// Stores a finished game under its id, so that it can be replayed later. Games that were
+    /// already recorded are left as they are.
+    pub async fn save<G: ReplayableGame>(&self, game: &G) -> CoreResult<()> {
+        if self
+            .stores
+            .game_record
+            .find_by_id(game.id())
+            .await?
+            .is_some()
+        {
+            return Ok(());
+        }
+
+        let log = game.log();
+        let model = game_record::ActiveModel {
+            id: Set(game.id()),
+            kind: Set(G::KIND.into()),
+            players: Set(serde_json::to_value(game.players())?),
+            seed: Set(game.seed().map(|seed| seed as i64)),
+            setup: Set(serde_json::to_value(game.setup())?),
+            moves: Set(serde_json::to_value(log)?),
+            outcomes: Set(serde_json::to_value(game.outcomes())?),
+            started_at: Set(log.started_at.naive_utc()),
+            ended_at: Set(chrono::Utc::now().naive_utc()),
+        };
+        self.stores.game_record.insert(model).await?;
+
+        Ok(())
+    }
+
+    /// Looks a record up by the id of its game as it is shown to users, ids that are not valid
+    /// are not found.
+    pub async fn find(&self, id: impl AsRef<str>) -> CoreResult<Option<game_record::Model>> {
+        let Ok(id) = Uuid::parse_str(id.as_ref().trim()) else {
+            return Ok(None);
+        };
+        self.stores.game_record.find_by_id(id).await
+    }
+}
@@ -0,0 +1,205 @@
+use crate::database::entity::{guild, user};
+use crate::error::CoreResult;
+use crate::events::level_up::LevelUp;
+use crate::leveling::activity::{ActivityBuffer, PendingActivity};
+use crate::leveling::progress::{citrine_reward, LevelProgress};
+use crate::stores::Stores;
+use crate::types::currency::Currency;
+use sea_orm::{IntoActiveModel, Set};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct LevelingService {
+    stores: Arc<Stores>,
+    activity: ActivityBuffer,
+}
+
+pub struct Rank {
+    pub position: u64,
+    pub messages: i64,
+    pub guild: LevelProgress,
+    pub global: LevelProgress,
+}
+
+impl LevelingService {
+    pub fn initialize(stores: &Arc<Stores>) -> Arc<Self> {
+        Arc::new(Self {
+            stores: stores.clone(),
+            activity: ActivityBuffer::default(),
+        })
+    }
+
+    /// Records a guild message in memory, it is persisted on the next [`LevelingService::flush`].
+    pub fn record_message(&self, user_id: &str, guild_id: &str, content: &str) {
+        self.activity.record(user_id, guild_id, content);
+    }
+
+    /// Writes all buffered activity to the database, granting Citrine for reached global levels.
+    /// Activity which fails to be written stays buffered for the next flush.
+    /// Returns all guild level ups that happened since the last flush.
+    pub async fn flush(&self) -> CoreResult<Vec<LevelUp>> {
+        let pending = self.activity.drain();
+
+        let mut global: HashMap<String, PendingActivity> = HashMap::new();
+        let mut level_ups = Vec::new();
+        for ((user_id, guild_id), activity) in &pending {
+            let entry = global.entry(user_id.clone()).or_default();
+            entry.global_messages += activity.global_messages;
+            entry.global_xp += activity.global_xp;
+
+            match self
+                .flush_guild_activity(user_id, guild_id, activity.guild_part())
+                .await
+            {
+                Ok(Some(level_up)) => level_ups.push(level_up),
+                Ok(None) => {}
+                Err(err) => {
+                    error!(
+                        "Failed to flush activity of user '{user_id}' in guild '{guild_id}': {err}"
+                    );
+                    self.activity
+                        .restore(user_id, guild_id, activity.guild_part());
+                }
+            }
+        }
+
+        for (user_id, activity) in global {
+            if let Err(err) = self.flush_global_activity(&user_id, activity).await {
+                error!("Failed to flush global activity of user '{user_id}': {err}");
+                for ((_, guild_id), activity) in pending
+                    .iter()
+                    .filter(|((pending_user_id, _), _)| *pending_user_id == user_id)
+                {
+                    self.activity
+                        .restore(&user_id, guild_id, activity.global_part());
+                }
+            }
+        }
+
+        Ok(level_ups)
+    }
+
+    pub async fn rank(&self, user: &user::Model, guild: &guild::Model) -> CoreResult<Rank> {
+        let pending = self.activity.pending_for(&user.id, &guild.id);
+        let pending_global_xp = self.activity.pending_global_xp(&user.id);
+
+        let guild_level = self
+            .stores
+            .user_guild_level
+            .find_by_id(&user.id, &guild.id)
+            .await?;
+        let user_level = self.stores.user_level.find(&user.id).await?;
+
+        let guild_xp = guild_level.as_ref().map_or(0, |level| level.xp) + pending.guild_xp;
+        let global_xp = user_level.as_ref().map_or(0, |level| level.xp) + pending_global_xp;
+        let messages = guild_level.map_or(0, |level| level.message_count) + pending.messages;
+        let position = self
+            .stores
+            .user_guild_level
+            .rank_in_guild(&guild.id, guild_xp)
+            .await?;
+
+        Ok(Rank {
+            position,
+            messages,
+            guild: LevelProgress::from_xp(guild_xp),
+            global: LevelProgress::from_xp(global_xp),
+        })
+    }
+
+    async fn flush_guild_activity(
+        &self,
+        user_id: &str,
+        guild_id: &str,
+        activity: PendingActivity,
+    ) -> CoreResult<Option<LevelUp>> {
+        // Guilds which did not enable leveling neither collect XP nor hand out reward roles.
+        let Some(settings) = self
+            .stores
+            .guild_leveling
+            .find_by_guild_id(guild_id)
+            .await?
+            .filter(|settings| settings.enabled)
+        else {
+            return Ok(None);
+        };
+
+        let user = self.stores.user.fetch_or_create(user_id).await?;
+        let guild = self.stores.guild.fetch_or_create(guild_id).await?;
+        self.stores
+            .user_guild
+            .fetch_or_create(&user, &guild)
+            .await?;
+
+        let guild_level = self
+            .stores
+            .user_guild_level
+            .fetch_or_create(&user, &guild)
+            .await?;
+        let previous_level = guild_level.level;
+        let xp = guild_level.xp.saturating_add(activity.guild_xp);
+        let level = LevelProgress::from_xp(xp).level;
+        let message_count = guild_level.message_count.saturating_add(activity.messages);
+
+        let mut active = guild_level.into_active_model();
+        active.xp = Set(xp);
+        active.level = Set(level);
+        active.message_count = Set(message_count);
+        self.stores.user_guild_level.update(active).await?;
+
+        if level <= previous_level {
+            return Ok(None);
+        }
+
+        let reward_role_ids = self
+            .stores
+            .guild_level_reward
+            .find_reached(&guild.id, previous_level, level)
+            .await?
+            .into_iter()
+            .map(|reward| reward.role_id)
+            .collect();
+
+        Ok(Some(LevelUp {
+            user_id: user.id,
+            guild_id: guild.id,
+            level,
+            previous_level,
+            channel_id: settings.notification_channel_id,
+            reward_role_ids,
+        }))
+    }
+
+    async fn flush_global_activity(
+        &self,
+        user_id: &str,
+        activity: PendingActivity,
+    ) -> CoreResult<()> {
+        let user = self.stores.user.fetch_or_create(user_id).await?;
+        let user_level = self.stores.user_level.fetch_or_create(&user).await?;
+        let previous_level = user_level.level;
+        let xp = user_level.xp.saturating_add(activity.global_xp);
+        let level = LevelProgress::from_xp(xp).level;
+        let message_count = user_level
+            .message_count
+            .saturating_add(activity.global_messages);
+
+        let mut active = user_level.into_active_model();
+        active.xp = Set(xp);
+        active.level = Set(level);
+        active.message_count = Set(message_count);
+        self.stores.user_level.update(active).await?;
+
+        if level > previous_level {
+            let citrine = citrine_reward(previous_level, level);
+            self.stores
+                .economy
+                .add(&user, Currency::Citrine, citrine)
+                .await?;
+            info!("User '{user_id}' reached global level {level} and earned {citrine} Citrine");
+        }
+
+        Ok(())
+    }
+}
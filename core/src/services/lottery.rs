@@ -0,0 +1,182 @@
+use crate::database::entity::{guild, guild_lottery, lottery_round, lottery_ticket, user};
+use crate::error::{CoreError, CoreResult};
+use crate::events::lottery_drawn::LotteryDrawn;
+use crate::games::lottery;
+use crate::stores::lottery_round::DrawnRound;
+use crate::stores::Stores;
+use crate::types::currency::Currency;
+use crate::types::feature::Feature;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Reservations outlive the draw by this much, so a slightly delayed draw can still commit them.
+const RESERVATION_GRACE: Duration = Duration::from_hours(2);
+
+pub struct LotteryService {
+    stores: Arc<Stores>,
+}
+
+impl LotteryService {
+    pub fn initialize(stores: &Arc<Stores>) -> Arc<Self> {
+        Arc::new(Self {
+            stores: stores.clone(),
+        })
+    }
+
+    pub fn reference_id(ticket_id: Uuid) -> String {
+        lottery::reference_id(ticket_id)
+    }
+
+    /// Returns the currently open round of a guild, opening a new one if there is none.
+    pub async fn open_round(
+        &self,
+        settings: &guild_lottery::Model,
+    ) -> CoreResult<lottery_round::Model> {
+        if let Some(round) = self
+            .stores
+            .lottery_round
+            .find_open(&settings.guild_id)
+            .await?
+        {
+            return Ok(round);
+        }
+
+        self.stores
+            .lottery_round
+            .create(
+                &settings.guild_id,
+                settings.ticket_price,
+                0,
+                next_draw_at(settings),
+            )
+            .await
+    }
+
+    /// Reserves the ticket cost and registers the tickets.
+    /// Returns `None` if the user can't afford the tickets.
+    pub async fn buy_tickets(
+        &self,
+        user: &user::Model,
+        guild: &guild::Model,
+        tickets: u32,
+    ) -> CoreResult<Option<(lottery_round::Model, lottery_ticket::Model)>> {
+        let settings = self.stores.guild_lottery.fetch_or_create(guild).await?;
+        if !settings.enabled {
+            return Err(CoreError::FeatureNotEnabled(Feature::Lottery));
+        }
+
+        if self.stores.economy.is_frozen(user).await? {
+            return Err(CoreError::WalletFrozen);
+        }
+
+        let round = self.open_round(&settings).await?;
+        if round.draws_at <= chrono::Utc::now().naive_utc() {
+            return Err(CoreError::LotteryRoundClosed);
+        }
+
+        let cost = round.ticket_price.saturating_mul(tickets as i64);
+        let until_draw = (round.draws_at.and_utc() - chrono::Utc::now())
+            .to_std()
+            .unwrap_or_default();
+
+        let ticket_id = Uuid::new_v4();
+        let reserved = self
+            .stores
+            .economy
+            .reserve(
+                Self::reference_id(ticket_id),
+                until_draw + RESERVATION_GRACE,
+                user,
+                Currency::Citrine,
+                cost,
+            )
+            .await?;
+        if !reserved {
+            return Ok(None);
+        }
+
+        let ticket = self
+            .stores
+            .lottery_ticket
+            .create(ticket_id, &round, user, tickets as i32, cost)
+            .await?;
+        let Some(ticket) = ticket else {
+            self.stores
+                .economy
+                .cancel(Self::reference_id(ticket_id), user, Currency::Citrine)
+                .await?;
+            return Err(CoreError::LotteryRoundClosed);
+        };
+
+        Ok(Some((round, ticket)))
+    }
+
+    /// Draws the round, see [`LotteryRoundStore::draw`]. If nobody bought a valid ticket, the
+    /// whole pot is carried over to the next round. Returns `None` if the round was already drawn.
+    ///
+    /// [`LotteryRoundStore::draw`]: crate::stores::lottery_round::LotteryRoundStore::draw
+    pub async fn draw(
+        &self,
+        settings: &guild_lottery::Model,
+        round: &lottery_round::Model,
+    ) -> CoreResult<Option<LotteryDrawn>> {
+        let next_round = settings
+            .enabled
+            .then(|| (settings.ticket_price, next_draw_at(settings)));
+        let Some(drawn) = self
+            .stores
+            .lottery_round
+            .draw(
+                &self.stores.economy,
+                round.id,
+                settings.sink_percent,
+                next_round,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let DrawnRound {
+            round,
+            result,
+            next_round,
+        } = drawn;
+        Ok(Some(LotteryDrawn {
+            guild_id: round.guild_id,
+            channel_id: settings
+                .notification_channel_id
+                .clone()
+                .filter(|_| settings.enabled),
+            round_id: round.id,
+            winner_id: result.winner_id,
+            winning_ticket: result.winning_ticket,
+            total_tickets: result.total_tickets,
+            pot: result.pot,
+            payout: result.payout,
+            sink: result.sink,
+            seed: round.seed,
+            seed_hash: round.seed_hash,
+            next_jackpot: result.next_jackpot,
+            next_draw_at: next_round.map(|round| round.draws_at.and_utc()),
+        }))
+    }
+
+    /// Gives back the reserved Citrine of every ticket in the open round of a guild, e.g. once
+    /// its lottery is disabled. Returns the number of refunded ticket purchases.
+    pub async fn refund_open_round(&self, guild_id: impl AsRef<str>) -> CoreResult<usize> {
+        let Some(round) = self.stores.lottery_round.find_open(guild_id).await? else {
+            return Ok(0);
+        };
+
+        self.stores
+            .lottery_round
+            .refund(&self.stores.economy, round.id)
+            .await
+    }
+}
+
+fn next_draw_at(settings: &guild_lottery::Model) -> chrono::DateTime<chrono::Utc> {
+    chrono::Utc::now() + chrono::Duration::hours(settings.round_hours.max(1) as i64)
+}
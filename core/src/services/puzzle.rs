@@ -0,0 +1,95 @@
+use crate::database::entity::{puzzle_score, user};
+use crate::error::CoreResult;
+use crate::games::game2048::Game2048;
+use crate::games::minesweeper::MinesweeperGame;
+use crate::types::puzzle_kind::PuzzleKind;
+use chrono::Utc;
+use std::sync::Arc;
+
+/// The best results of a player after a finished puzzle.
+pub struct PuzzleRecord {
+    pub score: puzzle_score::Model,
+    /// Set if the game beat the previous best score or time.
+    pub new_best: bool,
+}
+
+pub struct PuzzleService {
+    stores: Arc<crate::stores::Stores>,
+}
+
+impl PuzzleService {
+    pub fn initialize(stores: &Arc<crate::stores::Stores>) -> Arc<Self> {
+        Arc::new(Self {
+            stores: stores.clone(),
+        })
+    }
+
+    /// Only won games count towards the best time, which runs from the first reveal.
+    pub async fn record_minesweeper(
+        &self,
+        user: &user::Model,
+        game: &MinesweeperGame,
+    ) -> CoreResult<PuzzleRecord> {
+        let time_ms = game.is_won().then(|| game.elapsed_ms(Utc::now()));
+        self.record(
+            user,
+            game.difficulty.puzzle_kind(),
+            game.is_won(),
+            None,
+            time_ms,
+        )
+        .await
+    }
+
+    /// Games of 2048 are won by reaching the 2048 tile, every game counts towards the best score.
+    pub async fn record_2048(
+        &self,
+        user: &user::Model,
+        game: &Game2048,
+    ) -> CoreResult<PuzzleRecord> {
+        self.record(
+            user,
+            PuzzleKind::Game2048,
+            game.has_won(),
+            Some(game.score as i64),
+            None,
+        )
+        .await
+    }
+
+    async fn record(
+        &self,
+        user: &user::Model,
+        kind: PuzzleKind,
+        won: bool,
+        score: Option<i64>,
+        time_ms: Option<i64>,
+    ) -> CoreResult<PuzzleRecord> {
+        let model = self.stores.puzzle_score.fetch_or_create(user, kind).await?;
+
+        let new_best_score =
+            score.is_some_and(|score| model.best_score.is_none_or(|best| score > best));
+        let new_best_time =
+            time_ms.is_some_and(|time| model.best_time_ms.is_none_or(|best| time < best));
+        let best_score = if new_best_score {
+            score
+        } else {
+            model.best_score
+        };
+        let best_time_ms = if new_best_time {
+            time_ms
+        } else {
+            model.best_time_ms
+        };
+
+        let score = self
+            .stores
+            .puzzle_score
+            .update(model, won, best_score, best_time_ms)
+            .await?;
+        Ok(PuzzleRecord {
+            score,
+            new_best: new_best_score || new_best_time,
+        })
+    }
+}
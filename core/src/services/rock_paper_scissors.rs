@@ -1,10 +1,18 @@
 use crate::database::entity::{rps_games, rps_user, user};
 use crate::error::CoreResult;
+use crate::games::rps::bot::{RPSBot, RPSBotMode, HISTORY_LENGTH};
 use crate::games::rps::choice::RPSChoice;
+use crate::games::rps::state::RPSState;
 use crate::games::rps::stats::RPSStats;
+use crate::games::rps::tournament::RPSTournament;
+use crate::games::rps::RPSGame;
 use crate::stores::Stores;
+use crate::types::currency::Currency;
 use sea_orm::{IntoActiveModel, Set};
+use std::cmp::Reverse;
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub struct RockPaperScissorsService {
     stores: Arc<Stores>,
@@ -17,6 +25,126 @@ impl RockPaperScissorsService {
         })
     }
 
+    pub fn reference_id(game: &RPSGame) -> String {
+        format!("rps-{}", game.id)
+    }
+
+    /// Holds the wager of the given player until the game is settled or cancelled, players lock
+    /// it with their first throw. Returns false if the player cannot cover the wager.
+    pub async fn lock_wager(&self, game: &mut RPSGame, user: &user::Model) -> CoreResult<bool> {
+        let Some(wager) = game.wager else {
+            return Ok(true);
+        };
+        if game.is_locked(&user.id) {
+            return Ok(true);
+        }
+
+        let reserved = self
+            .stores
+            .economy
+            .reserve(
+                Self::reference_id(game),
+                Duration::from_hours(1),
+                user,
+                Currency::Citrine,
+                wager as i64,
+            )
+            .await?;
+        if reserved {
+            game.lock(&user.id);
+        }
+
+        Ok(reserved)
+    }
+
+    /// Releases the wagers of everyone who locked one, e.g. when the game times out.
+    pub async fn cancel_wagers(&self, game: &RPSGame) -> CoreResult<()> {
+        if game.wager.is_none() {
+            return Ok(());
+        }
+
+        let reference_id = Self::reference_id(game);
+        for (user, locked) in [(&game.user_1, game.locked_1), (&game.user_2, game.locked_2)] {
+            if locked {
+                self.stores
+                    .economy
+                    .cancel(&reference_id, user, Currency::Citrine)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The winner gets their stake back together with the loser's, a draw releases both stakes.
+    /// Only games between players are wagered, the house never pays out, so anything held in a
+    /// game against the bot is released.
+    pub async fn settle_wagers(&self, game: &RPSGame) -> CoreResult<()> {
+        let Some(wager) = game.wager else {
+            return Ok(());
+        };
+        if game.against_bot() {
+            return self.cancel_wagers(game).await;
+        }
+
+        let (winner, loser) = match game.state() {
+            RPSState::Winner1 => (&game.user_1, &game.user_2),
+            RPSState::Winner2 => (&game.user_2, &game.user_1),
+            RPSState::Draw => {
+                for user in [&game.user_1, &game.user_2] {
+                    self.record_wager(user, wager as i64, 0, 0).await?;
+                }
+                return self.cancel_wagers(game).await;
+            }
+            _ => return Ok(()),
+        };
+
+        let reference_id = Self::reference_id(game);
+        let wager = wager as i64;
+
+        let collected = self
+            .stores
+            .economy
+            .commit(&reference_id, loser, Currency::Citrine)
+            .await?;
+        let lost = if collected { wager } else { 0 };
+        self.record_wager(loser, wager, 0, lost).await?;
+
+        self.stores
+            .economy
+            .cancel(&reference_id, winner, Currency::Citrine)
+            .await?;
+        let won = if collected {
+            self.stores
+                .economy
+                .add(winner, Currency::Citrine, wager)
+                .await?;
+            wager
+        } else {
+            0
+        };
+        self.record_wager(winner, wager, won, 0).await?;
+
+        Ok(())
+    }
+
+    async fn record_wager(
+        &self,
+        user: &user::Model,
+        wagered: i64,
+        won: i64,
+        lost: i64,
+    ) -> CoreResult<rps_user::Model> {
+        let rps_user = self.stores.rps_user.fetch_or_create(user).await?;
+
+        let mut active = rps_user.clone().into_active_model();
+        active.total_citrine_wagered = Set(rps_user.total_citrine_wagered.saturating_add(wagered));
+        active.total_citrine_won = Set(rps_user.total_citrine_won.saturating_add(won));
+        active.total_citrine_lost = Set(rps_user.total_citrine_lost.saturating_add(lost));
+
+        self.stores.rps_user.update(active).await
+    }
+
     pub async fn register_winner(
         &self,
         winner: &user::Model,
@@ -68,7 +196,12 @@ impl RockPaperScissorsService {
             RPSChoice::Scissors => rps_user.times_scissors,
         };
 
+        let mut recent_throws = rps_user.recent_throws.clone();
+        recent_throws.push(choice.as_char());
+        let overflow = recent_throws.len().saturating_sub(HISTORY_LENGTH);
+
         let mut active = rps_user.into_active_model();
+        active.recent_throws = Set(recent_throws[overflow..].to_string());
         match choice {
             RPSChoice::Rock => active.times_rock = Set(choice_count.saturating_add(1)),
             RPSChoice::Paper => active.times_paper = Set(choice_count.saturating_add(1)),
@@ -78,6 +211,14 @@ impl RockPaperScissorsService {
         self.stores.rps_user.update(active).await
     }
 
+    /// A bot opponent for the given player, which learns from their past throws unless it is fair.
+    pub async fn create_bot(&self, user: &user::Model, mode: RPSBotMode) -> CoreResult<RPSBot> {
+        let rps_user = self.stores.rps_user.fetch_or_create(user).await?;
+        Ok(RPSBot::new(mode)
+            .with_frequencies(rps_user.frequencies())
+            .with_history(rps_user.recent_throws()))
+    }
+
     pub async fn get_stats(&self, user: &user::Model) -> CoreResult<RPSStats> {
         let user_stats = self.stores.rps_games.get_user_stats(&user.id).await?;
         let rps_user = self.stores.rps_user.fetch_or_create(user).await?;
@@ -89,6 +230,24 @@ impl RockPaperScissorsService {
             rock: rps_user.times_rock as u64,
             paper: rps_user.times_paper as u64,
             scissors: rps_user.times_scissors as u64,
+            citrine_wagered: rps_user.total_citrine_wagered,
+            citrine_won: rps_user.total_citrine_won,
+            citrine_lost: rps_user.total_citrine_lost,
         })
     }
+
+    /// Seeds the players by their wins, so that the best players only meet late, and starts the
+    /// tournament. Players with as many wins keep their sign-up order.
+    pub async fn start_tournament(&self, tournament: &mut RPSTournament) -> CoreResult<bool> {
+        let mut wins = HashMap::new();
+        for player in &tournament.players {
+            let stats = self.get_stats(player).await?;
+            wins.insert(player.id.clone(), stats.wins);
+        }
+
+        tournament
+            .players
+            .sort_by_key(|player| Reverse(wins.get(&player.id).copied().unwrap_or(0)));
+        Ok(tournament.start())
+    }
 }
@@ -85,12 +85,23 @@ impl UserService {
         user: &user::Model,
     ) -> CoreResult<UserSettings> {
         let user_birthday = self.stores.user_birthday.find_by_user_id(&user.id).await?;
-        let birthday = user_birthday.map(|ub| UserBirthdaySettings {
-            day: ub.day,
-            month: ub.month,
-            year: ub.year,
-            updatable: birthday_service.can_update(&ub),
-        });
+        let birthday = match user_birthday {
+            Some(ub) => Some(UserBirthdaySettings {
+                day: ub.day,
+                month: ub.month,
+                year: ub.year,
+                updatable: birthday_service.can_update(&ub),
+                hide_age: ub.hide_age,
+                hide_from_listings: ub.hide_from_listings,
+                opt_out_announcements: ub.opt_out_announcements,
+                opted_out_guild_ids: self
+                    .stores
+                    .birthday_opt_out
+                    .guild_ids_by_user(&user.id)
+                    .await?,
+            }),
+            None => None,
+        };
         Ok(UserSettings { birthday })
     }
 }
@@ -0,0 +1,118 @@
+use crate::error::CoreResult;
+use crate::games::turn_based::TurnBasedGame;
+use crate::stores::Stores;
+use crate::types::currency::Currency;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Stakes outlive the time limit of their game by this much, so that a game which is called off
+/// at its time limit can still settle them.
+const RESERVATION_GRACE: Duration = Duration::from_mins(30);
+
+/// Holds and settles the Citrine wagers of turn-based games, so that games only decide who wins
+/// and loses how much.
+pub struct WagerService {
+    stores: Arc<Stores>,
+}
+
+impl WagerService {
+    pub fn initialize(stores: &Arc<Stores>) -> Arc<Self> {
+        Arc::new(Self {
+            stores: stores.clone(),
+        })
+    }
+
+    /// Holds the stake of the given player until the game is settled or the player leaves, but
+    /// no longer than the time limit of the game and a grace period. Returns false if the player
+    /// cannot cover the stake.
+    pub async fn reserve(
+        &self,
+        reference_id: &str,
+        user_id: &str,
+        stake: i64,
+        time_limit: Duration,
+    ) -> CoreResult<bool> {
+        if stake <= 0 {
+            return Ok(true);
+        }
+
+        let user = self.stores.user.fetch_or_create(user_id).await?;
+        self.stores
+            .economy
+            .reserve(
+                reference_id,
+                time_limit + RESERVATION_GRACE,
+                &user,
+                Currency::Citrine,
+                stake,
+            )
+            .await
+    }
+
+    pub async fn cancel(&self, reference_id: &str, user_id: &str) -> CoreResult<()> {
+        let user = self.stores.user.fetch_or_create(user_id).await?;
+        self.stores
+            .economy
+            .cancel(reference_id, &user, Currency::Citrine)
+            .await
+    }
+
+    /// Releases the stakes of all given players, e.g. when a lobby is abandoned.
+    pub async fn cancel_all(&self, reference_id: &str, user_ids: &[String]) -> CoreResult<()> {
+        for user_id in user_ids {
+            self.cancel(reference_id, user_id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Settles a finished game with the default split of its stakes.
+    pub async fn settle_game<G: TurnBasedGame>(
+        &self,
+        reference_id: &str,
+        game: &G,
+        stake: i64,
+    ) -> CoreResult<()> {
+        if !game.is_over() {
+            return Ok(());
+        }
+
+        self.settle(reference_id, &game.settlement(stake)).await
+    }
+
+    /// Takes what the losers owe first, then pays the winners from what could be collected.
+    /// Everyone else gets their stake back.
+    pub async fn settle(&self, reference_id: &str, settlement: &[(String, i64)]) -> CoreResult<()> {
+        let mut collected = 0;
+        for (user_id, amount) in settlement.iter().filter(|(_, amount)| *amount < 0) {
+            let user = self.stores.user.fetch_or_create(user_id).await?;
+            if self
+                .stores
+                .economy
+                .commit_partial(reference_id, &user, Currency::Citrine, -amount)
+                .await?
+            {
+                collected += -amount;
+            }
+        }
+
+        for (user_id, amount) in settlement.iter().filter(|(_, amount)| *amount >= 0) {
+            let user = self.stores.user.fetch_or_create(user_id).await?;
+            self.stores
+                .economy
+                .cancel(reference_id, &user, Currency::Citrine)
+                .await?;
+
+            let payout = (*amount).min(collected);
+            if payout > 0 {
+                collected -= payout;
+                self.stores
+                    .economy
+                    .add(&user, Currency::Citrine, payout)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
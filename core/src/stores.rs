@@ -3,44 +3,80 @@ use std::sync::Arc;
 
 pub use sea_orm::{IntoActiveModel, Set};
 
+pub mod achievement_unlock;
+pub mod active_game;
 pub mod apod;
+pub mod birthday_opt_out;
+pub mod birthday_role;
 pub mod black_jack_user;
+pub mod connect4_ai;
+pub mod connect4_games;
+pub mod connect4_user;
 pub mod dashboard_session;
 pub mod economy;
 pub mod farming;
 pub mod farming_world;
+pub mod game_rating;
+pub mod game_record;
 pub mod guild;
 pub mod guild_apod;
 pub mod guild_birthday;
+pub mod guild_blackjack;
+pub mod guild_level_reward;
+pub mod guild_leveling;
+pub mod guild_lottery;
 pub mod guild_youtube;
 pub mod guild_youtube_channel;
 pub mod inventory_item;
+pub mod lottery_round;
+pub mod lottery_ticket;
+pub mod puzzle_score;
 pub mod rps_games;
 pub mod rps_user;
 pub mod user;
 pub mod user_birthday;
 pub mod user_guild;
+pub mod user_guild_level;
+pub mod user_level;
 pub mod youtube_channel;
 pub mod youtube_video;
 
 pub struct Stores {
+    pub achievement_unlock: Arc<achievement_unlock::AchievementUnlockStore>,
+    pub active_game: Arc<active_game::ActiveGameStore>,
     pub apod: Arc<apod::ApodStore>,
+    pub birthday_opt_out: Arc<birthday_opt_out::BirthdayOptOutStore>,
+    pub birthday_role: Arc<birthday_role::BirthdayRoleStore>,
     pub bj_user: Arc<black_jack_user::BlackJackUserStore>,
+    pub connect4_ai: Arc<connect4_ai::Connect4AiStore>,
+    pub connect4_games: Arc<connect4_games::Connect4GamesStore>,
+    pub connect4_user: Arc<connect4_user::Connect4UserStore>,
     pub dashboard_session: Arc<dashboard_session::DashboardSessionStore>,
     pub economy: Arc<economy::EconomyStore>,
     pub farming: Arc<farming::FarmingStore>,
     pub farming_world: Arc<farming_world::FarmingWorldStore>,
+    pub game_rating: Arc<game_rating::GameRatingStore>,
+    pub game_record: Arc<game_record::GameRecordStore>,
     pub guild: Arc<guild::GuildStore>,
     pub guild_apod: Arc<guild_apod::GuildApodStore>,
     pub guild_birthday: Arc<guild_birthday::GuildBirthdayStore>,
+    pub guild_blackjack: Arc<guild_blackjack::GuildBlackjackStore>,
+    pub guild_level_reward: Arc<guild_level_reward::GuildLevelRewardStore>,
+    pub guild_leveling: Arc<guild_leveling::GuildLevelingStore>,
+    pub guild_lottery: Arc<guild_lottery::GuildLotteryStore>,
     pub guild_youtube: Arc<guild_youtube::GuildYoutubeStore>,
     pub guild_youtube_channel: Arc<guild_youtube_channel::GuildYoutubeChannelStore>,
     pub item: Arc<inventory_item::InventoryItemStore>,
+    pub lottery_round: Arc<lottery_round::LotteryRoundStore>,
+    pub lottery_ticket: Arc<lottery_ticket::LotteryTicketStore>,
+    pub puzzle_score: Arc<puzzle_score::PuzzleScoreStore>,
     pub rps_games: Arc<rps_games::RPSGamesStore>,
     pub rps_user: Arc<rps_user::RPSUserStore>,
     pub user: Arc<user::UserStore>,
     pub user_birthday: Arc<user_birthday::UserBirthdayStore>,
     pub user_guild: Arc<user_guild::UserGuildStore>,
+    pub user_guild_level: Arc<user_guild_level::UserGuildLevelStore>,
+    pub user_level: Arc<user_level::UserLevelStore>,
     pub youtube_channel: Arc<youtube_channel::YoutubeChannelStore>,
     pub youtube_video: Arc<youtube_video::YoutubeVideoStore>,
 }
@@ -48,23 +84,41 @@ pub struct Stores {
 impl Stores {
     pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
         Arc::new(Self {
+            achievement_unlock: achievement_unlock::AchievementUnlockStore::initialize(db),
+            active_game: active_game::ActiveGameStore::initialize(db),
             apod: apod::ApodStore::initialize(db),
+            birthday_opt_out: birthday_opt_out::BirthdayOptOutStore::initialize(db),
+            birthday_role: birthday_role::BirthdayRoleStore::initialize(db),
             bj_user: black_jack_user::BlackJackUserStore::initialize(db),
+            connect4_ai: connect4_ai::Connect4AiStore::initialize(db),
+            connect4_games: connect4_games::Connect4GamesStore::initialize(db),
+            connect4_user: connect4_user::Connect4UserStore::initialize(db),
             dashboard_session: dashboard_session::DashboardSessionStore::initialize(db),
             economy: economy::EconomyStore::initialize(db),
             farming: farming::FarmingStore::initialize(db),
             farming_world: farming_world::FarmingWorldStore::initialize(db),
+            game_rating: game_rating::GameRatingStore::initialize(db),
+            game_record: game_record::GameRecordStore::initialize(db),
             guild: guild::GuildStore::initialize(db),
             guild_apod: guild_apod::GuildApodStore::initialize(db),
             guild_birthday: guild_birthday::GuildBirthdayStore::initialize(db),
+            guild_blackjack: guild_blackjack::GuildBlackjackStore::initialize(db),
+            guild_level_reward: guild_level_reward::GuildLevelRewardStore::initialize(db),
+            guild_leveling: guild_leveling::GuildLevelingStore::initialize(db),
+            guild_lottery: guild_lottery::GuildLotteryStore::initialize(db),
             guild_youtube: guild_youtube::GuildYoutubeStore::initialize(db),
             guild_youtube_channel: guild_youtube_channel::GuildYoutubeChannelStore::initialize(db),
             item: inventory_item::InventoryItemStore::initialize(db),
+            lottery_round: lottery_round::LotteryRoundStore::initialize(db),
+            lottery_ticket: lottery_ticket::LotteryTicketStore::initialize(db),
+            puzzle_score: puzzle_score::PuzzleScoreStore::initialize(db),
             rps_games: rps_games::RPSGamesStore::initialize(db),
             rps_user: rps_user::RPSUserStore::initialize(db),
             user: user::UserStore::initialize(db),
             user_birthday: user_birthday::UserBirthdayStore::initialize(db),
             user_guild: user_guild::UserGuildStore::initialize(db),
+            user_guild_level: user_guild_level::UserGuildLevelStore::initialize(db),
+            user_level: user_level::UserLevelStore::initialize(db),
             youtube_channel: youtube_channel::YoutubeChannelStore::initialize(db),
             youtube_video: youtube_video::YoutubeVideoStore::initialize(db),
         })
@@ -0,0 +1,65 @@
+use crate::achievements::Achievement;
+use crate::database::entity::{achievement_unlock, user};
+use crate::database::Database;
+use crate::error::CoreResult;
+use crate::stores::economy::EconomyStore;
+use crate::types::currency::Currency;
+use sea_orm::TryInsertResult;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait};
+use std::sync::Arc;
+
+pub struct AchievementUnlockStore {
+    db: Arc<Database>,
+}
+
+impl AchievementUnlockStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    /// The achievements of a user in the order they were unlocked.
+    pub async fn find_by_user_id(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> CoreResult<Vec<achievement_unlock::Model>> {
+        Ok(achievement_unlock::Entity::find()
+            .filter(achievement_unlock::Column::UserId.eq(user_id.as_ref()))
+            .order_by_asc(achievement_unlock::Column::UnlockedAt)
+            .all(self.db.conn())
+            .await?)
+    }
+
+    /// Unlocks the achievement and pays out its reward in one transaction. Returns false without
+    /// paying anything if the user already had it, e.g. when a concurrent event unlocked it first.
+    pub async fn unlock(
+        &self,
+        economy: &EconomyStore,
+        user: &user::Model,
+        achievement: Achievement,
+    ) -> CoreResult<bool> {
+        let txn = self.db.conn().begin().await?;
+        let new = achievement_unlock::ActiveModel {
+            user_id: Set(user.id.to_string()),
+            achievement: Set(achievement.into()),
+            ..Default::default()
+        };
+
+        let inserted = achievement_unlock::Entity::insert(new)
+            .on_conflict_do_nothing()
+            .exec_without_returning(&txn)
+            .await?;
+        if !matches!(inserted, TryInsertResult::Inserted(rows) if rows > 0) {
+            txn.rollback().await?;
+            return Ok(false);
+        }
+
+        if let Some(reward) = achievement.definition().reward {
+            economy
+                .add_in_txn(&txn, user, Currency::Citrine, reward as i64)
+                .await?;
+        }
+
+        txn.commit().await?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,66 @@
+use crate::database::entity::active_game;
+use crate::database::Database;
+use crate::error::CoreResult;
+use crate::types::active_game_kind::ActiveGameKind;
+use sea_orm::prelude::Json;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{EntityTrait, Set};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ActiveGameStore {
+    db: Arc<Database>,
+}
+
+impl ActiveGameStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find_all(&self) -> CoreResult<Vec<active_game::Model>> {
+        Ok(active_game::Entity::find().all(self.db.conn()).await?)
+    }
+
+    /// Inserts the game or replaces the state of an already stored one.
+    pub async fn save(
+        &self,
+        id: Uuid,
+        kind: ActiveGameKind,
+        channel_id: impl AsRef<str>,
+        message_id: impl AsRef<str>,
+        state: Json,
+    ) -> CoreResult<()> {
+        let model = active_game::ActiveModel {
+            id: Set(id),
+            kind: Set(kind.into()),
+            channel_id: Set(channel_id.as_ref().to_string()),
+            message_id: Set(message_id.as_ref().to_string()),
+            state: Set(state),
+            updated_at: Set(chrono::Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        active_game::Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(active_game::Column::Id)
+                    .update_columns([
+                        active_game::Column::ChannelId,
+                        active_game::Column::MessageId,
+                        active_game::Column::State,
+                        active_game::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(self.db.conn())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(&self, id: Uuid) -> CoreResult<bool> {
+        let result = active_game::Entity::delete_by_id(id)
+            .exec(self.db.conn())
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+}
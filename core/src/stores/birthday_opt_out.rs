@@ -0,0 +1,72 @@
+use crate::database::entity::birthday_opt_out;
+use crate::database::Database;
+use crate::error::CoreResult;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, Set};
+use std::sync::Arc;
+
+pub struct BirthdayOptOutStore {
+    db: Arc<Database>,
+}
+
+impl BirthdayOptOutStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn exists(
+        &self,
+        user_id: impl AsRef<str>,
+        guild_id: impl AsRef<str>,
+    ) -> CoreResult<bool> {
+        Ok(birthday_opt_out::Entity::find_by_id((
+            user_id.as_ref().to_string(),
+            guild_id.as_ref().to_string(),
+        ))
+        .one(self.db.conn())
+        .await?
+        .is_some())
+    }
+
+    pub async fn guild_ids_by_user(&self, user_id: impl AsRef<str>) -> CoreResult<Vec<String>> {
+        Ok(birthday_opt_out::Entity::find()
+            .select_only()
+            .column(birthday_opt_out::Column::GuildId)
+            .filter(birthday_opt_out::Column::UserId.eq(user_id.as_ref().to_string()))
+            .into_tuple()
+            .all(self.db.conn())
+            .await?)
+    }
+
+    pub async fn insert(
+        &self,
+        user_id: impl AsRef<str>,
+        guild_id: impl AsRef<str>,
+    ) -> CoreResult<()> {
+        let model = birthday_opt_out::ActiveModel {
+            user_id: Set(user_id.as_ref().to_string()),
+            guild_id: Set(guild_id.as_ref().to_string()),
+            ..Default::default()
+        };
+
+        birthday_opt_out::Entity::insert(model)
+            .on_conflict_do_nothing()
+            .exec(self.db.conn())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(
+        &self,
+        user_id: impl AsRef<str>,
+        guild_id: impl AsRef<str>,
+    ) -> CoreResult<bool> {
+        let result = birthday_opt_out::Entity::delete_by_id((
+            user_id.as_ref().to_string(),
+            guild_id.as_ref().to_string(),
+        ))
+        .exec(self.db.conn())
+        .await?;
+        Ok(result.rows_affected > 0)
+    }
+}
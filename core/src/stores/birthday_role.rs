@@ -0,0 +1,72 @@
+use crate::database::entity::birthday_role;
+use crate::database::Database;
+use crate::error::CoreResult;
+use chrono::NaiveDateTime;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, Set};
+use std::sync::Arc;
+
+pub struct BirthdayRoleStore {
+    db: Arc<Database>,
+}
+
+impl BirthdayRoleStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    /// Tracks a granted birthday role, replacing the previous grant of the user in that guild.
+    pub async fn save(
+        &self,
+        user_id: impl AsRef<str>,
+        guild_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        expires_at: NaiveDateTime,
+    ) -> CoreResult<()> {
+        let model = birthday_role::ActiveModel {
+            user_id: Set(user_id.as_ref().to_string()),
+            guild_id: Set(guild_id.as_ref().to_string()),
+            role_id: Set(role_id.as_ref().to_string()),
+            expires_at: Set(expires_at),
+            ..Default::default()
+        };
+
+        birthday_role::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    birthday_role::Column::UserId,
+                    birthday_role::Column::GuildId,
+                ])
+                .update_columns([
+                    birthday_role::Column::RoleId,
+                    birthday_role::Column::ExpiresAt,
+                ])
+                .to_owned(),
+            )
+            .exec(self.db.conn())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_expired(&self, now: NaiveDateTime) -> CoreResult<Vec<birthday_role::Model>> {
+        Ok(birthday_role::Entity::find()
+            .filter(birthday_role::Column::ExpiresAt.lte(now))
+            .all(self.db.conn())
+            .await?)
+    }
+
+    pub async fn delete(
+        &self,
+        user_id: impl AsRef<str>,
+        guild_id: impl AsRef<str>,
+    ) -> CoreResult<bool> {
+        let result = birthday_role::Entity::delete_by_id((
+            user_id.as_ref().to_string(),
+            guild_id.as_ref().to_string(),
+        ))
+        .exec(self.db.conn())
+        .await?;
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::database::entity::{connect4_ai, user};
+use crate::database::Database;
+use crate::error::CoreResult;
+use crate::games::connect4::difficulty::Connect4Difficulty;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set};
+use std::sync::Arc;
+
+pub struct Connect4AiStore {
+    db: Arc<Database>,
+}
+
+impl Connect4AiStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find_by_id(
+        &self,
+        user_id: impl AsRef<str>,
+        difficulty: Connect4Difficulty,
+    ) -> CoreResult<Option<connect4_ai::Model>> {
+        Ok(
+            connect4_ai::Entity::find_by_id((user_id.as_ref().to_string(), i16::from(difficulty)))
+                .one(self.db.conn())
+                .await?,
+        )
+    }
+
+    pub async fn find_by_user_id(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> CoreResult<Vec<connect4_ai::Model>> {
+        Ok(connect4_ai::Entity::find()
+            .filter(connect4_ai::Column::UserId.eq(user_id.as_ref()))
+            .order_by_asc(connect4_ai::Column::Difficulty)
+            .all(self.db.conn())
+            .await?)
+    }
+
+    pub async fn fetch_or_create(
+        &self,
+        user: &user::Model,
+        difficulty: Connect4Difficulty,
+    ) -> CoreResult<connect4_ai::Model> {
+        if let Some(existing) = self.find_by_id(&user.id, difficulty).await? {
+            return Ok(existing);
+        };
+
+        let new = connect4_ai::ActiveModel {
+            user_id: Set(user.id.to_string()),
+            difficulty: Set(difficulty.into()),
+            ..Default::default()
+        };
+
+        Ok(new.insert(self.db.conn()).await?)
+    }
+
+    pub async fn update(
+        &self,
+        mut model: connect4_ai::ActiveModel,
+    ) -> CoreResult<connect4_ai::Model> {
+        model.updated_at = Set(chrono::Utc::now().naive_utc());
+        Ok(model.update(self.db.conn()).await?)
+    }
+}
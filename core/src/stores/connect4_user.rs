@@ -0,0 +1,47 @@
+use crate::database::entity::{connect4_user, user};
+use crate::database::Database;
+use crate::error::CoreResult;
+use sea_orm::{ActiveModelTrait, EntityTrait, Set};
+use std::sync::Arc;
+
+pub struct Connect4UserStore {
+    db: Arc<Database>,
+}
+
+impl Connect4UserStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find_by_id(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> CoreResult<Option<connect4_user::Model>> {
+        Ok(
+            connect4_user::Entity::find_by_id(user_id.as_ref().to_string())
+                .one(self.db.conn())
+                .await?,
+        )
+    }
+
+    pub async fn fetch_or_create(&self, user: &user::Model) -> CoreResult<connect4_user::Model> {
+        if let Some(existing) = self.find_by_id(&user.id).await? {
+            return Ok(existing);
+        };
+
+        let new = connect4_user::ActiveModel {
+            user_id: Set(user.id.to_string()),
+            ..Default::default()
+        };
+
+        Ok(new.insert(self.db.conn()).await?)
+    }
+
+    pub async fn update(
+        &self,
+        mut model: connect4_user::ActiveModel,
+    ) -> CoreResult<connect4_user::Model> {
+        model.updated_at = Set(chrono::Utc::now().naive_utc());
+        Ok(model.update(self.db.conn()).await?)
+    }
+}
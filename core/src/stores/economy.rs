@@ -1,13 +1,15 @@
-use crate::database::entity::{economy, economy_pending, user};
+use crate::database::entity::{economy, economy_audit, economy_frozen, economy_pending, user};
 use crate::database::Database;
-use crate::error::CoreResult;
+use crate::error::{CoreError, CoreResult};
 use crate::types::currency::Currency;
+use crate::types::economy_audit_action::EconomyAuditAction;
 use sea_orm::prelude::Expr;
 use sea_orm::sea_query::{Alias, Func, Query, SimpleExpr, SubQueryStatement};
-use sea_orm::ExprTrait;
 use sea_orm::{ActiveModelTrait, EntityTrait, FromQueryResult, Set, TransactionTrait};
+use sea_orm::{ColumnTrait, ExprTrait, QueryFilter, QueryOrder, QuerySelect};
 use sea_orm::{ConnectionTrait, IntoActiveModel};
 use std::sync::Arc;
+use tracing::info;
 
 pub struct EconomyStore {
     db: Arc<Database>,
@@ -86,11 +88,102 @@ impl EconomyStore {
         Ok(result)
     }
 
+    async fn is_frozen_in_txn(
+        &self,
+        txn: &impl ConnectionTrait,
+        user_id: &str,
+    ) -> CoreResult<bool> {
+        Ok(economy_frozen::Entity::find_by_id(user_id)
+            .one(txn)
+            .await?
+            .is_some())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn insert_audit(
+        &self,
+        txn: &impl ConnectionTrait,
+        admin: &user::Model,
+        user: &user::Model,
+        action: EconomyAuditAction,
+        currency: Option<Currency>,
+        amount: Option<i64>,
+        balances: Option<(i64, i64)>,
+        reason: Option<String>,
+    ) -> CoreResult<economy_audit::Model> {
+        info!(
+            "Economy admin action '{action}' executed by '{}' on '{}' (currency: {currency:?}, amount: {amount:?}, reason: {reason:?})",
+            admin.id, user.id
+        );
+
+        let audit = economy_audit::ActiveModel {
+            id: Set(uuid::Uuid::new_v4()),
+            admin_id: Set(admin.id.to_string()),
+            user_id: Set(user.id.to_string()),
+            action: Set(action.into()),
+            currency: Set(currency.map(Into::into)),
+            amount: Set(amount),
+            balance_before: Set(balances.map(|(before, _)| before)),
+            balance_after: Set(balances.map(|(_, after)| after)),
+            reason: Set(reason),
+            ..Default::default()
+        };
+        Ok(audit.insert(txn).await?)
+    }
+
+    /// Changes the total amount of a user's currency on behalf of an admin and writes an audit entry.
+    async fn admin_modify(
+        &self,
+        admin: &user::Model,
+        user: &user::Model,
+        currency: Currency,
+        action: EconomyAuditAction,
+        amount: i64,
+        reason: Option<String>,
+    ) -> CoreResult<economy_audit::Model> {
+        if amount < 0 {
+            return Err(CoreError::NegativeAmount);
+        }
+
+        let txn = self.db.conn().begin().await?;
+        let economy = self.find_or_create(&txn, user, currency).await?;
+        let before = economy.amount;
+        let after = match action {
+            EconomyAuditAction::Grant => before.saturating_add(amount),
+            EconomyAuditAction::Revoke => before.saturating_sub(amount).max(0),
+            EconomyAuditAction::Set | EconomyAuditAction::Freeze | EconomyAuditAction::Unfreeze => {
+                amount
+            }
+        };
+
+        let mut active = economy.into_active_model();
+        active.amount = Set(after);
+        active.update(&txn).await?;
+
+        let audit = self
+            .insert_audit(
+                &txn,
+                admin,
+                user,
+                action,
+                Some(currency),
+                Some(amount),
+                Some((before, after)),
+                reason,
+            )
+            .await?;
+        txn.commit().await?;
+
+        Ok(audit)
+    }
+
     pub async fn balance(&self, user: &user::Model, currency: Currency) -> CoreResult<Balance> {
         self.balance_in_txn(self.db.conn(), &user.id, currency.into())
             .await
     }
 
+    /// Holds an amount of the available balance until it is committed, cancelled or expires.
+    /// Returns false if the balance cannot cover it and fails for frozen wallets.
     pub async fn reserve(
         &self,
         reference_id: impl AsRef<str>,
@@ -104,6 +197,11 @@ impl EconomyStore {
         };
 
         let txn = self.db.conn().begin().await?;
+        if self.is_frozen_in_txn(&txn, &user.id).await? {
+            txn.rollback().await?;
+            return Err(CoreError::WalletFrozen);
+        };
+
         let balance = self.balance_in_txn(&txn, &user.id, currency.into()).await?;
 
         if balance.available < amount {
@@ -127,13 +225,23 @@ impl EconomyStore {
         Ok(true)
     }
 
-    pub async fn commit(
+    /// Adds to an existing reservation without changing when it expires.
+    pub async fn extend(
         &self,
         reference_id: impl AsRef<str>,
         user: &user::Model,
         currency: Currency,
+        amount: i64,
     ) -> CoreResult<bool> {
+        if amount <= 0 {
+            return Ok(false);
+        };
+
         let txn = self.db.conn().begin().await?;
+        if self.is_frozen_in_txn(&txn, &user.id).await? {
+            txn.rollback().await?;
+            return Ok(false);
+        };
 
         let Some(pending) = economy_pending::Entity::find_by_id((
             reference_id.as_ref().to_string(),
@@ -152,27 +260,107 @@ impl EconomyStore {
             return Ok(false);
         };
 
-        economy_pending::Entity::delete_by_id((
+        let balance = self.balance_in_txn(&txn, &user.id, currency.into()).await?;
+        if balance.available < amount {
+            txn.rollback().await?;
+            return Ok(false);
+        };
+
+        let current_amount = pending.amount;
+        let mut active = pending.into_active_model();
+        active.amount = Set(current_amount.saturating_add(amount));
+        active.update(&txn).await?;
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    pub async fn commit(
+        &self,
+        reference_id: impl AsRef<str>,
+        user: &user::Model,
+        currency: Currency,
+    ) -> CoreResult<bool> {
+        self.commit_up_to(reference_id, user, currency, None).await
+    }
+
+    /// Takes only part of a reservation and releases the rest of it.
+    pub async fn commit_partial(
+        &self,
+        reference_id: impl AsRef<str>,
+        user: &user::Model,
+        currency: Currency,
+        amount: i64,
+    ) -> CoreResult<bool> {
+        self.commit_up_to(reference_id, user, currency, Some(amount))
+            .await
+    }
+
+    async fn commit_up_to(
+        &self,
+        reference_id: impl AsRef<str>,
+        user: &user::Model,
+        currency: Currency,
+        limit: Option<i64>,
+    ) -> CoreResult<bool> {
+        let txn = self.db.conn().begin().await?;
+        if !self
+            .commit_in_txn(&txn, reference_id, user, currency, limit)
+            .await?
+        {
+            txn.rollback().await?;
+            return Ok(false);
+        }
+
+        txn.commit().await?;
+        Ok(true)
+    }
+
+    /// Commits a reservation as part of a larger transaction. Nothing is written if the
+    /// reservation is missing, expired or not covered by the balance.
+    pub(crate) async fn commit_in_txn(
+        &self,
+        txn: &impl ConnectionTrait,
+        reference_id: impl AsRef<str>,
+        user: &user::Model,
+        currency: Currency,
+        limit: Option<i64>,
+    ) -> CoreResult<bool> {
+        let Some(pending) = economy_pending::Entity::find_by_id((
             reference_id.as_ref().to_string(),
             user.id.to_string(),
             currency.into(),
         ))
-        .exec(&txn)
-        .await?;
+        .one(txn)
+        .await?
+        else {
+            return Ok(false);
+        };
 
-        let economy = self.find_or_create(&txn, user, currency).await?;
+        if pending.expires_at < chrono::Utc::now().naive_utc() {
+            return Ok(false);
+        };
+
+        let amount = limit.map_or(pending.amount, |limit| limit.clamp(0, pending.amount));
+        let economy = self.find_or_create(txn, user, currency).await?;
         let current_amount = economy.amount;
 
-        if current_amount < pending.amount {
-            txn.rollback().await?;
+        if current_amount < amount {
             return Ok(false);
         }
 
+        economy_pending::Entity::delete_by_id((
+            reference_id.as_ref().to_string(),
+            user.id.to_string(),
+            currency.into(),
+        ))
+        .exec(txn)
+        .await?;
+
         let mut active = economy.into_active_model();
-        active.amount = Set(current_amount.saturating_sub(pending.amount));
-        active.update(&txn).await?;
+        active.amount = Set(current_amount.saturating_sub(amount));
+        active.update(txn).await?;
 
-        txn.commit().await?;
         Ok(true)
     }
 
@@ -181,13 +369,24 @@ impl EconomyStore {
         reference_id: impl AsRef<str>,
         user: &user::Model,
         currency: Currency,
+    ) -> CoreResult<()> {
+        self.cancel_in_txn(self.db.conn(), reference_id, &user.id, currency)
+            .await
+    }
+
+    pub(crate) async fn cancel_in_txn(
+        &self,
+        txn: &impl ConnectionTrait,
+        reference_id: impl AsRef<str>,
+        user_id: &str,
+        currency: Currency,
     ) -> CoreResult<()> {
         economy_pending::Entity::delete_by_id((
             reference_id.as_ref().to_string(),
-            user.id.to_string(),
+            user_id.to_string(),
             currency.into(),
         ))
-        .exec(self.db.conn())
+        .exec(txn)
         .await?;
 
         Ok(())
@@ -195,13 +394,25 @@ impl EconomyStore {
 
     pub async fn add(&self, user: &user::Model, currency: Currency, amount: i64) -> CoreResult<()> {
         let txn = self.db.conn().begin().await?;
-        let economy = self.find_or_create(&txn, user, currency).await?;
+        self.add_in_txn(&txn, user, currency, amount).await?;
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn add_in_txn(
+        &self,
+        txn: &impl ConnectionTrait,
+        user: &user::Model,
+        currency: Currency,
+        amount: i64,
+    ) -> CoreResult<()> {
+        let economy = self.find_or_create(txn, user, currency).await?;
         let current_amount = economy.amount;
 
         let mut active = economy.into_active_model();
         active.amount = Set(current_amount.saturating_add(amount));
-        active.update(&txn).await?;
-        txn.commit().await?;
+        active.update(txn).await?;
 
         Ok(())
     }
@@ -213,6 +424,11 @@ impl EconomyStore {
         amount: i64,
     ) -> CoreResult<bool> {
         let txn = self.db.conn().begin().await?;
+        if self.is_frozen_in_txn(&txn, &user.id).await? {
+            txn.rollback().await?;
+            return Ok(false);
+        };
+
         let economy = self.find_or_create(&txn, user, currency).await?;
         let current_amount = economy.amount;
 
@@ -228,6 +444,170 @@ impl EconomyStore {
 
         Ok(true)
     }
+
+    pub async fn is_frozen(&self, user: &user::Model) -> CoreResult<bool> {
+        self.is_frozen_in_txn(self.db.conn(), &user.id).await
+    }
+
+    pub async fn find_frozen(
+        &self,
+        user: &user::Model,
+    ) -> CoreResult<Option<economy_frozen::Model>> {
+        Ok(economy_frozen::Entity::find_by_id(user.id.as_str())
+            .one(self.db.conn())
+            .await?)
+    }
+
+    pub async fn admin_grant(
+        &self,
+        admin: &user::Model,
+        user: &user::Model,
+        currency: Currency,
+        amount: i64,
+        reason: Option<String>,
+    ) -> CoreResult<economy_audit::Model> {
+        self.admin_modify(
+            admin,
+            user,
+            currency,
+            EconomyAuditAction::Grant,
+            amount,
+            reason,
+        )
+        .await
+    }
+
+    pub async fn admin_revoke(
+        &self,
+        admin: &user::Model,
+        user: &user::Model,
+        currency: Currency,
+        amount: i64,
+        reason: Option<String>,
+    ) -> CoreResult<economy_audit::Model> {
+        self.admin_modify(
+            admin,
+            user,
+            currency,
+            EconomyAuditAction::Revoke,
+            amount,
+            reason,
+        )
+        .await
+    }
+
+    pub async fn admin_set(
+        &self,
+        admin: &user::Model,
+        user: &user::Model,
+        currency: Currency,
+        amount: i64,
+        reason: Option<String>,
+    ) -> CoreResult<economy_audit::Model> {
+        self.admin_modify(
+            admin,
+            user,
+            currency,
+            EconomyAuditAction::Set,
+            amount,
+            reason,
+        )
+        .await
+    }
+
+    /// Freezes a wallet, blocking new reservations and subtractions. Returns false if it already was frozen.
+    pub async fn freeze(
+        &self,
+        admin: &user::Model,
+        user: &user::Model,
+        reason: Option<String>,
+    ) -> CoreResult<bool> {
+        let txn = self.db.conn().begin().await?;
+        if self.is_frozen_in_txn(&txn, &user.id).await? {
+            txn.rollback().await?;
+            return Ok(false);
+        };
+
+        let frozen = economy_frozen::ActiveModel {
+            user_id: Set(user.id.to_string()),
+            frozen_by: Set(admin.id.to_string()),
+            reason: Set(reason.clone()),
+            ..Default::default()
+        };
+        frozen.insert(&txn).await?;
+
+        self.insert_audit(
+            &txn,
+            admin,
+            user,
+            EconomyAuditAction::Freeze,
+            None,
+            None,
+            None,
+            reason,
+        )
+        .await?;
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Unfreezes a wallet. Returns false if it was not frozen.
+    pub async fn unfreeze(
+        &self,
+        admin: &user::Model,
+        user: &user::Model,
+        reason: Option<String>,
+    ) -> CoreResult<bool> {
+        let txn = self.db.conn().begin().await?;
+        let result = economy_frozen::Entity::delete_by_id(user.id.as_str())
+            .exec(&txn)
+            .await?;
+        if result.rows_affected == 0 {
+            txn.rollback().await?;
+            return Ok(false);
+        };
+
+        self.insert_audit(
+            &txn,
+            admin,
+            user,
+            EconomyAuditAction::Unfreeze,
+            None,
+            None,
+            None,
+            reason,
+        )
+        .await?;
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    pub async fn pending_reservations(
+        &self,
+        user: &user::Model,
+    ) -> CoreResult<Vec<economy_pending::Model>> {
+        Ok(economy_pending::Entity::find()
+            .filter(economy_pending::Column::UserId.eq(&user.id))
+            .filter(economy_pending::Column::ExpiresAt.gt(chrono::Utc::now().naive_utc()))
+            .order_by_asc(economy_pending::Column::ExpiresAt)
+            .all(self.db.conn())
+            .await?)
+    }
+
+    pub async fn audit_log(
+        &self,
+        user: &user::Model,
+        limit: u64,
+    ) -> CoreResult<Vec<economy_audit::Model>> {
+        Ok(economy_audit::Entity::find()
+            .filter(economy_audit::Column::UserId.eq(&user.id))
+            .order_by_desc(economy_audit::Column::CreatedAt)
+            .limit(limit)
+            .all(self.db.conn())
+            .await?)
+    }
 }
 
 #[derive(FromQueryResult)]
@@ -0,0 +1,102 @@
+use crate::database::entity::{game_rating, game_rating_history, user};
+use crate::database::Database;
+use crate::error::CoreResult;
+use crate::games::rating::Glicko2Rating;
+use crate::types::rated_game_kind::RatedGameKind;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct GameRatingStore {
+    db: Arc<Database>,
+}
+
+impl GameRatingStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find_by_id(
+        &self,
+        user_id: impl AsRef<str>,
+        kind: RatedGameKind,
+    ) -> CoreResult<Option<game_rating::Model>> {
+        Ok(
+            game_rating::Entity::find_by_id((user_id.as_ref().to_string(), i16::from(kind)))
+                .one(self.db.conn())
+                .await?,
+        )
+    }
+
+    pub async fn fetch_or_create(
+        &self,
+        user: &user::Model,
+        kind: RatedGameKind,
+    ) -> CoreResult<game_rating::Model> {
+        if let Some(existing) = self.find_by_id(&user.id, kind).await? {
+            return Ok(existing);
+        };
+
+        let new = game_rating::ActiveModel {
+            user_id: Set(user.id.to_string()),
+            kind: Set(kind.into()),
+            ..Default::default()
+        };
+
+        Ok(new.insert(self.db.conn()).await?)
+    }
+
+    /// Stores the rating a player has after the given game and adds it to their history.
+    pub async fn update(
+        &self,
+        model: game_rating::Model,
+        rating: Glicko2Rating,
+        game_id: Uuid,
+    ) -> CoreResult<game_rating::Model> {
+        let txn = self.db.conn().begin().await?;
+
+        let history = game_rating_history::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            user_id: Set(model.user_id.clone()),
+            kind: Set(model.kind),
+            rating: Set(rating.rating),
+            deviation: Set(rating.deviation),
+            game_id: Set(game_id),
+            ..Default::default()
+        };
+        history.insert(&txn).await?;
+
+        let games = model.games.saturating_add(1);
+        let mut active = model.into_active_model();
+        active.rating = Set(rating.rating);
+        active.deviation = Set(rating.deviation);
+        active.volatility = Set(rating.volatility);
+        active.games = Set(games);
+        active.updated_at = Set(chrono::Utc::now().naive_utc());
+        let updated = active.update(&txn).await?;
+
+        txn.commit().await?;
+        Ok(updated)
+    }
+
+    /// The most recent ratings of a player, from oldest to newest.
+    pub async fn history(
+        &self,
+        user_id: impl AsRef<str>,
+        kind: RatedGameKind,
+        limit: u64,
+    ) -> CoreResult<Vec<game_rating_history::Model>> {
+        let mut history = game_rating_history::Entity::find()
+            .filter(game_rating_history::Column::UserId.eq(user_id.as_ref()))
+            .filter(game_rating_history::Column::Kind.eq(i16::from(kind)))
+            .order_by_desc(game_rating_history::Column::CreatedAt)
+            .limit(limit)
+            .all(self.db.conn())
+            .await?;
+        history.reverse();
+        Ok(history)
+    }
+}
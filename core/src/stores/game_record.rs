@@ -0,0 +1,26 @@
+use crate::database::entity::game_record;
+use crate::database::Database;
+use crate::error::CoreResult;
+use sea_orm::{ActiveModelTrait, EntityTrait};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct GameRecordStore {
+    db: Arc<Database>,
+}
+
+impl GameRecordStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> CoreResult<Option<game_record::Model>> {
+        Ok(game_record::Entity::find_by_id(id)
+            .one(self.db.conn())
+            .await?)
+    }
+
+    pub async fn insert(&self, model: game_record::ActiveModel) -> CoreResult<game_record::Model> {
+        Ok(model.insert(self.db.conn()).await?)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::database::entity::{guild, guild_blackjack};
+use crate::database::Database;
+use crate::error::CoreResult;
+use sea_orm::prelude::Json;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter};
+use sea_orm::{QuerySelect, Set, TransactionTrait};
+use std::sync::Arc;
+
+pub struct GuildBlackjackStore {
+    db: Arc<Database>,
+}
+
+impl GuildBlackjackStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find_by_guild_id(
+        &self,
+        id: impl AsRef<str>,
+    ) -> CoreResult<Option<guild_blackjack::Model>> {
+        Ok(guild_blackjack::Entity::find_by_id(id.as_ref())
+            .one(self.db.conn())
+            .await?)
+    }
+
+    pub async fn fetch_or_create(
+        &self,
+        guild: &guild::Model,
+    ) -> CoreResult<guild_blackjack::Model> {
+        if let Some(existing) = self.find_by_guild_id(&guild.id).await? {
+            return Ok(existing);
+        };
+
+        let new = guild_blackjack::ActiveModel {
+            guild_id: Set(guild.id.to_string()),
+            ..Default::default()
+        };
+
+        Ok(new.insert(self.db.conn()).await?)
+    }
+
+    pub async fn update(
+        &self,
+        mut model: guild_blackjack::ActiveModel,
+    ) -> CoreResult<guild_blackjack::Model> {
+        model.updated_at = Set(chrono::Utc::now().naive_utc());
+        Ok(model.update(self.db.conn()).await?)
+    }
+
+    /// Takes the shoe of the guild's table out of storage, so that concurrent games in the
+    /// guild never deal from the same shoe. The shoe is put back with [`Self::save_shoe`].
+    pub async fn take_shoe(&self, guild_id: impl AsRef<str>) -> CoreResult<Option<Json>> {
+        let txn = self.db.conn().begin().await?;
+        let Some(settings) = guild_blackjack::Entity::find_by_id(guild_id.as_ref())
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let shoe = settings.shoe.clone();
+        if shoe.is_some() {
+            let mut active = settings.into_active_model();
+            active.shoe = Set(None);
+            active.update(&txn).await?;
+        }
+        txn.commit().await?;
+
+        Ok(shoe)
+    }
+
+    pub async fn save_shoe(&self, guild_id: impl AsRef<str>, shoe: Json) -> CoreResult<()> {
+        guild_blackjack::Entity::update_many()
+            .col_expr(guild_blackjack::Column::Shoe, Expr::value(shoe))
+            .filter(guild_blackjack::Column::GuildId.eq(guild_id.as_ref().to_string()))
+            .exec(self.db.conn())
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use crate::database::entity::{guild, guild_level_reward};
+use crate::database::Database;
+use crate::error::CoreResult;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set};
+use std::sync::Arc;
+
+pub struct GuildLevelRewardStore {
+    db: Arc<Database>,
+}
+
+impl GuildLevelRewardStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find_by_guild_id(
+        &self,
+        guild_id: impl AsRef<str>,
+    ) -> CoreResult<Vec<guild_level_reward::Model>> {
+        Ok(guild_level_reward::Entity::find()
+            .filter(guild_level_reward::Column::GuildId.eq(guild_id.as_ref().to_string()))
+            .order_by_asc(guild_level_reward::Column::Level)
+            .all(self.db.conn())
+            .await?)
+    }
+
+    /// All rewards for levels in `(from_level, to_level]`.
+    pub async fn find_reached(
+        &self,
+        guild_id: impl AsRef<str>,
+        from_level: i32,
+        to_level: i32,
+    ) -> CoreResult<Vec<guild_level_reward::Model>> {
+        Ok(guild_level_reward::Entity::find()
+            .filter(guild_level_reward::Column::GuildId.eq(guild_id.as_ref().to_string()))
+            .filter(guild_level_reward::Column::Level.gt(from_level))
+            .filter(guild_level_reward::Column::Level.lte(to_level))
+            .order_by_asc(guild_level_reward::Column::Level)
+            .all(self.db.conn())
+            .await?)
+    }
+
+    pub async fn set(
+        &self,
+        guild: &guild::Model,
+        level: i32,
+        role_id: impl AsRef<str>,
+    ) -> CoreResult<()> {
+        let model = guild_level_reward::ActiveModel {
+            guild_id: Set(guild.id.to_string()),
+            level: Set(level),
+            role_id: Set(role_id.as_ref().to_string()),
+            ..Default::default()
+        };
+
+        guild_level_reward::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    guild_level_reward::Column::GuildId,
+                    guild_level_reward::Column::Level,
+                ])
+                .update_column(guild_level_reward::Column::RoleId)
+                .to_owned(),
+            )
+            .exec(self.db.conn())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(&self, guild: &guild::Model, level: i32) -> CoreResult<bool> {
+        let result = guild_level_reward::Entity::delete_by_id((guild.id.to_string(), level))
+            .exec(self.db.conn())
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::database::entity::{guild, guild_leveling};
+use crate::database::Database;
+use crate::error::CoreResult;
+use sea_orm::{ActiveModelTrait, EntityTrait, Set};
+use std::sync::Arc;
+
+pub struct GuildLevelingStore {
+    db: Arc<Database>,
+}
+
+impl GuildLevelingStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find_by_guild_id(
+        &self,
+        id: impl AsRef<str>,
+    ) -> CoreResult<Option<guild_leveling::Model>> {
+        Ok(guild_leveling::Entity::find_by_id(id.as_ref())
+            .one(self.db.conn())
+            .await?)
+    }
+
+    pub async fn fetch_or_create(&self, guild: &guild::Model) -> CoreResult<guild_leveling::Model> {
+        if let Some(existing) = self.find_by_guild_id(&guild.id).await? {
+            return Ok(existing);
+        };
+
+        let new = guild_leveling::ActiveModel {
+            guild_id: Set(guild.id.to_string()),
+            ..Default::default()
+        };
+
+        Ok(new.insert(self.db.conn()).await?)
+    }
+
+    pub async fn update(
+        &self,
+        mut model: guild_leveling::ActiveModel,
+    ) -> CoreResult<guild_leveling::Model> {
+        model.updated_at = Set(chrono::Utc::now().naive_utc());
+        Ok(model.update(self.db.conn()).await?)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::database::entity::{guild, guild_lottery};
+use crate::database::Database;
+use crate::error::CoreResult;
+use futures::StreamExt;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set};
+use std::sync::Arc;
+
+pub struct GuildLotteryStore {
+    db: Arc<Database>,
+}
+
+impl GuildLotteryStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find_by_guild_id(
+        &self,
+        id: impl AsRef<str>,
+    ) -> CoreResult<Option<guild_lottery::Model>> {
+        Ok(guild_lottery::Entity::find_by_id(id.as_ref())
+            .one(self.db.conn())
+            .await?)
+    }
+
+    pub async fn fetch_or_create(&self, guild: &guild::Model) -> CoreResult<guild_lottery::Model> {
+        if let Some(existing) = self.find_by_guild_id(&guild.id).await? {
+            return Ok(existing);
+        };
+
+        let new = guild_lottery::ActiveModel {
+            guild_id: Set(guild.id.to_string()),
+            ..Default::default()
+        };
+
+        Ok(new.insert(self.db.conn()).await?)
+    }
+
+    pub async fn update(
+        &self,
+        mut model: guild_lottery::ActiveModel,
+    ) -> CoreResult<guild_lottery::Model> {
+        model.updated_at = Set(chrono::Utc::now().naive_utc());
+        Ok(model.update(self.db.conn()).await?)
+    }
+
+    pub async fn stream_all_enabled(
+        &self,
+    ) -> CoreResult<impl futures::Stream<Item = CoreResult<guild_lottery::Model>>> {
+        Ok(guild_lottery::Entity::find()
+            .filter(guild_lottery::Column::Enabled.eq(true))
+            .stream(self.db.conn())
+            .await?
+            .map(|model| Ok(model?)))
+    }
+}
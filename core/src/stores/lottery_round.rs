@@ -0,0 +1,263 @@
+use crate::database::entity::{guild_lottery, lottery_round, lottery_ticket, user};
+use crate::database::Database;
+use crate::error::CoreResult;
+use crate::games::lottery::{self, generate_seed, seed_hash, LotteryResult};
+use crate::stores::economy::EconomyStore;
+use crate::types::currency::Currency;
+use sea_orm::sea_query::{Expr, Query};
+use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel};
+use sea_orm::{ExprTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait};
+use std::sync::Arc;
+use tracing::warn;
+use uuid::Uuid;
+
+/// A round which was drawn, together with the round opened after it.
+pub struct DrawnRound {
+    pub round: lottery_round::Model,
+    pub result: LotteryResult,
+    pub next_round: Option<lottery_round::Model>,
+}
+
+pub struct LotteryRoundStore {
+    db: Arc<Database>,
+}
+
+impl LotteryRoundStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> CoreResult<Option<lottery_round::Model>> {
+        Ok(lottery_round::Entity::find_by_id(id)
+            .one(self.db.conn())
+            .await?)
+    }
+
+    pub async fn find_open(
+        &self,
+        guild_id: impl AsRef<str>,
+    ) -> CoreResult<Option<lottery_round::Model>> {
+        Ok(lottery_round::Entity::find()
+            .filter(lottery_round::Column::GuildId.eq(guild_id.as_ref().to_string()))
+            .filter(lottery_round::Column::DrawnAt.is_null())
+            .order_by_asc(lottery_round::Column::CreatedAt)
+            .one(self.db.conn())
+            .await?)
+    }
+
+    pub async fn find_last_drawn(
+        &self,
+        guild_id: impl AsRef<str>,
+    ) -> CoreResult<Option<lottery_round::Model>> {
+        Ok(lottery_round::Entity::find()
+            .filter(lottery_round::Column::GuildId.eq(guild_id.as_ref().to_string()))
+            .filter(lottery_round::Column::DrawnAt.is_not_null())
+            .order_by_desc(lottery_round::Column::DrawnAt)
+            .one(self.db.conn())
+            .await?)
+    }
+
+    /// Open rounds of guilds which disabled their lottery while tickets were still bought.
+    pub async fn find_open_of_disabled(&self) -> CoreResult<Vec<lottery_round::Model>> {
+        Ok(lottery_round::Entity::find()
+            .filter(lottery_round::Column::DrawnAt.is_null())
+            .filter(
+                lottery_round::Column::GuildId.in_subquery(
+                    Query::select()
+                        .column(guild_lottery::Column::GuildId)
+                        .from(guild_lottery::Entity)
+                        .and_where(Expr::col(guild_lottery::Column::Enabled).eq(false))
+                        .to_owned(),
+                ),
+            )
+            .filter(
+                lottery_round::Column::Id.in_subquery(
+                    Query::select()
+                        .column(lottery_ticket::Column::RoundId)
+                        .from(lottery_ticket::Entity)
+                        .to_owned(),
+                ),
+            )
+            .all(self.db.conn())
+            .await?)
+    }
+
+    /// Opens a new round with a freshly generated seed, only the seed hash should be shown until the draw.
+    pub async fn create(
+        &self,
+        guild_id: impl AsRef<str>,
+        ticket_price: i64,
+        carried_over: i64,
+        draws_at: chrono::DateTime<chrono::Utc>,
+    ) -> CoreResult<lottery_round::Model> {
+        self.create_in_txn(
+            self.db.conn(),
+            guild_id,
+            ticket_price,
+            carried_over,
+            draws_at,
+        )
+        .await
+    }
+
+    async fn create_in_txn(
+        &self,
+        txn: &impl ConnectionTrait,
+        guild_id: impl AsRef<str>,
+        ticket_price: i64,
+        carried_over: i64,
+        draws_at: chrono::DateTime<chrono::Utc>,
+    ) -> CoreResult<lottery_round::Model> {
+        let seed = generate_seed();
+        let new = lottery_round::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            guild_id: Set(guild_id.as_ref().to_string()),
+            seed: Set(hex::encode(seed)),
+            seed_hash: Set(seed_hash(&seed)),
+            ticket_price: Set(ticket_price),
+            carried_over: Set(carried_over),
+            draws_at: Set(draws_at.naive_utc()),
+            ..Default::default()
+        };
+        Ok(new.insert(txn).await?)
+    }
+
+    /// Draws an open round in a single transaction: the round is locked first, so no tickets
+    /// can be bought while it is drawn. Then the ticket reservations are committed, the winner
+    /// is paid, the round is closed and the next round is opened with `next_round`, given as
+    /// ticket price and draw time. Tickets whose reservation cannot be committed are voided.
+    /// Returns `None` if the round was already drawn.
+    pub async fn draw(
+        &self,
+        economy: &EconomyStore,
+        round_id: Uuid,
+        sink_percent: i16,
+        next_round: Option<(i64, chrono::DateTime<chrono::Utc>)>,
+    ) -> CoreResult<Option<DrawnRound>> {
+        let txn = self.db.conn().begin().await?;
+        let Some(round) = lottery_round::Entity::find_by_id(round_id)
+            .filter(lottery_round::Column::DrawnAt.is_null())
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+        else {
+            txn.rollback().await?;
+            return Ok(None);
+        };
+
+        let mut valid_tickets = Vec::new();
+        for (ticket, user) in lottery_ticket::Entity::find()
+            .filter(lottery_ticket::Column::RoundId.eq(round.id))
+            .order_by_asc(lottery_ticket::Column::CreatedAt)
+            .order_by_asc(lottery_ticket::Column::Id)
+            .find_also_related(user::Entity)
+            .all(&txn)
+            .await?
+        {
+            let committed = match &user {
+                Some(user) => {
+                    economy
+                        .commit_in_txn(
+                            &txn,
+                            lottery::reference_id(ticket.id),
+                            user,
+                            Currency::Citrine,
+                            None,
+                        )
+                        .await?
+                }
+                None => false,
+            };
+            if committed {
+                valid_tickets.push(ticket);
+            } else {
+                warn!(
+                    "Voiding lottery ticket '{}' of user '{}', reservation could not be committed",
+                    ticket.id, ticket.user_id
+                );
+                lottery_ticket::Entity::delete_by_id(ticket.id)
+                    .exec(&txn)
+                    .await?;
+            }
+        }
+
+        let result = lottery::draw(&round, &valid_tickets, sink_percent)?;
+        if let Some(winner_id) = &result.winner_id
+            && let Some(winner) = user::Entity::find_by_id(winner_id).one(&txn).await?
+        {
+            economy
+                .add_in_txn(&txn, &winner, Currency::Citrine, result.payout)
+                .await?;
+        }
+
+        let guild_id = round.guild_id.clone();
+        let mut active = round.into_active_model();
+        active.pot = Set(Some(result.pot));
+        active.sink = Set(Some(result.sink));
+        active.winner_id = Set(result.winner_id.clone());
+        active.winning_ticket = Set(result.winning_ticket.map(|index| index as i64));
+        active.drawn_at = Set(Some(chrono::Utc::now().naive_utc()));
+        let round = active.update(&txn).await?;
+
+        let next_round = match next_round {
+            Some((ticket_price, draws_at)) => Some(
+                self.create_in_txn(&txn, guild_id, ticket_price, result.next_jackpot, draws_at)
+                    .await?,
+            ),
+            None => None,
+        };
+
+        txn.commit().await?;
+        Ok(Some(DrawnRound {
+            round,
+            result,
+            next_round,
+        }))
+    }
+
+    /// Releases the reservations of every ticket of an open round and removes the tickets.
+    /// The round itself stays open, so its carried over jackpot is kept. Returns the number
+    /// of ticket purchases that were refunded.
+    pub async fn refund(&self, economy: &EconomyStore, round_id: Uuid) -> CoreResult<usize> {
+        let txn = self.db.conn().begin().await?;
+        if lottery_round::Entity::find_by_id(round_id)
+            .filter(lottery_round::Column::DrawnAt.is_null())
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .is_none()
+        {
+            txn.rollback().await?;
+            return Ok(0);
+        }
+
+        let tickets = lottery_ticket::Entity::find()
+            .filter(lottery_ticket::Column::RoundId.eq(round_id))
+            .all(&txn)
+            .await?;
+        for ticket in &tickets {
+            economy
+                .cancel_in_txn(
+                    &txn,
+                    lottery::reference_id(ticket.id),
+                    &ticket.user_id,
+                    Currency::Citrine,
+                )
+                .await?;
+        }
+        lottery_ticket::Entity::delete_many()
+            .filter(lottery_ticket::Column::RoundId.eq(round_id))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+        Ok(tickets.len())
+    }
+
+    pub async fn update(
+        &self,
+        model: lottery_round::ActiveModel,
+    ) -> CoreResult<lottery_round::Model> {
+        Ok(model.update(self.db.conn()).await?)
+    }
+}
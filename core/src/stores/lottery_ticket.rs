@@ -0,0 +1,63 @@
+use crate::database::entity::{lottery_round, lottery_ticket, user};
+use crate::database::Database;
+use crate::error::CoreResult;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use sea_orm::{Set, TransactionTrait};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct LotteryTicketStore {
+    db: Arc<Database>,
+}
+
+impl LotteryTicketStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    /// Registers the tickets unless the round is closed or due to be drawn. The round is locked
+    /// while the tickets are added, so a draw either includes them or they are rejected.
+    pub async fn create(
+        &self,
+        id: Uuid,
+        round: &lottery_round::Model,
+        user: &user::Model,
+        tickets: i32,
+        cost: i64,
+    ) -> CoreResult<Option<lottery_ticket::Model>> {
+        let txn = self.db.conn().begin().await?;
+        let open = lottery_round::Entity::find_by_id(round.id)
+            .filter(lottery_round::Column::DrawnAt.is_null())
+            .filter(lottery_round::Column::DrawsAt.gt(chrono::Utc::now().naive_utc()))
+            .lock_shared()
+            .one(&txn)
+            .await?;
+        if open.is_none() {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        let new = lottery_ticket::ActiveModel {
+            id: Set(id),
+            round_id: Set(round.id),
+            user_id: Set(user.id.to_string()),
+            tickets: Set(tickets),
+            cost: Set(cost),
+            ..Default::default()
+        };
+        let ticket = new.insert(&txn).await?;
+
+        txn.commit().await?;
+        Ok(Some(ticket))
+    }
+
+    /// All ticket purchases of a round in the order they were bought, which is also the ticket numbering.
+    pub async fn find_by_round(&self, round_id: Uuid) -> CoreResult<Vec<lottery_ticket::Model>> {
+        Ok(lottery_ticket::Entity::find()
+            .filter(lottery_ticket::Column::RoundId.eq(round_id))
+            .order_by_asc(lottery_ticket::Column::CreatedAt)
+            .order_by_asc(lottery_ticket::Column::Id)
+            .all(self.db.conn())
+            .await?)
+    }
+}
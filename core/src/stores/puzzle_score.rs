@@ -0,0 +1,75 @@
+use crate::database::entity::{puzzle_score, user};
+use crate::database::Database;
+use crate::error::CoreResult;
+use crate::types::puzzle_kind::PuzzleKind;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter, Set};
+use std::sync::Arc;
+
+pub struct PuzzleScoreStore {
+    db: Arc<Database>,
+}
+
+impl PuzzleScoreStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find_by_id(
+        &self,
+        user_id: impl AsRef<str>,
+        kind: PuzzleKind,
+    ) -> CoreResult<Option<puzzle_score::Model>> {
+        Ok(
+            puzzle_score::Entity::find_by_id((user_id.as_ref().to_string(), i16::from(kind)))
+                .one(self.db.conn())
+                .await?,
+        )
+    }
+
+    pub async fn find_by_user_id(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> CoreResult<Vec<puzzle_score::Model>> {
+        Ok(puzzle_score::Entity::find()
+            .filter(puzzle_score::Column::UserId.eq(user_id.as_ref()))
+            .all(self.db.conn())
+            .await?)
+    }
+
+    pub async fn fetch_or_create(
+        &self,
+        user: &user::Model,
+        kind: PuzzleKind,
+    ) -> CoreResult<puzzle_score::Model> {
+        if let Some(existing) = self.find_by_id(&user.id, kind).await? {
+            return Ok(existing);
+        };
+
+        let new = puzzle_score::ActiveModel {
+            user_id: Set(user.id.to_string()),
+            kind: Set(kind.into()),
+            ..Default::default()
+        };
+
+        Ok(new.insert(self.db.conn()).await?)
+    }
+
+    /// Counts a finished game and stores its score and time where they are the new best.
+    pub async fn update(
+        &self,
+        model: puzzle_score::Model,
+        won: bool,
+        best_score: Option<i64>,
+        best_time_ms: Option<i64>,
+    ) -> CoreResult<puzzle_score::Model> {
+        let games = model.games.saturating_add(1);
+        let wins = model.wins.saturating_add(won as i32);
+        let mut active = model.into_active_model();
+        active.games = Set(games);
+        active.wins = Set(wins);
+        active.best_score = Set(best_score);
+        active.best_time_ms = Set(best_time_ms);
+        active.updated_at = Set(chrono::Utc::now().naive_utc());
+        Ok(active.update(self.db.conn()).await?)
+    }
+}
@@ -1,8 +1,10 @@
-use crate::database::entity::{user, user_birthday, user_guild};
+use crate::database::entity::{birthday_opt_out, user, user_birthday, user_guild};
 use crate::database::Database;
 use crate::error::CoreResult;
+use chrono::NaiveDateTime;
+use sea_orm::sea_query::{Expr, Query};
 use sea_orm::PaginatorTrait;
-use sea_orm::{ActiveModelTrait, ColumnTrait, JoinType, QuerySelect, RelationTrait};
+use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, JoinType, QuerySelect, RelationTrait};
 use sea_orm::{EntityTrait, Set};
 use sea_orm::{QueryFilter, QueryOrder};
 use std::sync::Arc;
@@ -35,6 +37,31 @@ impl UserBirthdayStore {
         Ok(model)
     }
 
+    /// Saves the privacy settings without counting as a change made to the birthday itself.
+    pub async fn update_settings(
+        &self,
+        model: user_birthday::ActiveModel,
+    ) -> CoreResult<user_birthday::Model> {
+        Ok(model.update(self.db.conn()).await?)
+    }
+
+    /// Moves the next birthday without counting as a change made by the user.
+    pub async fn set_next_birthday(
+        &self,
+        user_id: impl AsRef<str>,
+        next_birthday: NaiveDateTime,
+    ) -> CoreResult<()> {
+        user_birthday::Entity::update_many()
+            .col_expr(
+                user_birthday::Column::NextBirthday,
+                Expr::value(next_birthday),
+            )
+            .filter(user_birthday::Column::UserId.eq(user_id.as_ref().to_string()))
+            .exec(self.db.conn())
+            .await?;
+        Ok(())
+    }
+
     pub async fn insert(
         &self,
         model: user_birthday::ActiveModel,
@@ -48,11 +75,21 @@ impl UserBirthdayStore {
         Ok(user_birthday::Entity::find().stream(self.db.conn()).await?)
     }
 
+    pub async fn stream_due(
+        &self,
+        now: NaiveDateTime,
+    ) -> CoreResult<impl futures::Stream<Item = Result<user_birthday::Model, sea_orm::DbErr>>> {
+        Ok(user_birthday::Entity::find()
+            .filter(user_birthday::Column::NextBirthday.lte(now))
+            .stream(self.db.conn())
+            .await?)
+    }
+
     pub async fn count_by_guild(&self, guild_id: impl AsRef<str>) -> CoreResult<u64> {
         Ok(user_birthday::Entity::find()
             .join(JoinType::InnerJoin, user_birthday::Relation::User.def())
             .join(JoinType::InnerJoin, user::Relation::UserGuild.def())
-            .filter(user_guild::Column::GuildId.eq(guild_id.as_ref().to_string()))
+            .filter(Self::listed_in_guild(guild_id))
             .count(self.db.conn())
             .await?)
     }
@@ -66,7 +103,7 @@ impl UserBirthdayStore {
         let mut query = user_birthday::Entity::find()
             .join(JoinType::InnerJoin, user_birthday::Relation::User.def())
             .join(JoinType::InnerJoin, user::Relation::UserGuild.def())
-            .filter(user_guild::Column::GuildId.eq(guild_id.as_ref().to_string()))
+            .filter(Self::listed_in_guild(guild_id))
             .order_by_asc(user_birthday::Column::NextBirthday);
 
         if let Some(limit) = limit {
@@ -79,4 +116,21 @@ impl UserBirthdayStore {
 
         Ok(query.stream(self.db.conn()).await?)
     }
+
+    /// Members of the guild who neither hide from listings nor opted out of the guild.
+    fn listed_in_guild(guild_id: impl AsRef<str>) -> Condition {
+        let guild_id = guild_id.as_ref().to_string();
+        Condition::all()
+            .add(user_guild::Column::GuildId.eq(guild_id.clone()))
+            .add(user_birthday::Column::HideFromListings.eq(false))
+            .add(
+                user_birthday::Column::UserId.not_in_subquery(
+                    Query::select()
+                        .column(birthday_opt_out::Column::UserId)
+                        .from(birthday_opt_out::Entity)
+                        .and_where(birthday_opt_out::Column::GuildId.eq(guild_id))
+                        .to_owned(),
+                ),
+            )
+    }
 }
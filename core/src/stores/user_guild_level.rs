@@ -0,0 +1,63 @@
+use crate::database::entity::{guild, user, user_guild_level};
+use crate::database::Database;
+use crate::error::CoreResult;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, Set};
+use std::sync::Arc;
+
+pub struct UserGuildLevelStore {
+    db: Arc<Database>,
+}
+
+impl UserGuildLevelStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find_by_id(
+        &self,
+        user_id: impl AsRef<str>,
+        guild_id: impl AsRef<str>,
+    ) -> CoreResult<Option<user_guild_level::Model>> {
+        Ok(user_guild_level::Entity::find_by_id((
+            user_id.as_ref().to_string(),
+            guild_id.as_ref().to_string(),
+        ))
+        .one(self.db.conn())
+        .await?)
+    }
+
+    pub async fn fetch_or_create(
+        &self,
+        user: &user::Model,
+        guild: &guild::Model,
+    ) -> CoreResult<user_guild_level::Model> {
+        if let Some(existing) = self.find_by_id(&user.id, &guild.id).await? {
+            return Ok(existing);
+        };
+
+        let new = user_guild_level::ActiveModel {
+            user_id: Set(user.id.to_string()),
+            guild_id: Set(guild.id.to_string()),
+            ..Default::default()
+        };
+        Ok(new.insert(self.db.conn()).await?)
+    }
+
+    pub async fn update(
+        &self,
+        mut model: user_guild_level::ActiveModel,
+    ) -> CoreResult<user_guild_level::Model> {
+        model.updated_at = Set(chrono::Utc::now().naive_utc());
+        Ok(model.update(self.db.conn()).await?)
+    }
+
+    /// The 1-based leaderboard position of a given amount of XP within a guild.
+    pub async fn rank_in_guild(&self, guild_id: impl AsRef<str>, xp: i64) -> CoreResult<u64> {
+        let ahead = user_guild_level::Entity::find()
+            .filter(user_guild_level::Column::GuildId.eq(guild_id.as_ref().to_string()))
+            .filter(user_guild_level::Column::Xp.gt(xp))
+            .count(self.db.conn())
+            .await?;
+        Ok(ahead + 1)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::database::entity::{user, user_level};
+use crate::database::Database;
+use crate::error::CoreResult;
+use sea_orm::{ActiveModelTrait, EntityTrait, Set};
+use std::sync::Arc;
+
+pub struct UserLevelStore {
+    db: Arc<Database>,
+}
+
+impl UserLevelStore {
+    pub fn initialize(db: &Arc<Database>) -> Arc<Self> {
+        Arc::new(Self { db: db.clone() })
+    }
+
+    pub async fn find(&self, user_id: impl AsRef<str>) -> CoreResult<Option<user_level::Model>> {
+        Ok(user_level::Entity::find_by_id(user_id.as_ref())
+            .one(self.db.conn())
+            .await?)
+    }
+
+    pub async fn fetch_or_create(&self, user: &user::Model) -> CoreResult<user_level::Model> {
+        if let Some(existing) = self.find(&user.id).await? {
+            return Ok(existing);
+        };
+
+        let new = user_level::ActiveModel {
+            user_id: Set(user.id.to_string()),
+            ..Default::default()
+        };
+
+        Ok(new.insert(self.db.conn()).await?)
+    }
+
+    pub async fn update(
+        &self,
+        mut model: user_level::ActiveModel,
+    ) -> CoreResult<user_level::Model> {
+        model.updated_at = Set(chrono::Utc::now().naive_utc());
+        Ok(model.update(self.db.conn()).await?)
+    }
+}
@@ -1,7 +1,11 @@
+pub mod active_game_kind;
 pub mod color_gradient;
 pub mod currency;
+pub mod economy_audit_action;
 pub mod feature;
 pub mod grid;
+pub mod puzzle_kind;
+pub mod rated_game_kind;
 pub mod user_guild_info;
 pub mod user_permissions;
 pub mod user_settings;
@@ -0,0 +1,24 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
+#[repr(i16)]
+pub enum ActiveGameKind {
+    Blackjack = 0,
+    Connect4 = 1,
+    RockPaperScissors = 2,
+    RockPaperScissorsTournament = 3,
+    TurnBased = 4,
+}
+
+impl Display for ActiveGameKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Blackjack => write!(f, "Blackjack"),
+            Self::Connect4 => write!(f, "Connect Four"),
+            Self::RockPaperScissors => write!(f, "Rock Paper Scissors"),
+            Self::RockPaperScissorsTournament => write!(f, "Rock Paper Scissors Tournament"),
+            Self::TurnBased => write!(f, "Game"),
+        }
+    }
+}
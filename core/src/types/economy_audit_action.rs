@@ -0,0 +1,18 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
+#[repr(i16)]
+pub enum EconomyAuditAction {
+    Grant = 0,
+    Revoke = 1,
+    Set = 2,
+    Freeze = 3,
+    Unfreeze = 4,
+}
+
+impl Display for EconomyAuditAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
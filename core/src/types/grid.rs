@@ -159,10 +159,6 @@ impl<T> Grid<T> {
         count
     }
 
-    pub fn into_iter(self) -> impl Iterator<Item = T> {
-        self.tiles.into_iter()
-    }
-
     pub fn iter_tiles(&self) -> impl Iterator<Item = &T> {
         self.tiles.iter()
     }
@@ -190,3 +186,12 @@ impl<T> Grid<T> {
         self.tiles.iter_mut()
     }
 }
+
+impl<T> IntoIterator for Grid<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tiles.into_iter()
+    }
+}
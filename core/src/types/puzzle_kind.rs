@@ -0,0 +1,38 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use std::fmt::Display;
+
+/// The single-player puzzles players keep their best results in, every Minesweeper difficulty
+/// has its own best time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
+#[repr(i16)]
+pub enum PuzzleKind {
+    MinesweeperBeginner = 0,
+    MinesweeperIntermediate = 1,
+    MinesweeperExpert = 2,
+    Game2048 = 3,
+}
+
+impl PuzzleKind {
+    pub const ALL: [Self; 4] = [
+        Self::MinesweeperBeginner,
+        Self::MinesweeperIntermediate,
+        Self::MinesweeperExpert,
+        Self::Game2048,
+    ];
+
+    /// Whether the puzzle is ranked by the time it took to solve, instead of its score.
+    pub fn is_timed(&self) -> bool {
+        !matches!(self, Self::Game2048)
+    }
+}
+
+impl Display for PuzzleKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MinesweeperBeginner => write!(f, "Minesweeper (Beginner)"),
+            Self::MinesweeperIntermediate => write!(f, "Minesweeper (Intermediate)"),
+            Self::MinesweeperExpert => write!(f, "Minesweeper (Expert)"),
+            Self::Game2048 => write!(f, "2048"),
+        }
+    }
+}
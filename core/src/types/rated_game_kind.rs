@@ -0,0 +1,23 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use std::fmt::Display;
+
+/// The games players are rated in, each kind has its own rating.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
+#[repr(i16)]
+pub enum RatedGameKind {
+    RockPaperScissors = 0,
+    Connect4 = 1,
+}
+
+impl RatedGameKind {
+    pub const ALL: [Self; 2] = [Self::RockPaperScissors, Self::Connect4];
+}
+
+impl Display for RatedGameKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RockPaperScissors => write!(f, "Rock Paper Scissors"),
+            Self::Connect4 => write!(f, "Connect Four"),
+        }
+    }
+}
@@ -6,4 +6,9 @@ pub struct UserBirthdaySettings {
     pub month: i16,
     pub year: Option<i16>,
     pub updatable: bool,
+    pub hide_age: bool,
+    pub hide_from_listings: bool,
+    pub opt_out_announcements: bool,
+    /// The guilds the birthday is not announced in.
+    pub opted_out_guild_ids: Vec<String>,
 }
@@ -1,3 +1,4 @@
 pub mod dates;
 pub mod string;
+pub mod template;
 pub mod timezone;
@@ -1,24 +1,42 @@
-use chrono::{Datelike, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
 
-pub fn upcoming_date(day: u32, month: u32) -> Option<NaiveDate> {
-    let now = Utc::now();
-
-    let (day, month) = if !is_leap_year(now.year()) && day == 29 && month == 2 {
-        (1, 3)
-    } else {
-        (day, month)
-    };
-
-    let this_years_date = NaiveDate::from_ymd_opt(now.year(), month, day)?;
-    if this_years_date > now.date_naive() {
-        Some(this_years_date)
+/// The date a birthday falls on in the given year, leap day birthdays move to March 1st in common years.
+pub fn birthday_in_year(day: u32, month: u32, year: i32) -> Option<NaiveDate> {
+    if !is_leap_year(year) && day == 29 && month == 2 {
+        NaiveDate::from_ymd_opt(year, 3, 1)
     } else {
-        NaiveDate::from_ymd_opt(now.year() + 1, month, day)
+        NaiveDate::from_ymd_opt(year, month, day)
     }
 }
 
-pub fn upcoming_date_time(day: u32, month: u32) -> Option<chrono::DateTime<Utc>> {
-    upcoming_date(day, month).map(|date| date.and_hms_opt(9, 0, 0).map(|date| date.and_utc()))?
+/// The next time the birthday reaches the given local hour in the timezone, strictly after `now`.
+pub fn upcoming_birthday(
+    day: u32,
+    month: u32,
+    hour: u32,
+    tz: Tz,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let local_year = now.with_timezone(&tz).year();
+    (local_year..=local_year + 1)
+        .filter_map(|year| local_date_time(birthday_in_year(day, month, year)?, hour, tz))
+        .find(|date_time| *date_time > now)
+}
+
+/// The moment a local date and hour happen in the timezone, skipping ahead if it falls into a DST gap.
+pub fn local_date_time(date: NaiveDate, hour: u32, tz: Tz) -> Option<DateTime<Utc>> {
+    let local = date.and_hms_opt(hour, 0, 0)?;
+    (0..3)
+        .find_map(|hours| {
+            tz.from_local_datetime(&(local + chrono::Duration::hours(hours)))
+                .earliest()
+        })
+        .map(|date_time| date_time.with_timezone(&Utc))
+}
+
+pub fn end_of_local_day(date: NaiveDate, tz: Tz) -> Option<DateTime<Utc>> {
+    local_date_time(date.succ_opt()?, 0, tz)
 }
 
 pub fn year_month_min_max_day(year: i32, month: u32) -> Option<(u32, u32)> {
@@ -43,3 +61,86 @@ pub fn iter_dates(start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = Naiv
     let days = (end - start).num_days() + 1;
     (0..days).map(move |i| start + chrono::Duration::days(i))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_leap_day_birthdays_move_to_march_in_common_years() {
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day);
+        assert_eq!(birthday_in_year(29, 2, 2024), date(2024, 2, 29));
+        assert_eq!(birthday_in_year(29, 2, 2025), date(2025, 3, 1));
+        assert_eq!(birthday_in_year(29, 2, 2100), date(2100, 3, 1));
+        assert_eq!(birthday_in_year(29, 2, 2000), date(2000, 2, 29));
+        assert_eq!(birthday_in_year(31, 4, 2025), None);
+    }
+
+    #[test]
+    fn test_upcoming_leap_day_birthday() {
+        let tz = chrono_tz::UTC;
+        assert_eq!(
+            upcoming_birthday(29, 2, 9, tz, utc(2027, 6, 1, 0, 0)),
+            Some(utc(2028, 2, 29, 9, 0))
+        );
+        assert_eq!(
+            upcoming_birthday(29, 2, 9, tz, utc(2028, 2, 29, 9, 0)),
+            Some(utc(2029, 3, 1, 9, 0))
+        );
+    }
+
+    #[test]
+    fn test_upcoming_birthday_uses_the_local_hour() {
+        let sydney = chrono_tz::Australia::Sydney;
+        let los_angeles = chrono_tz::America::Los_Angeles;
+
+        // 09:00 on June 1st in Sydney (UTC+10) is still May 31st in UTC.
+        assert_eq!(
+            upcoming_birthday(1, 6, 9, sydney, utc(2025, 5, 1, 0, 0)),
+            Some(utc(2025, 5, 31, 23, 0))
+        );
+        // 09:00 on June 1st in Los Angeles (UTC-7) is in the afternoon in UTC.
+        assert_eq!(
+            upcoming_birthday(1, 6, 9, los_angeles, utc(2025, 5, 1, 0, 0)),
+            Some(utc(2025, 6, 1, 16, 0))
+        );
+        // Already celebrated locally, even though it is still June 1st in UTC.
+        assert_eq!(
+            upcoming_birthday(1, 6, 9, sydney, utc(2025, 6, 1, 12, 0)),
+            Some(utc(2026, 5, 31, 23, 0))
+        );
+    }
+
+    #[test]
+    fn test_upcoming_birthday_around_the_new_year() {
+        let honolulu = chrono_tz::Pacific::Honolulu;
+        // Still December 31st in Honolulu (UTC-10).
+        assert_eq!(
+            upcoming_birthday(31, 12, 9, honolulu, utc(2026, 1, 1, 5, 0)),
+            Some(utc(2026, 12, 31, 19, 0))
+        );
+        assert_eq!(
+            upcoming_birthday(1, 1, 9, honolulu, utc(2026, 1, 1, 5, 0)),
+            Some(utc(2026, 1, 1, 19, 0))
+        );
+    }
+
+    #[test]
+    fn test_local_date_time_skips_dst_gaps() {
+        let havana = chrono_tz::America::Havana;
+        let date = NaiveDate::from_ymd_opt(2025, 3, 9).unwrap();
+        assert_eq!(
+            local_date_time(date, 0, havana),
+            Some(utc(2025, 3, 9, 5, 0))
+        );
+        assert_eq!(
+            end_of_local_day(date, chrono_tz::UTC),
+            Some(utc(2025, 3, 10, 0, 0))
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use crate::error::{CoreError, CoreResult};
+
+/// A parsed text template with `{name}` placeholders, `{{` and `}}` escape literal braces.
+///
+/// Only the placeholders the template was parsed against can appear, values are inserted as-is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    parts: Vec<TemplatePart>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplatePart {
+    Text(String),
+    Placeholder(&'static str),
+}
+
+impl Template {
+    pub fn parse(source: &str, placeholders: &[&'static str]) -> CoreResult<Self> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut chars = source.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) if c.is_ascii_alphanumeric() || c == '_' => name.push(c),
+                            _ => {
+                                return Err(CoreError::invalid_template(
+                                    "Unclosed placeholder, use `{{` for a literal `{`.",
+                                ));
+                            }
+                        }
+                    }
+
+                    let Some(placeholder) = placeholders.iter().find(|p| **p == name) else {
+                        return Err(CoreError::invalid_template(format!(
+                            "Unknown placeholder `{{{name}}}`."
+                        )));
+                    };
+
+                    if !text.is_empty() {
+                        parts.push(TemplatePart::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(TemplatePart::Placeholder(placeholder));
+                }
+                '}' => {
+                    return Err(CoreError::invalid_template(
+                        "Unopened `}`, use `}}` for a literal `}`.",
+                    ));
+                }
+                c => text.push(c),
+            }
+        }
+
+        if !text.is_empty() {
+            parts.push(TemplatePart::Text(text));
+        }
+
+        Ok(Self { parts })
+    }
+
+    pub fn uses(&self, placeholder: &str) -> bool {
+        self.parts
+            .iter()
+            .any(|part| matches!(part, TemplatePart::Placeholder(name) if *name == placeholder))
+    }
+
+    /// Placeholders resolving to nothing also swallow one adjacent space, so
+    /// `Happy {belated} birthday` doesn't end up with a double space.
+    pub fn render(&self, resolve: impl Fn(&str) -> String) -> String {
+        let mut output = String::new();
+        let mut skip_space = false;
+
+        for part in &self.parts {
+            match part {
+                TemplatePart::Text(text) => {
+                    let text = match text.strip_prefix(' ') {
+                        Some(stripped) if skip_space => stripped,
+                        _ => text,
+                    };
+                    output.push_str(text);
+                    skip_space = false;
+                }
+                TemplatePart::Placeholder(name) => {
+                    let value = resolve(name);
+                    if value.is_empty() {
+                        skip_space = output.is_empty() || output.ends_with(' ');
+                    } else {
+                        output.push_str(&value);
+                        skip_space = false;
+                    }
+                }
+            }
+        }
+
+        output.trim().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLACEHOLDERS: &[&str] = &["user", "belated"];
+
+    fn render(source: &str, belated: bool) -> String {
+        Template::parse(source, PLACEHOLDERS)
+            .unwrap()
+            .render(|name| match name {
+                "user" => "Alice".to_string(),
+                "belated" if belated => "belated".to_string(),
+                _ => String::new(),
+            })
+    }
+
+    #[test]
+    fn test_renders_placeholders() {
+        assert_eq!(
+            render("Happy {belated} birthday {user}!", true),
+            "Happy belated birthday Alice!"
+        );
+        assert_eq!(render("{user}{user}", false), "AliceAlice");
+    }
+
+    #[test]
+    fn test_empty_values_swallow_a_space() {
+        assert_eq!(
+            render("Happy {belated} birthday {user}!", false),
+            "Happy birthday Alice!"
+        );
+        assert_eq!(render("{belated} birthday", false), "birthday");
+    }
+
+    #[test]
+    fn test_escapes_braces() {
+        assert_eq!(render("{{user}} is {user}}}", false), "{user} is Alice}");
+    }
+
+    #[test]
+    fn test_rejects_invalid_templates() {
+        assert!(Template::parse("Hi {age}", PLACEHOLDERS).is_err());
+        assert!(Template::parse("Hi {user", PLACEHOLDERS).is_err());
+        assert!(Template::parse("Hi {us er}", PLACEHOLDERS).is_err());
+        assert!(Template::parse("Hi user}", PLACEHOLDERS).is_err());
+    }
+
+    #[test]
+    fn test_tracks_used_placeholders() {
+        let template = Template::parse("Hi {user}", PLACEHOLDERS).unwrap();
+        assert!(template.uses("user"));
+        assert!(!template.uses("belated"));
+    }
+}
@@ -10,6 +10,10 @@ export interface UserBirthdaySettings {
   month: number
   year?: number
   updatable: boolean
+  hide_age: boolean
+  hide_from_listings: boolean
+  opt_out_announcements: boolean
+  opted_out_guild_ids: string[]
 }
 
 export const useSettingsStore = defineStore('settings', () => {